@@ -0,0 +1,154 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Attribute, Error, Expr, GenericArgument, LitInt, PathArguments, Type};
+
+/// Max tag number that fits into the one-byte tag.
+const MAX_TAG_NUMBER: u8 = 30;
+
+#[derive(Default)]
+pub struct ContainerAttributes {
+    pub set: bool,
+}
+
+impl ContainerAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("asn1")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("set") {
+                    container.set = true;
+                } else {
+                    return Err(meta.error("unsupported container attribute: expected `set`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(container)
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum Tagging {
+    #[default]
+    Universal,
+    Explicit(u8),
+    Implicit(u8),
+}
+
+impl Tagging {
+    pub fn tokens(self) -> TokenStream {
+        match self {
+            Tagging::Universal => quote! { ::asn1_parser::typed::Tagging::Universal },
+            Tagging::Explicit(number) => quote! { ::asn1_parser::typed::Tagging::Explicit(#number) },
+            Tagging::Implicit(number) => quote! { ::asn1_parser::typed::Tagging::Implicit(#number) },
+        }
+    }
+}
+
+#[derive(Default)]
+pub enum Presence {
+    #[default]
+    Required,
+    Optional,
+    /// `None` means `Default::default()`.
+    Default(Option<Expr>),
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum Collection {
+    #[default]
+    Single,
+    SequenceOf,
+    SetOf,
+}
+
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub tagging: Tagging,
+    pub presence: Presence,
+    pub collection: Collection,
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut field = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("asn1")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("explicit") || meta.path.is_ident("implicit") {
+                    if !matches!(field.tagging, Tagging::Universal) {
+                        return Err(meta.error("the field can have only one `explicit` or `implicit` tag"));
+                    }
+
+                    let number: LitInt = meta.value()?.parse()?;
+                    let number = number.base10_parse::<u8>()?;
+                    if number > MAX_TAG_NUMBER {
+                        return Err(meta.error("tag number must be in range 0..=30"));
+                    }
+
+                    field.tagging = if meta.path.is_ident("explicit") {
+                        Tagging::Explicit(number)
+                    } else {
+                        Tagging::Implicit(number)
+                    };
+                } else if meta.path.is_ident("optional") || meta.path.is_ident("default") {
+                    if !matches!(field.presence, Presence::Required) {
+                        return Err(meta.error("the field can not be `optional` and `default` at the same time"));
+                    }
+
+                    field.presence = if meta.path.is_ident("optional") {
+                        Presence::Optional
+                    } else if meta.input.peek(syn::Token![=]) {
+                        Presence::Default(Some(meta.value()?.parse()?))
+                    } else {
+                        Presence::Default(None)
+                    };
+                } else if meta.path.is_ident("sequence_of") || meta.path.is_ident("set_of") {
+                    if field.collection != Collection::Single {
+                        return Err(meta.error("the field can not be `sequence_of` and `set_of` at the same time"));
+                    }
+
+                    field.collection = if meta.path.is_ident("sequence_of") {
+                        Collection::SequenceOf
+                    } else {
+                        Collection::SetOf
+                    };
+                } else {
+                    return Err(meta.error(
+                        "unsupported field attribute: expected `explicit`, `implicit`, `optional`, `default`, `sequence_of`, or `set_of`",
+                    ));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(field)
+    }
+}
+
+/// Returns `T` from the `Wrapper<T>` type.
+pub fn generic_argument<'ty>(ty: &'ty Type, wrapper: &str, attribute: &str) -> syn::Result<&'ty Type> {
+    let error = || Error::new(ty.span(), format!("`{attribute}` field must have `{wrapper}<T>` type"));
+
+    let Type::Path(path) = ty else {
+        return Err(error());
+    };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    if segment.ident != wrapper {
+        return Err(error());
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return Err(error());
+    };
+
+    match arguments.args.first() {
+        Some(GenericArgument::Type(ty)) if arguments.args.len() == 1 => Ok(ty),
+        _ => Err(error()),
+    }
+}
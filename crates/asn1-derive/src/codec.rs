@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Type;
+
+use crate::attributes::{Collection, FieldAttributes, Presence, Tagging, generic_argument};
+
+/// Generates the encoding and decoding code for one field (or `CHOICE` alternative) value.
+pub struct FieldCodec<'ty> {
+    tagging: Tagging,
+    collection: Collection,
+    /// The field value type without `Option` and `Vec` wrappers.
+    ty: &'ty Type,
+}
+
+impl<'ty> FieldCodec<'ty> {
+    pub fn new(ty: &'ty Type, attributes: &FieldAttributes) -> syn::Result<Self> {
+        let ty = match attributes.presence {
+            Presence::Optional => generic_argument(ty, "Option", "optional")?,
+            Presence::Required | Presence::Default(_) => ty,
+        };
+
+        let ty = match attributes.collection {
+            Collection::Single => ty,
+            Collection::SequenceOf => generic_argument(ty, "Vec", "sequence_of")?,
+            Collection::SetOf => generic_argument(ty, "Vec", "set_of")?,
+        };
+
+        Ok(Self {
+            tagging: attributes.tagging,
+            collection: attributes.collection,
+            ty,
+        })
+    }
+
+    /// `Option<Tag>` expression: the tag of the untagged value.
+    fn universal_tag(&self) -> TokenStream {
+        let ty = self.ty;
+
+        match self.collection {
+            Collection::Single => quote! { <#ty as ::asn1_parser::Asn1Decode>::ASN1_TAG },
+            Collection::SequenceOf => quote! { ::core::option::Option::Some(::asn1_parser::Sequence::TAG) },
+            Collection::SetOf => quote! { ::core::option::Option::Some(::asn1_parser::Set::TAG) },
+        }
+    }
+
+    /// Compile-time check that the implicitly tagged value is not a `CHOICE` or `ANY` (X.680 31.2.7).
+    ///
+    /// `is_choice` is the `bool` expression that is `true` for such types.
+    fn implicit_check(&self, is_choice: TokenStream) -> TokenStream {
+        match (self.tagging, self.collection) {
+            (Tagging::Implicit(_), Collection::Single) => quote! {
+                const { ::core::assert!(!(#is_choice), "CHOICE and ANY types can not be implicitly tagged") };
+            },
+            _ => quote! {},
+        }
+    }
+
+    /// `Fn(Tag) -> bool` closure that checks the node tag.
+    pub fn compare_tags(&self) -> TokenStream {
+        let tagging = self.tagging.tokens();
+        let ty = self.ty;
+        let universal_tag = self.universal_tag();
+
+        let universal = match self.collection {
+            Collection::Single => quote! { <#ty as ::asn1_parser::Asn1Decode>::compare_tags },
+            Collection::SequenceOf => quote! { |tag| tag == ::asn1_parser::Sequence::TAG },
+            Collection::SetOf => quote! { |tag| tag == ::asn1_parser::Set::TAG },
+        };
+
+        quote! { |tag: ::asn1_parser::Tag| #tagging.compare_tags(tag, #universal_tag, #universal) }
+    }
+
+    /// Expression of the `Asn1Result<T>` type that decodes the `node: &Asn1`.
+    pub fn decode(&self, node: TokenStream) -> TokenStream {
+        let tagging = self.tagging.tokens();
+        let ty = self.ty;
+        let universal_tag = self.universal_tag();
+        let check = self.implicit_check(quote! { #universal_tag.is_none() });
+
+        let decode = match self.collection {
+            Collection::Single => quote! { <#ty as ::asn1_parser::Asn1Decode>::decode_asn1 },
+            Collection::SequenceOf => quote! { ::asn1_parser::typed::decode_sequence_of::<#ty> },
+            Collection::SetOf => quote! { ::asn1_parser::typed::decode_set_of::<#ty> },
+        };
+
+        quote! {
+            {
+                #check
+                #tagging.unwrap(#node, #universal_tag).and_then(|node| #decode(&node))
+            }
+        }
+    }
+
+    /// Expression of the `Asn1Result<Asn1>` type that encodes the `value: &T`.
+    pub fn encode(&self, value: TokenStream) -> TokenStream {
+        let tagging = self.tagging.tokens();
+        let ty = self.ty;
+
+        let encode = match self.collection {
+            Collection::Single => quote! { <#ty as ::asn1_parser::Asn1Encode>::encode_asn1 },
+            Collection::SequenceOf => quote! { ::asn1_parser::typed::encode_sequence_of::<#ty> },
+            Collection::SetOf => quote! { ::asn1_parser::typed::encode_set_of::<#ty> },
+        };
+
+        let check = self.implicit_check(quote! { <#ty as ::asn1_parser::Asn1Encode>::IS_CHOICE });
+
+        quote! {
+            {
+                #check
+                #encode(#value).and_then(|asn1| #tagging.wrap(asn1))
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, DataStruct, DeriveInput};
+
+use crate::attributes::{ContainerAttributes, FieldAttributes, Presence};
+use crate::codec::FieldCodec;
+use crate::{choice_alternative, named_fields};
+
+pub fn derive_struct(
+    input: &DeriveInput,
+    data: &DataStruct,
+    container: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (asn1_type, asn1_type_name, fields_decoder) = if container.set {
+        (quote! { Set }, "Expected SET", quote! { set })
+    } else {
+        (quote! { Sequence }, "Expected SEQUENCE", quote! { sequence })
+    };
+
+    let mut decode_fields = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in named_fields(data)? {
+        let ident = field.ident.as_ref().expect("named field");
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        let codec = FieldCodec::new(&field.ty, &attributes)?;
+
+        let location = format!("{name}::{ident}");
+        let compare_tags = codec.compare_tags();
+        let decode = codec.decode(quote! { node });
+
+        decode_fields.push(match &attributes.presence {
+            Presence::Required => quote! {
+                let #ident = __asn1_fields
+                    .next_required(#compare_tags)
+                    .and_then(|node| #decode)
+                    .map_err(|err| err.at(#location))?;
+            },
+            Presence::Optional => quote! {
+                let #ident = __asn1_fields
+                    .next_optional(#compare_tags)
+                    .map(|node| #decode)
+                    .transpose()
+                    .map_err(|err| err.at(#location))?;
+            },
+            Presence::Default(default) => {
+                let ty = &field.ty;
+                let default = default
+                    .as_ref()
+                    .map(|default| quote! { #default })
+                    .unwrap_or_else(|| quote! { <#ty as ::core::default::Default>::default() });
+
+                quote! {
+                    let #ident = match __asn1_fields.next_optional(#compare_tags) {
+                        ::core::option::Option::Some(node) => (#decode).map_err(|err| err.at(#location))?,
+                        ::core::option::Option::None => #default,
+                    };
+                }
+            }
+        });
+        field_names.push(ident);
+    }
+
+    let type_name = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics ::asn1_parser::Asn1Decode for #name #ty_generics #where_clause {
+            const ASN1_TAG: ::core::option::Option<::asn1_parser::Tag> =
+                ::core::option::Option::Some(::asn1_parser::#asn1_type::TAG);
+
+            fn decode_asn1(asn1: &::asn1_parser::Asn1) -> ::asn1_parser::Asn1Result<Self> {
+                let ::asn1_parser::Asn1Type::#asn1_type(__asn1_node) = asn1.inner_asn1() else {
+                    return ::core::result::Result::Err(::asn1_parser::Error::from(#asn1_type_name).at(#type_name));
+                };
+                let __asn1_node = __asn1_node.get();
+                let mut __asn1_fields = ::asn1_parser::typed::FieldsDecoder::#fields_decoder(__asn1_node.fields());
+
+                #(#decode_fields)*
+
+                __asn1_fields.finish().map_err(|err| err.at(#type_name))?;
+
+                ::core::result::Result::Ok(Self { #(#field_names),* })
+            }
+        }
+    })
+}
+
+pub fn derive_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut compare_alternatives = Vec::new();
+    let mut decode_alternatives = Vec::new();
+
+    for variant in &data.variants {
+        let (field, attributes) = choice_alternative(variant)?;
+        let codec = FieldCodec::new(&field.ty, &attributes)?;
+
+        let ident = &variant.ident;
+        let location = format!("{name}::{ident}");
+        let compare_tags = codec.compare_tags();
+        let decode = codec.decode(quote! { asn1 });
+
+        compare_alternatives.push(quote! { (#compare_tags)(tag) });
+        decode_alternatives.push(quote! {
+            if (#compare_tags)(::asn1_parser::Asn1Entity::tag(asn1)) {
+                return (#decode).map(Self::#ident).map_err(|err| err.at(#location));
+            }
+        });
+    }
+
+    let type_name = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics ::asn1_parser::Asn1Decode for #name #ty_generics #where_clause {
+            const ASN1_TAG: ::core::option::Option<::asn1_parser::Tag> = ::core::option::Option::None;
+
+            fn compare_tags(tag: ::asn1_parser::Tag) -> bool {
+                false #(|| #compare_alternatives)*
+            }
+
+            fn decode_asn1(asn1: &::asn1_parser::Asn1) -> ::asn1_parser::Asn1Result<Self> {
+                #(#decode_alternatives)*
+
+                ::core::result::Result::Err(
+                    ::asn1_parser::Error::from("No CHOICE alternative matches the tag").at(#type_name),
+                )
+            }
+        }
+    })
+}
@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, DataStruct, DeriveInput};
+
+use crate::attributes::{ContainerAttributes, FieldAttributes, Presence};
+use crate::codec::FieldCodec;
+use crate::{choice_alternative, named_fields};
+
+pub fn derive_struct(
+    input: &DeriveInput,
+    data: &DataStruct,
+    container: &ContainerAttributes,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut encode_fields = Vec::new();
+
+    for field in named_fields(data)? {
+        let ident = field.ident.as_ref().expect("named field");
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        let codec = FieldCodec::new(&field.ty, &attributes)?;
+
+        let location = format!("{name}::{ident}");
+        let encode = codec.encode(quote! { value });
+
+        encode_fields.push(match &attributes.presence {
+            Presence::Required => quote! {
+                {
+                    let value = &self.#ident;
+                    ::core::option::Option::Some((#encode).map_err(|err| err.at(#location))?)
+                }
+            },
+            Presence::Optional => quote! {
+                match &self.#ident {
+                    ::core::option::Option::Some(value) => {
+                        ::core::option::Option::Some((#encode).map_err(|err| err.at(#location))?)
+                    }
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            },
+            Presence::Default(default) => {
+                let ty = &field.ty;
+                let default = default
+                    .as_ref()
+                    .map(|default| quote! { #default })
+                    .unwrap_or_else(|| quote! { <#ty as ::core::default::Default>::default() });
+
+                // DER: the field value equal to the default one must be omitted.
+                quote! {
+                    {
+                        let value = &self.#ident;
+                        if *value == #default {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some((#encode).map_err(|err| err.at(#location))?)
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    let fields_amount = encode_fields.len();
+    let set = container.set;
+
+    Ok(quote! {
+        impl #impl_generics ::asn1_parser::Asn1Encode for #name #ty_generics #where_clause {
+            fn encode_asn1(&self) -> ::asn1_parser::Asn1Result<::asn1_parser::Asn1> {
+                let __asn1_fields: [::core::option::Option<::asn1_parser::Asn1>; #fields_amount] = [#(#encode_fields),*];
+
+                ::asn1_parser::typed::encode_fields(__asn1_fields, #set)
+            }
+        }
+    })
+}
+
+pub fn derive_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut encode_alternatives = Vec::new();
+
+    for variant in &data.variants {
+        let (field, attributes) = choice_alternative(variant)?;
+        let codec = FieldCodec::new(&field.ty, &attributes)?;
+
+        let ident = &variant.ident;
+        let location = format!("{name}::{ident}");
+        let encode = codec.encode(quote! { value });
+
+        encode_alternatives.push(quote! {
+            Self::#ident(value) => (#encode).map_err(|err| err.at(#location)),
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::asn1_parser::Asn1Encode for #name #ty_generics #where_clause {
+            const IS_CHOICE: bool = true;
+
+            fn encode_asn1(&self) -> ::asn1_parser::Asn1Result<::asn1_parser::Asn1> {
+                match self {
+                    #(#encode_alternatives)*
+                }
+            }
+        }
+    })
+}
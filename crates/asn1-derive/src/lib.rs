@@ -0,0 +1,115 @@
+//! Derive macros for the `asn1_parser::Asn1Decode` and `asn1_parser::Asn1Encode` traits.
+//!
+//! Structs with named fields are mapped onto the `SEQUENCE` (or `SET` with `#[asn1(set)]`).
+//! Enums are mapped onto the `CHOICE`: every variant must have exactly one unnamed field.
+//!
+//! Supported field (and `CHOICE` alternative) attributes:
+//!
+//! * `#[asn1(explicit = n)]`: `[n] EXPLICIT` tag.
+//! * `#[asn1(implicit = n)]`: `[n] IMPLICIT` tag.
+//! * `#[asn1(optional)]`: `OPTIONAL` field. The field type must be `Option<T>`.
+//! * `#[asn1(default)]` or `#[asn1(default = expr)]`: `DEFAULT` field. The field type must implement `PartialEq`.
+//! * `#[asn1(sequence_of)]` and `#[asn1(set_of)]`: `SEQUENCE OF T` and `SET OF T`. The field type must be `Vec<T>`.
+//!
+//! ```ignore
+//! #[derive(Asn1Decode, Asn1Encode)]
+//! struct EncryptedData {
+//!     #[asn1(implicit = 0)]
+//!     etype: Integer,
+//!     #[asn1(explicit = 1, optional)]
+//!     kvno: Option<Integer>,
+//!     #[asn1(explicit = 2)]
+//!     cipher: OctetString,
+//! }
+//! ```
+//!
+//! `CHOICE` and `ANY` types can not be implicitly tagged (X.680 31.2.7): their tag is needed to select the
+//! alternative. Such fields are rejected at compile time:
+//!
+//! ```compile_fail
+//! use asn1_parser::{Asn1, Asn1Decode};
+//!
+//! #[derive(asn1_derive::Asn1Decode)]
+//! struct Extension {
+//!     #[asn1(implicit = 0)]
+//!     value: Asn1,
+//! }
+//!
+//! Extension::from_der(b"\x30\x00").unwrap();
+//! ```
+
+mod attributes;
+mod codec;
+mod decode;
+mod encode;
+
+use proc_macro::TokenStream;
+use syn::spanned::Spanned;
+use syn::{Data, DataStruct, DeriveInput, Error, Field, Fields, Variant, parse_macro_input};
+
+use crate::attributes::{ContainerAttributes, FieldAttributes, Presence};
+
+#[proc_macro_derive(Asn1Decode, attributes(asn1))]
+pub fn derive_asn1_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input, decode::derive_struct, decode::derive_enum)
+}
+
+#[proc_macro_derive(Asn1Encode, attributes(asn1))]
+pub fn derive_asn1_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input, encode::derive_struct, encode::derive_enum)
+}
+
+type DeriveStruct = fn(&DeriveInput, &DataStruct, &ContainerAttributes) -> syn::Result<proc_macro2::TokenStream>;
+type DeriveEnum = fn(&DeriveInput, &syn::DataEnum) -> syn::Result<proc_macro2::TokenStream>;
+
+fn expand(input: &DeriveInput, derive_struct: DeriveStruct, derive_enum: DeriveEnum) -> TokenStream {
+    let result = ContainerAttributes::parse(&input.attrs).and_then(|container| match &input.data {
+        Data::Struct(data) => derive_struct(input, data, &container),
+        Data::Enum(data) if container.set => {
+            Err(Error::new(data.enum_token.span(), "`set` can be used only on structs"))
+        }
+        Data::Enum(data) => derive_enum(input, data),
+        Data::Union(data) => Err(Error::new(data.union_token.span(), "unions are not supported")),
+    });
+
+    result.unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn named_fields(data: &DataStruct) -> syn::Result<impl Iterator<Item = &Field>> {
+    match &data.fields {
+        Fields::Named(fields) => Ok(fields.named.iter()),
+        fields => Err(Error::new(
+            fields.span(),
+            "only structs with named fields can be mapped onto SEQUENCE or SET",
+        )),
+    }
+}
+
+/// Returns the `CHOICE` alternative field and its attributes.
+///
+/// The attributes are specified on the variant itself: `#[asn1(explicit = 0)] Name(Utf8String)`.
+fn choice_alternative(variant: &Variant) -> syn::Result<(&Field, FieldAttributes)> {
+    let field = match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(Error::new(
+                variant.span(),
+                "CHOICE alternative must be a tuple variant with exactly one field",
+            ));
+        }
+    };
+
+    let attributes = FieldAttributes::parse(&variant.attrs)?;
+    if !matches!(attributes.presence, Presence::Required) {
+        return Err(Error::new(
+            variant.span(),
+            "CHOICE alternative can not be `optional` or `default`",
+        ));
+    }
+
+    Ok((field, attributes))
+}
@@ -0,0 +1,185 @@
+use asn1_parser::{
+    Asn1, Asn1Decode, Asn1Encode, Asn1Type, GeneralString, GeneralizedTime, IA5String, Integer, ObjectIdentifier,
+    OctetString, Utf8String,
+};
+
+#[derive(Debug, PartialEq, Asn1Decode, Asn1Encode)]
+struct EncryptedData {
+    #[asn1(explicit = 0)]
+    etype: Integer,
+    #[asn1(explicit = 1, optional)]
+    kvno: Option<Integer>,
+    #[asn1(explicit = 2)]
+    cipher: OctetString,
+}
+
+#[derive(Debug, PartialEq, Asn1Decode, Asn1Encode)]
+struct PrincipalName {
+    #[asn1(explicit = 0)]
+    name_type: Integer,
+    #[asn1(explicit = 1, sequence_of)]
+    name_string: Vec<GeneralString>,
+}
+
+#[derive(Debug, PartialEq, Asn1Decode, Asn1Encode)]
+enum GeneralName {
+    #[asn1(implicit = 1)]
+    Rfc822(IA5String),
+    #[asn1(implicit = 2)]
+    Dns(IA5String),
+    #[asn1(explicit = 4)]
+    Directory(Asn1),
+}
+
+#[derive(Debug, PartialEq, Asn1Decode, Asn1Encode)]
+struct Extension {
+    id: ObjectIdentifier,
+    #[asn1(default)]
+    critical: bool,
+    value: OctetString,
+}
+
+#[derive(Debug, PartialEq, Asn1Decode, Asn1Encode)]
+#[asn1(set)]
+struct Attributes {
+    #[asn1(implicit = 1)]
+    time: GeneralizedTime,
+    #[asn1(implicit = 0)]
+    name: Utf8String,
+    #[asn1(explicit = 2, set_of)]
+    names: Vec<GeneralName>,
+}
+
+#[test]
+fn encrypted_data() {
+    let raw = [
+        48, 20, 160, 3, 2, 1, 18, 161, 3, 2, 1, 2, 162, 8, 4, 6, 1, 2, 3, 4, 5, 6,
+    ];
+
+    let encrypted_data = EncryptedData::from_der(&raw).unwrap();
+
+    assert_eq!(encrypted_data.etype, Integer::from(vec![18]));
+    assert_eq!(encrypted_data.kvno, Some(Integer::from(vec![2])));
+    assert_eq!(encrypted_data.cipher.octets(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(encrypted_data.to_der().unwrap(), raw);
+}
+
+#[test]
+fn optional_field_absent() {
+    let raw = [48, 15, 160, 3, 2, 1, 18, 162, 8, 4, 6, 1, 2, 3, 4, 5, 6];
+
+    let encrypted_data = EncryptedData::from_der(&raw).unwrap();
+
+    assert_eq!(encrypted_data.kvno, None);
+    assert_eq!(encrypted_data.to_der().unwrap(), raw);
+}
+
+#[test]
+fn sequence_of() {
+    let principal_name = PrincipalName {
+        name_type: Integer::from(vec![2]),
+        name_string: vec![GeneralString::from("krbtgt"), GeneralString::from("EXAMPLE.COM")],
+    };
+
+    let raw = principal_name.to_der().unwrap();
+
+    assert_eq!(
+        raw,
+        [
+            48, 30, 160, 3, 2, 1, 2, 161, 23, 48, 21, 27, 6, 107, 114, 98, 116, 103, 116, 27, 11, 69, 88, 65, 77, 80,
+            76, 69, 46, 67, 79, 77
+        ]
+    );
+    assert_eq!(PrincipalName::from_der(&raw).unwrap(), principal_name);
+}
+
+#[test]
+fn choice() {
+    let name = GeneralName::Dns(IA5String::from("example.com"));
+
+    let raw = name.to_der().unwrap();
+
+    assert_eq!(raw[0], 0x82);
+    assert_eq!(GeneralName::from_der(&raw).unwrap(), name);
+
+    let directory_name = GeneralName::Directory(Utf8String::from("Name").encode_asn1().unwrap());
+    let raw = directory_name.to_der().unwrap();
+
+    assert_eq!(raw, [164, 6, 12, 4, 78, 97, 109, 101]);
+    assert!(matches!(
+        GeneralName::from_der(&raw).unwrap(),
+        GeneralName::Directory(asn1) if matches!(asn1.inner_asn1(), Asn1Type::Utf8String(_))
+    ));
+}
+
+#[test]
+fn default_field() {
+    let extension = Extension {
+        id: ObjectIdentifier::new_unchecked("2.5.29.19"),
+        critical: false,
+        value: OctetString::new(vec![48, 0]),
+    };
+
+    let raw = extension.to_der().unwrap();
+
+    // DER: the default value is omitted.
+    assert_eq!(raw, [48, 9, 6, 3, 85, 29, 19, 4, 2, 48, 0]);
+
+    let decoded = Extension::from_der(&raw).unwrap();
+    assert_eq!(decoded.id, extension.id);
+    assert!(!decoded.critical);
+    assert_eq!(decoded.value.octets(), extension.value.octets());
+
+    let raw = [48, 12, 6, 3, 85, 29, 19, 1, 1, 255, 4, 2, 48, 0];
+    assert!(Extension::from_der(&raw).unwrap().critical);
+}
+
+#[test]
+fn set_with_implicit_fields() {
+    let attributes = Attributes {
+        time: GeneralizedTime::from_der(b"\x18\x0f20240101120000Z").unwrap(),
+        name: Utf8String::from("TbT"),
+        names: vec![
+            GeneralName::Rfc822(IA5String::from("a@b.c")),
+            GeneralName::Dns(IA5String::from("b.c")),
+        ],
+    };
+
+    let raw = attributes.to_der().unwrap();
+
+    // DER: SET fields are sorted by tags.
+    assert_eq!(&raw[2..7], &[0x80, 3, b'T', b'b', b'T']);
+    assert_eq!(Attributes::from_der(&raw).unwrap(), attributes);
+}
+
+#[test]
+fn implicit_tag_keeps_constructed_bit() {
+    let attributes = Attributes {
+        time: GeneralizedTime::from_der(b"\x18\x0f20240101120000Z").unwrap(),
+        name: Utf8String::from("TbT"),
+        names: Vec::new(),
+    };
+
+    let mut raw = attributes.to_der().unwrap();
+    // The primitive Utf8String field must not match the constructed [0] tag.
+    raw[2] = 0xa0;
+
+    assert!(Attributes::from_der(&raw).is_err());
+}
+
+#[test]
+fn error_location() {
+    let raw = [
+        48, 21, 160, 3, 2, 1, 2, 161, 14, 48, 12, 27, 6, 107, 114, 98, 116, 103, 116, 2, 2, 1, 2,
+    ];
+
+    let error = PrincipalName::from_der(&raw).unwrap_err();
+
+    assert_eq!(error.to_string(), "PrincipalName::name_string: Expected GeneralString");
+
+    let raw = [48, 8, 160, 3, 2, 1, 18, 4, 1, 0];
+
+    let error = EncryptedData::from_der(&raw).unwrap_err();
+
+    assert_eq!(error.to_string(), "EncryptedData::cipher: Unexpected field tag");
+}
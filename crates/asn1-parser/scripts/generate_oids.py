@@ -0,0 +1,177 @@
+#!/usr/bin/env python3
+"""Generates `src/oid_registry/entries.rs` from the public object identifier datasets.
+
+Sources (the first entry for the same object identifier wins):
+
+1. The existing `entries.rs`: hand-written names and descriptions are kept across regenerations.
+2. Rusticata `oid-registry` database (`assets/oid_db.txt`): short names and descriptions.
+3. OpenSSL `include/openssl/obj_mac.h`: short names and long names (`openssl-src` crate).
+4. Botan `src/build-data/oids.txt` (`botan-src` crate).
+5. RustCrypto `const-oid` database (`src/db/generated.rs`): names collected from RFCs, FIPS, and STB standards.
+6. Windows SDK `szOID_*`, `SPC_*_OBJID`, and `CAT_*_OBJID` constants (`windows-sys` crate): Microsoft identifiers.
+
+Usage:
+
+    python3 scripts/generate_oids.py <oid_db.txt> <obj_mac.h> <oids.txt> <const-oid generated.rs> <windows-sys src/Windows dir>
+    rustfmt --edition 2024 src/oid_registry/entries.rs
+"""
+
+import pathlib
+import re
+import sys
+
+ENTRIES = pathlib.Path(__file__).resolve().parent.parent / 'src' / 'oid_registry' / 'entries.rs'
+
+HEADER = '''//! Built-in object identifiers sorted by their dotted representation.
+//!
+//! Generated by `scripts/generate_oids.py` from the `oid-registry`, OpenSSL, Botan, and `const-oid` databases,
+//! and the Windows SDK object identifiers on top of the hand-written entries.
+
+use super::OidInfo;
+
+pub(super) static OIDS: &[OidInfo<'static>] = &[
+'''
+
+FOOTER = '];\n'
+
+
+def is_valid(oid):
+    arcs = oid.split('.')
+    if len(arcs) < 2 or not all(arc.isdigit() for arc in arcs):
+        return False
+    # The `oid` crate limits the second arc to 39 and stores arcs as `usize` (32 bits on wasm).
+    return int(arcs[0]) <= 2 and int(arcs[1]) < 40 and all(int(arc) < 2**32 for arc in arcs)
+
+
+def existing_entries():
+    source = ENTRIES.read_text()
+    pattern = re.compile(r'OidInfo::new\(\s*"([^"]*)",\s*"([^"]*)",\s*"([^"]*)",?\s*\)')
+    return [match.groups() for match in pattern.finditer(source)]
+
+
+def oid_registry_entries(oid_db):
+    entries = []
+    for line in pathlib.Path(oid_db).read_text().splitlines():
+        fields = line.split('\t')
+        if line.startswith('#') or len(fields) != 5:
+            continue
+        _feature, _constant, oid, name, description = fields
+        entries.append((oid, name, description))
+
+    return entries
+
+
+def openssl_entries(obj_mac):
+    defines = {}
+    for line in pathlib.Path(obj_mac).read_text().splitlines():
+        match = re.match(r'#define\s+(SN|LN|OBJ)_(\w+)\s+(.+?)\s*$', line)
+        if match:
+            defines[(match.group(1), match.group(2))] = match.group(3)
+
+    def resolve(value):
+        arcs = []
+        for part in value.split(','):
+            part = part.strip()
+            if part.startswith('OBJ_'):
+                arcs.extend(resolve(defines[('OBJ', part[4:])]))
+            else:
+                arcs.append(part.rstrip('L'))
+        return arcs
+
+    entries = []
+    for (kind, ident), value in defines.items():
+        if kind != 'OBJ':
+            continue
+        short_name = defines.get(('SN', ident), '').strip('"')
+        long_name = defines.get(('LN', ident), '').strip('"')
+        name = short_name or long_name
+        if not name:
+            continue
+        entries.append(('.'.join(resolve(value)), name, long_name or short_name))
+
+    return entries
+
+
+def botan_entries(oids_txt):
+    entries = []
+    section = None
+    for line in pathlib.Path(oids_txt).read_text().splitlines():
+        line = line.strip()
+        if line.startswith('['):
+            section = line.strip('[]')
+        elif line and not line.startswith('#'):
+            oid, name = (part.strip() for part in line.split('=', 1))
+            entries.append((oid, name, f'{name} ({section})'))
+
+    return entries
+
+
+def standard(module):
+    if module.startswith('rfc'):
+        return f'RFC {module[3:]}'
+    if module.startswith('fips'):
+        return f'FIPS {module[4:]}'
+    if module == 'tcgtpm':
+        return 'TCG TPM'
+    # bake, bash, belt, bign, bpki, brng, btok.
+    return f'STB 34.101 ({module})'
+
+
+def const_oid_entries(generated):
+    source = pathlib.Path(generated).read_text()
+
+    oids = {}
+    for module, body in re.findall(r'^pub mod (\w+) \{(.*?)^\}', source, re.MULTILINE | re.DOTALL):
+        for ident, oid in re.findall(r'pub const (\w+): crate::ObjectIdentifier =\s*crate::ObjectIdentifier::new_unwrap\("([0-9.]+)"\)', body):
+            oids[(module, ident)] = oid
+
+    entries = []
+    for module, ident, name in re.findall(r'\(&(\w+)::(\w+), "([^"]+)"\)', source):
+        entries.append((oids[(module, ident)], name, f'Defined in {standard(module)}'))
+
+    return entries
+
+
+def windows_entries(windows_dir):
+    pattern = re.compile(r'pub const (\w+): windows_sys::core::PCSTR = windows_sys::core::s!\("([0-9]+(?:\.[0-9]+)+)"\)')
+
+    entries = []
+    for path in sorted(pathlib.Path(windows_dir).rglob('*.rs')):
+        for name, oid in pattern.findall(path.read_text()):
+            entries.append((oid, name, 'Windows SDK object identifier'))
+
+    return sorted(entries, key=lambda entry: entry[1])
+
+
+def main():
+    oid_db, obj_mac, oids_txt, generated, windows_dir = sys.argv[1:]
+
+    oids = {}
+    names = set()
+    for oid, name, description in [
+        *existing_entries(),
+        *oid_registry_entries(oid_db),
+        *openssl_entries(obj_mac),
+        *botan_entries(oids_txt),
+        *const_oid_entries(generated),
+        *windows_entries(windows_dir),
+    ]:
+        # Names must stay unique for the lookup by name.
+        if oid in oids or name.lower() in names or not is_valid(oid) or '"' in name + description:
+            continue
+
+        oids[oid] = (name, description)
+        names.add(name.lower())
+
+    with ENTRIES.open('w') as entries:
+        entries.write(HEADER)
+        for oid in sorted(oids):
+            name, description = oids[oid]
+            entries.write(f'    OidInfo::new("{oid}", "{name}", "{description}"),\n')
+        entries.write(FOOTER)
+
+    print(f'{len(oids)} object identifiers', file=sys.stderr)
+
+
+if __name__ == '__main__':
+    main()
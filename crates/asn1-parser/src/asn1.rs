@@ -1,3 +1,4 @@
+use core::cell::Cell;
 use core::ops::Range;
 
 use crate::reader::Reader;
@@ -160,6 +161,45 @@ impl Asn1Encoder for Asn1Type {
     }
 }
 
+impl Asn1Type {
+    /// Returns `true` if the node value was modified (see [Mutable::is_modified]).
+    ///
+    /// Modifications of the nested nodes are not taken into account.
+    pub fn is_modified(&self) -> bool {
+        self.modified_flag().get()
+    }
+
+    pub(crate) fn clear_modified(&self) {
+        self.modified_flag().set(false)
+    }
+
+    fn modified_flag(&self) -> &Cell<bool> {
+        match self {
+            Asn1Type::Sequence(sequence) => sequence.modified_flag(),
+            Asn1Type::Set(set) => set.modified_flag(),
+            Asn1Type::OctetString(octet_string) => octet_string.modified_flag(),
+            Asn1Type::Utf8String(utf8_string) => utf8_string.modified_flag(),
+            Asn1Type::BitString(bit_string) => bit_string.modified_flag(),
+            Asn1Type::BmpString(bmp_string) => bmp_string.modified_flag(),
+            Asn1Type::IA5String(ia5_string) => ia5_string.modified_flag(),
+            Asn1Type::PrintableString(printable_string) => printable_string.modified_flag(),
+            Asn1Type::GeneralString(general_string) => general_string.modified_flag(),
+            Asn1Type::NumericString(numeric_string) => numeric_string.modified_flag(),
+            Asn1Type::VisibleString(visible_string) => visible_string.modified_flag(),
+            Asn1Type::UtcTime(utc_time) => utc_time.modified_flag(),
+            Asn1Type::GeneralizedTime(generalized_time) => generalized_time.modified_flag(),
+            Asn1Type::Bool(boolean) => boolean.modified_flag(),
+            Asn1Type::Null(null) => null.modified_flag(),
+            Asn1Type::Integer(integer) => integer.modified_flag(),
+            Asn1Type::Enumerated(enumerated) => enumerated.modified_flag(),
+            Asn1Type::ObjectIdentifier(object_identifier) => object_identifier.modified_flag(),
+            Asn1Type::ExplicitTag(explicit_tag) => explicit_tag.modified_flag(),
+            Asn1Type::ImplicitTag(implicit_tag) => implicit_tag.modified_flag(),
+            Asn1Type::ApplicationTag(application_tag) => application_tag.modified_flag(),
+        }
+    }
+}
+
 impl MetaInfo for Asn1Type {
     fn clear_meta(&mut self) {
         match self {
@@ -0,0 +1,189 @@
+//! Helpers for constructing asn1 trees in code.
+//!
+//! Every function creates a single asn1 node without metadata. The [build] function (or the [asn1!](crate::asn1)
+//! macro) encodes the tree and decodes it back, so the resulting tree has valid metadata and node ids.
+//!
+//! ```
+//! use asn1_parser::asn1;
+//!
+//! let tree = asn1! {
+//!     seq {
+//!         int(5),
+//!         explicit(0, utf8("x")),
+//!         oid("1.2.3"),
+//!     }
+//! }
+//! .unwrap();
+//! ```
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    ApplicationTag, Asn1, Asn1Decoder, Asn1Encoder, Asn1Result, Asn1Type, BitString, BmpString, Bool, Enumerated,
+    ExplicitTag, GeneralString, GeneralizedTime, IA5String, ImplicitTag, Integer, Mutable, Null, NumericString,
+    ObjectIdentifier, OctetString, PrintableString, Sequence, Set, UtcTime, Utf8String, VisibleString,
+};
+
+/// Encodes the tree and decodes it back to fill in the metadata and node ids.
+pub fn build(tree: Asn1) -> Asn1Result<Asn1> {
+    Asn1::decode_buff(&encode(&tree)?)
+}
+
+/// Encodes the tree into a new buffer.
+pub fn encode(tree: &Asn1) -> Asn1Result<Vec<u8>> {
+    let mut buf = vec![0; tree.needed_buf_size()];
+    tree.encode_buff(&mut buf)?;
+
+    Ok(buf)
+}
+
+fn node(asn1: Asn1Type) -> Asn1 {
+    Asn1::from_asn1_type(asn1)
+}
+
+pub fn seq(fields: impl Into<Vec<Asn1>>) -> Asn1 {
+    node(Asn1Type::Sequence(Mutable::new(Sequence::new(fields.into()))))
+}
+
+pub fn set(fields: impl Into<Vec<Asn1>>) -> Asn1 {
+    node(Asn1Type::Set(Mutable::new(Set::new(fields.into()))))
+}
+
+pub fn explicit(tag: u8, inner: impl Into<Vec<Asn1>>) -> Asn1 {
+    node(Asn1Type::ExplicitTag(Mutable::new(ExplicitTag::new(tag, inner.into()))))
+}
+
+pub fn application(tag: u8, inner: impl Into<Vec<Asn1>>) -> Asn1 {
+    node(Asn1Type::ApplicationTag(Mutable::new(ApplicationTag::new(
+        tag,
+        inner.into(),
+    ))))
+}
+
+pub fn implicit(tag: u8, octets: impl Into<Vec<u8>>) -> Asn1 {
+    node(Asn1Type::ImplicitTag(Mutable::new(ImplicitTag::new(
+        tag,
+        octets.into(),
+    ))))
+}
+
+pub fn int(value: impl Into<Integer>) -> Asn1 {
+    node(Asn1Type::Integer(Mutable::new(value.into())))
+}
+
+pub fn enumerated(value: impl Into<Vec<u8>>) -> Asn1 {
+    node(Asn1Type::Enumerated(Mutable::new(Enumerated::from(value.into()))))
+}
+
+pub fn bool(value: bool) -> Asn1 {
+    node(Asn1Type::Bool(Mutable::new(Bool::from(value))))
+}
+
+pub fn null() -> Asn1 {
+    node(Asn1Type::Null(Mutable::new(Null)))
+}
+
+/// Creates an object identifier node from its dotted representation.
+///
+/// # Panics
+///
+/// Panics if the object identifier is not valid.
+pub fn oid(oid: &str) -> Asn1 {
+    node(Asn1Type::ObjectIdentifier(Mutable::new(
+        ObjectIdentifier::new_unchecked(oid),
+    )))
+}
+
+pub fn octets(octets: impl Into<Vec<u8>>) -> Asn1 {
+    node(Asn1Type::OctetString(Mutable::new(OctetString::new(octets.into()))))
+}
+
+/// Creates a bit string node. The first byte is the number of unused bits.
+pub fn bits(data: impl Into<Vec<u8>>) -> Asn1 {
+    node(Asn1Type::BitString(Mutable::new(BitString::from(data.into()))))
+}
+
+pub fn utf8(value: impl Into<String>) -> Asn1 {
+    node(Asn1Type::Utf8String(Mutable::new(Utf8String::new(value.into()))))
+}
+
+pub fn printable(value: impl Into<String>) -> Asn1 {
+    node(Asn1Type::PrintableString(Mutable::new(PrintableString::new(
+        value.into(),
+    ))))
+}
+
+pub fn ia5(value: impl Into<String>) -> Asn1 {
+    node(Asn1Type::IA5String(Mutable::new(IA5String::new(value.into()))))
+}
+
+pub fn visible(value: impl Into<String>) -> Asn1 {
+    node(Asn1Type::VisibleString(Mutable::new(VisibleString::new(value.into()))))
+}
+
+pub fn numeric(value: impl Into<String>) -> Asn1 {
+    node(Asn1Type::NumericString(Mutable::new(NumericString::new(value.into()))))
+}
+
+pub fn general(value: impl Into<String>) -> Asn1 {
+    node(Asn1Type::GeneralString(Mutable::new(GeneralString::new(value.into()))))
+}
+
+pub fn bmp(value: &str) -> Asn1 {
+    node(Asn1Type::BmpString(Mutable::new(BmpString::new(
+        value.encode_utf16().flat_map(|c| c.to_be_bytes()).collect(),
+    ))))
+}
+
+pub fn utc_time(value: UtcTime) -> Asn1 {
+    node(Asn1Type::UtcTime(Mutable::new(value)))
+}
+
+pub fn generalized_time(value: GeneralizedTime) -> Asn1 {
+    node(Asn1Type::GeneralizedTime(Mutable::new(value)))
+}
+
+/// Builds the asn1 tree using the [builder](crate::builder) functions. Returns [Asn1Result] with the tree
+/// that has valid metadata.
+///
+/// Constructed nodes (`seq`, `set`, `explicit`, `application`) take nested nodes, the other nodes take the
+/// arguments of the corresponding [builder](crate::builder) function. `tree(expr)` inserts an already built tree.
+///
+/// ```
+/// use asn1_parser::asn1;
+///
+/// let tree = asn1! {
+///     seq {
+///         int(-3),
+///         set { bool(true), null() },
+///         application(1, octets(vec![1, 2, 3])),
+///     }
+/// };
+/// assert!(tree.is_ok());
+/// ```
+#[macro_export]
+macro_rules! asn1 {
+    (@node seq { $($name:ident $body:tt),* $(,)? }) => {
+        $crate::builder::seq([$($crate::asn1!(@node $name $body)),*])
+    };
+    (@node set { $($name:ident $body:tt),* $(,)? }) => {
+        $crate::builder::set([$($crate::asn1!(@node $name $body)),*])
+    };
+    (@node explicit ($tag:expr $(, $name:ident $body:tt)* $(,)?)) => {
+        $crate::builder::explicit($tag, [$($crate::asn1!(@node $name $body)),*])
+    };
+    (@node application ($tag:expr $(, $name:ident $body:tt)* $(,)?)) => {
+        $crate::builder::application($tag, [$($crate::asn1!(@node $name $body)),*])
+    };
+    (@node tree ($tree:expr)) => {
+        $tree
+    };
+    (@node $name:ident ($($arg:expr),* $(,)?)) => {
+        $crate::builder::$name($($arg),*)
+    };
+    ($name:ident $body:tt) => {
+        $crate::builder::build($crate::asn1!(@node $name $body))
+    };
+}
@@ -0,0 +1,244 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use num_bigint_dig::BigInt;
+
+use super::TAG_NAMES;
+use crate::dump::{encoded_data, is_printable};
+use crate::length::{INDEFINITE_LEN, len_size};
+use crate::{Asn1, Asn1Entity, Asn1Type};
+
+/// Integers longer than this amount of bytes are decompiled as hex literals.
+const MAX_DECIMAL_INTEGER_LEN: usize = 16;
+
+const INDENT: &str = "  ";
+
+/// Decompiles asn1 trees into the der-ascii text.
+pub fn decompile(trees: &[Asn1]) -> String {
+    let mut text = String::new();
+
+    for tree in trees {
+        decompile_node(&mut text, tree, 0);
+    }
+
+    text
+}
+
+fn decompile_node(text: &mut String, asn1: &Asn1, depth: usize) {
+    for _ in 0..depth {
+        text.push_str(INDENT);
+    }
+
+    let tag = u8::from(asn1.tag());
+    let length = length_modifiers(asn1);
+    let name = |name: &str| format!("{name}{length}");
+
+    match asn1.inner_asn1() {
+        Asn1Type::Sequence(sequence) => decompile_block(text, &name("SEQUENCE"), sequence.get().fields(), depth),
+        Asn1Type::Set(set) => decompile_block(text, &name("SET"), set.get().fields(), depth),
+        Asn1Type::ExplicitTag(explicit) => {
+            let explicit = explicit.get();
+            decompile_block(
+                text,
+                &name(&format!("[{}]", explicit.tag_number())),
+                explicit.inner(),
+                depth,
+            );
+        }
+        Asn1Type::ApplicationTag(application) => {
+            let application = application.get();
+            decompile_block(
+                text,
+                &name(&format!("[APPLICATION {}]", application.tag_number())),
+                application.inner(),
+                depth,
+            );
+        }
+        Asn1Type::ImplicitTag(implicit) => {
+            let implicit = implicit.get();
+            let _ = write!(text, "{} {{ ", name(&format!("[{} PRIMITIVE]", implicit.tag_number())));
+            write_hex(text, implicit.octets());
+            text.push_str(" }\n");
+        }
+        Asn1Type::OctetString(octet_string) => {
+            let octet_string = octet_string.get();
+            match octet_string.inner().filter(|inner| !inner.get().is_empty()) {
+                Some(inner) => decompile_block(text, &name("OCTET_STRING"), &inner.get(), depth),
+                None => write_leaf_hex(text, &name("OCTET_STRING"), octet_string.octets()),
+            }
+        }
+        Asn1Type::BitString(bit_string) => {
+            let bit_string = bit_string.get();
+            let bits = bit_string.raw_bits();
+
+            match bit_string.inner().filter(|inner| !inner.get().is_empty()) {
+                Some(inner) if !bits.is_empty() => {
+                    let _ = writeln!(text, "{} {{", name("BIT_STRING"));
+                    for _ in 0..=depth {
+                        text.push_str(INDENT);
+                    }
+                    write_hex(text, &bits[..1]);
+                    text.push('\n');
+                    for node in inner.get().iter() {
+                        decompile_node(text, node, depth + 1);
+                    }
+                    close_block(text, depth);
+                }
+                _ => write_leaf_hex(text, &name("BIT_STRING"), bits),
+            }
+        }
+        Asn1Type::BmpString(bmp) => write_leaf_hex(text, &name("BMPString"), bmp.get().raw_data()),
+        Asn1Type::Utf8String(string) => write_leaf_string(text, &name("UTF8String"), string.get().string()),
+        Asn1Type::IA5String(string) => write_leaf_string(text, &name("IA5String"), string.get().string()),
+        Asn1Type::PrintableString(string) => write_leaf_string(text, &name("PrintableString"), string.get().string()),
+        Asn1Type::GeneralString(string) => write_leaf_string(text, &name("GeneralString"), string.get().string()),
+        Asn1Type::NumericString(string) => write_leaf_string(text, &name("NumericString"), string.get().string()),
+        Asn1Type::VisibleString(string) => write_leaf_string(text, &name("VisibleString"), string.get().string()),
+        Asn1Type::UtcTime(_) | Asn1Type::GeneralizedTime(_) => {
+            let data = encoded_data(asn1, asn1.meta());
+            write_leaf_string(text, &name(tag_name(tag)), &String::from_utf8_lossy(&data));
+        }
+        Asn1Type::Bool(_) => match encoded_data(asn1, asn1.meta()).as_slice() {
+            [0xff] => {
+                let _ = writeln!(text, "{} {{ TRUE }}", name("BOOLEAN"));
+            }
+            [0x00] => {
+                let _ = writeln!(text, "{} {{ FALSE }}", name("BOOLEAN"));
+            }
+            data => write_leaf_hex(text, &name("BOOLEAN"), data),
+        },
+        Asn1Type::Null(_) => {
+            let _ = writeln!(text, "{} {{}}", name("NULL"));
+        }
+        Asn1Type::Integer(integer) => write_leaf_integer(text, &name("INTEGER"), integer.get().raw_data()),
+        Asn1Type::Enumerated(enumerated) => write_leaf_integer(text, &name("ENUMERATED"), enumerated.get().raw_data()),
+        Asn1Type::ObjectIdentifier(oid) => {
+            let oid = oid.get();
+            let data = encoded_data(asn1, asn1.meta());
+
+            // Malformed content (e.g. a dangling continuation byte) is decoded leniently,
+            // so the dotted form is used only when it encodes back into the same bytes.
+            let encoded: Vec<u8> = oid.oid().clone().into();
+            if encoded == data {
+                let _ = writeln!(text, "{} {{ {} }}", name("OBJECT_IDENTIFIER"), oid.format());
+            } else {
+                write_leaf_hex(text, &name("OBJECT_IDENTIFIER"), &data);
+            }
+        }
+    }
+}
+
+fn decompile_block(text: &mut String, name: &str, nodes: &[Asn1], depth: usize) {
+    if nodes.is_empty() {
+        let _ = writeln!(text, "{name} {{}}");
+        return;
+    }
+
+    let _ = writeln!(text, "{name} {{");
+    for node in nodes {
+        decompile_node(text, node, depth + 1);
+    }
+    close_block(text, depth);
+}
+
+fn close_block(text: &mut String, depth: usize) {
+    for _ in 0..depth {
+        text.push_str(INDENT);
+    }
+    text.push_str("}\n");
+}
+
+fn write_leaf_hex(text: &mut String, name: &str, bytes: &[u8]) {
+    if bytes.is_empty() {
+        let _ = writeln!(text, "{name} {{}}");
+    } else {
+        let _ = write!(text, "{name} {{ ");
+        write_hex(text, bytes);
+        text.push_str(" }\n");
+    }
+}
+
+/// Returns the der-ascii length modifiers (with the leading space) of the node with non-DER length encoding.
+///
+/// Nodes without the raw data are always encoded with the DER length, so they do not need modifiers.
+fn length_modifiers(asn1: &Asn1) -> String {
+    let meta = asn1.meta();
+
+    match meta.length_bytes() {
+        [INDEFINITE_LEN] if asn1.tag().is_constructed() => " indefinite".into(),
+        [first, bytes @ ..] if *first > INDEFINITE_LEN => {
+            let value = bytes
+                .iter()
+                .fold(0_usize, |value, byte| (value << 8) | usize::from(*byte));
+            let content_len = meta.data_bytes().len();
+
+            let mut modifiers = String::new();
+            if bytes.len() != len_size(value) - 1 {
+                let _ = write!(modifiers, " long-form:{}", bytes.len());
+            }
+            if value != content_len {
+                let _ = write!(modifiers, " length:{value}");
+            }
+
+            modifiers
+        }
+        [value @ 0..=0x7f] if usize::from(*value) != meta.data_bytes().len() => format!(" length:{value}"),
+        _ => String::new(),
+    }
+}
+
+/// Checks if the two's complement integer is encoded using the minimal amount of bytes.
+fn is_minimal_integer(bytes: &[u8]) -> bool {
+    match bytes {
+        [0x00, next, ..] => next & 0x80 != 0,
+        [0xff, next, ..] => next & 0x80 == 0,
+        _ => true,
+    }
+}
+
+fn write_leaf_integer(text: &mut String, name: &str, bytes: &[u8]) {
+    // Non-minimal encodings are kept as they are, so the decompiled text compiles back into the same bytes.
+    if bytes.is_empty() || bytes.len() > MAX_DECIMAL_INTEGER_LEN || !is_minimal_integer(bytes) {
+        write_leaf_hex(text, name, bytes);
+    } else {
+        let _ = writeln!(text, "{name} {{ {} }}", BigInt::from_signed_bytes_be(bytes));
+    }
+}
+
+fn write_leaf_string(text: &mut String, name: &str, value: &str) {
+    let _ = write!(text, "{name} {{ \"");
+
+    for c in value.chars() {
+        match c {
+            '"' => text.push_str("\\\""),
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            '\t' => text.push_str("\\t"),
+            c if c.is_ascii() && !is_printable(c as u8) => {
+                let _ = write!(text, "\\x{:02x}", c as u8);
+            }
+            c => text.push(c),
+        }
+    }
+
+    text.push_str("\" }\n");
+}
+
+fn write_hex(text: &mut String, bytes: &[u8]) {
+    text.push('`');
+    for byte in bytes {
+        let _ = write!(text, "{byte:02x}");
+    }
+    text.push('`');
+}
+
+fn tag_name(tag: u8) -> &'static str {
+    TAG_NAMES
+        .iter()
+        .find(|(_, value)| *value == tag)
+        .map(|(name, _)| *name)
+        .unwrap_or_default()
+}
@@ -0,0 +1,219 @@
+//! [der-ascii](https://github.com/google/der-ascii) style textual language for authoring DER by hand.
+//!
+//! ```text
+//! # Comments start with `#`.
+//! SEQUENCE {
+//!   INTEGER { 5 }
+//!   [0] { UTF8String { "x" } }
+//!   OBJECT_IDENTIFIER { 1.2.840.113549.1.1.11 }
+//!   OCTET_STRING { `0102ff` }
+//!   # Malformed length: the block has 1 byte but the length says 5.
+//!   INTEGER length:5 { 1 }
+//! }
+//! ```
+//!
+//! Every token emits bytes: tag names (`SEQUENCE`, `[APPLICATION 1]`, `[0 PRIMITIVE]`) emit encoded tags,
+//! `{ ... }` emits the length followed by the block content, and literals emit their contents:
+//! decimal integers (two's complement), OIDs, `"strings"`, `` `hex` ``, `TRUE`, and `FALSE`.
+//! The length of the next block can be altered with `length:N`, `long-form:N`, and `indefinite` modifiers.
+
+mod decompiler;
+mod parser;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+pub use decompiler::decompile;
+
+use crate::length::{len_size, write_len};
+use crate::writer::Writer;
+use crate::{Asn1Encoder, Asn1Result, Error};
+
+/// der-ascii syntax error with its position in the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    message: &'static str,
+    line: usize,
+    column: usize,
+}
+
+impl SyntaxError {
+    pub fn message(&self) -> &str {
+        self.message
+    }
+
+    /// One-based line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// One-based column number.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl From<SyntaxError> for Error {
+    fn from(error: SyntaxError) -> Self {
+        Error::from(error.message)
+    }
+}
+
+/// Length of the `{ ... }` block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct BlockLength {
+    /// `length:N`: the encoded length value instead of the actual content length.
+    value: Option<usize>,
+    /// `long-form:N`: the length is encoded in the long form using N bytes.
+    long_form: Option<usize>,
+    /// `indefinite`: the `0x80` length and the end-of-contents octets.
+    indefinite: bool,
+}
+
+impl BlockLength {
+    fn needed_buf_size(&self, content_len: usize) -> usize {
+        if self.indefinite {
+            1 /* 0x80 */ + 2 /* end-of-contents */
+        } else if let Some(long_form) = self.long_form {
+            1 + long_form
+        } else {
+            len_size(self.value.unwrap_or(content_len))
+        }
+    }
+
+    fn encode(&self, content_len: usize, writer: &mut Writer) -> Asn1Result<()> {
+        if self.indefinite {
+            return writer.write_byte(0x80);
+        }
+
+        let value = self.value.unwrap_or(content_len);
+
+        match self.long_form {
+            Some(long_form) => {
+                let bytes = value.to_be_bytes();
+                let significant = bytes.len() - bytes.iter().take_while(|byte| **byte == 0).count();
+                // `0x80` alone is the indefinite length marker, so at least one length byte is required.
+                if long_form == 0 || significant > long_form || long_form > 0x7f {
+                    return Err("Length does not fit into the long-form bytes".into());
+                }
+
+                writer.write_byte(0x80 | u8::try_from(long_form)?)?;
+                for _ in bytes.len()..long_form {
+                    writer.write_byte(0)?;
+                }
+                writer.write_slice(&bytes[bytes.len() - long_form.min(bytes.len())..])
+            }
+            None => write_len(value, writer),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Bytes(Vec<u8>),
+    Block { length: BlockLength, nodes: Vec<Node> },
+}
+
+impl Asn1Encoder for Node {
+    fn needed_buf_size(&self) -> usize {
+        match self {
+            Node::Bytes(bytes) => bytes.len(),
+            Node::Block { length, nodes } => {
+                let content_len = nodes.needed_buf_size();
+                length.needed_buf_size(content_len) + content_len
+            }
+        }
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        match self {
+            Node::Bytes(bytes) => writer.write_slice(bytes),
+            Node::Block { length, nodes } => {
+                length.encode(nodes.needed_buf_size(), writer)?;
+                nodes.encode(writer)?;
+
+                if length.indefinite {
+                    writer.write_slice(&[0, 0])?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Asn1Encoder for Vec<Node> {
+    fn needed_buf_size(&self) -> usize {
+        self.iter().map(|node| node.needed_buf_size()).sum()
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        self.iter().try_for_each(|node| node.encode(writer))
+    }
+}
+
+/// Parsed der-ascii program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerAscii {
+    nodes: Vec<Node>,
+}
+
+impl DerAscii {
+    /// Parses the der-ascii source text.
+    pub fn parse(source: &str) -> Result<Self, SyntaxError> {
+        Ok(Self {
+            nodes: parser::Parser::new(source).parse()?,
+        })
+    }
+}
+
+impl Asn1Encoder for DerAscii {
+    fn needed_buf_size(&self) -> usize {
+        self.nodes.needed_buf_size()
+    }
+
+    fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
+        self.nodes.encode(writer)
+    }
+}
+
+/// Compiles the der-ascii source text into bytes.
+pub fn compile(source: &str) -> Asn1Result<Vec<u8>> {
+    let program = DerAscii::parse(source)?;
+
+    let mut buff = vec![0; program.needed_buf_size()];
+    program.encode_buff(&mut buff)?;
+
+    Ok(buff)
+}
+
+/// Universal tag names.
+const TAG_NAMES: [(&str, u8); 20] = [
+    ("BOOLEAN", 0x01),
+    ("INTEGER", 0x02),
+    ("BIT_STRING", 0x03),
+    ("OCTET_STRING", 0x04),
+    ("NULL", 0x05),
+    ("OBJECT_IDENTIFIER", 0x06),
+    ("ENUMERATED", 0x0a),
+    ("UTF8String", 0x0c),
+    ("NumericString", 0x12),
+    ("PrintableString", 0x13),
+    ("T61String", 0x14),
+    ("IA5String", 0x16),
+    ("UTCTime", 0x17),
+    ("GeneralizedTime", 0x18),
+    ("VisibleString", 0x1a),
+    ("GeneralString", 0x1b),
+    ("UniversalString", 0x1c),
+    ("BMPString", 0x1e),
+    ("SEQUENCE", 0x30),
+    ("SET", 0x31),
+];
@@ -0,0 +1,338 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::CharIndices;
+
+use num_bigint_dig::BigInt;
+
+use super::{BlockLength, Node, SyntaxError, TAG_NAMES};
+
+pub struct Parser<'source> {
+    source: &'source str,
+    chars: Peekable<CharIndices<'source>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'source> Parser<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self {
+            source,
+            chars: source.char_indices().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn parse(mut self) -> Result<Vec<Node>, SyntaxError> {
+        self.parse_nodes(false)
+    }
+
+    fn error(&self, message: &'static str) -> SyntaxError {
+        SyntaxError {
+            message,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let (_, c) = self.chars.next()?;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn skip_whitespaces_and_comments(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '#' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.next();
+                }
+            } else if c.is_whitespace() {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Reads the word consisting of the chars that satisfy the predicate.
+    fn read_word(&mut self, predicate: impl Fn(char) -> bool) -> &'source str {
+        let start = self.chars.peek().map(|(i, _)| *i).unwrap_or(self.source.len());
+
+        while self.peek().is_some_and(&predicate) {
+            self.next();
+        }
+
+        let end = self.chars.peek().map(|(i, _)| *i).unwrap_or(self.source.len());
+
+        &self.source[start..end]
+    }
+
+    fn parse_nodes(&mut self, nested: bool) -> Result<Vec<Node>, SyntaxError> {
+        let mut nodes = Vec::new();
+        let mut length: Option<BlockLength> = None;
+
+        loop {
+            self.skip_whitespaces_and_comments();
+
+            let Some(c) = self.peek() else {
+                if nested {
+                    return Err(self.error("Unclosed `{`"));
+                }
+                break;
+            };
+
+            if length.is_some() && c != '{' {
+                return Err(self.error("Length modifier must be followed by `{`"));
+            }
+
+            match c {
+                '{' => {
+                    self.next();
+                    nodes.push(Node::Block {
+                        length: length.take().unwrap_or_default(),
+                        nodes: self.parse_nodes(true)?,
+                    });
+                }
+                '}' => {
+                    if !nested {
+                        return Err(self.error("Unexpected `}`"));
+                    }
+                    self.next();
+                    break;
+                }
+                '`' => nodes.push(Node::Bytes(self.parse_hex()?)),
+                '"' => nodes.push(Node::Bytes(self.parse_string()?)),
+                '[' => nodes.push(Node::Bytes(self.parse_bracket_tag()?)),
+                '-' | '0'..='9' => nodes.push(Node::Bytes(self.parse_number()?)),
+                c if c.is_ascii_alphabetic() => {
+                    let word = self.read_word(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':'));
+
+                    if let Some(modifier) = self.parse_length_modifier(word, length.take().unwrap_or_default())? {
+                        length = Some(modifier);
+                    } else {
+                        nodes.push(Node::Bytes(self.parse_keyword(word)?));
+                    }
+                }
+                _ => return Err(self.error("Unexpected character")),
+            }
+        }
+
+        if length.is_some() {
+            return Err(self.error("Length modifier must be followed by `{`"));
+        }
+
+        Ok(nodes)
+    }
+
+    /// Returns `None` if the word is not a length modifier.
+    fn parse_length_modifier(&self, word: &str, mut length: BlockLength) -> Result<Option<BlockLength>, SyntaxError> {
+        if word == "indefinite" {
+            length.indefinite = true;
+        } else if let Some(value) = word.strip_prefix("length:") {
+            length.value = Some(value.parse().map_err(|_| self.error("Invalid `length:N` value"))?);
+        } else if let Some(value) = word.strip_prefix("long-form:") {
+            let long_form = value.parse().map_err(|_| self.error("Invalid `long-form:N` value"))?;
+            if long_form == 0 || long_form > 0x7f {
+                return Err(self.error("`long-form:N` must be in range 1..=127"));
+            }
+            length.long_form = Some(long_form);
+        } else {
+            return Ok(None);
+        }
+
+        if length.indefinite && (length.value.is_some() || length.long_form.is_some()) {
+            return Err(self.error("`indefinite` can not be combined with other length modifiers"));
+        }
+
+        Ok(Some(length))
+    }
+
+    fn parse_keyword(&self, word: &str) -> Result<Vec<u8>, SyntaxError> {
+        match word {
+            "TRUE" => Ok(vec![0xff]),
+            "FALSE" => Ok(vec![0x00]),
+            _ => TAG_NAMES
+                .iter()
+                .find(|(name, _)| *name == word)
+                .map(|(_, tag)| vec![*tag])
+                .ok_or_else(|| self.error("Unknown keyword")),
+        }
+    }
+
+    fn parse_hex(&mut self) -> Result<Vec<u8>, SyntaxError> {
+        // Opening '`'.
+        self.next();
+
+        let hex = self.read_word(|c| c != '`');
+        if self.next() != Some('`') {
+            return Err(self.error("Unclosed hex literal"));
+        }
+
+        let digits = hex.chars().filter(|c| !c.is_whitespace()).collect::<Vec<_>>();
+        if digits.len() % 2 != 0 {
+            return Err(self.error("Hex literal must have an even number of digits"));
+        }
+
+        digits
+            .chunks(2)
+            .map(|pair| {
+                let high = pair[0].to_digit(16);
+                let low = pair[1].to_digit(16);
+
+                match (high, low) {
+                    (Some(high), Some(low)) => Ok((high * 16 + low) as u8),
+                    _ => Err(self.error("Invalid hex digit")),
+                }
+            })
+            .collect()
+    }
+
+    fn parse_string(&mut self) -> Result<Vec<u8>, SyntaxError> {
+        // Opening '"'.
+        self.next();
+
+        let mut bytes = Vec::new();
+        let mut buf = [0; 4];
+
+        loop {
+            match self.next() {
+                None => return Err(self.error("Unclosed string literal")),
+                Some('"') => break,
+                Some('\\') => match self.next() {
+                    Some('n') => bytes.push(b'\n'),
+                    Some('r') => bytes.push(b'\r'),
+                    Some('t') => bytes.push(b'\t'),
+                    Some('"') => bytes.push(b'"'),
+                    Some('\\') => bytes.push(b'\\'),
+                    Some('x') => {
+                        let high = self.next().and_then(|c| c.to_digit(16));
+                        let low = self.next().and_then(|c| c.to_digit(16));
+
+                        match (high, low) {
+                            (Some(high), Some(low)) => bytes.push((high * 16 + low) as u8),
+                            _ => return Err(self.error("Invalid `\\xNN` escape")),
+                        }
+                    }
+                    _ => return Err(self.error("Unknown escape sequence")),
+                },
+                Some(c) => bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes()),
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    /// Parses `[CLASS NUMBER MODIFIER]` tag. The class defaults to context-specific and the modifier defaults to constructed.
+    fn parse_bracket_tag(&mut self) -> Result<Vec<u8>, SyntaxError> {
+        // Opening '['.
+        self.next();
+
+        let content = self.read_word(|c| c != ']');
+        if self.next() != Some(']') {
+            return Err(self.error("Unclosed `[`"));
+        }
+
+        let mut words = content.split_whitespace().peekable();
+
+        let class = match words.peek() {
+            Some(&"UNIVERSAL") => 0x00,
+            Some(&"APPLICATION") => 0x40,
+            Some(&"CONTEXT-SPECIFIC") => 0x80,
+            Some(&"PRIVATE") => 0xc0,
+            _ => 0x80,
+        };
+        if words
+            .peek()
+            .is_some_and(|word| !word.starts_with(|c: char| c.is_ascii_digit()))
+        {
+            words.next();
+        }
+
+        let number: u64 = words
+            .next()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| self.error("Invalid tag number"))?;
+
+        let constructed = match words.next() {
+            None | Some("CONSTRUCTED") => 0x20,
+            Some("PRIMITIVE") => 0x00,
+            Some(_) => return Err(self.error("Expected `PRIMITIVE` or `CONSTRUCTED`")),
+        };
+
+        if words.next().is_some() {
+            return Err(self.error("Unexpected tag content"));
+        }
+
+        if number < 31 {
+            Ok(vec![class | constructed | number as u8])
+        } else {
+            let mut tag = vec![class | constructed | 0x1f];
+            tag.extend_from_slice(&base128(number.into()));
+            Ok(tag)
+        }
+    }
+
+    /// Parses either decimal integer or dotted object identifier.
+    fn parse_number(&mut self) -> Result<Vec<u8>, SyntaxError> {
+        let number = self.read_word(|c| c.is_ascii_digit() || c == '.' || c == '-');
+
+        if number.contains('.') {
+            self.encode_oid(number)
+        } else {
+            BigInt::parse_bytes(number.as_bytes(), 10)
+                .map(|number| number.to_signed_bytes_be())
+                .ok_or_else(|| self.error("Invalid integer"))
+        }
+    }
+
+    fn encode_oid(&self, oid: &str) -> Result<Vec<u8>, SyntaxError> {
+        let arcs = oid
+            .split('.')
+            .map(|arc| arc.parse::<u128>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| self.error("Invalid object identifier"))?;
+
+        let [first, second, rest @ ..] = arcs.as_slice() else {
+            return Err(self.error("Object identifier must have at least two arcs"));
+        };
+        if *first > 2 || (*first < 2 && *second >= 40) {
+            return Err(self.error("Invalid object identifier root arcs"));
+        }
+
+        let mut bytes = base128(
+            (first * 40)
+                .checked_add(*second)
+                .ok_or_else(|| self.error("Object identifier arc is too big"))?,
+        );
+        rest.iter().for_each(|arc| bytes.extend_from_slice(&base128(*arc)));
+
+        Ok(bytes)
+    }
+}
+
+/// Encodes the number in base 128 with the continuation bits.
+fn base128(mut value: u128) -> Vec<u8> {
+    let mut bytes = vec![(value & 0x7f) as u8];
+    value >>= 7;
+
+    while value > 0 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes.reverse();
+    bytes
+}
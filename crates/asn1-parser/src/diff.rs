@@ -0,0 +1,411 @@
+//! Structural diff of the asn1 trees.
+//!
+//! [diff_trees] aligns two lists of trees node by node. Children of the constructed nodes are aligned by their tags
+//! and positions, so an inserted optional field does not turn all following fields into changes. Elements of
+//! `SET`s are matched regardless of their order and reported as moved when the order differs.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::slice;
+
+use crate::dump::encoded_data;
+use crate::stats::type_name;
+use crate::{Asn1, Asn1Encoder, Asn1Entity, Asn1Type};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// The node is present only in the changed trees.
+    Added,
+    /// The node is present only in the original trees.
+    Removed,
+    /// The node value differs.
+    Changed,
+    /// The `SET` element is equal but placed at another position.
+    Moved,
+}
+
+impl AsRef<str> for ChangeKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+            ChangeKind::Moved => "moved",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeChange {
+    pub kind: ChangeKind,
+    /// Path of the node in the form of `0:SEQUENCE/2:[1]/0:INTEGER`. Indices are taken from the changed trees
+    /// for added nodes and from the original trees otherwise.
+    pub path: String,
+    /// Node id in the original trees.
+    pub original: Option<u64>,
+    /// Node id in the changed trees.
+    pub changed: Option<u64>,
+    /// Node value in the original trees. `None` for constructed nodes.
+    pub original_value: Option<String>,
+    /// Node value in the changed trees. `None` for constructed nodes.
+    pub changed_value: Option<String>,
+}
+
+/// Compares the original and changed trees and returns the changes in the tree order.
+pub fn diff_trees(original: &[Asn1], changed: &[Asn1]) -> Vec<NodeChange> {
+    let mut changes = Vec::new();
+    diff_ordered(original, changed, "", &mut changes);
+
+    changes
+}
+
+/// Encoded node. Nodes without the original bytes (e.g. just created) are encoded.
+fn encoded(asn1: &Asn1) -> Vec<u8> {
+    let raw_bytes = asn1.meta().raw_bytes();
+    if !raw_bytes.is_empty() {
+        return raw_bytes.to_vec();
+    }
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    if asn1.encode_buff(&mut buff).is_err() {
+        return Vec::new();
+    }
+
+    buff
+}
+
+fn node_name(asn1: &Asn1) -> String {
+    match asn1.inner_asn1() {
+        Asn1Type::ExplicitTag(explicit) => format!("[{}]", explicit.get().tag_number()),
+        Asn1Type::ImplicitTag(implicit) => format!("[{}]", implicit.get().tag_number()),
+        Asn1Type::ApplicationTag(application) => format!("[APPLICATION {}]", application.get().tag_number()),
+        asn1_type => type_name(asn1_type).to_string(),
+    }
+}
+
+fn node_path(parent: &str, index: usize, asn1: &Asn1) -> String {
+    if parent.is_empty() {
+        format!("{}:{}", index, node_name(asn1))
+    } else {
+        format!("{}/{}:{}", parent, index, node_name(asn1))
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Calls the provided function with the child trees of the constructed node, including the trees encapsulated
+/// in octet and bit strings. Returns `None` for the primitive nodes.
+fn with_children<R>(asn1: &Asn1, f: impl FnOnce(&[Asn1]) -> R) -> Option<R> {
+    match asn1.inner_asn1() {
+        Asn1Type::Sequence(sequence) => Some(f(sequence.get().fields())),
+        Asn1Type::Set(set) => Some(f(set.get().fields())),
+        Asn1Type::ExplicitTag(explicit) => Some(f(explicit.get().inner())),
+        Asn1Type::ApplicationTag(application) => Some(f(application.get().inner())),
+        Asn1Type::OctetString(octet_string) => octet_string.get().inner().map(|inner| f(&inner.get())),
+        Asn1Type::BitString(bit_string) => bit_string.get().inner().map(|inner| f(&inner.get())),
+        Asn1Type::ImplicitTag(implicit) => implicit.get().inner_asn1().map(|inner| f(slice::from_ref(inner))),
+        _ => None,
+    }
+}
+
+/// Short textual value of the primitive node. Returns `None` for constructed nodes.
+pub fn node_value(asn1: &Asn1) -> Option<String> {
+    Some(match asn1.inner_asn1() {
+        Asn1Type::Sequence(_) | Asn1Type::Set(_) | Asn1Type::ExplicitTag(_) | Asn1Type::ApplicationTag(_) => {
+            return None;
+        }
+        Asn1Type::OctetString(octet_string) => hex(octet_string.get().octets()),
+        Asn1Type::BitString(bit_string) => hex(bit_string.get().raw_bits()),
+        Asn1Type::ImplicitTag(implicit) => hex(implicit.get().octets()),
+        Asn1Type::BmpString(bmp) => {
+            let bmp = bmp.get();
+            let units = bmp.raw_data().chunks(2).map(|unit| match unit {
+                [high, low] => u16::from_be_bytes([*high, *low]),
+                _ => u16::from(unit[0]),
+            });
+            char::decode_utf16(units)
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect()
+        }
+        Asn1Type::Utf8String(string) => string.get().string().to_string(),
+        Asn1Type::IA5String(string) => string.get().string().to_string(),
+        Asn1Type::PrintableString(string) => string.get().string().to_string(),
+        Asn1Type::GeneralString(string) => string.get().string().to_string(),
+        Asn1Type::NumericString(string) => string.get().string().to_string(),
+        Asn1Type::VisibleString(string) => string.get().string().to_string(),
+        Asn1Type::Bool(boolean) => if boolean.get().value() { "TRUE" } else { "FALSE" }.to_string(),
+        Asn1Type::Null(_) => "NULL".to_string(),
+        Asn1Type::Integer(integer) => hex(integer.get().raw_data()),
+        Asn1Type::Enumerated(enumerated) => hex(enumerated.get().raw_data()),
+        Asn1Type::ObjectIdentifier(oid) => oid.get().format(),
+        Asn1Type::UtcTime(_) | Asn1Type::GeneralizedTime(_) => {
+            encoded_data(asn1, asn1.meta()).into_iter().map(char::from).collect()
+        }
+    })
+}
+
+/// Reports the whole subtree as added or removed.
+fn subtree_change(kind: ChangeKind, asn1: &Asn1, path: String) -> NodeChange {
+    let value = node_value(asn1);
+    let (original, changed, original_value, changed_value) = match kind {
+        ChangeKind::Added => (None, Some(asn1.id()), None, value),
+        _ => (Some(asn1.id()), None, value, None),
+    };
+
+    NodeChange {
+        kind,
+        path,
+        original,
+        changed,
+        original_value,
+        changed_value,
+    }
+}
+
+/// Compares two nodes with the same tag.
+fn diff_nodes(original: &Asn1, changed: &Asn1, path: String, changes: &mut Vec<NodeChange>) {
+    if encoded(original) == encoded(changed) {
+        return;
+    }
+
+    let diffed = with_children(original, |original_children| {
+        with_children(changed, |changed_children| {
+            if matches!(original.inner_asn1(), Asn1Type::Set(_)) {
+                diff_unordered(original_children, changed_children, &path, changes);
+            } else {
+                diff_ordered(original_children, changed_children, &path, changes);
+            }
+        })
+    })
+    .flatten();
+
+    if diffed.is_none() {
+        changes.push(NodeChange {
+            kind: ChangeKind::Changed,
+            path,
+            original: Some(original.id()),
+            changed: Some(changed.id()),
+            original_value: node_value(original),
+            changed_value: node_value(changed),
+        });
+    }
+}
+
+/// Alignment weight of the node pair. Equal nodes weigh more than the nodes with only the same tag.
+struct Weights<'a> {
+    original: &'a [&'a Asn1],
+    changed: &'a [&'a Asn1],
+    original_encoded: Vec<Vec<u8>>,
+    changed_encoded: Vec<Vec<u8>>,
+}
+
+impl<'a> Weights<'a> {
+    fn new(original: &'a [&'a Asn1], changed: &'a [&'a Asn1]) -> Self {
+        Self {
+            original,
+            changed,
+            original_encoded: original.iter().map(|node| encoded(node)).collect(),
+            changed_encoded: changed.iter().map(|node| encoded(node)).collect(),
+        }
+    }
+
+    fn weight(&self, i: usize, j: usize) -> usize {
+        if self.original[i].tag() != self.changed[j].tag() {
+            0
+        } else if self.original_encoded[i] == self.changed_encoded[j] {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Returns the best alignment scores of `original` and every prefix of `changed` (or every suffix if
+    /// `reversed`). Only two rows of the scores matrix are kept.
+    fn scores(&self, original: Range<usize>, changed: Range<usize>, reversed: bool) -> Vec<usize> {
+        let index = |range: &Range<usize>, k: usize| if reversed { range.end - 1 - k } else { range.start + k };
+
+        let mut previous = vec![0_usize; changed.len() + 1];
+        let mut current = vec![0_usize; changed.len() + 1];
+        for i in 0..original.len() {
+            for j in 0..changed.len() {
+                let matched = match self.weight(index(&original, i), index(&changed, j)) {
+                    0 => 0,
+                    weight => weight + previous[j],
+                };
+                current[j + 1] = matched.max(previous[j + 1]).max(current[j]);
+            }
+            core::mem::swap(&mut previous, &mut current);
+        }
+
+        previous
+    }
+
+    /// Hirschberg's algorithm: the original nodes are split in half, and the changed nodes are split at the point
+    /// where the halves' scores sum up to the best one.
+    fn align(&self, original: Range<usize>, changed: Range<usize>, pairs: &mut Vec<(Option<usize>, Option<usize>)>) {
+        match original.len() {
+            0 => pairs.extend(changed.map(|j| (None, Some(j)))),
+            1 => {
+                let i = original.start;
+                let best = changed
+                    .clone()
+                    .filter(|j| self.weight(i, *j) > 0)
+                    .fold(None, |best: Option<usize>, j| match best {
+                        Some(best) if self.weight(i, best) >= self.weight(i, j) => Some(best),
+                        _ => Some(j),
+                    });
+
+                match best {
+                    Some(best) => {
+                        pairs.extend((changed.start..best).map(|j| (None, Some(j))));
+                        pairs.push((Some(i), Some(best)));
+                        pairs.extend((best + 1..changed.end).map(|j| (None, Some(j))));
+                    }
+                    None => {
+                        pairs.push((Some(i), None));
+                        pairs.extend(changed.map(|j| (None, Some(j))));
+                    }
+                }
+            }
+            len => {
+                let middle = original.start + len / 2;
+                let head = self.scores(original.start..middle, changed.clone(), false);
+                let tail = self.scores(middle..original.end, changed.clone(), true);
+
+                let split = (0..=changed.len())
+                    .max_by_key(|k| (head[*k] + tail[changed.len() - k], usize::MAX - k))
+                    .unwrap_or_default();
+
+                self.align(original.start..middle, changed.start..changed.start + split, pairs);
+                self.align(middle..original.end, changed.start + split..changed.end, pairs);
+            }
+        }
+    }
+}
+
+/// Aligns the nodes by the longest common subsequence of tags. Equal nodes weigh more than the nodes with only
+/// the same tag, so the unchanged nodes stay aligned around the inserted and removed ones.
+fn align(original: &[&Asn1], changed: &[&Asn1]) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut pairs = Vec::new();
+    Weights::new(original, changed).align(0..original.len(), 0..changed.len(), &mut pairs);
+
+    pairs
+}
+
+fn diff_ordered(original: &[Asn1], changed: &[Asn1], parent: &str, changes: &mut Vec<NodeChange>) {
+    let original_nodes = original.iter().collect::<Vec<_>>();
+    let changed_nodes = changed.iter().collect::<Vec<_>>();
+
+    for pair in align(&original_nodes, &changed_nodes) {
+        match pair {
+            (Some(i), Some(j)) => diff_nodes(&original[i], &changed[j], node_path(parent, i, &original[i]), changes),
+            (Some(i), None) => changes.push(subtree_change(
+                ChangeKind::Removed,
+                &original[i],
+                node_path(parent, i, &original[i]),
+            )),
+            (None, Some(j)) => changes.push(subtree_change(
+                ChangeKind::Added,
+                &changed[j],
+                node_path(parent, j, &changed[j]),
+            )),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Returns the indices of the longest increasing subsequence of the values.
+fn longest_increasing(values: &[usize]) -> Vec<usize> {
+    // lengths[i] is the length of the longest increasing subsequence ending at i.
+    let mut lengths = vec![1_usize; values.len()];
+    let mut previous = vec![None; values.len()];
+
+    for i in 0..values.len() {
+        for j in 0..i {
+            if values[j] < values[i] && lengths[j] + 1 > lengths[i] {
+                lengths[i] = lengths[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    let mut indices = Vec::new();
+    let mut current = (0..values.len()).max_by_key(|i| (lengths[*i], usize::MAX - *i));
+    while let Some(i) = current {
+        indices.push(i);
+        current = previous[i];
+    }
+    indices.reverse();
+
+    indices
+}
+
+/// Compares the `SET` elements. Equal elements are matched regardless of their positions.
+fn diff_unordered(original: &[Asn1], changed: &[Asn1], parent: &str, changes: &mut Vec<NodeChange>) {
+    let changed_encoded = changed.iter().map(encoded).collect::<Vec<_>>();
+
+    let mut changed_used = vec![false; changed.len()];
+    // (original index, changed index) pairs of the equal elements.
+    let mut equal = Vec::new();
+    for (i, node) in original.iter().enumerate() {
+        let node_encoded = encoded(node);
+
+        if let Some(j) = (0..changed.len()).find(|j| !changed_used[*j] && changed_encoded[*j] == node_encoded) {
+            changed_used[j] = true;
+            equal.push((i, j));
+        }
+    }
+
+    // Elements out of the longest increasing sequence of positions are the moved ones.
+    let in_order = longest_increasing(&equal.iter().map(|(_, j)| *j).collect::<Vec<_>>());
+    for (k, (i, j)) in equal.iter().enumerate() {
+        if !in_order.contains(&k) {
+            changes.push(NodeChange {
+                kind: ChangeKind::Moved,
+                path: node_path(parent, *i, &original[*i]),
+                original: Some(original[*i].id()),
+                changed: Some(changed[*j].id()),
+                original_value: node_value(&original[*i]),
+                changed_value: node_value(&changed[*j]),
+            });
+        }
+    }
+
+    let original_left = (0..original.len())
+        .filter(|i| !equal.iter().any(|(original_index, _)| original_index == i))
+        .collect::<Vec<_>>();
+    let changed_left = (0..changed.len()).filter(|j| !changed_used[*j]).collect::<Vec<_>>();
+
+    let original_nodes = original_left.iter().map(|i| &original[*i]).collect::<Vec<_>>();
+    let changed_nodes = changed_left.iter().map(|j| &changed[*j]).collect::<Vec<_>>();
+
+    for pair in align(&original_nodes, &changed_nodes) {
+        match pair {
+            (Some(i), Some(j)) => {
+                let index = original_left[i];
+                diff_nodes(
+                    original_nodes[i],
+                    changed_nodes[j],
+                    node_path(parent, index, original_nodes[i]),
+                    changes,
+                );
+            }
+            (Some(i), None) => changes.push(subtree_change(
+                ChangeKind::Removed,
+                original_nodes[i],
+                node_path(parent, original_left[i], original_nodes[i]),
+            )),
+            (None, Some(j)) => changes.push(subtree_change(
+                ChangeKind::Added,
+                changed_nodes[j],
+                node_path(parent, changed_left[j], changed_nodes[j]),
+            )),
+            (None, None) => {}
+        }
+    }
+}
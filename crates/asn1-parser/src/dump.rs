@@ -0,0 +1,427 @@
+//! Textual dumps of the parsed asn1 trees.
+//!
+//! [OpensslDump] produces the `openssl asn1parse -i` output and [DumpAsn1] produces the `dumpasn1` output.
+//! Offsets, header lengths, and data lengths are taken from the [RawAsn1EntityData] of every node,
+//! so the trees should be freshly decoded (or re-decoded after editing).
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::{Asn1, Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Type, RawAsn1EntityData};
+
+/// Amount of bytes per one line in the multi-line hex dump.
+const HEX_LINE_LEN: usize = 16;
+
+/// `openssl asn1parse -i` compatible dump.
+///
+/// ```text
+///     0:d=0  hl=2 l=  13 cons: SEQUENCE
+///     2:d=1  hl=2 l=   1 prim:  INTEGER           :05
+///     5:d=1  hl=2 l=   8 cons:  cont [ 0 ]
+///     7:d=2  hl=2 l=   6 prim:   UTF8STRING        :crypto
+/// ```
+pub struct OpensslDump<'a> {
+    trees: &'a [Asn1],
+}
+
+impl<'a> OpensslDump<'a> {
+    pub fn new(trees: &'a [Asn1]) -> Self {
+        Self { trees }
+    }
+}
+
+impl Display for OpensslDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.trees.iter().try_for_each(|tree| write_openssl_node(f, tree, 0))
+    }
+}
+
+fn write_openssl_node(f: &mut Formatter<'_>, asn1: &Asn1, depth: usize) -> fmt::Result {
+    let meta = asn1.meta();
+    let tag = asn1.tag();
+
+    write!(
+        f,
+        "{:5}:d={:<2} hl={} l={:4} {}: {:depth$}",
+        meta.tag_position(),
+        depth,
+        meta.data.start,
+        meta.data.len(),
+        if tag.is_constructed() { "cons" } else { "prim" },
+        "",
+    )?;
+
+    match asn1.inner_asn1() {
+        Asn1Type::Sequence(sequence) => {
+            writeln!(f, "{:<18}", "SEQUENCE")?;
+            return write_openssl_nodes(f, sequence.get().fields(), depth + 1);
+        }
+        Asn1Type::Set(set) => {
+            writeln!(f, "{:<18}", "SET")?;
+            return write_openssl_nodes(f, set.get().fields(), depth + 1);
+        }
+        Asn1Type::ExplicitTag(explicit) => {
+            let explicit = explicit.get();
+            writeln!(f, "{:<18}", ContextTagName("cont", explicit.tag_number()))?;
+            return write_openssl_nodes(f, explicit.inner(), depth + 1);
+        }
+        Asn1Type::ApplicationTag(application) => {
+            let application = application.get();
+            writeln!(f, "{:<18}", ContextTagName("appl", application.tag_number()))?;
+            return write_openssl_nodes(f, application.inner(), depth + 1);
+        }
+        Asn1Type::ImplicitTag(implicit) => write!(f, "{:<18}", ContextTagName("cont", implicit.get().tag_number()))?,
+        Asn1Type::OctetString(octet_string) => {
+            write!(f, "{:<18}", "OCTET STRING")?;
+
+            let octet_string = octet_string.get();
+            let octets = octet_string.octets();
+            if octets.iter().all(|byte| is_printable(*byte)) {
+                f.write_char(':')?;
+                write_latin1(f, octets)?;
+            } else {
+                f.write_str("[HEX DUMP]:")?;
+                write_hex(f, octets, "")?;
+            }
+        }
+        Asn1Type::BitString(_) => write!(f, "{:<18}", "BIT STRING")?,
+        Asn1Type::BmpString(_) => write!(f, "{:<18}", "BMPSTRING")?,
+        Asn1Type::GeneralString(_) => write!(f, "{:<18}", "GENERALSTRING")?,
+        Asn1Type::Utf8String(string) => write!(f, "{:<18}:{}", "UTF8STRING", string.get().string())?,
+        Asn1Type::IA5String(string) => write!(f, "{:<18}:{}", "IA5STRING", string.get().string())?,
+        Asn1Type::PrintableString(string) => write!(f, "{:<18}:{}", "PRINTABLESTRING", string.get().string())?,
+        Asn1Type::NumericString(string) => write!(f, "{:<18}:{}", "NUMERICSTRING", string.get().string())?,
+        Asn1Type::VisibleString(string) => write!(f, "{:<18}:{}", "VISIBLESTRING", string.get().string())?,
+        Asn1Type::UtcTime(_) => {
+            write!(f, "{:<18}:", "UTCTIME")?;
+            write_latin1(f, &encoded_data(asn1, meta))?;
+        }
+        Asn1Type::GeneralizedTime(_) => {
+            write!(f, "{:<18}:", "GENERALIZEDTIME")?;
+            write_latin1(f, &encoded_data(asn1, meta))?;
+        }
+        Asn1Type::Bool(_) => {
+            write!(f, "{:<18}:", "BOOLEAN")?;
+            write!(f, "{}", encoded_data(asn1, meta).first().copied().unwrap_or_default())?;
+        }
+        Asn1Type::Null(_) => write!(f, "{:<18}", "NULL")?,
+        Asn1Type::Integer(integer) => {
+            write!(f, "{:<18}:", "INTEGER")?;
+            write_openssl_integer(f, integer.get().raw_data())?;
+        }
+        Asn1Type::Enumerated(enumerated) => {
+            write!(f, "{:<18}:", "ENUMERATED")?;
+            write_openssl_integer(f, enumerated.get().raw_data())?;
+        }
+        Asn1Type::ObjectIdentifier(oid) => write!(f, "{:<18}:{}", "OBJECT", oid.get().format())?,
+    }
+
+    f.write_char('\n')
+}
+
+fn write_openssl_nodes(f: &mut Formatter<'_>, nodes: &[Asn1], depth: usize) -> fmt::Result {
+    nodes.iter().try_for_each(|node| write_openssl_node(f, node, depth))
+}
+
+fn write_openssl_integer(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    let (negative, magnitude) = integer_magnitude(bytes);
+
+    if negative {
+        f.write_char('-')?;
+    }
+
+    if magnitude.is_empty() {
+        f.write_str("00")
+    } else {
+        write_hex(f, &magnitude, "")
+    }
+}
+
+/// `dumpasn1` compatible dump.
+///
+/// ```text
+///    0   13: SEQUENCE {
+///    2    1:   INTEGER 5
+///    5    8:   [0] {
+///    7    6:     UTF8String 'crypto'
+///          :     }
+///          :   }
+/// ```
+pub struct DumpAsn1<'a> {
+    trees: &'a [Asn1],
+}
+
+impl<'a> DumpAsn1<'a> {
+    pub fn new(trees: &'a [Asn1]) -> Self {
+        Self { trees }
+    }
+}
+
+impl Display for DumpAsn1<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.trees.iter().try_for_each(|tree| write_dumpasn1_node(f, tree, 0))
+    }
+}
+
+/// Prefix of the `dumpasn1` lines that do not start a new node.
+const DUMPASN1_CONTINUATION: &str = "         : ";
+
+fn write_dumpasn1_node(f: &mut Formatter<'_>, asn1: &Asn1, depth: usize) -> fmt::Result {
+    let meta = asn1.meta();
+
+    write!(
+        f,
+        "{:4} {:4}: {:indent$}",
+        meta.tag_position(),
+        meta.data.len(),
+        "",
+        indent = depth * 2
+    )?;
+
+    match asn1.inner_asn1() {
+        Asn1Type::Sequence(sequence) => write_dumpasn1_constructed(f, "SEQUENCE", sequence.get().fields(), depth),
+        Asn1Type::Set(set) => write_dumpasn1_constructed(f, "SET", set.get().fields(), depth),
+        Asn1Type::ExplicitTag(explicit) => {
+            let explicit = explicit.get();
+            write_dumpasn1_constructed(f, TagNumber("", explicit.tag_number()), explicit.inner(), depth)
+        }
+        Asn1Type::ApplicationTag(application) => {
+            let application = application.get();
+            write_dumpasn1_constructed(
+                f,
+                TagNumber("APPLICATION ", application.tag_number()),
+                application.inner(),
+                depth,
+            )
+        }
+        Asn1Type::ImplicitTag(implicit) => {
+            let implicit = implicit.get();
+            write_dumpasn1_octets(f, TagNumber("", implicit.tag_number()), implicit.octets(), depth)
+        }
+        Asn1Type::OctetString(octet_string) => {
+            let octet_string = octet_string.get();
+            match octet_string.inner().filter(|inner| !inner.get().is_empty()) {
+                Some(inner) => write_dumpasn1_constructed(f, "OCTET STRING, encapsulates", &inner.get(), depth),
+                None => write_dumpasn1_octets(f, "OCTET STRING", octet_string.octets(), depth),
+            }
+        }
+        Asn1Type::BitString(bit_string) => {
+            let bit_string = bit_string.get();
+            match bit_string.inner().filter(|inner| !inner.get().is_empty()) {
+                Some(inner) => write_dumpasn1_constructed(f, "BIT STRING, encapsulates", &inner.get(), depth),
+                None => {
+                    let (unused_bits, bits) = bit_string.raw_bits().split_first().unwrap_or((&0, &[]));
+                    if *unused_bits > 0 {
+                        write!(f, "BIT STRING {unused_bits} unused bits")?;
+                        write_dumpasn1_hex_lines(f, bits, depth)
+                    } else {
+                        write_dumpasn1_hex(f, "BIT STRING", bits, depth)
+                    }
+                }
+            }
+        }
+        Asn1Type::BmpString(bmp) => {
+            let bmp = bmp.get();
+            let units = bmp.raw_data().chunks(2).map(|unit| match unit {
+                [high, low] => u16::from_be_bytes([*high, *low]),
+                _ => u16::from(unit[0]),
+            });
+            f.write_str("BMPString '")?;
+            char::decode_utf16(units).try_for_each(|c| f.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER)))?;
+            f.write_str("'\n")
+        }
+        Asn1Type::Utf8String(string) => writeln!(f, "UTF8String '{}'", string.get().string()),
+        Asn1Type::IA5String(string) => writeln!(f, "IA5String '{}'", string.get().string()),
+        Asn1Type::PrintableString(string) => writeln!(f, "PrintableString '{}'", string.get().string()),
+        Asn1Type::GeneralString(string) => writeln!(f, "GeneralString '{}'", string.get().string()),
+        Asn1Type::NumericString(string) => writeln!(f, "NumericString '{}'", string.get().string()),
+        Asn1Type::VisibleString(string) => writeln!(f, "VisibleString '{}'", string.get().string()),
+        Asn1Type::UtcTime(utc_time) => {
+            let utc_time = utc_time.get();
+            let year = u16::from(u8::from(utc_time.year));
+            let year = if year < 50 { 2000 + year } else { 1900 + year };
+            writeln!(
+                f,
+                "UTCTime {}/{}/{} {}:{}:{:02} GMT",
+                utc_time.day,
+                utc_time.month,
+                year,
+                utc_time.hour,
+                utc_time.minute,
+                utc_time.second.map(u8::from).unwrap_or_default(),
+            )
+        }
+        Asn1Type::GeneralizedTime(generalized_time) => {
+            let generalized_time = generalized_time.get();
+            let second = f32::from(generalized_time.second);
+
+            if generalized_time.local_time.is_none() && second == (second as u8).into() {
+                writeln!(
+                    f,
+                    "GeneralizedTime {}/{}/{} {}:{}:{:02} GMT",
+                    generalized_time.day,
+                    generalized_time.month,
+                    generalized_time.year,
+                    generalized_time.hour,
+                    generalized_time.minute,
+                    second as u8,
+                )
+            } else {
+                f.write_str("GeneralizedTime '")?;
+                write_latin1(f, &encoded_data(asn1, meta))?;
+                f.write_str("'\n")
+            }
+        }
+        Asn1Type::Bool(boolean) => writeln!(f, "BOOLEAN {}", if boolean.get().value() { "TRUE" } else { "FALSE" }),
+        Asn1Type::Null(_) => writeln!(f, "NULL"),
+        Asn1Type::Integer(integer) => write_dumpasn1_integer(f, "INTEGER", integer.get().raw_data(), depth),
+        Asn1Type::Enumerated(enumerated) => write_dumpasn1_integer(f, "ENUMERATED", enumerated.get().raw_data(), depth),
+        Asn1Type::ObjectIdentifier(oid) => {
+            writeln!(f, "OBJECT IDENTIFIER '{}'", oid.get().format().replace('.', " "))
+        }
+    }
+}
+
+fn write_dumpasn1_constructed(f: &mut Formatter<'_>, name: impl Display, nodes: &[Asn1], depth: usize) -> fmt::Result {
+    writeln!(f, "{name} {{")?;
+
+    nodes
+        .iter()
+        .try_for_each(|node| write_dumpasn1_node(f, node, depth + 1))?;
+
+    writeln!(f, "{DUMPASN1_CONTINUATION}{:indent$}}}", "", indent = (depth + 1) * 2)
+}
+
+fn write_dumpasn1_integer(f: &mut Formatter<'_>, name: &str, bytes: &[u8], depth: usize) -> fmt::Result {
+    // `dumpasn1` prints small integers as decimal numbers.
+    if bytes.len() <= 4 {
+        let value = bytes.iter().fold(
+            if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+                -1
+            } else {
+                0
+            },
+            |value: i64, byte| (value << 8) | i64::from(*byte),
+        );
+
+        writeln!(f, "{name} {value}")
+    } else {
+        f.write_str(name)?;
+        write_dumpasn1_hex_lines(f, bytes, depth)
+    }
+}
+
+fn write_dumpasn1_octets(f: &mut Formatter<'_>, name: impl Display, octets: &[u8], depth: usize) -> fmt::Result {
+    if !octets.is_empty() && octets.iter().all(|byte| is_printable(*byte)) {
+        write!(f, "{name} '")?;
+        write_latin1(f, octets)?;
+        f.write_str("'\n")
+    } else {
+        write_dumpasn1_hex(f, name, octets, depth)
+    }
+}
+
+fn write_dumpasn1_hex(f: &mut Formatter<'_>, name: impl Display, bytes: &[u8], depth: usize) -> fmt::Result {
+    if bytes.len() <= HEX_LINE_LEN / 2 {
+        write!(f, "{name} ")?;
+        write_hex(f, bytes, " ")?;
+        f.write_char('\n')
+    } else {
+        write!(f, "{name}")?;
+        write_dumpasn1_hex_lines(f, bytes, depth)
+    }
+}
+
+/// Finishes the current line and writes the bytes on the continuation lines.
+fn write_dumpasn1_hex_lines(f: &mut Formatter<'_>, bytes: &[u8], depth: usize) -> fmt::Result {
+    f.write_char('\n')?;
+
+    for line in bytes.chunks(HEX_LINE_LEN) {
+        write!(f, "{DUMPASN1_CONTINUATION}{:indent$}", "", indent = (depth + 1) * 2)?;
+        write_hex(f, line, " ")?;
+        f.write_char('\n')?;
+    }
+
+    Ok(())
+}
+
+struct ContextTagName(&'static str, u8);
+
+impl Display for ContextTagName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut name = alloc::string::String::new();
+        write!(name, "{} [ {} ]", self.0, self.1)?;
+
+        // Pad like any other tag name.
+        f.pad(&name)
+    }
+}
+
+struct TagNumber(&'static str, u8);
+
+impl Display for TagNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}{}]", self.0, self.1)
+    }
+}
+
+/// Returns the node data bytes. The node is encoded if it does not have the raw data.
+pub(crate) fn encoded_data(asn1: &Asn1, meta: &RawAsn1EntityData) -> Vec<u8> {
+    if !meta.raw_bytes().is_empty() {
+        return meta.data_bytes().to_vec();
+    }
+
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    if asn1.encode_buff(&mut buff).is_err() {
+        return Vec::new();
+    }
+
+    match Asn1::decode_buff(&buff) {
+        Ok(decoded) => decoded.meta().data_bytes().to_vec(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Splits the two's complement integer into the sign and magnitude without leading zeros.
+fn integer_magnitude(bytes: &[u8]) -> (bool, Vec<u8>) {
+    let negative = bytes.first().is_some_and(|byte| byte & 0x80 != 0);
+    let mut magnitude = bytes.to_vec();
+
+    if negative {
+        magnitude.iter_mut().for_each(|byte| *byte = !*byte);
+
+        for byte in magnitude.iter_mut().rev() {
+            let (value, overflow) = byte.overflowing_add(1);
+            *byte = value;
+
+            if !overflow {
+                break;
+            }
+        }
+    }
+
+    let leading_zeros = magnitude.iter().take_while(|byte| **byte == 0).count();
+    magnitude.drain(..leading_zeros);
+
+    (negative, magnitude)
+}
+
+pub(crate) fn is_printable(byte: u8) -> bool {
+    (b' '..=b'~').contains(&byte) || matches!(byte, b'\n' | b'\r' | b'\t')
+}
+
+fn write_latin1(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| f.write_char(char::from(*byte)))
+}
+
+fn write_hex(f: &mut Formatter<'_>, bytes: &[u8], separator: &str) -> fmt::Result {
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{byte:02X}")?;
+    }
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use alloc::string::FromUtf16Error;
+use alloc::vec::Vec;
+use core::fmt;
 use core::num::{ParseFloatError, ParseIntError, TryFromIntError};
 use core::str::Utf8Error;
 
@@ -7,62 +9,86 @@ use oid::ObjectIdentifierError;
 #[derive(Debug)]
 pub struct Error {
     message: &'static str,
+    location: Vec<&'static str>,
 }
 
 impl Error {
     pub fn message(&self) -> &str {
         self.message
     }
+
+    /// Adds one more (outer) location to the error path.
+    ///
+    /// It's used by the typed decoding to point to the exact field that failed: `Outer::field -> Inner::field`.
+    pub fn at(mut self, location: &'static str) -> Self {
+        self.location.push(location);
+        self
+    }
+
+    /// Returns error location starting from the outermost entity.
+    pub fn location(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.location.iter().rev().copied()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, location) in self.location().enumerate() {
+            if i > 0 {
+                f.write_str(" -> ")?;
+            }
+            f.write_str(location)?;
+        }
+
+        if !self.location.is_empty() {
+            f.write_str(": ")?;
+        }
+
+        f.write_str(self.message)
+    }
 }
 
 impl From<&'static str> for Error {
     fn from(message: &'static str) -> Self {
-        Self { message }
+        Self {
+            message,
+            location: Vec::new(),
+        }
     }
 }
 
 impl From<Utf8Error> for Error {
     fn from(_value: Utf8Error) -> Self {
-        Self { message: "Utf8error" }
+        Self::from("Utf8error")
     }
 }
 
 impl From<FromUtf16Error> for Error {
     fn from(_value: FromUtf16Error) -> Self {
-        Self {
-            message: "FromUtf16Error",
-        }
+        Self::from("FromUtf16Error")
     }
 }
 
 impl From<TryFromIntError> for Error {
     fn from(_value: TryFromIntError) -> Self {
-        Self {
-            message: "Numbers conversion error",
-        }
+        Self::from("Numbers conversion error")
     }
 }
 
 impl From<ObjectIdentifierError> for Error {
     fn from(_value: ObjectIdentifierError) -> Self {
-        Self {
-            message: "ObjectIdentifierError",
-        }
+        Self::from("ObjectIdentifierError")
     }
 }
 
 impl From<ParseFloatError> for Error {
     fn from(_value: ParseFloatError) -> Self {
-        Self {
-            message: "Float parse error",
-        }
+        Self::from("Float parse error")
     }
 }
 
 impl From<ParseIntError> for Error {
     fn from(_value: ParseIntError) -> Self {
-        Self {
-            message: "Int parse error",
-        }
+        Self::from("Int parse error")
     }
 }
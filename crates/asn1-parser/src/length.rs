@@ -7,6 +7,9 @@ use crate::{Asn1Result, Error};
 
 const USIZE_LEN: usize = size_of::<usize>();
 
+/// The length byte of the indefinite-length constructed node (X.690 8.1.3.6).
+pub const INDEFINITE_LEN: u8 = 0x80;
+
 /// Reads length from the reader
 pub fn read_len(reader: &mut Reader) -> Asn1Result<(usize, Range<usize>)> {
     let before = reader.position();
@@ -31,6 +34,35 @@ pub fn read_len(reader: &mut Reader) -> Asn1Result<(usize, Range<usize>)> {
     Ok((length, before..after))
 }
 
+/// Returns the contents length of the indefinite-length node: the amount of bytes before its end-of-contents octets.
+///
+/// `data` starts right after the indefinite length byte.
+pub fn indefinite_len(data: &[u8]) -> Asn1Result<usize> {
+    let mut reader = Reader::new(data);
+
+    loop {
+        let position = reader.position();
+        let tag = reader.read_byte()?;
+
+        if tag == 0 {
+            if reader.read_byte()? != 0 {
+                return Err(Error::from("Invalid end-of-contents octets"));
+            }
+
+            return Ok(position);
+        }
+
+        if tag & 0x20 != 0 && reader.peek_byte()? == INDEFINITE_LEN {
+            reader.read_byte()?;
+            let len = indefinite_len(reader.remaining())?;
+            reader.read(len + 2)?;
+        } else {
+            let (len, _) = read_len(&mut reader)?;
+            reader.read(len)?;
+        }
+    }
+}
+
 /// Writes asn1 length into provided writer
 pub fn write_len(length: usize, writer: &mut Writer) -> Asn1Result<()> {
     if length < 128 {
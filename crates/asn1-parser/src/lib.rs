@@ -2,6 +2,8 @@
 
 extern crate alloc;
 
+use core::ops::Range;
+
 #[macro_use]
 mod macros;
 
@@ -10,20 +12,31 @@ mod macros;
 extern crate tracing;
 
 mod asn1;
+pub mod builder;
 mod constructors;
+pub mod der_ascii;
+pub mod diff;
+pub mod dump;
 mod error;
 mod length;
 mod mutable;
+pub mod oid_registry;
+pub mod preserving;
 mod primitives;
 mod reader;
+pub mod refresh;
+pub mod stats;
 mod string;
 mod tag;
 mod tags;
 mod time;
 mod tlv;
+pub mod typed;
 mod writer;
 
 pub use asn1::{Asn1, Asn1Type, RawAsn1EntityData};
+#[cfg(feature = "derive")]
+pub use asn1_derive::{Asn1Decode, Asn1Encode};
 pub use constructors::*;
 pub use error::Error;
 pub use mutable::{IntoMutable, Mutable};
@@ -34,6 +47,7 @@ pub use tag::Tag;
 pub use tags::*;
 pub use time::*;
 pub use tlv::Tlv;
+pub use typed::{Asn1Decode, Asn1Encode};
 use writer::Writer;
 
 pub type Asn1Result<T> = Result<T, Error>;
@@ -65,6 +79,30 @@ pub fn decode_buff_vec(buff: &[u8]) -> Asn1Result<Vec<Asn1>> {
     Ok(trees)
 }
 
+/// Decodes the provided parts of the buffer into the vectors of asn1 trees (one vector per part).
+///
+/// Node positions are relative to the whole buffer and node ids are unique across all parts. It is useful
+/// when the asn1 messages are separated by non-asn1 data (e.g. length-prefixed framing).
+pub fn decode_buff_ranges(buff: &[u8], ranges: &[Range<usize>]) -> Asn1Result<Vec<Vec<Asn1>>> {
+    let mut last_id = 0;
+
+    ranges
+        .iter()
+        .map(|range| {
+            let data = buff.get(range.clone()).ok_or_else(|| Error::from("Invalid range"))?;
+
+            let mut reader = Reader::new(data);
+            reader.set_offset(range.start);
+            reader.set_next_id(last_id);
+
+            let trees = decode_reader_vec(&mut reader)?;
+            last_id = reader.last_id();
+
+            Ok(trees)
+        })
+        .collect()
+}
+
 /// Decodes the provided reader into the vector of asn1 trees.
 pub fn decode_reader_vec(reader: &mut Reader<'_>) -> Asn1Result<Vec<Asn1>> {
     let mut trees = Vec::new();
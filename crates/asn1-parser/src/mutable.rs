@@ -1,51 +1,81 @@
 use alloc::rc::Rc;
-use core::cell::{Ref, RefCell, RefMut};
+use core::cell::{Cell, Ref, RefCell, RefMut};
 
 use crate::writer::Writer;
 use crate::{Asn1Encoder, Asn1Result, MetaInfo, Tag, Taggable};
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct Mutable<T>(Rc<RefCell<T>>);
+/// Shared mutable value. It remembers whether the value was borrowed mutably (modified) since its creation.
+#[derive(Debug)]
+pub struct Mutable<T> {
+    value: Rc<RefCell<T>>,
+    modified: Rc<Cell<bool>>,
+}
 
 impl<T> Mutable<T> {
     pub fn new(value: T) -> Self {
-        Mutable(Rc::new(RefCell::new(value)))
+        Mutable {
+            value: Rc::new(RefCell::new(value)),
+            modified: Rc::new(Cell::new(false)),
+        }
     }
 
     pub fn get(&self) -> Ref<'_, T> {
-        self.0.borrow()
+        self.value.borrow()
     }
 
+    /// Borrows the value mutably and marks it as modified.
     pub fn get_mut(&self) -> RefMut<'_, T> {
-        self.0.borrow_mut()
+        self.modified.set(true);
+        self.value.borrow_mut()
+    }
+
+    /// Returns `true` if the value was borrowed mutably.
+    pub fn is_modified(&self) -> bool {
+        self.modified.get()
+    }
+
+    pub(crate) fn modified_flag(&self) -> &Cell<bool> {
+        &self.modified
     }
 }
 
 impl<T> Clone for Mutable<T> {
     fn clone(&self) -> Self {
-        Mutable(Clone::clone(&self.0))
+        Mutable {
+            value: Rc::clone(&self.value),
+            modified: Rc::clone(&self.modified),
+        }
     }
 }
 
+/// Only the values are compared. The modification flag is ignored.
+impl<T: PartialEq> PartialEq for Mutable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Mutable<T> {}
+
 impl<T: Taggable> Taggable for Mutable<T> {
     fn tag(&self) -> Tag {
-        self.0.borrow().tag()
+        self.value.borrow().tag()
     }
 }
 
 impl<T: Asn1Encoder> Asn1Encoder for Mutable<T> {
     fn needed_buf_size(&self) -> usize {
-        self.0.borrow().needed_buf_size()
+        self.value.borrow().needed_buf_size()
     }
 
     fn encode(&self, writer: &mut Writer) -> Asn1Result<()> {
-        self.0.borrow().encode(writer)
+        self.value.borrow().encode(writer)
     }
 }
 
 impl<T: MetaInfo> MetaInfo for Mutable<T> {
     fn clear_meta(&mut self) {
-        self.0.borrow_mut().clear_meta()
+        self.value.borrow_mut().clear_meta()
     }
 }
 
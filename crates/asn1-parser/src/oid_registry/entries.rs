@@ -0,0 +1,6361 @@
+//! Built-in object identifiers sorted by their dotted representation.
+//!
+//! Generated by `scripts/generate_oids.py` from the `oid-registry`, OpenSSL, Botan, and `const-oid` databases,
+//! and the Windows SDK object identifiers on top of the hand-written entries.
+
+use super::OidInfo;
+
+pub(super) static OIDS: &[OidInfo<'static>] = &[
+    OidInfo::new("0.3.4401.5.3.1.9.1", "CAMELLIA-128-ECB", "camellia-128-ecb"),
+    OidInfo::new("0.3.4401.5.3.1.9.10", "CAMELLIA-128-CMAC", "camellia-128-cmac"),
+    OidInfo::new("0.3.4401.5.3.1.9.21", "CAMELLIA-192-ECB", "camellia-192-ecb"),
+    OidInfo::new("0.3.4401.5.3.1.9.23", "CAMELLIA-192-OFB", "camellia-192-ofb"),
+    OidInfo::new("0.3.4401.5.3.1.9.24", "CAMELLIA-192-CFB", "camellia-192-cfb"),
+    OidInfo::new("0.3.4401.5.3.1.9.26", "CAMELLIA-192-GCM", "camellia-192-gcm"),
+    OidInfo::new("0.3.4401.5.3.1.9.27", "CAMELLIA-192-CCM", "camellia-192-ccm"),
+    OidInfo::new("0.3.4401.5.3.1.9.29", "CAMELLIA-192-CTR", "camellia-192-ctr"),
+    OidInfo::new("0.3.4401.5.3.1.9.3", "CAMELLIA-128-OFB", "camellia-128-ofb"),
+    OidInfo::new("0.3.4401.5.3.1.9.30", "CAMELLIA-192-CMAC", "camellia-192-cmac"),
+    OidInfo::new("0.3.4401.5.3.1.9.4", "CAMELLIA-128-CFB", "camellia-128-cfb"),
+    OidInfo::new("0.3.4401.5.3.1.9.41", "CAMELLIA-256-ECB", "camellia-256-ecb"),
+    OidInfo::new("0.3.4401.5.3.1.9.43", "CAMELLIA-256-OFB", "camellia-256-ofb"),
+    OidInfo::new("0.3.4401.5.3.1.9.44", "CAMELLIA-256-CFB", "camellia-256-cfb"),
+    OidInfo::new("0.3.4401.5.3.1.9.46", "CAMELLIA-256-GCM", "camellia-256-gcm"),
+    OidInfo::new("0.3.4401.5.3.1.9.47", "CAMELLIA-256-CCM", "camellia-256-ccm"),
+    OidInfo::new("0.3.4401.5.3.1.9.49", "CAMELLIA-256-CTR", "camellia-256-ctr"),
+    OidInfo::new("0.3.4401.5.3.1.9.50", "CAMELLIA-256-CMAC", "camellia-256-cmac"),
+    OidInfo::new("0.3.4401.5.3.1.9.6", "CAMELLIA-128-GCM", "camellia-128-gcm"),
+    OidInfo::new("0.3.4401.5.3.1.9.7", "CAMELLIA-128-CCM", "camellia-128-ccm"),
+    OidInfo::new("0.3.4401.5.3.1.9.9", "CAMELLIA-128-CTR", "camellia-128-ctr"),
+    OidInfo::new("0.4", "itu-t-identified-organization", "itu-t-identified-organization"),
+    OidInfo::new("0.4.0", "etsi", "etsi"),
+    OidInfo::new("0.4.0.127.0.15.1.1.13.0", "XMSS", "XMSS (pubkey)"),
+    OidInfo::new(
+        "0.4.0.1733",
+        "electronic-signature-standard",
+        "electronic-signature-standard",
+    ),
+    OidInfo::new("0.4.0.1733.2", "ess-attributes", "ess-attributes"),
+    OidInfo::new("0.4.0.1733.2.1", "id-aa-ets-mimeType", "id-aa-ets-mimeType"),
+    OidInfo::new(
+        "0.4.0.1733.2.2",
+        "id-aa-ets-longTermValidation",
+        "id-aa-ets-longTermValidation",
+    ),
+    OidInfo::new(
+        "0.4.0.1733.2.3",
+        "id-aa-ets-SignaturePolicyDocument",
+        "id-aa-ets-SignaturePolicyDocument",
+    ),
+    OidInfo::new(
+        "0.4.0.1733.2.4",
+        "id-aa-ets-archiveTimestampV3",
+        "id-aa-ets-archiveTimestampV3",
+    ),
+    OidInfo::new("0.4.0.1733.2.5", "id-aa-ATSHashIndex", "id-aa-ATSHashIndex"),
+    OidInfo::new(
+        "0.4.0.1862.1.1",
+        "szOID_QC_EU_COMPLIANCE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("0.4.0.1862.1.4", "szOID_QC_SSCD", "Windows SDK object identifier"),
+    OidInfo::new("0.4.0.19122", "cades", "cades"),
+    OidInfo::new("0.4.0.19122.1", "cades-attributes", "cades-attributes"),
+    OidInfo::new("0.4.0.19122.1.1", "id-aa-ets-signerAttrV2", "id-aa-ets-signerAttrV2"),
+    OidInfo::new(
+        "0.4.0.19122.1.3",
+        "id-aa-ets-sigPolicyStore",
+        "id-aa-ets-sigPolicyStore",
+    ),
+    OidInfo::new("0.4.0.19122.1.4", "id-aa-ATSHashIndex-v2", "id-aa-ATSHashIndex-v2"),
+    OidInfo::new("0.4.0.19122.1.5", "id-aa-ATSHashIndex-v3", "id-aa-ATSHashIndex-v3"),
+    OidInfo::new("0.4.0.19122.1.6", "signedAssertion", "signedAssertion"),
+    OidInfo::new("0.9", "data", "data"),
+    OidInfo::new("0.9.2342", "pss", "pss"),
+    OidInfo::new("0.9.2342.19200300", "ucl", "ucl"),
+    OidInfo::new("0.9.2342.19200300.100", "pilot", "pilot"),
+    OidInfo::new("0.9.2342.19200300.100.1", "pilotAttributeType", "pilotAttributeType"),
+    OidInfo::new("0.9.2342.19200300.100.1.1", "userId", "User identifier (uid)"),
+    OidInfo::new("0.9.2342.19200300.100.1.10", "manager", "Manager of the object"),
+    OidInfo::new("0.9.2342.19200300.100.1.11", "documentIdentifier", "documentIdentifier"),
+    OidInfo::new("0.9.2342.19200300.100.1.12", "documentTitle", "documentTitle"),
+    OidInfo::new("0.9.2342.19200300.100.1.13", "documentVersion", "documentVersion"),
+    OidInfo::new("0.9.2342.19200300.100.1.14", "documentAuthor", "documentAuthor"),
+    OidInfo::new("0.9.2342.19200300.100.1.15", "documentLocation", "documentLocation"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.2",
+        "textEncodedORAddress",
+        "textEncodedORAddress",
+    ),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.20",
+        "homeTelephoneNumber",
+        "homeTelephoneNumber",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.21", "secretary", "secretary"),
+    OidInfo::new("0.9.2342.19200300.100.1.22", "otherMailbox", "otherMailbox"),
+    OidInfo::new("0.9.2342.19200300.100.1.23", "lastModifiedTime", "lastModifiedTime"),
+    OidInfo::new("0.9.2342.19200300.100.1.24", "lastModifiedBy", "lastModifiedBy"),
+    OidInfo::new("0.9.2342.19200300.100.1.25", "domainComponent", "Domain component (dc)"),
+    OidInfo::new("0.9.2342.19200300.100.1.26", "aRecord", "aRecord"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.27",
+        "pilotAttributeType27",
+        "pilotAttributeType27",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.28", "mXRecord", "mXRecord"),
+    OidInfo::new("0.9.2342.19200300.100.1.29", "nSRecord", "nSRecord"),
+    OidInfo::new("0.9.2342.19200300.100.1.3", "rfc822Mailbox", "Mail address (mail)"),
+    OidInfo::new("0.9.2342.19200300.100.1.30", "sOARecord", "sOARecord"),
+    OidInfo::new("0.9.2342.19200300.100.1.31", "cNAMERecord", "cNAMERecord"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.37",
+        "associatedDomain",
+        "Associated DNS domain",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.38", "associatedName", "associatedName"),
+    OidInfo::new("0.9.2342.19200300.100.1.39", "homePostalAddress", "homePostalAddress"),
+    OidInfo::new("0.9.2342.19200300.100.1.4", "info", "General information"),
+    OidInfo::new("0.9.2342.19200300.100.1.40", "personalTitle", "personalTitle"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.41",
+        "mobileTelephoneNumber",
+        "mobileTelephoneNumber",
+    ),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.42",
+        "pagerTelephoneNumber",
+        "pagerTelephoneNumber",
+    ),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.43",
+        "friendlyCountryName",
+        "friendlyCountryName",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.44", "uid", "uniqueIdentifier"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.45",
+        "organizationalStatus",
+        "organizationalStatus",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.46", "janetMailbox", "janetMailbox"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.47",
+        "mailPreferenceOption",
+        "mailPreferenceOption",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.48", "buildingName", "buildingName"),
+    OidInfo::new("0.9.2342.19200300.100.1.49", "dSAQuality", "dSAQuality"),
+    OidInfo::new("0.9.2342.19200300.100.1.5", "drink", "Favourite drink"),
+    OidInfo::new("0.9.2342.19200300.100.1.50", "singleLevelQuality", "singleLevelQuality"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.51",
+        "subtreeMinimumQuality",
+        "subtreeMinimumQuality",
+    ),
+    OidInfo::new(
+        "0.9.2342.19200300.100.1.52",
+        "subtreeMaximumQuality",
+        "subtreeMaximumQuality",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.1.53", "personalSignature", "personalSignature"),
+    OidInfo::new("0.9.2342.19200300.100.1.54", "dITRedirect", "dITRedirect"),
+    OidInfo::new("0.9.2342.19200300.100.1.55", "audio", "audio"),
+    OidInfo::new("0.9.2342.19200300.100.1.56", "documentPublisher", "documentPublisher"),
+    OidInfo::new("0.9.2342.19200300.100.1.6", "roomNumber", "roomNumber"),
+    OidInfo::new("0.9.2342.19200300.100.1.60", "jpegPhoto", "Defined in RFC 2798"),
+    OidInfo::new("0.9.2342.19200300.100.1.7", "photo", "photo"),
+    OidInfo::new("0.9.2342.19200300.100.1.8", "userClass", "userClass"),
+    OidInfo::new("0.9.2342.19200300.100.1.9", "host", "host"),
+    OidInfo::new("0.9.2342.19200300.100.10", "pilotGroups", "pilotGroups"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.3",
+        "pilotAttributeSyntax",
+        "pilotAttributeSyntax",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.3.4", "iA5StringSyntax", "iA5StringSyntax"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.3.5",
+        "caseIgnoreIA5StringSyntax",
+        "caseIgnoreIA5StringSyntax",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.4", "pilotObjectClass", "pilotObjectClass"),
+    OidInfo::new("0.9.2342.19200300.100.4.13", "domain", "Domain"),
+    OidInfo::new("0.9.2342.19200300.100.4.14", "rFC822localPart", "rFC822localPart"),
+    OidInfo::new("0.9.2342.19200300.100.4.15", "dNSDomain", "dNSDomain"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.4.17",
+        "domainRelatedObject",
+        "domainRelatedObject",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.4.18", "friendlyCountry", "friendlyCountry"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.4.19",
+        "simpleSecurityObject",
+        "simpleSecurityObject",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.4.20", "pilotOrganization", "pilotOrganization"),
+    OidInfo::new("0.9.2342.19200300.100.4.21", "pilotDSA", "pilotDSA"),
+    OidInfo::new(
+        "0.9.2342.19200300.100.4.22",
+        "qualityLabelledData",
+        "qualityLabelledData",
+    ),
+    OidInfo::new("0.9.2342.19200300.100.4.3", "pilotObject", "pilotObject"),
+    OidInfo::new("0.9.2342.19200300.100.4.4", "pilotPerson", "pilotPerson"),
+    OidInfo::new("0.9.2342.19200300.100.4.5", "account", "account"),
+    OidInfo::new("0.9.2342.19200300.100.4.6", "document", "document"),
+    OidInfo::new("0.9.2342.19200300.100.4.7", "room", "room"),
+    OidInfo::new("0.9.2342.19200300.100.4.9", "documentSeries", "documentSeries"),
+    OidInfo::new("1.0", "IMAPI2FS_FullVersion_STR", "Windows SDK object identifier"),
+    OidInfo::new("1.0.10118.3.0.55", "whirlpool", "whirlpool"),
+    OidInfo::new("1.0.14888.3.0.5", "ECKCDSA", "ECKCDSA (pubkey)"),
+    OidInfo::new("1.0.9797.3.4", "GMAC", "gmac"),
+    OidInfo::new("1.2", "member-body", "ISO Member Body"),
+    OidInfo::new("1.2.112.0.2.0.34.101.31", "belt", "Defined in STB 34.101 (belt)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.101",
+        "belt-keyrep",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.11",
+        "belt-ecb128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.111",
+        "belt-bde128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.112",
+        "belt-bde192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.113",
+        "belt-bde256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.12",
+        "belt-ecb192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.121",
+        "belt-sde128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.122",
+        "belt-sde192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.123",
+        "belt-sde256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.13",
+        "belt-ecb256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.131",
+        "belt-fmt128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.132",
+        "belt-fmt192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.133",
+        "belt-fmt256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.21",
+        "belt-cbc128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.22",
+        "belt-cbc192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.23",
+        "belt-cbc256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.3",
+        "belt-block128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.31",
+        "belt-cfb128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.32",
+        "belt-cfb192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.33",
+        "belt-cfb256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.4",
+        "belt-block192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.41",
+        "belt-ctr128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.42",
+        "belt-ctr192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.43",
+        "belt-ctr256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.5",
+        "belt-block256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.51",
+        "belt-mac128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.52",
+        "belt-mac192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.53",
+        "belt-mac256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.6",
+        "belt-wblock128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.61",
+        "belt-dwp128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.62",
+        "belt-dwp192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.63",
+        "belt-dwp256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.64",
+        "belt-che128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.65",
+        "belt-che192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.66",
+        "belt-che256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.7",
+        "belt-wblock192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.71",
+        "belt-kwp128",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.72",
+        "belt-kwp192",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.73",
+        "belt-kwp256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.8",
+        "belt-wblock256",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.81",
+        "belt-hash",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.9",
+        "belt-compress",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.31.91",
+        "belt-keyexpand",
+        "Defined in STB 34.101 (belt)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.45", "bign", "Defined in STB 34.101 (bign)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.11",
+        "bign-with-hspec",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.12",
+        "bign-with-hbelt",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.45.2", "bign-keys", "Defined in STB 34.101 (bign)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.2.1",
+        "bign-pubkey",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.21",
+        "bign-genec",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.22",
+        "bign-valec",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.3",
+        "bign-curves",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.3.1",
+        "bign-curve256v1",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.3.2",
+        "bign-curve384v1",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.3.3",
+        "bign-curve512v1",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.31",
+        "bign-genkeypair",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.32",
+        "bign-valpubkey",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.4",
+        "bign-fields",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.4.1",
+        "bign-primefield",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.41",
+        "bign-keytransport",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.61",
+        "bign-genk",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.71",
+        "bign-ibs-with-hspec",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.45.72",
+        "bign-ibs-with-hbelt",
+        "Defined in STB 34.101 (bign)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.47", "brng", "Defined in STB 34.101 (brng)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.11",
+        "hmac-hspec",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.111",
+        "hotp-hspec",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.112",
+        "hotp-hbelt",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.12",
+        "hmac-hbelt",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.121",
+        "totp-hspec",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.122",
+        "totp-hbelt",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.131",
+        "ocra-hspec",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.132",
+        "ocra-hbelt",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.21",
+        "brng-ctr-hspec",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.22",
+        "brng-ctr-hbelt",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.23",
+        "brng-ctr-stb11761",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.31",
+        "brng-hmac-hspec",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.47.32",
+        "brng-hmac-hbelt",
+        "Defined in STB 34.101 (brng)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.66", "bake", "Defined in STB 34.101 (bake)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.66.101",
+        "bake-kdf",
+        "Defined in STB 34.101 (bake)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.66.11",
+        "bake-bmqv",
+        "Defined in STB 34.101 (bake)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.66.12",
+        "bake-bsts",
+        "Defined in STB 34.101 (bake)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.66.2", "bake-keys", "Defined in STB 34.101 (bake)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.66.2.1",
+        "bake-pubkey",
+        "Defined in STB 34.101 (bake)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.66.201",
+        "bake-swu",
+        "Defined in STB 34.101 (bake)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.66.21",
+        "bake-bpace",
+        "Defined in STB 34.101 (bake)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.66.31", "bake-dh", "Defined in STB 34.101 (bake)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.77", "bash", "Defined in STB 34.101 (bash)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.77.101", "bash-f", "Defined in STB 34.101 (bash)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.77.11", "bash256", "Defined in STB 34.101 (bash)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.77.12", "bash384", "Defined in STB 34.101 (bash)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.77.13", "bash512", "Defined in STB 34.101 (bash)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.21",
+        "bash-prg-hash2561",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.22",
+        "bash-prg-hash2562",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.23",
+        "bash-prg-hash3841",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.24",
+        "bash-prg-hash3842",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.25",
+        "bash-prg-hash5121",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.26",
+        "bash-prg-hash5122",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.31",
+        "bash-prg-ae1281",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.32",
+        "bash-prg-ae1282",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.33",
+        "bash-prg-ae1921",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.34",
+        "bash-prg-ae1922",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.35",
+        "bash-prg-ae2561",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.77.36",
+        "bash-prg-ae2562",
+        "Defined in STB 34.101 (bash)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.78", "bpki", "Defined in STB 34.101 (bpki)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.78.2", "bpki-role", "Defined in STB 34.101 (bpki)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.0",
+        "bpki-role-ca0",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.1",
+        "bpki-role-ca1",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.10",
+        "bpki-role-aa",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.2",
+        "bpki-role-ca2",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.20",
+        "bpki-role-ra",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.30",
+        "bpki-role-ocsp",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.31",
+        "bpki-role-tsa",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.32",
+        "bpki-role-dvcs",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.33",
+        "bpki-role-ids",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.50",
+        "bpki-role-tls",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.60",
+        "bpki-role-np",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.61",
+        "bpki-role-fnp",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.62",
+        "bpki-role-lr",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.2.70",
+        "bpki-role-acd",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.78.3", "bpki-eku", "Defined in STB 34.101 (bpki)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.3.1",
+        "bpki-eku-serverTM",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.3.2",
+        "bpki-eku-clientTM",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.78.4", "bpki-at", "Defined in STB 34.101 (bpki)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.78.5", "bpki-ct", "Defined in STB 34.101 (bpki)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.1",
+        "bpki-ct-enroll1-req",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.2",
+        "bpki-ct-enroll2-req",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.3",
+        "bpki-ct-reenroll-req",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.4",
+        "bpki-ct-spawn-req",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.5",
+        "bpki-ct-setpwd-req",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.6",
+        "bpki-ct-revoke-req",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.78.5.7",
+        "bpki-ct-resp",
+        "Defined in STB 34.101 (bpki)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.79", "btok", "Defined in STB 34.101 (btok)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.11",
+        "btok-bauth",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.12",
+        "btok-bauth1",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.5",
+        "btok-attrs",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.5.1",
+        "id-DocumentValidity",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.5.2",
+        "id-AgeVerification",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.5.3",
+        "id-PlaceVerification",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.6",
+        "btok-access",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.6.1",
+        "id-eIdAccess",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.6.2",
+        "id-eSignAccess",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new("1.2.112.0.2.0.34.101.79.7", "btok-apps", "Defined in STB 34.101 (btok)"),
+    OidInfo::new("1.2.112.0.2.0.34.101.79.7.1", "id-eID", "Defined in STB 34.101 (btok)"),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.7.2",
+        "id-eSign",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.8",
+        "btok-cvext",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new(
+        "1.2.112.0.2.0.34.101.79.8.1",
+        "id-SignAuthExt",
+        "Defined in STB 34.101 (btok)",
+    ),
+    OidInfo::new("1.2.156", "ISO-CN", "ISO CN Member Body"),
+    OidInfo::new("1.2.156.10197", "oscca", "oscca"),
+    OidInfo::new("1.2.156.10197.1", "sm-scheme", "sm-scheme"),
+    OidInfo::new("1.2.156.10197.1.104.1", "SM4-ECB", "sm4-ecb"),
+    OidInfo::new("1.2.156.10197.1.104.10", "SM4-XTS", "sm4-xts"),
+    OidInfo::new("1.2.156.10197.1.104.100", "SM4/OCB", "SM4/OCB (cipher)"),
+    OidInfo::new("1.2.156.10197.1.104.2", "SM4-CBC", "sm4-cbc"),
+    OidInfo::new("1.2.156.10197.1.104.3", "SM4-OFB", "sm4-ofb"),
+    OidInfo::new("1.2.156.10197.1.104.4", "SM4-CFB", "sm4-cfb"),
+    OidInfo::new("1.2.156.10197.1.104.5", "SM4-CFB1", "sm4-cfb1"),
+    OidInfo::new("1.2.156.10197.1.104.6", "SM4-CFB8", "sm4-cfb8"),
+    OidInfo::new("1.2.156.10197.1.104.7", "SM4-CTR", "sm4-ctr"),
+    OidInfo::new("1.2.156.10197.1.104.8", "SM4-GCM", "sm4-gcm"),
+    OidInfo::new("1.2.156.10197.1.104.9", "SM4-CCM", "sm4-ccm"),
+    OidInfo::new("1.2.156.10197.1.301", "sm2", "SM2 elliptic curve"),
+    OidInfo::new("1.2.156.10197.1.301.1", "SM2_Sig", "SM2_Sig (pubkey)"),
+    OidInfo::new("1.2.156.10197.1.301.2", "SM2_Kex", "SM2_Kex (pubkey)"),
+    OidInfo::new("1.2.156.10197.1.301.3", "SM2_Enc", "SM2_Enc (pubkey)"),
+    OidInfo::new("1.2.156.10197.1.401", "sm3", "SM3 hash"),
+    OidInfo::new("1.2.156.10197.1.401.3.1", "hmacWithSM3", "hmacWithSM3"),
+    OidInfo::new("1.2.156.10197.1.501", "SM2-with-SM3", "SM2 signature with SM3"),
+    OidInfo::new("1.2.156.10197.1.504", "RSA-SM3", "sm3WithRSAEncryption"),
+    OidInfo::new(
+        "1.2.156.11235.1.1.1",
+        "szOID_CN_ECDSA_SHA256",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.156.11235.1.1.2.1",
+        "szOID_ECC_CURVE_EC192WAPI",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.2.250.1.223.101.256.1", "frp256v1", "frp256v1 (ecc_param)"),
+    OidInfo::new("1.2.36.79672281.1.13.2", "componentFilterMatch", "Defined in RFC 3687"),
+    OidInfo::new("1.2.36.79672281.1.13.3", "rdnMatch", "Defined in RFC 3687"),
+    OidInfo::new("1.2.36.79672281.1.13.5", "presentMatch", "Defined in RFC 3687"),
+    OidInfo::new("1.2.36.79672281.1.13.6", "allComponentsMatch", "Defined in RFC 3687"),
+    OidInfo::new(
+        "1.2.392.200011.61.1.1.1.2",
+        "camellia128-cbc",
+        "Camellia-128 in CBC mode",
+    ),
+    OidInfo::new(
+        "1.2.392.200011.61.1.1.1.3",
+        "camellia192-cbc",
+        "Camellia-192 in CBC mode",
+    ),
+    OidInfo::new(
+        "1.2.392.200011.61.1.1.1.4",
+        "camellia256-cbc",
+        "Camellia-256 in CBC mode",
+    ),
+    OidInfo::new(
+        "1.2.392.200011.61.1.1.3.2",
+        "id-camellia128-wrap",
+        "id-camellia128-wrap",
+    ),
+    OidInfo::new(
+        "1.2.392.200011.61.1.1.3.3",
+        "id-camellia192-wrap",
+        "id-camellia192-wrap",
+    ),
+    OidInfo::new(
+        "1.2.392.200011.61.1.1.3.4",
+        "id-camellia256-wrap",
+        "id-camellia256-wrap",
+    ),
+    OidInfo::new("1.2.410.200004", "KISA", "kisa"),
+    OidInfo::new("1.2.410.200004.1.100.4.3", "ECKCDSA/SHA-1", "ECKCDSA/SHA-1 (signature)"),
+    OidInfo::new(
+        "1.2.410.200004.1.100.4.4",
+        "ECKCDSA/SHA-224",
+        "ECKCDSA/SHA-224 (signature)",
+    ),
+    OidInfo::new(
+        "1.2.410.200004.1.100.4.5",
+        "ECKCDSA/SHA-256",
+        "ECKCDSA/SHA-256 (signature)",
+    ),
+    OidInfo::new("1.2.410.200004.1.3", "SEED-ECB", "seed-ecb"),
+    OidInfo::new("1.2.410.200004.1.4", "SEED-CBC", "seed-cbc"),
+    OidInfo::new("1.2.410.200004.1.5", "SEED-CFB", "seed-cfb"),
+    OidInfo::new("1.2.410.200004.1.6", "SEED-OFB", "seed-ofb"),
+    OidInfo::new("1.2.410.200046.1.1.1", "ARIA-128-ECB", "aria-128-ecb"),
+    OidInfo::new("1.2.410.200046.1.1.10", "ARIA-192-CTR", "aria-192-ctr"),
+    OidInfo::new("1.2.410.200046.1.1.11", "ARIA-256-ECB", "aria-256-ecb"),
+    OidInfo::new("1.2.410.200046.1.1.12", "ARIA-256-CBC", "aria-256-cbc"),
+    OidInfo::new("1.2.410.200046.1.1.13", "ARIA-256-CFB", "aria-256-cfb"),
+    OidInfo::new("1.2.410.200046.1.1.14", "ARIA-256-OFB", "aria-256-ofb"),
+    OidInfo::new("1.2.410.200046.1.1.15", "ARIA-256-CTR", "aria-256-ctr"),
+    OidInfo::new("1.2.410.200046.1.1.2", "ARIA-128-CBC", "aria-128-cbc"),
+    OidInfo::new("1.2.410.200046.1.1.3", "ARIA-128-CFB", "aria-128-cfb"),
+    OidInfo::new("1.2.410.200046.1.1.34", "ARIA-128-GCM", "aria-128-gcm"),
+    OidInfo::new("1.2.410.200046.1.1.35", "ARIA-192-GCM", "aria-192-gcm"),
+    OidInfo::new("1.2.410.200046.1.1.36", "ARIA-256-GCM", "aria-256-gcm"),
+    OidInfo::new("1.2.410.200046.1.1.37", "ARIA-128-CCM", "aria-128-ccm"),
+    OidInfo::new("1.2.410.200046.1.1.38", "ARIA-192-CCM", "aria-192-ccm"),
+    OidInfo::new("1.2.410.200046.1.1.39", "ARIA-256-CCM", "aria-256-ccm"),
+    OidInfo::new("1.2.410.200046.1.1.4", "ARIA-128-OFB", "aria-128-ofb"),
+    OidInfo::new("1.2.410.200046.1.1.5", "ARIA-128-CTR", "aria-128-ctr"),
+    OidInfo::new("1.2.410.200046.1.1.6", "ARIA-192-ECB", "aria-192-ecb"),
+    OidInfo::new("1.2.410.200046.1.1.7", "ARIA-192-CBC", "aria-192-cbc"),
+    OidInfo::new("1.2.410.200046.1.1.8", "ARIA-192-CFB", "aria-192-cfb"),
+    OidInfo::new("1.2.410.200046.1.1.9", "ARIA-192-OFB", "aria-192-ofb"),
+    OidInfo::new("1.2.643.100.1", "OGRN", "OGRN"),
+    OidInfo::new("1.2.643.100.111", "subjectSignTool", "Signing Tool of Subject"),
+    OidInfo::new("1.2.643.100.112", "issuerSignTool", "Signing Tool of Issuer"),
+    OidInfo::new("1.2.643.100.113", "classSignTool", "Class of Signing Tool"),
+    OidInfo::new("1.2.643.100.113.1", "classSignToolKC1", "Class of Signing Tool KC1"),
+    OidInfo::new("1.2.643.100.113.2", "classSignToolKC2", "Class of Signing Tool KC2"),
+    OidInfo::new("1.2.643.100.113.3", "classSignToolKC3", "Class of Signing Tool KC3"),
+    OidInfo::new("1.2.643.100.113.4", "classSignToolKB1", "Class of Signing Tool KB1"),
+    OidInfo::new("1.2.643.100.113.5", "classSignToolKB2", "Class of Signing Tool KB2"),
+    OidInfo::new("1.2.643.100.113.6", "classSignToolKA1", "Class of Signing Tool KA1"),
+    OidInfo::new("1.2.643.100.3", "SNILS", "SNILS"),
+    OidInfo::new("1.2.643.100.5", "OGRNIP", "OGRNIP"),
+    OidInfo::new("1.2.643.2.2", "cryptopro", "cryptopro"),
+    OidInfo::new("1.2.643.2.2.10", "id-HMACGostR3411-94", "HMAC GOST 34.11-94"),
+    OidInfo::new(
+        "1.2.643.2.2.14.0",
+        "id-Gost28147-89-None-KeyMeshing",
+        "id-Gost28147-89-None-KeyMeshing",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.14.1",
+        "id-Gost28147-89-CryptoPro-KeyMeshing",
+        "id-Gost28147-89-CryptoPro-KeyMeshing",
+    ),
+    OidInfo::new("1.2.643.2.2.19", "gostR3410-2001", "GOST R 34.10-2001"),
+    OidInfo::new("1.2.643.2.2.20", "gost94", "GOST R 34.10-94"),
+    OidInfo::new("1.2.643.2.2.20.1", "id-GostR3410-94-a", "id-GostR3410-94-a"),
+    OidInfo::new("1.2.643.2.2.20.2", "id-GostR3410-94-aBis", "id-GostR3410-94-aBis"),
+    OidInfo::new("1.2.643.2.2.20.3", "id-GostR3410-94-b", "id-GostR3410-94-b"),
+    OidInfo::new("1.2.643.2.2.20.4", "id-GostR3410-94-bBis", "id-GostR3410-94-bBis"),
+    OidInfo::new("1.2.643.2.2.21", "gost89", "GOST 28147-89"),
+    OidInfo::new("1.2.643.2.2.22", "gost-mac", "GOST 28147-89 MAC"),
+    OidInfo::new("1.2.643.2.2.23", "prf-gostr3411-94", "GOST R 34.11-94 PRF"),
+    OidInfo::new(
+        "1.2.643.2.2.3",
+        "id-GostR3411-94-with-GostR3410-2001",
+        "GOST R 3411-94 with GOST R 3410-2001",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.30.0",
+        "id-GostR3411-94-TestParamSet",
+        "id-GostR3411-94-TestParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.30.1",
+        "id-GostR3411-94-CryptoProParamSet",
+        "id-GostR3411-94-CryptoProParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.0",
+        "id-Gost28147-89-TestParamSet",
+        "id-Gost28147-89-TestParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.1",
+        "id-Gost28147-89-CryptoPro-A-ParamSet",
+        "id-Gost28147-89-CryptoPro-A-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.2",
+        "id-Gost28147-89-CryptoPro-B-ParamSet",
+        "id-Gost28147-89-CryptoPro-B-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.3",
+        "id-Gost28147-89-CryptoPro-C-ParamSet",
+        "id-Gost28147-89-CryptoPro-C-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.4",
+        "id-Gost28147-89-CryptoPro-D-ParamSet",
+        "id-Gost28147-89-CryptoPro-D-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.5",
+        "id-Gost28147-89-CryptoPro-Oscar-1-1-ParamSet",
+        "id-Gost28147-89-CryptoPro-Oscar-1-1-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.6",
+        "id-Gost28147-89-CryptoPro-Oscar-1-0-ParamSet",
+        "id-Gost28147-89-CryptoPro-Oscar-1-0-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.31.7",
+        "id-Gost28147-89-CryptoPro-RIC-1-ParamSet",
+        "id-Gost28147-89-CryptoPro-RIC-1-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.32.0",
+        "id-GostR3410-94-TestParamSet",
+        "id-GostR3410-94-TestParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.32.2",
+        "id-GostR3410-94-CryptoPro-A-ParamSet",
+        "id-GostR3410-94-CryptoPro-A-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.32.3",
+        "id-GostR3410-94-CryptoPro-B-ParamSet",
+        "id-GostR3410-94-CryptoPro-B-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.32.4",
+        "id-GostR3410-94-CryptoPro-C-ParamSet",
+        "id-GostR3410-94-CryptoPro-C-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.32.5",
+        "id-GostR3410-94-CryptoPro-D-ParamSet",
+        "id-GostR3410-94-CryptoPro-D-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.33.1",
+        "id-GostR3410-94-CryptoPro-XchA-ParamSet",
+        "id-GostR3410-94-CryptoPro-XchA-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.33.2",
+        "id-GostR3410-94-CryptoPro-XchB-ParamSet",
+        "id-GostR3410-94-CryptoPro-XchB-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.33.3",
+        "id-GostR3410-94-CryptoPro-XchC-ParamSet",
+        "id-GostR3410-94-CryptoPro-XchC-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.35.0",
+        "id-GostR3410-2001-TestParamSet",
+        "id-GostR3410-2001-TestParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.35.1",
+        "id-GostR3410-2001-CryptoPro-A-ParamSet",
+        "id-GostR3410-2001-CryptoPro-A-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.35.2",
+        "id-GostR3410-2001-CryptoPro-B-ParamSet",
+        "id-GostR3410-2001-CryptoPro-B-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.35.3",
+        "id-GostR3410-2001-CryptoPro-C-ParamSet",
+        "id-GostR3410-2001-CryptoPro-C-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.36.0",
+        "id-GostR3410-2001-CryptoPro-XchA-ParamSet",
+        "id-GostR3410-2001-CryptoPro-XchA-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.36.1",
+        "id-GostR3410-2001-CryptoPro-XchB-ParamSet",
+        "id-GostR3410-2001-CryptoPro-XchB-ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.2.4",
+        "id-GostR3411-94-with-GostR3410-94",
+        "GOST R 34.11-94 with GOST R 34.10-94",
+    ),
+    OidInfo::new("1.2.643.2.2.9", "md_gost94", "GOST R 34.11-94"),
+    OidInfo::new("1.2.643.2.2.98", "id-GostR3410-2001DH", "GOST R 34.10-2001 DH"),
+    OidInfo::new("1.2.643.2.2.99", "id-GostR3410-94DH", "GOST R 34.10-94 DH"),
+    OidInfo::new("1.2.643.2.9", "cryptocom", "cryptocom"),
+    OidInfo::new(
+        "1.2.643.2.9.1.3.3",
+        "id-GostR3411-94-with-GostR3410-94-cc",
+        "GOST R 34.11-94 with GOST R 34.10-94 Cryptocom",
+    ),
+    OidInfo::new(
+        "1.2.643.2.9.1.3.4",
+        "id-GostR3411-94-with-GostR3410-2001-cc",
+        "GOST R 34.11-94 with GOST R 34.10-2001 Cryptocom",
+    ),
+    OidInfo::new("1.2.643.2.9.1.5.3", "gost94cc", "GOST 34.10-94 Cryptocom"),
+    OidInfo::new("1.2.643.2.9.1.5.4", "gost2001cc", "GOST 34.10-2001 Cryptocom"),
+    OidInfo::new(
+        "1.2.643.2.9.1.6.1",
+        "id-Gost28147-89-cc",
+        "GOST 28147-89 Cryptocom ParamSet",
+    ),
+    OidInfo::new(
+        "1.2.643.2.9.1.8.1",
+        "id-GostR3410-2001-ParamSet-cc",
+        "GOST R 3410-2001 Parameter Set Cryptocom",
+    ),
+    OidInfo::new("1.2.643.3.131.1.1", "INN", "INN"),
+    OidInfo::new("1.2.643.7.1", "id-tc26", "id-tc26"),
+    OidInfo::new("1.2.643.7.1.1", "id-tc26-algorithms", "id-tc26-algorithms"),
+    OidInfo::new("1.2.643.7.1.1.1", "id-tc26-sign", "id-tc26-sign"),
+    OidInfo::new(
+        "1.2.643.7.1.1.1.1",
+        "gost3410-2012-256",
+        "GOST R 34.10-2012 public keys with 256 bits private key length",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.1.1.2",
+        "gost3410-2012-512",
+        "GOST R 34.10-2012 public keys with 512 bits private key length",
+    ),
+    OidInfo::new("1.2.643.7.1.1.2", "id-tc26-digest", "id-tc26-digest"),
+    OidInfo::new(
+        "1.2.643.7.1.1.2.2",
+        "md_gost12_256",
+        "GOST R 34.11-2012 with 256 bit hash",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.1.2.3",
+        "md_gost12_512",
+        "GOST R 34.11-2012 with 512 bit hash",
+    ),
+    OidInfo::new("1.2.643.7.1.1.3", "id-tc26-signwithdigest", "id-tc26-signwithdigest"),
+    OidInfo::new(
+        "1.2.643.7.1.1.3.2",
+        "id-tc26-signwithdigest-gost3410-12-256",
+        "GOST R 34.10-2012 signature algorithm with 256-bit key length and GOST R 34.11-2012 hash function with 256-bit hash code",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.1.3.3",
+        "id-tc26-signwithdigest-gost3410-12-512",
+        "GOST R 34.10-2012 signature algorithm with 512-bit key length and GOST R 34.11-2012 hash function with 512-bit hash code",
+    ),
+    OidInfo::new("1.2.643.7.1.1.4", "id-tc26-mac", "id-tc26-mac"),
+    OidInfo::new(
+        "1.2.643.7.1.1.4.1",
+        "id-tc26-hmac-gost-3411-2012-256",
+        "HMAC GOST 34.11-2012 256 bit",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.1.4.2",
+        "id-tc26-hmac-gost-3411-2012-512",
+        "HMAC GOST 34.11-2012 512 bit",
+    ),
+    OidInfo::new("1.2.643.7.1.1.5", "id-tc26-cipher", "id-tc26-cipher"),
+    OidInfo::new(
+        "1.2.643.7.1.1.5.1",
+        "id-tc26-cipher-gostr3412-2015-magma",
+        "id-tc26-cipher-gostr3412-2015-magma",
+    ),
+    OidInfo::new("1.2.643.7.1.1.5.1.1", "magma-ctr-acpkm", "magma-ctr-acpkm"),
+    OidInfo::new("1.2.643.7.1.1.5.1.2", "magma-ctr-acpkm-omac", "magma-ctr-acpkm-omac"),
+    OidInfo::new(
+        "1.2.643.7.1.1.5.2",
+        "id-tc26-cipher-gostr3412-2015-kuznyechik",
+        "id-tc26-cipher-gostr3412-2015-kuznyechik",
+    ),
+    OidInfo::new("1.2.643.7.1.1.5.2.1", "kuznyechik-ctr-acpkm", "kuznyechik-ctr-acpkm"),
+    OidInfo::new(
+        "1.2.643.7.1.1.5.2.2",
+        "kuznyechik-ctr-acpkm-omac",
+        "kuznyechik-ctr-acpkm-omac",
+    ),
+    OidInfo::new("1.2.643.7.1.1.6", "id-tc26-agreement", "id-tc26-agreement"),
+    OidInfo::new(
+        "1.2.643.7.1.1.6.1",
+        "id-tc26-agreement-gost-3410-2012-256",
+        "id-tc26-agreement-gost-3410-2012-256",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.1.6.2",
+        "id-tc26-agreement-gost-3410-2012-512",
+        "id-tc26-agreement-gost-3410-2012-512",
+    ),
+    OidInfo::new("1.2.643.7.1.1.7", "id-tc26-wrap", "id-tc26-wrap"),
+    OidInfo::new(
+        "1.2.643.7.1.1.7.1",
+        "id-tc26-wrap-gostr3412-2015-magma",
+        "id-tc26-wrap-gostr3412-2015-magma",
+    ),
+    OidInfo::new("1.2.643.7.1.1.7.1.1", "magma-kexp15", "magma-kexp15"),
+    OidInfo::new(
+        "1.2.643.7.1.1.7.2",
+        "id-tc26-wrap-gostr3412-2015-kuznyechik",
+        "id-tc26-wrap-gostr3412-2015-kuznyechik",
+    ),
+    OidInfo::new("1.2.643.7.1.1.7.2.1", "kuznyechik-kexp15", "kuznyechik-kexp15"),
+    OidInfo::new("1.2.643.7.1.2", "id-tc26-constants", "id-tc26-constants"),
+    OidInfo::new("1.2.643.7.1.2.1", "id-tc26-sign-constants", "id-tc26-sign-constants"),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.1",
+        "id-tc26-gost-3410-2012-256-constants",
+        "id-tc26-gost-3410-2012-256-constants",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.1.1",
+        "id-tc26-gost-3410-2012-256-paramSetA",
+        "GOST R 34.10-2012 (256 bit) ParamSet A",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.1.2",
+        "id-tc26-gost-3410-2012-256-paramSetB",
+        "GOST R 34.10-2012 (256 bit) ParamSet B",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.1.3",
+        "id-tc26-gost-3410-2012-256-paramSetC",
+        "GOST R 34.10-2012 (256 bit) ParamSet C",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.1.4",
+        "id-tc26-gost-3410-2012-256-paramSetD",
+        "GOST R 34.10-2012 (256 bit) ParamSet D",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.2",
+        "id-tc26-gost-3410-2012-512-constants",
+        "id-tc26-gost-3410-2012-512-constants",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.2.0",
+        "id-tc26-gost-3410-2012-512-paramSetTest",
+        "GOST R 34.10-2012 (512 bit) testing parameter set",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.2.1",
+        "id-tc26-gost-3410-2012-512-paramSetA",
+        "GOST R 34.10-2012 (512 bit) ParamSet A",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.2.2",
+        "id-tc26-gost-3410-2012-512-paramSetB",
+        "GOST R 34.10-2012 (512 bit) ParamSet B",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.1.2.3",
+        "id-tc26-gost-3410-2012-512-paramSetC",
+        "GOST R 34.10-2012 (512 bit) ParamSet C",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.2",
+        "id-tc26-digest-constants",
+        "id-tc26-digest-constants",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.5",
+        "id-tc26-cipher-constants",
+        "id-tc26-cipher-constants",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.5.1",
+        "id-tc26-gost-28147-constants",
+        "id-tc26-gost-28147-constants",
+    ),
+    OidInfo::new(
+        "1.2.643.7.1.2.5.1.1",
+        "id-tc26-gost-28147-param-Z",
+        "GOST 28147-89 TC26 parameter set",
+    ),
+    OidInfo::new("1.2.804", "ISO-UA", "ISO-UA"),
+    OidInfo::new("1.2.804.2.1.1.1", "ua-pki", "ua-pki"),
+    OidInfo::new("1.2.804.2.1.1.1.1.1.1", "dstu28147", "DSTU Gost 28147-2009"),
+    OidInfo::new(
+        "1.2.804.2.1.1.1.1.1.1.2",
+        "dstu28147-ofb",
+        "DSTU Gost 28147-2009 OFB mode",
+    ),
+    OidInfo::new(
+        "1.2.804.2.1.1.1.1.1.1.3",
+        "dstu28147-cfb",
+        "DSTU Gost 28147-2009 CFB mode",
+    ),
+    OidInfo::new(
+        "1.2.804.2.1.1.1.1.1.1.5",
+        "dstu28147-wrap",
+        "DSTU Gost 28147-2009 key wrap",
+    ),
+    OidInfo::new("1.2.804.2.1.1.1.1.1.2", "hmacWithDstu34311", "HMAC DSTU Gost 34311-95"),
+    OidInfo::new("1.2.804.2.1.1.1.1.2.1", "dstu34311", "DSTU Gost 34311-95"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1", "dstu4145le", "DSTU 4145-2002 little endian"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.1.1", "dstu4145be", "DSTU 4145-2002 big endian"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.0", "uacurve0", "DSTU curve 0"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.1", "uacurve1", "DSTU curve 1"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.2", "uacurve2", "DSTU curve 2"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.3", "uacurve3", "DSTU curve 3"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.4", "uacurve4", "DSTU curve 4"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.5", "uacurve5", "DSTU curve 5"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.6", "uacurve6", "DSTU curve 6"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.7", "uacurve7", "DSTU curve 7"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.8", "uacurve8", "DSTU curve 8"),
+    OidInfo::new("1.2.804.2.1.1.1.1.3.1.1.2.9", "uacurve9", "DSTU curve 9"),
+    OidInfo::new("1.2.840", "ISO-US", "ISO US Member Body"),
+    OidInfo::new("1.2.840.10040", "X9-57", "X9.57"),
+    OidInfo::new("1.2.840.10040.2.1", "holdInstructionNone", "Hold Instruction None"),
+    OidInfo::new(
+        "1.2.840.10040.2.2",
+        "holdInstructionCallIssuer",
+        "Hold Instruction Call Issuer",
+    ),
+    OidInfo::new("1.2.840.10040.2.3", "holdInstructionReject", "Hold Instruction Reject"),
+    OidInfo::new("1.2.840.10040.4", "X9cm", "X9.57 CM ?"),
+    OidInfo::new("1.2.840.10040.4.1", "id-dsa", "DSA public key"),
+    OidInfo::new("1.2.840.10040.4.3", "id-dsa-with-sha1", "DSA signature with SHA-1"),
+    OidInfo::new("1.2.840.10045", "ansi-X9-62", "ANSI X9.62"),
+    OidInfo::new("1.2.840.10045.1.1", "prime-field", "Prime finite field"),
+    OidInfo::new(
+        "1.2.840.10045.1.2",
+        "characteristic-two-field",
+        "Characteristic two finite field",
+    ),
+    OidInfo::new(
+        "1.2.840.10045.1.2.3",
+        "id-characteristic-two-basis",
+        "id-characteristic-two-basis",
+    ),
+    OidInfo::new("1.2.840.10045.1.2.3.1", "onBasis", "onBasis"),
+    OidInfo::new("1.2.840.10045.1.2.3.2", "tpBasis", "tpBasis"),
+    OidInfo::new("1.2.840.10045.1.2.3.3", "ppBasis", "ppBasis"),
+    OidInfo::new("1.2.840.10045.2.1", "id-ecPublicKey", "Elliptic curve public key"),
+    OidInfo::new("1.2.840.10045.3.0.1", "c2pnb163v1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.10", "c2pnb208w1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.11", "c2tnb239v1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.12", "c2tnb239v2", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.13", "c2tnb239v3", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.14", "c2onb239v4", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.15", "c2onb239v5", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.16", "c2pnb272w1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.17", "c2pnb304w1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.18", "c2tnb359v1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.19", "c2pnb368w1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.2", "c2pnb163v2", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.20", "c2tnb431r1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.3", "c2pnb163v3", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.4", "c2pnb176w1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.5", "c2tnb191v1", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.6", "c2tnb191v2", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.7", "c2tnb191v3", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.8", "c2onb191v4", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.0.9", "c2onb191v5", "X9.62 characteristic two curve"),
+    OidInfo::new("1.2.840.10045.3.1.1", "prime192v1", "NIST P-192 (secp192r1) curve"),
+    OidInfo::new("1.2.840.10045.3.1.2", "prime192v2", "X9.62 prime curve"),
+    OidInfo::new("1.2.840.10045.3.1.3", "prime192v3", "X9.62 prime curve"),
+    OidInfo::new("1.2.840.10045.3.1.4", "prime239v1", "X9.62 prime curve"),
+    OidInfo::new("1.2.840.10045.3.1.5", "prime239v2", "X9.62 prime curve"),
+    OidInfo::new("1.2.840.10045.3.1.6", "prime239v3", "X9.62 prime curve"),
+    OidInfo::new("1.2.840.10045.3.1.7", "prime256v1", "NIST P-256 (secp256r1) curve"),
+    OidInfo::new("1.2.840.10045.4.1", "ecdsa-with-SHA1", "ECDSA signature with SHA-1"),
+    OidInfo::new("1.2.840.10045.4.2", "ecdsa-with-Recommended", "ecdsa-with-Recommended"),
+    OidInfo::new("1.2.840.10045.4.3", "ecdsa-with-SHA2", "ECDSA signatures with SHA-2"),
+    OidInfo::new(
+        "1.2.840.10045.4.3.1",
+        "ecdsa-with-SHA224",
+        "ECDSA signature with SHA-224",
+    ),
+    OidInfo::new(
+        "1.2.840.10045.4.3.2",
+        "ecdsa-with-SHA256",
+        "ECDSA signature with SHA-256",
+    ),
+    OidInfo::new(
+        "1.2.840.10045.4.3.3",
+        "ecdsa-with-SHA384",
+        "ECDSA signature with SHA-384",
+    ),
+    OidInfo::new(
+        "1.2.840.10045.4.3.4",
+        "ecdsa-with-SHA512",
+        "ECDSA signature with SHA-512",
+    ),
+    OidInfo::new("1.2.840.10046", "szOID_ANSI_X942", "Windows SDK object identifier"),
+    OidInfo::new("1.2.840.10046.2.1", "dhpublicnumber", "X9.42 Diffie-Hellman public key"),
+    OidInfo::new("1.2.840.113533.7.65.0", "entrustVersInfo", "Entrust version extension"),
+    OidInfo::new("1.2.840.113533.7.66.10", "CAST5-CBC", "cast5-cbc"),
+    OidInfo::new(
+        "1.2.840.113533.7.66.12",
+        "pbeWithMD5AndCast5CBC",
+        "pbeWithMD5AndCast5CBC",
+    ),
+    OidInfo::new("1.2.840.113533.7.66.13", "id-PasswordBasedMAC", "password based MAC"),
+    OidInfo::new(
+        "1.2.840.113533.7.66.15",
+        "KeyWrap.CAST-128",
+        "KeyWrap.CAST-128 (keywrap)",
+    ),
+    OidInfo::new("1.2.840.113533.7.66.30", "id-DHBasedMac", "Diffie-Hellman based MAC"),
+    OidInfo::new("1.2.840.113549", "rsadsi", "RSA Data Security, Inc."),
+    OidInfo::new("1.2.840.113549.1", "pkcs", "RSA Data Security, Inc. PKCS"),
+    OidInfo::new("1.2.840.113549.1.1", "pkcs1", "pkcs1"),
+    OidInfo::new("1.2.840.113549.1.1.1", "rsaEncryption", "RSA public key"),
+    OidInfo::new("1.2.840.113549.1.1.10", "id-RSASSA-PSS", "RSASSA-PSS signature scheme"),
+    OidInfo::new(
+        "1.2.840.113549.1.1.11",
+        "sha256WithRSAEncryption",
+        "RSA signature with SHA-256",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.1.12",
+        "sha384WithRSAEncryption",
+        "RSA signature with SHA-384",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.1.13",
+        "sha512WithRSAEncryption",
+        "RSA signature with SHA-512",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.1.14",
+        "sha224WithRSAEncryption",
+        "RSA signature with SHA-224",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.1.15",
+        "sha512-224WithRSAEncryption",
+        "RSA signature with SHA-512/224",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.1.16",
+        "sha512-256WithRSAEncryption",
+        "RSA signature with SHA-512/256",
+    ),
+    OidInfo::new("1.2.840.113549.1.1.2", "md2WithRSAEncryption", "RSA signature with MD2"),
+    OidInfo::new("1.2.840.113549.1.1.3", "md4WithRSAEncryption", "RSA signature with MD4"),
+    OidInfo::new("1.2.840.113549.1.1.4", "md5WithRSAEncryption", "RSA signature with MD5"),
+    OidInfo::new(
+        "1.2.840.113549.1.1.5",
+        "sha1WithRSAEncryption",
+        "RSA signature with SHA-1",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.1.6",
+        "rsaOAEPEncryptionSET",
+        "RSA OAEP encryption for SET",
+    ),
+    OidInfo::new("1.2.840.113549.1.1.7", "id-RSAES-OAEP", "RSAES-OAEP encryption scheme"),
+    OidInfo::new("1.2.840.113549.1.1.8", "id-mgf1", "MGF1 mask generation function"),
+    OidInfo::new(
+        "1.2.840.113549.1.1.9",
+        "id-pSpecified",
+        "OAEP encoding parameters source",
+    ),
+    OidInfo::new("1.2.840.113549.1.10", "szOID_PKCS_10", "Windows SDK object identifier"),
+    OidInfo::new(
+        "1.2.840.113549.1.12",
+        "pkcs-12",
+        "Public-Key Cryptography Standard (PKCS) #12",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1",
+        "pkcs-12PbeIds",
+        "PKCS #12 Password Based Encryption IDs",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1.1",
+        "pbeWithSHAAnd128BitRC4",
+        "PKCS #12 PBE with SHA-1 and 128-bit RC4",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1.2",
+        "pbeWithSHAAnd40BitRC4",
+        "PKCS #12 PBE with SHA-1 and 40-bit RC4",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1.3",
+        "pbeWithSHAAnd3-KeyTripleDES-CBC",
+        "PKCS #12 PBE with SHA-1 and 3-key Triple-DES",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1.4",
+        "pbeWithSHAAnd2-KeyTripleDES-CBC",
+        "PKCS #12 PBE with SHA-1 and 2-key Triple-DES",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1.5",
+        "pbeWithSHAAnd128BitRC2-CBC",
+        "PKCS #12 PBE with SHA-1 and 128-bit RC2",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.12.1.6",
+        "pbeWithSHAAnd40BitRC2-CBC",
+        "PKCS #12 PBE with SHA-1 and 40-bit RC2",
+    ),
+    OidInfo::new("1.2.840.113549.1.12.10.1", "bagtypes", "Defined in RFC 7292"),
+    OidInfo::new("1.2.840.113549.1.12.10.1.1", "keyBag", "PKCS #12 key bag"),
+    OidInfo::new(
+        "1.2.840.113549.1.12.10.1.2",
+        "pkcs8ShroudedKeyBag",
+        "PKCS #12 shrouded key bag",
+    ),
+    OidInfo::new("1.2.840.113549.1.12.10.1.3", "certBag", "PKCS #12 certificate bag"),
+    OidInfo::new("1.2.840.113549.1.12.10.1.4", "crlBag", "PKCS #12 CRL bag"),
+    OidInfo::new("1.2.840.113549.1.12.10.1.5", "secretBag", "PKCS #12 secret bag"),
+    OidInfo::new(
+        "1.2.840.113549.1.12.10.1.6",
+        "safeContentsBag",
+        "PKCS #12 safe contents bag",
+    ),
+    OidInfo::new("1.2.840.113549.1.2", "szOID_PKCS_2", "Windows SDK object identifier"),
+    OidInfo::new("1.2.840.113549.1.3", "pkcs3", "pkcs3"),
+    OidInfo::new(
+        "1.2.840.113549.1.3.1",
+        "dhKeyAgreement",
+        "PKCS #3 Diffie-Hellman key agreement",
+    ),
+    OidInfo::new("1.2.840.113549.1.4", "szOID_PKCS_4", "Windows SDK object identifier"),
+    OidInfo::new("1.2.840.113549.1.5", "pkcs5", "pkcs5"),
+    OidInfo::new(
+        "1.2.840.113549.1.5.1",
+        "pbeWithMD2AndDES-CBC",
+        "PBES1 with MD2 and DES-CBC",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.5.10",
+        "pbeWithSHA1AndDES-CBC",
+        "PBES1 with SHA-1 and DES-CBC",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.5.11",
+        "pbeWithSHA1AndRC2-CBC",
+        "PBES1 with SHA-1 and RC2-CBC",
+    ),
+    OidInfo::new("1.2.840.113549.1.5.12", "id-PBKDF2", "PBKDF2 key derivation function"),
+    OidInfo::new("1.2.840.113549.1.5.13", "id-PBES2", "PBES2 encryption scheme"),
+    OidInfo::new(
+        "1.2.840.113549.1.5.14",
+        "id-PBMAC1",
+        "PBMAC1 message authentication scheme",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.5.3",
+        "pbeWithMD5AndDES-CBC",
+        "PBES1 with MD5 and DES-CBC",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.5.4",
+        "pbeWithMD2AndRC2-CBC",
+        "PBES1 with MD2 and RC2-CBC",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.5.6",
+        "pbeWithMD5AndRC2-CBC",
+        "PBES1 with MD5 and RC2-CBC",
+    ),
+    OidInfo::new("1.2.840.113549.1.6", "szOID_PKCS_6", "Windows SDK object identifier"),
+    OidInfo::new("1.2.840.113549.1.7", "pkcs7", "pkcs7"),
+    OidInfo::new("1.2.840.113549.1.7.1", "id-data", "Data content type"),
+    OidInfo::new("1.2.840.113549.1.7.2", "id-signedData", "Signed data content type"),
+    OidInfo::new(
+        "1.2.840.113549.1.7.3",
+        "id-envelopedData",
+        "Enveloped data content type",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.7.4",
+        "id-signedAndEnvelopedData",
+        "Signed and enveloped data content type",
+    ),
+    OidInfo::new("1.2.840.113549.1.7.5", "id-digestedData", "Digested data content type"),
+    OidInfo::new(
+        "1.2.840.113549.1.7.6",
+        "id-encryptedData",
+        "Encrypted data content type",
+    ),
+    OidInfo::new("1.2.840.113549.1.8", "szOID_PKCS_8", "Windows SDK object identifier"),
+    OidInfo::new("1.2.840.113549.1.9", "pkcs9", "pkcs9"),
+    OidInfo::new("1.2.840.113549.1.9.0", "pkcs-9-mo", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.1", "emailAddress", "E-mail address"),
+    OidInfo::new("1.2.840.113549.1.9.12", "pkcs-9-at-publicKey", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.13", "signingDescription", "Signing description"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.14",
+        "extensionRequest",
+        "Certificate extensions request",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.15", "smimeCapabilities", "S/MIME capabilities"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.15.1",
+        "szOID_RSA_preferSignedData",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16", "id-smime", "S/MIME arc"),
+    OidInfo::new("1.2.840.113549.1.9.16.0", "id-smime-mod", "id-smime-mod"),
+    OidInfo::new("1.2.840.113549.1.9.16.0.1", "id-smime-mod-cms", "id-smime-mod-cms"),
+    OidInfo::new("1.2.840.113549.1.9.16.0.2", "id-smime-mod-ess", "id-smime-mod-ess"),
+    OidInfo::new("1.2.840.113549.1.9.16.0.3", "id-smime-mod-oid", "id-smime-mod-oid"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.0.4",
+        "id-smime-mod-msg-v3",
+        "id-smime-mod-msg-v3",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.0.5",
+        "id-smime-mod-ets-eSignature-88",
+        "id-smime-mod-ets-eSignature-88",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.0.6",
+        "id-smime-mod-ets-eSignature-97",
+        "id-smime-mod-ets-eSignature-97",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.0.7",
+        "id-smime-mod-ets-eSigPolicy-88",
+        "id-smime-mod-ets-eSigPolicy-88",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.0.78",
+        "id-mod-sha3-oids-2023",
+        "Defined in RFC 9688",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.0.8",
+        "id-smime-mod-ets-eSigPolicy-97",
+        "id-smime-mod-ets-eSigPolicy-97",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.1", "id-smime-ct", "id-smime-ct"),
+    OidInfo::new("1.2.840.113549.1.9.16.1.1", "id-ct-receipt", "Receipt content type"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.16",
+        "id-ct-firmwarePackage",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.19",
+        "id-smime-ct-contentCollection",
+        "id-smime-ct-contentCollection",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.2",
+        "id-ct-authData",
+        "Authenticated data content type",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.20",
+        "id-ct-contentWithAttrs",
+        "Defined in RFC 6268",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.21",
+        "id-ct-encKeyWithID",
+        "Defined in RFC 5912",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.23",
+        "id-ct-authEnvelopedData",
+        "Authenticated enveloped data content type",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.24",
+        "id-ct-routeOriginAuthz",
+        "id-ct-routeOriginAuthz",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.1.26", "id-ct-rpkiManifest", "id-ct-rpkiManifest"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.27",
+        "id-ct-asciiTextWithCRLF",
+        "id-ct-asciiTextWithCRLF",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.1.28", "id-ct-xml", "id-ct-xml"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.3",
+        "id-smime-ct-publishCert",
+        "id-smime-ct-publishCert",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.35",
+        "id-ct-rpkiGhostbusters",
+        "id-ct-rpkiGhostbusters",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.36",
+        "id-ct-resourceTaggedAttest",
+        "id-ct-resourceTaggedAttest",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.4",
+        "id-ct-TSTInfo",
+        "Time-stamp token info content type",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.47",
+        "id-ct-geofeedCSVwithCRLF",
+        "id-ct-geofeedCSVwithCRLF",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.48",
+        "id-ct-signedChecklist",
+        "id-ct-signedChecklist",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.1.49", "id-ct-ASPA", "id-ct-ASPA"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.5",
+        "id-smime-ct-TDTInfo",
+        "id-smime-ct-TDTInfo",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.1.50", "id-ct-signedTAL", "id-ct-signedTAL"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.51",
+        "id-ct-rpkiSignedPrefixList",
+        "id-ct-rpkiSignedPrefixList",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.6",
+        "id-smime-ct-contentInfo",
+        "id-smime-ct-contentInfo",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.7",
+        "id-smime-ct-DVCSRequestData",
+        "id-smime-ct-DVCSRequestData",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.8",
+        "id-smime-ct-DVCSResponseData",
+        "id-smime-ct-DVCSResponseData",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.1.9",
+        "id-ct-compressedData",
+        "Compressed data content type",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.10", "id-eit", "Defined in RFC 7107"),
+    OidInfo::new("1.2.840.113549.1.9.16.11", "id-cap", "Defined in RFC 5911"),
+    OidInfo::new("1.2.840.113549.1.9.16.12", "id-pskc", "Defined in RFC 7107"),
+    OidInfo::new("1.2.840.113549.1.9.16.13", "id-smime-ori", "id-smime-ori"),
+    OidInfo::new("1.2.840.113549.1.9.16.13.3", "id-smime-ori-kem", "id-smime-ori-kem"),
+    OidInfo::new("1.2.840.113549.1.9.16.2", "id-smime-aa", "id-smime-aa"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.1",
+        "id-aa-receiptRequest",
+        "Receipt request attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.10",
+        "id-smime-aa-contentReference",
+        "id-smime-aa-contentReference",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.11",
+        "id-aa-encrypKeyPref",
+        "S/MIME encryption key preference attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.12",
+        "id-aa-signingCertificate",
+        "Signing certificate attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.13",
+        "id-smime-aa-smimeEncryptCerts",
+        "id-smime-aa-smimeEncryptCerts",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.14",
+        "id-aa-timeStampToken",
+        "Time-stamp token attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.15",
+        "id-aa-ets-sigPolicyId",
+        "Signature policy identifier attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.16",
+        "id-aa-ets-commitmentType",
+        "Commitment type attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.17",
+        "id-aa-ets-signerLocation",
+        "Signer location attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.18",
+        "id-aa-ets-signerAttr",
+        "Signer attributes attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.19",
+        "id-aa-ets-otherSigCert",
+        "Other signing certificate attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.2",
+        "id-aa-securityLabel",
+        "Security label attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.20",
+        "id-aa-ets-contentTimestamp",
+        "Content time-stamp attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.21",
+        "id-aa-ets-CertificateRefs",
+        "Complete certificate references attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.22",
+        "id-aa-ets-RevocationRefs",
+        "Complete revocation references attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.23",
+        "id-aa-ets-certValues",
+        "Certificate values attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.24",
+        "id-aa-ets-revocationValues",
+        "Revocation values attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.25",
+        "id-aa-ets-escTimeStamp",
+        "ES-C time-stamp attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.26",
+        "id-aa-ets-certCRLTimestamp",
+        "Certificate and CRL time-stamp attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.27",
+        "id-aa-ets-archiveTimeStamp",
+        "Archive time-stamp attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.28",
+        "id-smime-aa-signatureType",
+        "id-smime-aa-signatureType",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.29",
+        "id-smime-aa-dvcs-dvc",
+        "id-smime-aa-dvcs-dvc",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.3",
+        "id-aa-mlExpandHistory",
+        "Mail list expansion history attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.34",
+        "id-aa-cmc-unsignedData",
+        "Defined in RFC 5912",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.37",
+        "id-aa-decryptKeyID",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.38",
+        "id-aa-implCryptoAlgs",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.4",
+        "id-aa-contentHint",
+        "Content hint attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.43",
+        "id-aa-implCompressAlgs",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.44",
+        "id-aa-ets-attrCertificateRefs",
+        "id-aa-ets-attrCertificateRefs",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.45",
+        "id-aa-ets-attrRevocationRefs",
+        "id-aa-ets-attrRevocationRefs",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.47",
+        "id-aa-signingCertificateV2",
+        "Signing certificate V2 attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.48",
+        "id-aa-ets-archiveTimestampV2",
+        "id-aa-ets-archiveTimestampV2",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.2.49", "id-aa-er-internal", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.5",
+        "id-smime-aa-msgSigDigest",
+        "id-smime-aa-msgSigDigest",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.2.50", "id-aa-er-external", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.6",
+        "id-smime-aa-encapContentType",
+        "id-smime-aa-encapContentType",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.7",
+        "id-aa-contentIdentifier",
+        "Content identifier attribute",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.8",
+        "id-smime-aa-macValue",
+        "id-smime-aa-macValue",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.2.9",
+        "id-aa-equivalentLabels",
+        "Equivalent labels attribute",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.3", "id-smime-alg", "id-smime-alg"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.1",
+        "id-smime-alg-ESDHwith3DES",
+        "id-smime-alg-ESDHwith3DES",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.3.10", "id-alg-SSDH", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.17",
+        "id-alg-hss-lms-hashsig",
+        "id-alg-hss-lms-hashsig",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.18",
+        "id-alg-AEADChaCha20Poly1305",
+        "ChaCha20-Poly1305 AEAD",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.2",
+        "id-smime-alg-ESDHwithRC2",
+        "id-smime-alg-ESDHwithRC2",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.28",
+        "id-alg-hkdf-with-sha256",
+        "HKDF with SHA-256",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.29",
+        "id-alg-hkdf-with-sha384",
+        "HKDF with SHA-384",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.3",
+        "id-smime-alg-3DESwrap",
+        "id-smime-alg-3DESwrap",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.30",
+        "id-alg-hkdf-with-sha512",
+        "HKDF with SHA-512",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.4",
+        "id-smime-alg-RC2wrap",
+        "id-smime-alg-RC2wrap",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.3.5", "id-alg-CMS3DESwrap", "Triple-DES key wrap"),
+    OidInfo::new("1.2.840.113549.1.9.16.3.6", "id-alg-CMSRC2wrap", "RC2 key wrap"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.7",
+        "id-smime-alg-CMSRC2wrap",
+        "id-smime-alg-CMSRC2wrap",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.3.8", "id-alg-zlibCompress", "ZLIB compression"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.3.9",
+        "id-alg-PWRI-KEK",
+        "Password based key encryption key",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.4", "id-smime-cd", "id-smime-cd"),
+    OidInfo::new("1.2.840.113549.1.9.16.4.1", "id-smime-cd-ldap", "id-smime-cd-ldap"),
+    OidInfo::new("1.2.840.113549.1.9.16.5", "id-smime-spq", "id-smime-spq"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.5.1",
+        "id-smime-spq-ets-sqt-uri",
+        "id-smime-spq-ets-sqt-uri",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.5.2",
+        "id-smime-spq-ets-sqt-unotice",
+        "id-smime-spq-ets-sqt-unotice",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.6", "id-smime-cti", "id-smime-cti"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.6.1",
+        "id-smime-cti-ets-proofOfOrigin",
+        "id-smime-cti-ets-proofOfOrigin",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.6.2",
+        "id-smime-cti-ets-proofOfReceipt",
+        "id-smime-cti-ets-proofOfReceipt",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.6.3",
+        "id-smime-cti-ets-proofOfDelivery",
+        "id-smime-cti-ets-proofOfDelivery",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.6.4",
+        "id-smime-cti-ets-proofOfSender",
+        "id-smime-cti-ets-proofOfSender",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.6.5",
+        "id-smime-cti-ets-proofOfApproval",
+        "id-smime-cti-ets-proofOfApproval",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.6.6",
+        "id-smime-cti-ets-proofOfCreation",
+        "id-smime-cti-ets-proofOfCreation",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.7", "id-tsp", "Defined in RFC 7107"),
+    OidInfo::new("1.2.840.113549.1.9.16.8", "id-skd", "Defined in RFC 5911"),
+    OidInfo::new("1.2.840.113549.1.9.16.8.1", "id-skd-glUseKEK", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.8.11",
+        "id-skd-glaQueryRequest",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.8.13",
+        "id-skd-glProvideCert",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.8.14",
+        "id-skd-glManageCert",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.8.15", "id-skd-glKey", "Defined in RFC 5911"),
+    OidInfo::new("1.2.840.113549.1.9.16.8.2", "id-skd-glDelete", "Defined in RFC 5911"),
+    OidInfo::new("1.2.840.113549.1.9.16.8.3", "id-skd-glAddMember", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.8.4",
+        "id-skd-glDeleteMember",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.8.5", "id-skd-glRekey", "Defined in RFC 5911"),
+    OidInfo::new("1.2.840.113549.1.9.16.8.6", "id-skd-glAddOwner", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.8.7",
+        "id-skd-glRemoveOwner",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.16.8.8",
+        "id-skd-glKeyCompromise",
+        "Defined in RFC 5911",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.16.8.9", "id-skd-glkRefresh", "Defined in RFC 5911"),
+    OidInfo::new("1.2.840.113549.1.9.16.9", "id-sti", "Defined in RFC 7107"),
+    OidInfo::new("1.2.840.113549.1.9.2", "unstructuredName", "Unstructured name"),
+    OidInfo::new("1.2.840.113549.1.9.20", "friendlyName", "Friendly name"),
+    OidInfo::new("1.2.840.113549.1.9.21", "localKeyId", "Local key identifier"),
+    OidInfo::new("1.2.840.113549.1.9.22", "certTypes", "Defined in RFC 2985"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.22.1",
+        "x509Certificate",
+        "X.509 certificate bag type",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.22.2",
+        "sdsiCertificate",
+        "SDSI certificate bag type",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.23", "crlTypes", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.23.1", "x509Crl", "X.509 CRL bag type"),
+    OidInfo::new("1.2.840.113549.1.9.24", "pkcs-9-oc", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.24.1", "pkcs-9-oc-pkcsEntity", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.25", "pkcs-9-at", "Defined in RFC 2985"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.25.1",
+        "pkcs-9-at-pkcs15Token",
+        "Defined in RFC 2985",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.25.3", "randomNonce", "Random nonce"),
+    OidInfo::new("1.2.840.113549.1.9.25.4", "sequenceNumber", "Sequence number"),
+    OidInfo::new("1.2.840.113549.1.9.25.5", "pkcs-9-at-pkcs7PDU", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.26", "pkcs-9-sx", "Defined in RFC 2985"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.26.1",
+        "pkcs-9-sx-pkcs9String",
+        "Defined in RFC 2985",
+    ),
+    OidInfo::new(
+        "1.2.840.113549.1.9.26.2",
+        "pkcs-9-sx-signingTime",
+        "Defined in RFC 2985",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.27", "pkcs-9-mr", "Defined in RFC 2985"),
+    OidInfo::new("1.2.840.113549.1.9.3", "contentType", "Content type"),
+    OidInfo::new("1.2.840.113549.1.9.4", "messageDigest", "Message digest"),
+    OidInfo::new("1.2.840.113549.1.9.5", "signingTime", "Signing time"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.52",
+        "id-aa-CMSAlgorithmProtection",
+        "CMS algorithm protection",
+    ),
+    OidInfo::new("1.2.840.113549.1.9.6", "countersignature", "Countersignature"),
+    OidInfo::new("1.2.840.113549.1.9.7", "challengePassword", "Challenge password"),
+    OidInfo::new("1.2.840.113549.1.9.8", "unstructuredAddress", "Unstructured address"),
+    OidInfo::new(
+        "1.2.840.113549.1.9.9",
+        "extendedCertificateAttributes",
+        "Extended certificate attributes",
+    ),
+    OidInfo::new("1.2.840.113549.2", "digestAlgorithm", "RSADSI digest algorithms"),
+    OidInfo::new("1.2.840.113549.2.10", "hmacWithSHA384", "HMAC with SHA-384"),
+    OidInfo::new("1.2.840.113549.2.11", "hmacWithSHA512", "HMAC with SHA-512"),
+    OidInfo::new("1.2.840.113549.2.12", "hmacWithSHA512-224", "HMAC with SHA-512/224"),
+    OidInfo::new("1.2.840.113549.2.13", "hmacWithSHA512-256", "HMAC with SHA-512/256"),
+    OidInfo::new("1.2.840.113549.2.2", "md2", "MD2 hash"),
+    OidInfo::new("1.2.840.113549.2.4", "md4", "MD4 hash"),
+    OidInfo::new("1.2.840.113549.2.5", "md5", "MD5 hash"),
+    OidInfo::new("1.2.840.113549.2.6", "hmacWithMD5", "hmacWithMD5"),
+    OidInfo::new("1.2.840.113549.2.7", "hmacWithSHA1", "HMAC with SHA-1"),
+    OidInfo::new("1.2.840.113549.2.8", "hmacWithSHA224", "HMAC with SHA-224"),
+    OidInfo::new("1.2.840.113549.2.9", "hmacWithSHA256", "HMAC with SHA-256"),
+    OidInfo::new("1.2.840.113549.3", "szOID_RSA_ENCRYPT", "Windows SDK object identifier"),
+    OidInfo::new("1.2.840.113549.3.10", "DES-CDMF", "des-cdmf"),
+    OidInfo::new("1.2.840.113549.3.2", "rc2-cbc", "RC2 in CBC mode"),
+    OidInfo::new("1.2.840.113549.3.4", "rc4", "RC4 stream cipher"),
+    OidInfo::new("1.2.840.113549.3.7", "des-ede3-cbc", "Triple-DES in CBC mode"),
+    OidInfo::new("1.2.840.113549.3.8", "RC5-CBC", "rc5-cbc"),
+    OidInfo::new("1.2.840.113549.3.9", "rc5-cbc-pad", "RC5 in CBC mode with padding"),
+    OidInfo::new("1.2.840.113554.1.2.1.1", "gss-nt-user-name", "GSS-API user name type"),
+    OidInfo::new(
+        "1.2.840.113554.1.2.1.2",
+        "gss-nt-machine-uid-name",
+        "GSS-API machine UID name type",
+    ),
+    OidInfo::new(
+        "1.2.840.113554.1.2.1.3",
+        "gss-nt-string-uid-name",
+        "GSS-API string UID name type",
+    ),
+    OidInfo::new(
+        "1.2.840.113554.1.2.1.4",
+        "gss-nt-service-name",
+        "GSS-API legacy host-based service name type",
+    ),
+    OidInfo::new("1.2.840.113554.1.2.2", "krb5", "Kerberos 5 GSS-API mechanism"),
+    OidInfo::new(
+        "1.2.840.113554.1.2.2.1",
+        "gss-krb5-nt-principal-name",
+        "Kerberos 5 principal name type",
+    ),
+    OidInfo::new(
+        "1.2.840.113554.1.2.2.3",
+        "user-to-user-mechanism",
+        "Kerberos 5 user-to-user GSS-API mechanism",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1338",
+        "LDAP_SERVER_VERIFY_NAME_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1339",
+        "LDAP_SERVER_DOMAIN_SCOPE_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1340",
+        "LDAP_SERVER_SEARCH_OPTIONS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1413",
+        "LDAP_SERVER_PERMISSIVE_MODIFY_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1504",
+        "LDAP_SERVER_ASQ_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1670",
+        "LDAP_CAP_ACTIVE_DIRECTORY_V51_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1781",
+        "LDAP_SERVER_FAST_BIND_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1791",
+        "LDAP_CAP_ACTIVE_DIRECTORY_LDAP_INTEG_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1851",
+        "LDAP_CAP_ACTIVE_DIRECTORY_ADAM_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1852",
+        "LDAP_SERVER_QUOTA_CONTROL_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1907",
+        "LDAP_SERVER_SHUTDOWN_NOTIFY_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1920",
+        "LDAP_CAP_ACTIVE_DIRECTORY_PARTIAL_SECRETS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1935",
+        "LDAP_CAP_ACTIVE_DIRECTORY_V60_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1941",
+        "LDAP_MATCHING_RULE_TRANSITIVE_EVALUATION",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1948",
+        "LDAP_SERVER_RANGE_RETRIEVAL_NOERR_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.1974",
+        "LDAP_SERVER_FORCE_UPDATE_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2026",
+        "LDAP_SERVER_DN_INPUT_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2064",
+        "LDAP_SERVER_SHOW_RECYCLED_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2065",
+        "LDAP_SERVER_SHOW_DEACTIVATED_LINK_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2066",
+        "LDAP_SERVER_POLICY_HINTS_DEPRECATED_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2080",
+        "LDAP_CAP_ACTIVE_DIRECTORY_V61_R2_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2090",
+        "LDAP_SERVER_DIRSYNC_EX_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2204",
+        "LDAP_SERVER_TREE_DELETE_EX_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2205",
+        "LDAP_SERVER_UPDATE_STATS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2206",
+        "LDAP_SERVER_SEARCH_HINTS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2207",
+        "LDAP_SEARCH_HINT_INDEX_ONLY_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2208",
+        "LDAP_UPDATE_STATS_USN_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2209",
+        "LDAP_UPDATE_STATS_INVOCATIONID_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2210",
+        "LDAP_SEARCH_HINT_SOFT_SIZE_LIMIT_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2211",
+        "LDAP_SERVER_EXPECTED_ENTRY_COUNT_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2212",
+        "LDAP_SERVER_BATCH_REQUEST_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2237",
+        "LDAP_CAP_ACTIVE_DIRECTORY_W8_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2239",
+        "LDAP_SERVER_POLICY_HINTS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2253",
+        "LDAP_MATCHING_RULE_DN_BINARY_COMPLEX",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2255",
+        "LDAP_SERVER_SET_OWNER_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2256",
+        "LDAP_SERVER_BYPASS_QUOTA_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2306",
+        "LDAP_SEARCH_HINT_REQUIRED_INDEX_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.2309",
+        "LDAP_SERVER_LINK_TTL_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.319",
+        "LDAP_PAGED_RESULT_OID_STRING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.417",
+        "LDAP_SERVER_SHOW_DELETED_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.473",
+        "LDAP_SERVER_SORT_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.474",
+        "LDAP_SERVER_RESP_SORT_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.2.840.113556.1.4.478", "calCalURI", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.479", "calFBURL", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.480", "calCAPURI", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.481", "calCalAdrURI", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.482", "calOtherCalURIs", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.483", "calOtherFBURLs", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.484", "calOtherCAPURIs", "Defined in RFC 2739"),
+    OidInfo::new("1.2.840.113556.1.4.485", "calOtherCalAdrURIs", "Defined in RFC 2739"),
+    OidInfo::new(
+        "1.2.840.113556.1.4.521",
+        "LDAP_SERVER_CROSSDOM_MOVE_TARGET_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.528",
+        "LDAP_SERVER_NOTIFICATION_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.529",
+        "LDAP_SERVER_EXTENDED_DN_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.616",
+        "LDAP_CONTROL_REFERRALS",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.619",
+        "LDAP_SERVER_LAZY_COMMIT_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.800",
+        "LDAP_CAP_ACTIVE_DIRECTORY_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.801",
+        "LDAP_SERVER_SD_FLAGS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.802",
+        "LDAP_SERVER_RANGE_OPTION_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.803",
+        "LDAP_MATCHING_RULE_BIT_AND",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.804",
+        "LDAP_MATCHING_RULE_BIT_OR",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.805",
+        "LDAP_SERVER_TREE_DELETE_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.841",
+        "LDAP_SERVER_DIRSYNC_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.2.840.113556.1.4.970",
+        "LDAP_SERVER_GET_STATS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.2.840.113556.1.5.87", "calEntry", "Defined in RFC 2739"),
+    OidInfo::new(
+        "1.2.840.48018.1.2.2",
+        "MS-KILE",
+        "Microsoft Kerberos 5 GSS-API mechanism",
+    ),
+    OidInfo::new("1.3", "identified-organization", "identified-organization"),
+    OidInfo::new("1.3.101", "id-edwards-curve-algs", "Defined in RFC 8410"),
+    OidInfo::new("1.3.101.1.4.1", "SXNetID", "Strong Extranet ID"),
+    OidInfo::new("1.3.101.110", "id-X25519", "X25519 key agreement"),
+    OidInfo::new("1.3.101.111", "id-X448", "X448 key agreement"),
+    OidInfo::new("1.3.101.112", "id-Ed25519", "Ed25519 signature"),
+    OidInfo::new("1.3.101.113", "id-Ed448", "Ed448 signature"),
+    OidInfo::new("1.3.111", "ieee", "ieee"),
+    OidInfo::new("1.3.111.2.1619", "ieee-siswg", "IEEE Security in Storage Working Group"),
+    OidInfo::new("1.3.111.2.1619.0.1.1", "AES-128-XTS", "aes-128-xts"),
+    OidInfo::new("1.3.111.2.1619.0.1.2", "AES-256-XTS", "aes-256-xts"),
+    OidInfo::new("1.3.132", "certicom-arc", "certicom-arc"),
+    OidInfo::new("1.3.132.0.1", "sect163k1", "SEC 2 binary curve (NIST K-163)"),
+    OidInfo::new("1.3.132.0.10", "secp256k1", "SEC 2 prime Koblitz curve (Bitcoin)"),
+    OidInfo::new("1.3.132.0.15", "sect163r2", "SEC 2 binary curve (NIST B-163)"),
+    OidInfo::new("1.3.132.0.16", "sect283k1", "SEC 2 binary curve (NIST K-283)"),
+    OidInfo::new("1.3.132.0.17", "sect283r1", "SEC 2 binary curve (NIST B-283)"),
+    OidInfo::new("1.3.132.0.2", "sect163r1", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.22", "sect131r1", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.23", "sect131r2", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.24", "sect193r1", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.25", "sect193r2", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.26", "sect233k1", "SEC 2 binary curve (NIST K-233)"),
+    OidInfo::new("1.3.132.0.27", "sect233r1", "SEC 2 binary curve (NIST B-233)"),
+    OidInfo::new("1.3.132.0.28", "secp128r1", "SEC 2 prime curve"),
+    OidInfo::new("1.3.132.0.29", "secp128r2", "SEC 2 prime curve"),
+    OidInfo::new("1.3.132.0.3", "sect239k1", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.30", "secp160r2", "SEC 2 prime curve"),
+    OidInfo::new("1.3.132.0.31", "secp192k1", "SEC 2 prime Koblitz curve"),
+    OidInfo::new("1.3.132.0.32", "secp224k1", "SEC 2 prime Koblitz curve"),
+    OidInfo::new("1.3.132.0.33", "secp224r1", "SEC 2 prime curve (NIST P-224)"),
+    OidInfo::new("1.3.132.0.34", "secp384r1", "SEC 2 prime curve (NIST P-384)"),
+    OidInfo::new("1.3.132.0.35", "secp521r1", "SEC 2 prime curve (NIST P-521)"),
+    OidInfo::new("1.3.132.0.36", "sect409k1", "SEC 2 binary curve (NIST K-409)"),
+    OidInfo::new("1.3.132.0.37", "sect409r1", "SEC 2 binary curve (NIST B-409)"),
+    OidInfo::new("1.3.132.0.38", "sect571k1", "SEC 2 binary curve (NIST K-571)"),
+    OidInfo::new("1.3.132.0.39", "sect571r1", "SEC 2 binary curve (NIST B-571)"),
+    OidInfo::new("1.3.132.0.4", "sect113r1", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.5", "sect113r2", "SEC 2 binary curve"),
+    OidInfo::new("1.3.132.0.6", "secp112r1", "SEC 2 prime curve"),
+    OidInfo::new("1.3.132.0.7", "secp112r2", "SEC 2 prime curve"),
+    OidInfo::new("1.3.132.0.8", "secp160r1", "SEC 2 prime curve"),
+    OidInfo::new("1.3.132.0.9", "secp160k1", "SEC 2 prime Koblitz curve"),
+    OidInfo::new("1.3.132.1", "secg-scheme", "Defined in RFC 5753"),
+    OidInfo::new(
+        "1.3.132.1.11.0",
+        "dhSinglePass-stdDH-sha224kdf-scheme",
+        "ECDH with SHA-224 X9.63 KDF",
+    ),
+    OidInfo::new(
+        "1.3.132.1.11.1",
+        "dhSinglePass-stdDH-sha256kdf-scheme",
+        "ECDH with SHA-256 X9.63 KDF",
+    ),
+    OidInfo::new(
+        "1.3.132.1.11.2",
+        "dhSinglePass-stdDH-sha384kdf-scheme",
+        "ECDH with SHA-384 X9.63 KDF",
+    ),
+    OidInfo::new(
+        "1.3.132.1.11.3",
+        "dhSinglePass-stdDH-sha512kdf-scheme",
+        "ECDH with SHA-512 X9.63 KDF",
+    ),
+    OidInfo::new("1.3.132.1.12", "id-ecDH", "Elliptic curve Diffie-Hellman"),
+    OidInfo::new("1.3.132.1.13", "id-ecMQV", "Elliptic curve MQV"),
+    OidInfo::new(
+        "1.3.132.1.14.0",
+        "dhSinglePass-cofactorDH-sha224kdf-scheme",
+        "dhSinglePass-cofactorDH-sha224kdf-scheme",
+    ),
+    OidInfo::new(
+        "1.3.132.1.14.1",
+        "dhSinglePass-cofactorDH-sha256kdf-scheme",
+        "dhSinglePass-cofactorDH-sha256kdf-scheme",
+    ),
+    OidInfo::new(
+        "1.3.132.1.14.2",
+        "dhSinglePass-cofactorDH-sha384kdf-scheme",
+        "dhSinglePass-cofactorDH-sha384kdf-scheme",
+    ),
+    OidInfo::new(
+        "1.3.132.1.14.3",
+        "dhSinglePass-cofactorDH-sha512kdf-scheme",
+        "dhSinglePass-cofactorDH-sha512kdf-scheme",
+    ),
+    OidInfo::new("1.3.133.16.840.63.0", "x9-63-scheme", "Defined in RFC 5753"),
+    OidInfo::new(
+        "1.3.133.16.840.63.0.2",
+        "dhSinglePass-stdDH-sha1kdf-scheme",
+        "Single pass Secure Hash Algorithm 1 (SHA1) key derivation",
+    ),
+    OidInfo::new(
+        "1.3.133.16.840.63.0.3",
+        "dhSinglePass-cofactorDH-sha1kdf-scheme",
+        "dhSinglePass-cofactorDH-sha1kdf-scheme",
+    ),
+    OidInfo::new("1.3.133.16.840.9.44", "x9-44", "Defined in RFC 9688"),
+    OidInfo::new("1.3.133.16.840.9.44.1", "x9-44-components", "Defined in RFC 9688"),
+    OidInfo::new("1.3.133.16.840.9.44.1.1", "id-kdf-kdf2", "Defined in RFC 9688"),
+    OidInfo::new("1.3.133.16.840.9.44.1.2", "id-kdf-kdf3", "Defined in RFC 9688"),
+    OidInfo::new("1.3.14", "szOID_OIW", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.3.2", "algorithm", "algorithm"),
+    OidInfo::new("1.3.14.3.2.10", "szOID_OIWSEC_desMAC", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.3.2.11", "rsaSignature", "rsaSignature"),
+    OidInfo::new("1.3.14.3.2.12", "DSA-old", "dsaEncryption-old"),
+    OidInfo::new("1.3.14.3.2.13", "DSA-SHA", "dsaWithSHA"),
+    OidInfo::new("1.3.14.3.2.14", "szOID_OIWSEC_mdc2RSA", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.3.2.15", "RSA-SHA", "shaWithRSAEncryption"),
+    OidInfo::new(
+        "1.3.14.3.2.16",
+        "szOID_OIWSEC_dhCommMod",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.14.3.2.17", "desEDE", "DES-EDE"),
+    OidInfo::new("1.3.14.3.2.18", "SHA", "sha"),
+    OidInfo::new("1.3.14.3.2.19", "szOID_OIWSEC_mdc2", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.3.2.2", "md4WithRSA", "OIW RSA signature with MD4"),
+    OidInfo::new("1.3.14.3.2.20", "szOID_OIWSEC_dsaComm", "Windows SDK object identifier"),
+    OidInfo::new(
+        "1.3.14.3.2.21",
+        "szOID_OIWSEC_dsaCommSHA",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.14.3.2.22", "szOID_OIWSEC_rsaXchg", "Windows SDK object identifier"),
+    OidInfo::new(
+        "1.3.14.3.2.23",
+        "szOID_OIWSEC_keyHashSeal",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.14.3.2.24",
+        "szOID_OIWSEC_md2RSASign",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.14.3.2.25",
+        "md5WithRSASignature",
+        "RSA algorithm coupled with the MD5 hashing algorithm (Oddball using ISO/IEC 9796-2 padding rules)",
+    ),
+    OidInfo::new("1.3.14.3.2.26", "id-sha1", "SHA-1 hash"),
+    OidInfo::new("1.3.14.3.2.27", "dsaWithSHA1", "OIW DSA signature with SHA-1"),
+    OidInfo::new(
+        "1.3.14.3.2.28",
+        "szOID_OIWSEC_dsaCommSHA1",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.14.3.2.29", "sha1WithRSASignature", "OIW RSA signature with SHA-1"),
+    OidInfo::new("1.3.14.3.2.3", "md5WithRSA", "OIW RSA signature with MD5"),
+    OidInfo::new("1.3.14.3.2.4", "szOID_OIWSEC_md4RSA2", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.3.2.6", "desECB", "DES in ECB mode"),
+    OidInfo::new("1.3.14.3.2.7", "desCBC", "DES in CBC mode"),
+    OidInfo::new("1.3.14.3.2.8", "desOFB", "DES in OFB mode"),
+    OidInfo::new("1.3.14.3.2.9", "desCFB", "DES in CFB mode"),
+    OidInfo::new("1.3.14.7.2", "szOID_OIWDIR", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.7.2.1", "szOID_OIWDIR_CRPT", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.7.2.2", "szOID_OIWDIR_HASH", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.7.2.2.1", "szOID_OIWDIR_md2", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.7.2.3", "szOID_OIWDIR_SIGN", "Windows SDK object identifier"),
+    OidInfo::new("1.3.14.7.2.3.1", "szOID_OIWDIR_md2RSA", "Windows SDK object identifier"),
+    OidInfo::new("1.3.18.0.2.24.46.1.101", "printer-device-id", "Defined in RFC 7612"),
+    OidInfo::new("1.3.18.0.2.24.46.1.104", "printer-uuid", "Defined in RFC 7612"),
+    OidInfo::new("1.3.18.0.2.24.46.1.105", "printer-charge-info", "Defined in RFC 7612"),
+    OidInfo::new(
+        "1.3.18.0.2.24.46.1.106",
+        "printer-charge-info-uri",
+        "Defined in RFC 7612",
+    ),
+    OidInfo::new("1.3.18.0.2.24.46.1.107", "printer-geo-location", "Defined in RFC 7612"),
+    OidInfo::new("1.3.18.0.2.4.1107", "printer-xri-supported", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1108", "printer-aliases", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1113", "printer-service-person", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1122", "printer-media-supported", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1123", "printer-sides-supported", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1129", "printer-color-supported", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1134", "printer-more-info", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1135", "printer-name", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1136", "printer-location", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1138", "printer-make-and-model", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1139", "printer-info", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.4.1140", "printer-uri", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.6.253", "printerLPR", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.6.254", "slpServicePrinter", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.6.255", "printerService", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.6.256", "printerIPP", "Defined in RFC 3712"),
+    OidInfo::new("1.3.18.0.2.6.258", "printerAbstract", "Defined in RFC 3712"),
+    OidInfo::new("1.3.36.3.2.1", "RIPEMD160", "ripemd160"),
+    OidInfo::new(
+        "1.3.36.3.3.1.2",
+        "rsaSignatureWithripemd160",
+        "RSA signature in combination with hash algorithm RIPEMD-160",
+    ),
+    OidInfo::new("1.3.36.3.3.2.5.2.1", "ECGDSA", "ECGDSA (pubkey)"),
+    OidInfo::new(
+        "1.3.36.3.3.2.5.4.1",
+        "ECGDSA/RIPEMD-160",
+        "ECGDSA/RIPEMD-160 (signature)",
+    ),
+    OidInfo::new("1.3.36.3.3.2.5.4.2", "ECGDSA/SHA-1", "ECGDSA/SHA-1 (signature)"),
+    OidInfo::new("1.3.36.3.3.2.5.4.3", "ECGDSA/SHA-224", "ECGDSA/SHA-224 (signature)"),
+    OidInfo::new("1.3.36.3.3.2.5.4.4", "ECGDSA/SHA-256", "ECGDSA/SHA-256 (signature)"),
+    OidInfo::new("1.3.36.3.3.2.5.4.5", "ECGDSA/SHA-384", "ECGDSA/SHA-384 (signature)"),
+    OidInfo::new("1.3.36.3.3.2.5.4.6", "ECGDSA/SHA-512", "ECGDSA/SHA-512 (signature)"),
+    OidInfo::new("1.3.36.3.3.2.8.1", "ellipticCurve", "Defined in RFC 5639"),
+    OidInfo::new("1.3.36.3.3.2.8.1.1", "versionOne", "Defined in RFC 5639"),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.1", "brainpoolP160r1", "Brainpool 160-bit curve"),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.10",
+        "brainpoolP320t1",
+        "Brainpool 320-bit twisted curve",
+    ),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.11", "brainpoolP384r1", "Brainpool 384-bit curve"),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.12",
+        "brainpoolP384t1",
+        "Brainpool 384-bit twisted curve",
+    ),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.13", "brainpoolP512r1", "Brainpool 512-bit curve"),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.14",
+        "brainpoolP512t1",
+        "Brainpool 512-bit twisted curve",
+    ),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.2",
+        "brainpoolP160t1",
+        "Brainpool 160-bit twisted curve",
+    ),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.3", "brainpoolP192r1", "Brainpool 192-bit curve"),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.4",
+        "brainpoolP192t1",
+        "Brainpool 192-bit twisted curve",
+    ),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.5", "brainpoolP224r1", "Brainpool 224-bit curve"),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.6",
+        "brainpoolP224t1",
+        "Brainpool 224-bit twisted curve",
+    ),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.7", "brainpoolP256r1", "Brainpool 256-bit curve"),
+    OidInfo::new(
+        "1.3.36.3.3.2.8.1.1.8",
+        "brainpoolP256t1",
+        "Brainpool 256-bit twisted curve",
+    ),
+    OidInfo::new("1.3.36.3.3.2.8.1.1.9", "brainpoolP320r1", "Brainpool 320-bit curve"),
+    OidInfo::new(
+        "1.3.36.8.3.3",
+        "x509ExtAdmission",
+        "Professional Information or basis for Admission",
+    ),
+    OidInfo::new("1.3.6", "DOD", "dod"),
+    OidInfo::new("1.3.6.1", "IANA", "iana"),
+    OidInfo::new("1.3.6.1.1", "directory", "Directory"),
+    OidInfo::new("1.3.6.1.1.10.15.2", "uddiContactNameForm", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.15.3", "uddiAddressNameForm", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.15.7", "uddiTModelNameForm", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.1", "uddiBusinessKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.10", "uddiEMail", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.11", "uddiSortCode", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.12", "uddiTModelKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.13", "uddiAddressLine", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.14", "uddiIdentifierBag", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.15", "uddiCategoryBag", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.16", "uddiKeyedReference", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.17", "uddiServiceKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.18", "uddiBindingKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.19", "uddiAccessPoint", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.2", "uddiAuthorizedName", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.20", "uddiHostingRedirector", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.22", "uddiInstanceParms", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.24", "uddiOverviewURL", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.25", "uddiFromKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.26", "uddiToKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.27", "uddiUUID", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.28", "uddiIsHidden", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.29", "uddiIsProjection", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.3", "uddiOperator", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.30", "uddiLang", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.31", "uddiv3BusinessKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.32", "uddiv3ServiceKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.33", "uddiv3BindingKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.34", "uddiv3TmodelKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.36", "uddiv3NodeId", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.38", "uddiv3SubscriptionKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.4", "uddiName", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.41", "uddiv3MaxEntities", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.42", "uddiv3ExpiresAfter", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.43", "uddiv3BriefResponse", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.44", "uddiv3EntityKey", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.5", "uddiDescription", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.6", "uddiDiscoveryURLs", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.7", "uddiUseType", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.8", "uddiPersonName", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.4.9", "uddiPhone", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.1", "uddiBusinessEntity", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.10", "uddiv3EntityObituary", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.2", "uddiContact", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.3", "uddiAddress", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.4", "uddiBusinessService", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.5", "uddiBindingTemplate", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.7", "uddiTModel", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.8", "uddiPublisherAssertion", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.10.6.9", "uddiv3Subscription", "Defined in RFC 4403"),
+    OidInfo::new("1.3.6.1.1.11.1.1", "vPIMUser", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.11.2.1", "vPIMTelephoneNumber", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.11.2.10", "vPIMSubMailboxes", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.11.2.2", "vPIMRfc822Mailbox", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.11.2.3", "vPIMSpokenName", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.11.2.7", "vPIMTextName", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.11.2.9", "vPIMMaxMessageSize", "Defined in RFC 4237"),
+    OidInfo::new("1.3.6.1.1.16.2", "uuidMatch", "Defined in RFC 4530"),
+    OidInfo::new("1.3.6.1.1.16.3", "uuidOrderingMatch", "Defined in RFC 4530"),
+    OidInfo::new("1.3.6.1.1.16.4", "entryUUID", "Defined in RFC 4530"),
+    OidInfo::new("1.3.6.1.1.20", "entryDN", "Defined in RFC 5020"),
+    OidInfo::new("1.3.6.1.1.23.1", "JIDObject", "Defined in RFC 8284"),
+    OidInfo::new("1.3.6.1.1.23.2", "jid", "Defined in RFC 8284"),
+    OidInfo::new("1.3.6.1.1.3.1", "uidObject", "Defined in RFC 4519"),
+    OidInfo::new("1.3.6.1.1.6.1.1", "pcimPolicy", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.11", "pcimConditionAuxClass", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.12", "pcimTPCAuxClass", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.14", "pcimActionAuxClass", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.16", "pcimPolicyInstance", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.17", "pcimElementAuxClass", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.18", "pcimRepository", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.2", "pcimGroup", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.20", "pcimRepositoryInstance", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.3", "pcimGroupAuxClass", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.4", "pcimGroupInstance", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.5", "pcimRule", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.6", "pcimRuleAuxClass", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.1.7", "pcimRuleInstance", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.11", "pcimRuleUsage", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.12", "pcimRulePriority", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.13", "pcimRuleMandatory", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.15", "pcimRoles", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.17", "pcimConditionNegated", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.18", "pcimConditionName", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.19", "pcimConditionDN", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.22", "pcimActionName", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.23", "pcimActionOrder", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.24", "pcimActionDN", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.25", "pcimTPCTime", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.28", "pcimTPCDayOfWeekMask", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.29", "pcimTPCTimeOfDayMask", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.3", "pcimKeywords", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.33", "pcimVendorActionData", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.36", "pcimRepositoryName", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.4", "pcimGroupName", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.5", "pcimRuleName", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.6", "pcimRuleEnabled", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.8", "pcimRuleConditionList", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.6.2.9", "pcimRuleActionList", "Defined in RFC 3703"),
+    OidInfo::new("1.3.6.1.1.9.1.1", "pcelsPolicySet", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.10", "pcelsActionAssociation", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.16", "pcelsVariable", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.3", "pcelsGroup", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.4", "pcelsGroupAuxClass", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.40", "pcelsValueAuxClass", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.48", "pcelsReusableContainer", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.5", "pcelsGroupInstance", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.51", "pcelsRoleCollection", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.52", "pcelsFilterEntryBase", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.53", "pcelsIPHeadersFilter", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.54", "pcels8021Filter", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.6", "pcelsRule", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.7", "pcelsRuleAuxClass", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.1.8", "pcelsRuleInstance", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.1", "pcelsPolicySetName", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.10", "pcelsExecutionStrategy", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.11", "pcelsVariableDN", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.12", "pcelsValueDN", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.13", "pcelsIsMirrored", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.14", "pcelsVariableName", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.19", "pcelsValueName", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.2", "pcelsDecisionStrategy", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.20", "pcelsIPv4AddrList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.21", "pcelsIPv6AddrList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.22", "pcelsMACAddrList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.23", "pcelsStringList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.24", "pcelsBitStringList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.25", "pcelsIntegerList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.26", "pcelsBoolean", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.29", "pcelsRole", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.3", "pcelsPolicySetList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.31", "pcelsElementList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.32", "pcelsFilterName", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.33", "pcelsFilterIsNegated", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.34", "pcelsIPHdrVersion", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.37", "pcelsIPHdrSourceMask", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.38", "pcelsIPHdrDestAddress", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.4", "pcelsPriority", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.40", "pcelsIPHdrDestMask", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.41", "pcelsIPHdrProtocolID", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.46", "pcelsIPHdrDSCPList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.47", "pcelsIPHdrFlowLabel", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.5", "pcelsPolicySetDN", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.53", "pcels8021HdrPriority", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.54", "pcels8021HdrVLANID", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.55", "pcelsFilterListName", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.56", "pcelsFilterDirection", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.57", "pcelsFilterEntryList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.60", "pcelsVendorValueData", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.7", "pcelsConditionList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.8", "pcelsActionList", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.1.9.2.9", "pcelsSequencedActions", "Defined in RFC 4104"),
+    OidInfo::new("1.3.6.1.2", "mgmt", "Management"),
+    OidInfo::new("1.3.6.1.3", "experimental", "Experimental"),
+    OidInfo::new("1.3.6.1.4", "private", "Private"),
+    OidInfo::new("1.3.6.1.4.1", "enterprises", "Enterprises"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.0", "defaultServerList", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.1", "defaultSearchBase", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.10", "credentialLevel", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.11", "objectclassMap", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.12", "defaultSearchScope", "Defined in RFC 4876"),
+    OidInfo::new(
+        "1.3.6.1.4.1.11.1.3.1.1.13",
+        "serviceCredentialLevel",
+        "Defined in RFC 4876",
+    ),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.16", "dereferenceAliases", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.2", "preferredServerList", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.3", "searchTimeLimit", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.4", "bindTimeLimit", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.5", "followReferrals", "Defined in RFC 4876"),
+    OidInfo::new(
+        "1.3.6.1.4.1.11.1.3.1.1.6",
+        "authenticationMethod",
+        "Defined in RFC 4876",
+    ),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.7", "profileTTL", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.1.9", "attributeMap", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11.1.3.1.2.5", "DUAConfigProfile", "Defined in RFC 4876"),
+    OidInfo::new("1.3.6.1.4.1.11129", "google", "Defined in RFC 6962"),
+    OidInfo::new(
+        "1.3.6.1.4.1.11129.2.4.2",
+        "signedCertificateTimestampList",
+        "Certificate Transparency SCT list extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.11129.2.4.3",
+        "precertificatePoison",
+        "Certificate Transparency precertificate poison",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.11129.2.4.4",
+        "precertificateSigning",
+        "Certificate Transparency precertificate signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.11129.2.4.5",
+        "ocspSignedCertificateTimestampList",
+        "Certificate Transparency OCSP SCT list",
+    ),
+    OidInfo::new("1.3.6.1.4.1.11591.15.1", "OpenPGP.Ed25519", "OpenPGP.Ed25519 (pubkey)"),
+    OidInfo::new("1.3.6.1.4.1.11591.4.11", "id-scrypt", "scrypt"),
+    OidInfo::new(
+        "1.3.6.1.4.1.1466.101.119.1",
+        "LDAP_TTL_EXTENDED_OP_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.1466.101.119.2", "dynamicObject", "Defined in RFC 2589"),
+    OidInfo::new("1.3.6.1.4.1.1466.101.119.3", "entryTtl", "Defined in RFC 2589"),
+    OidInfo::new("1.3.6.1.4.1.1466.101.119.4", "dynamicSubtrees", "Defined in RFC 2589"),
+    OidInfo::new(
+        "1.3.6.1.4.1.1466.101.120.111",
+        "extensibleObject",
+        "Defined in RFC 4512",
+    ),
+    OidInfo::new("1.3.6.1.4.1.1466.101.120.13", "supportedControl", "Defined in RFC 4512"),
+    OidInfo::new(
+        "1.3.6.1.4.1.1466.101.120.15",
+        "supportedLDAPVersion",
+        "Defined in RFC 4512",
+    ),
+    OidInfo::new("1.3.6.1.4.1.1466.101.120.16", "ldapSyntaxes", "Defined in RFC 4512"),
+    OidInfo::new("1.3.6.1.4.1.1466.101.120.5", "namingContexts", "Defined in RFC 4512"),
+    OidInfo::new("1.3.6.1.4.1.1466.101.120.6", "altServer", "Defined in RFC 4512"),
+    OidInfo::new(
+        "1.3.6.1.4.1.1466.101.120.7",
+        "supportedExtension",
+        "Defined in RFC 4512",
+    ),
+    OidInfo::new("1.3.6.1.4.1.1466.109.114.1", "caseExactIA5Match", "Defined in RFC 4517"),
+    OidInfo::new(
+        "1.3.6.1.4.1.1466.109.114.2",
+        "caseIgnoreIA5Match",
+        "Defined in RFC 4517",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.1466.20037",
+        "LDAP_START_TLS_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.1466.344", "dcobject", "dcObject"),
+    OidInfo::new("1.3.6.1.4.1.1466.345", "domainNameForm", "Defined in RFC 2247"),
+    OidInfo::new(
+        "1.3.6.1.4.1.16572.2.1.1",
+        "LDIFLocationURLObject",
+        "Defined in RFC 6109",
+    ),
+    OidInfo::new("1.3.6.1.4.1.16572.2.1.2", "provider", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.16572.2.2.2", "providerCertificate", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.16572.2.2.3", "providerName", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.16572.2.2.4", "mailReceipt", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.16572.2.2.5", "managedDomains", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.16572.2.2.6", "LDIFLocationURL", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.16572.2.2.7", "providerUnit", "Defined in RFC 6109"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.1", "BLAKE2BMAC", "blake2bmac"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.1.12", "id-blake2b384", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.1.16", "BLAKE2b512", "blake2b512"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.1.5", "id-blake2b160", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.1.8", "id-blake2b256", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.2", "BLAKE2SMAC", "blake2smac"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.2.4", "id-blake2s128", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.2.5", "id-blake2s160", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.2.7", "id-blake2s224", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.2.2.8", "BLAKE2s256", "blake2s256"),
+    OidInfo::new("1.3.6.1.4.1.1722.12.3", "macAlgs", "Defined in RFC 7693"),
+    OidInfo::new("1.3.6.1.4.1.188.7.1.1.2", "IDEA-CBC", "idea-cbc"),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.1",
+        "ClassicMcEliece_348864",
+        "ClassicMcEliece_348864 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.10",
+        "ClassicMcEliece_8192128f",
+        "ClassicMcEliece_8192128f (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.2",
+        "ClassicMcEliece_348864f",
+        "ClassicMcEliece_348864f (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.3",
+        "ClassicMcEliece_460896",
+        "ClassicMcEliece_460896 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.4",
+        "ClassicMcEliece_460896f",
+        "ClassicMcEliece_460896f (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.5",
+        "ClassicMcEliece_6688128",
+        "ClassicMcEliece_6688128 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.6",
+        "ClassicMcEliece_6688128f",
+        "ClassicMcEliece_6688128f (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.7",
+        "ClassicMcEliece_6960119",
+        "ClassicMcEliece_6960119 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.8",
+        "ClassicMcEliece_6960119f",
+        "ClassicMcEliece_6960119f (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.22554.5.1.9",
+        "ClassicMcEliece_8192128",
+        "ClassicMcEliece_8192128 (pubkey)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.250.1.57", "labeledURI", "Defined in RFC 2079"),
+    OidInfo::new("1.3.6.1.4.1.250.3.15", "labeledURIObject", "Defined in RFC 2079"),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.10.1",
+        "Dilithium-4x4-AES-r3",
+        "Dilithium-4x4-AES-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.10.2",
+        "Dilithium-6x5-AES-r3",
+        "Dilithium-6x5-AES-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.10.3",
+        "Dilithium-8x7-AES-r3",
+        "Dilithium-8x7-AES-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.11.1",
+        "Kyber-512-90s-r3",
+        "Kyber-512-90s-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.11.2",
+        "Kyber-768-90s-r3",
+        "Kyber-768-90s-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.11.3",
+        "Kyber-1024-90s-r3",
+        "Kyber-1024-90s-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.1.1",
+        "SphincsPlus-shake-128s-r3.1",
+        "SphincsPlus-shake-128s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.1.2",
+        "SphincsPlus-shake-128f-r3.1",
+        "SphincsPlus-shake-128f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.1.3",
+        "SphincsPlus-shake-192s-r3.1",
+        "SphincsPlus-shake-192s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.1.4",
+        "SphincsPlus-shake-192f-r3.1",
+        "SphincsPlus-shake-192f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.1.5",
+        "SphincsPlus-shake-256s-r3.1",
+        "SphincsPlus-shake-256s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.1.6",
+        "SphincsPlus-shake-256f-r3.1",
+        "SphincsPlus-shake-256f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.2.1",
+        "SphincsPlus-sha2-128s-r3.1",
+        "SphincsPlus-sha2-128s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.2.2",
+        "SphincsPlus-sha2-128f-r3.1",
+        "SphincsPlus-sha2-128f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.2.3",
+        "SphincsPlus-sha2-192s-r3.1",
+        "SphincsPlus-sha2-192s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.2.4",
+        "SphincsPlus-sha2-192f-r3.1",
+        "SphincsPlus-sha2-192f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.2.5",
+        "SphincsPlus-sha2-256s-r3.1",
+        "SphincsPlus-sha2-256s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.2.6",
+        "SphincsPlus-sha2-256f-r3.1",
+        "SphincsPlus-sha2-256f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.3.1",
+        "SphincsPlus-haraka-128s-r3.1",
+        "SphincsPlus-haraka-128s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.3.2",
+        "SphincsPlus-haraka-128f-r3.1",
+        "SphincsPlus-haraka-128f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.3.3",
+        "SphincsPlus-haraka-192s-r3.1",
+        "SphincsPlus-haraka-192s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.3.4",
+        "SphincsPlus-haraka-192f-r3.1",
+        "SphincsPlus-haraka-192f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.3.5",
+        "SphincsPlus-haraka-256s-r3.1",
+        "SphincsPlus-haraka-256s-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.12.3.6",
+        "SphincsPlus-haraka-256f-r3.1",
+        "SphincsPlus-haraka-256f-r3.1 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.13",
+        "HSS-LMS-Private-Key",
+        "HSS-LMS-Private-Key (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.14.1",
+        "FrodoKEM-640-SHAKE",
+        "FrodoKEM-640-SHAKE (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.14.2",
+        "FrodoKEM-976-SHAKE",
+        "FrodoKEM-976-SHAKE (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.14.3",
+        "FrodoKEM-1344-SHAKE",
+        "FrodoKEM-1344-SHAKE (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.15.1",
+        "FrodoKEM-640-AES",
+        "FrodoKEM-640-AES (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.15.2",
+        "FrodoKEM-976-AES",
+        "FrodoKEM-976-AES (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.15.3",
+        "FrodoKEM-1344-AES",
+        "FrodoKEM-1344-AES (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.16.1",
+        "eFrodoKEM-640-SHAKE",
+        "eFrodoKEM-640-SHAKE (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.16.2",
+        "eFrodoKEM-976-SHAKE",
+        "eFrodoKEM-976-SHAKE (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.16.3",
+        "eFrodoKEM-1344-SHAKE",
+        "eFrodoKEM-1344-SHAKE (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.17.1",
+        "eFrodoKEM-640-AES",
+        "eFrodoKEM-640-AES (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.17.2",
+        "eFrodoKEM-976-AES",
+        "eFrodoKEM-976-AES (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.17.3",
+        "eFrodoKEM-1344-AES",
+        "eFrodoKEM-1344-AES (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.18.1",
+        "ClassicMcEliece_6688128pc",
+        "ClassicMcEliece_6688128pc (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.18.2",
+        "ClassicMcEliece_6688128pcf",
+        "ClassicMcEliece_6688128pcf (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.18.3",
+        "ClassicMcEliece_6960119pc",
+        "ClassicMcEliece_6960119pc (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.18.4",
+        "ClassicMcEliece_6960119pcf",
+        "ClassicMcEliece_6960119pcf (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.18.5",
+        "ClassicMcEliece_8192128pc",
+        "ClassicMcEliece_8192128pc (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.18.6",
+        "ClassicMcEliece_8192128pcf",
+        "ClassicMcEliece_8192128pcf (pubkey)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.25258.1.3", "McEliece", "McEliece (pubkey)"),
+    OidInfo::new("1.3.6.1.4.1.25258.1.5", "XMSS-draft6", "XMSS-draft6 (pubkey)"),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.6.1",
+        "GOST-34.10-2012-256/SHA-256",
+        "GOST-34.10-2012-256/SHA-256 (signature)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.25258.1.7.1", "Kyber-512-r3", "Kyber-512-r3 (pubkey)"),
+    OidInfo::new("1.3.6.1.4.1.25258.1.7.2", "Kyber-768-r3", "Kyber-768-r3 (pubkey)"),
+    OidInfo::new("1.3.6.1.4.1.25258.1.7.3", "Kyber-1024-r3", "Kyber-1024-r3 (pubkey)"),
+    OidInfo::new("1.3.6.1.4.1.25258.1.8", "XMSS-draft12", "XMSS-draft12 (pubkey)"),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.9.1",
+        "Dilithium-4x4-r3",
+        "Dilithium-4x4-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.9.2",
+        "Dilithium-6x5-r3",
+        "Dilithium-6x5-r3 (pubkey)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.1.9.3",
+        "Dilithium-8x7-r3",
+        "Dilithium-8x7-r3 (pubkey)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.25258.3.1", "Serpent/CBC", "Serpent/CBC (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.101", "Serpent/GCM", "Serpent/GCM (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.102", "Twofish/GCM", "Twofish/GCM (cipher)"),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.2",
+        "Threefish-512/CBC",
+        "Threefish-512/CBC (cipher)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.25258.3.2.1", "AES-128/OCB", "AES-128/OCB (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.2.2", "AES-192/OCB", "AES-192/OCB (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.2.3", "AES-256/OCB", "AES-256/OCB (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.2.4", "Serpent/OCB", "Serpent/OCB (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.2.5", "Twofish/OCB", "Twofish/OCB (cipher)"),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.2.6",
+        "Camellia-128/OCB",
+        "Camellia-128/OCB (cipher)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.2.7",
+        "Camellia-192/OCB",
+        "Camellia-192/OCB (cipher)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.2.8",
+        "Camellia-256/OCB",
+        "Camellia-256/OCB (cipher)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.25258.3.3", "Twofish/CBC", "Twofish/CBC (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.4.1", "AES-128/SIV", "AES-128/SIV (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.4.2", "AES-192/SIV", "AES-192/SIV (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.4.3", "AES-256/SIV", "AES-256/SIV (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.4.4", "Serpent/SIV", "Serpent/SIV (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.3.4.5", "Twofish/SIV", "Twofish/SIV (cipher)"),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.4.6",
+        "Camellia-128/SIV",
+        "Camellia-128/SIV (cipher)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.4.7",
+        "Camellia-192/SIV",
+        "Camellia-192/SIV (cipher)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.25258.3.4.8",
+        "Camellia-256/SIV",
+        "Camellia-256/SIV (cipher)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.25258.3.4.9", "SM4/SIV", "SM4/SIV (cipher)"),
+    OidInfo::new("1.3.6.1.4.1.25258.4.1", "numsp256d1", "numsp256d1 (ecc_param)"),
+    OidInfo::new("1.3.6.1.4.1.25258.4.2", "numsp384d1", "numsp384d1 (ecc_param)"),
+    OidInfo::new("1.3.6.1.4.1.25258.4.3", "numsp512d1", "numsp512d1 (ecc_param)"),
+    OidInfo::new("1.3.6.1.4.1.3029.1.2", "BF-CBC", "bf-cbc"),
+    OidInfo::new("1.3.6.1.4.1.3029.1.2.1", "ElGamal", "ElGamal (pubkey)"),
+    OidInfo::new(
+        "1.3.6.1.4.1.3029.1.5.1",
+        "OpenPGP.Curve25519",
+        "OpenPGP.Curve25519 (pubkey)",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.10.1", "szOID_CTL", "Certificate trust list"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.1.1",
+        "szOID_SORTED_CTL",
+        "Sorted certificate trust list",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.10.1",
+        "szOID_CMC_ADD_ATTRIBUTES",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.104",
+        "CERT_DISALLOWED_FILETIME_PROP_ID",
+        "Disallowed file time property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.105",
+        "CERT_ROOT_PROGRAM_CHAIN_POLICIES_PROP_ID",
+        "Root program chain policies property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.11",
+        "CERT_FRIENDLY_NAME_PROP_ID",
+        "Friendly name property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.122",
+        "CERT_DISALLOWED_ENHKEY_USAGE_PROP_ID",
+        "Disallowed enhanced key usage property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.126",
+        "CERT_NOT_BEFORE_FILETIME_PROP_ID",
+        "Not before file time property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.127",
+        "CERT_NOT_BEFORE_ENHKEY_USAGE_PROP_ID",
+        "Not before enhanced key usage property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.128",
+        "szOID_CERT_DISALLOWED_CA_FILETIME_PROP_ID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.15",
+        "szOID_CERT_SIGNATURE_HASH_PROP_ID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.20",
+        "CERT_KEY_IDENTIFIER_PROP_ID",
+        "Key identifier property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.28",
+        "szOID_CERT_ISSUER_SERIAL_NUMBER_MD5_HASH_PROP_ID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.29",
+        "CERT_SUBJECT_NAME_MD5_HASH_PROP_ID",
+        "Subject name MD5 hash property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.4",
+        "szOID_CERT_MD5_HASH_PROP_ID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.83",
+        "CERT_ROOT_PROGRAM_CERT_POLICIES_PROP_ID",
+        "Root program certificate policies property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.9",
+        "CERT_ENHKEY_USAGE_PROP_ID",
+        "Enhanced key usage property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.11.98",
+        "CERT_AUTH_ROOT_SHA256_HASH_PROP_ID",
+        "Authroot SHA-256 hash property",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.12.1",
+        "szOID_ANY_APPLICATION_POLICY",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.2",
+        "szOID_NEXT_UPDATE_LOCATION",
+        "Next update location",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.1",
+        "szOID_KP_CTL_USAGE_SIGNING",
+        "Certificate trust list signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.10",
+        "szOID_KP_QUALIFIED_SUBORDINATION",
+        "Qualified subordination",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.10.3.11", "szOID_KP_KEY_RECOVERY", "Key recovery"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.12",
+        "szOID_KP_DOCUMENT_SIGNING",
+        "Document signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.13",
+        "szOID_KP_LIFETIME_SIGNING",
+        "Lifetime signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.14",
+        "szOID_KP_MOBILE_DEVICE_SOFTWARE",
+        "Mobile device software",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.15",
+        "szOID_KP_SMART_DISPLAY",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.16",
+        "szOID_KP_CSP_SIGNATURE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.19",
+        "szOID_REVOKED_LIST_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.2",
+        "szOID_KP_TIME_STAMP_SIGNING",
+        "Time-stamp signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.20",
+        "szOID_WINDOWS_KITS_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.21",
+        "szOID_WINDOWS_RT_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.22",
+        "szOID_PROTECTED_PROCESS_LIGHT_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.23",
+        "szOID_WINDOWS_TCB_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.24",
+        "szOID_PROTECTED_PROCESS_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.25",
+        "szOID_WINDOWS_THIRD_PARTY_COMPONENT_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.26",
+        "szOID_WINDOWS_SOFTWARE_EXTENSION_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.27",
+        "szOID_KP_FLIGHT_SIGNING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.28",
+        "szOID_PLATFORM_MANIFEST_BINARY_ID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.3",
+        "szOID_SERVER_GATED_CRYPTO",
+        "Microsoft server gated crypto",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.3.1",
+        "szOID_SERIALIZED",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.30",
+        "szOID_DISALLOWED_LIST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.31",
+        "szOID_PIN_RULES_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.32",
+        "szOID_PIN_RULES_CTL",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.33",
+        "szOID_PIN_RULES_EXT",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.34",
+        "szOID_PIN_RULES_DOMAIN_NAME",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.35",
+        "szOID_PIN_RULES_LOG_END_DATE_EXT",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.37",
+        "szOID_IUM_SIGNING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.39",
+        "szOID_EV_WHQL_CRYPTO",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.10.3.4", "szOID_KP_EFS", "Encrypting file system"),
+    OidInfo::new("1.3.6.1.4.1.311.10.3.4.1", "szOID_EFS_RECOVERY", "File recovery"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.41",
+        "szOID_BIOMETRIC_SIGNING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.42",
+        "szOID_ENCLAVE_SIGNING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.5",
+        "szOID_WHQL_CRYPTO",
+        "Windows hardware driver verification",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.5.1",
+        "szOID_ATTEST_WHQL_CRYPTO",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.50",
+        "szOID_SYNC_ROOT_CTL_EXT",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.6",
+        "szOID_NT5_CRYPTO",
+        "Windows system component verification",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.60",
+        "szOID_HPKP_DOMAIN_NAME_CTL",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.61",
+        "szOID_HPKP_HEADER_VALUE_CTL",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.7",
+        "szOID_OEM_WHQL_CRYPTO",
+        "OEM Windows system component verification",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.3.8",
+        "szOID_EMBEDDED_NT_CRYPTO",
+        "Embedded Windows system component verification",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.10.3.9", "szOID_ROOT_LIST_SIGNER", "Root list signer"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.4.1",
+        "szOID_YESNO_TRUST_ATTR",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.4.2",
+        "szOID_SITE_PIN_RULES_INDEX_ATTR",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.4.3",
+        "szOID_SITE_PIN_RULES_FLAGS_ATTR",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.41.1",
+        "SPC_WINDOWS_HELLO_COMPATIBILITY_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.10.5.1", "szOID_DRM", "Digital rights"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.5.2",
+        "szOID_DRM_INDIVIDUALIZATION",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.10.6.1", "szOID_LICENSES", "Key pack licenses"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.6.2",
+        "szOID_LICENSE_SERVER",
+        "License server verification",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.7.1",
+        "szOID_KEYID_RDN",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.8.1",
+        "szOID_REMOVE_CERTIFICATE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.10.9.1",
+        "szOID_CROSS_CERT_DIST_POINTS",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.12.1.1",
+        "szOID_CATALOG_LIST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.12.1.2",
+        "szOID_CATALOG_LIST_MEMBER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.12.1.3",
+        "szOID_CATALOG_LIST_MEMBER2",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.12.2.1",
+        "CAT_NAMEVALUE_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.12.2.2",
+        "CAT_MEMBERINFO_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.12.2.3",
+        "CAT_MEMBERINFO2_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.13.1",
+        "szOID_RENEWAL_CERTIFICATE",
+        "Renewal certificate",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.13.2.1",
+        "szOID_ENROLLMENT_NAME_VALUE_PAIR",
+        "Enrollment name value pair",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.13.2.2",
+        "szOID_ENROLLMENT_CSP_PROVIDER",
+        "Enrollment CSP provider",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.13.2.3", "szOID_OS_VERSION", "Operating system version"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.17.1",
+        "szOID_PKCS_12_KEY_PROVIDER_NAME_ATTR",
+        "Microsoft CSP name",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.17.2",
+        "szOID_LOCAL_MACHINE_KEYSET",
+        "Local machine keyset",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.17.3",
+        "szOID_PKCS_12_EXTENDED_ATTRIBUTES",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.17.4",
+        "szOID_PKCS_12_PROTECTED_PASSWORD_SECRET_BAG_TYPE_ID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.10",
+        "SPC_SP_AGENCY_INFO_OBJID",
+        "Authenticode agency information",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.11",
+        "SPC_STATEMENT_TYPE_OBJID",
+        "Authenticode statement type",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.12",
+        "SPC_SP_OPUS_INFO_OBJID",
+        "Authenticode program information",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.14",
+        "SPC_CERT_EXTENSIONS_OBJID",
+        "Authenticode certificate extensions",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.15",
+        "SPC_PE_IMAGE_DATA_OBJID",
+        "Authenticode PE image data",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.18",
+        "SPC_RAW_FILE_DATA_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.19",
+        "SPC_STRUCTURED_STORAGE_DATA_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.20",
+        "SPC_JAVA_CLASS_DATA_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.21",
+        "SPC_INDIVIDUAL_SP_KEY_PURPOSE_OBJID",
+        "Authenticode individual code signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.22",
+        "SPC_COMMERCIAL_SP_KEY_PURPOSE_OBJID",
+        "Authenticode commercial code signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.25",
+        "SPC_CAB_DATA_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.26",
+        "SPC_MINIMAL_CRITERIA_OBJID",
+        "Authenticode minimal criteria",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.27",
+        "SPC_FINANCIAL_CRITERIA_OBJID",
+        "Authenticode financial criteria",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.2.1.28", "SPC_LINK_OBJID", "Authenticode link"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.29",
+        "SPC_HASH_INFO_OBJID",
+        "Authenticode hash information",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.30",
+        "SPC_SIPINFO_OBJID",
+        "Authenticode SIP information",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.1.4",
+        "SPC_INDIRECT_DATA_OBJID",
+        "Authenticode indirect data content",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.2.1",
+        "szOID_TRUSTED_CODESIGNING_CA_LIST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.2.2.10", "NTLM", "NTLM security mechanism"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.2.2",
+        "szOID_TRUSTED_CLIENT_AUTH_CA_LIST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.2.3",
+        "szOID_TRUSTED_SERVER_AUTH_CA_LIST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.2.30",
+        "NEGOEX",
+        "Extended negotiation security mechanism",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.3.1",
+        "SPC_PE_IMAGE_PAGE_HASHES_V1_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.3.2",
+        "SPC_PE_IMAGE_PAGE_HASHES_V2_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.4.1",
+        "SPC_NESTED_SIGNATURE_OBJID",
+        "Authenticode nested signature",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.4.2",
+        "szOID_INTENT_TO_SEAL",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.4.3",
+        "szOID_SEALING_SIGNATURE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.4.4",
+        "szOID_SEALING_TIMESTAMP",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.5.1",
+        "szOID_ENHANCED_HASH",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.6.1",
+        "SPC_RELAXED_PE_MARKER_CHECK_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.2.6.2",
+        "SPC_ENCRYPTED_DIGEST_RETRY_COUNT_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.20.1",
+        "szOID_AUTO_ENROLL_CTL_USAGE",
+        "Auto enrollment CTL usage",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.20.2",
+        "szOID_ENROLL_CERTTYPE_EXTENSION",
+        "Certificate template name",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.20.2.1",
+        "szOID_ENROLLMENT_AGENT",
+        "Certificate request agent",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.20.2.2", "szOID_KP_SMARTCARD_LOGON", "Smart card logon"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.20.2.3",
+        "szOID_NT_PRINCIPAL_NAME",
+        "User principal name",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.20.2.4",
+        "szOID_INTERNATIONALIZED_EMAIL_ADDRESS",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.20.3", "szOID_CERT_MANIFOLD", "Certificate manifold"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.1",
+        "szOID_CERTSRV_CA_VERSION",
+        "Certificate services CA version",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.10",
+        "szOID_APPLICATION_CERT_POLICIES",
+        "Application policies",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.11",
+        "szOID_APPLICATION_POLICY_MAPPINGS",
+        "Application policy mappings",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.12",
+        "szOID_APPLICATION_POLICY_CONSTRAINTS",
+        "Application policy constraints",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.21.13", "szOID_ARCHIVED_KEY_ATTR", "Archived key"),
+    OidInfo::new("1.3.6.1.4.1.311.21.14", "szOID_CRL_SELF_CDP", "CRL self CDP"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.15",
+        "szOID_REQUIRE_CERT_CHAIN_POLICY",
+        "Require certificate chain policy",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.16",
+        "szOID_ARCHIVED_KEY_CERT_HASH",
+        "Archived key certificate hash",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.17",
+        "szOID_ISSUED_CERT_HASH",
+        "Issued certificate hash",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.19",
+        "szOID_DS_EMAIL_REPLICATION",
+        "Directory service e-mail replication",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.2",
+        "szOID_CERTSRV_PREVIOUS_CERT_HASH",
+        "Previous CA certificate hash",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.20",
+        "szOID_REQUEST_CLIENT_INFO",
+        "Request client information",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.21",
+        "szOID_ENCRYPTED_KEY_HASH",
+        "Encrypted key hash",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.22",
+        "szOID_CERTSRV_CROSSCA_VERSION",
+        "Certificate services cross CA version",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.23",
+        "szOID_ENROLL_EK_INFO",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.24",
+        "szOID_ENROLL_ATTESTATION_STATEMENT",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.25",
+        "szOID_ENROLL_KSP_NAME",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.26",
+        "szOID_ENROLL_EKPUB_CHALLENGE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.27",
+        "szOID_ENROLL_CAXCHGCERT_HASH",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.28",
+        "szOID_ENROLL_ATTESTATION_CHALLENGE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.29",
+        "szOID_ENROLL_ENCRYPTION_ALGORITHM",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.3",
+        "szOID_CRL_VIRTUAL_BASE",
+        "Virtual base CRL number",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.30",
+        "szOID_ENROLL_EKVERIFYKEY",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.31",
+        "szOID_ENROLL_EKVERIFYCERT",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.32",
+        "szOID_ENROLL_EKVERIFYCREDS",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.33",
+        "szOID_ENROLL_SCEP_ERROR",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.34",
+        "szOID_ENROLL_SCEP_SERVER_STATE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.35",
+        "szOID_ENROLL_SCEP_CHALLENGE_ANSWER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.36",
+        "szOID_KP_PRIVACY_CA",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.37",
+        "szOID_ENROLL_SCEP_CLIENT_REQUEST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.38",
+        "szOID_ENROLL_SCEP_SERVER_MESSAGE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.39",
+        "szOID_ENROLL_AIK_INFO",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.21.4", "szOID_CRL_NEXT_PUBLISH", "Next CRL publish"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.40",
+        "szOID_ENROLL_SCEP_SERVER_SECRET",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.41",
+        "szOID_ENROLL_KEY_AFFINITY",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.42",
+        "szOID_ENROLL_SCEP_SIGNER_HASH",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.43",
+        "szOID_ENROLL_EK_CA_KEYID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.21.5", "szOID_KP_CA_EXCHANGE", "Private key archival"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.6",
+        "szOID_KP_KEY_RECOVERY_AGENT",
+        "Key recovery agent",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.7",
+        "szOID_CERTIFICATE_TEMPLATE",
+        "Certificate template information",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.21.8",
+        "szOID_ENTERPRISE_OID_ROOT",
+        "Enterprise OID root",
+    ),
+    OidInfo::new("1.3.6.1.4.1.311.21.9", "szOID_RDN_DUMMY_SIGNER", "Dummy signer"),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.25.1",
+        "szOID_NTDS_REPLICATION",
+        "Directory service replication",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.25.2",
+        "szOID_NTDS_CA_SECURITY_EXT",
+        "Directory service CA security extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.25.2.1",
+        "szOID_NTDS_OBJECTSID",
+        "Directory service object SID",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.3.2.1",
+        "SPC_TIME_STAMP_REQUEST_OBJID",
+        "Authenticode time-stamp request",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.3.3.1",
+        "szOID_RFC3161_counterSign",
+        "RFC 3161 counter signature (Ms-CounterSign)",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.3.3.2",
+        "szOID_RFC3161v21_counterSign",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.3.3.3",
+        "szOID_RFC3161v21_thumbprints",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.31.1",
+        "szOID_PRODUCT_UPDATE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.54.1.2",
+        "szOID_TS_KP_TS_SERVER_AUTH",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.1.1",
+        "szOID_ROOT_PROGRAM_FLAGS",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.2.1.1",
+        "jurisdictionOfIncorporationLocalityName",
+        "EV jurisdiction locality",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.2.1.2",
+        "jurisdictionOfIncorporationStateOrProvinceName",
+        "EV jurisdiction state or province",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.2.1.3",
+        "jurisdictionOfIncorporationCountryName",
+        "EV jurisdiction country",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.3.1",
+        "szOID_ROOT_PROGRAM_AUTO_UPDATE_CA_REVOCATION",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.3.2",
+        "szOID_AUTO_UPDATE_END_REVOCATION",
+        "Auto update end revocation",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.60.3.3",
+        "szOID_ROOT_PROGRAM_NO_OCSP_FAILOVER_TO_CRL",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.61.1.1",
+        "szOID_KP_KERNEL_MODE_CODE_SIGNING",
+        "Kernel mode code signing",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.61.4.1",
+        "szOID_KP_KERNEL_MODE_TRUSTED_BOOT_SIGNING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.61.5.1",
+        "szOID_KP_KERNEL_MODE_HAL_EXTENSION_SIGNING",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.72.1.1",
+        "szOID_CERT_STRONG_SIGN_OS_1",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.72.2.1",
+        "szOID_CERT_STRONG_KEY_OS_1",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.74.1",
+        "ProtectionDescriptorType",
+        "DPAPI-NG protection descriptor type",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.74.1.1",
+        "SID Protection Descriptor",
+        "DPAPI-NG SID protection descriptor",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.74.1.2",
+        "Key File Protection Descriptor",
+        "DPAPI-NG key file protection descriptor",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.74.1.5",
+        "SDDL Protection Descriptor",
+        "DPAPI-NG SDDL protection descriptor",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.74.1.8",
+        "LOCAL Protection Descriptor",
+        "DPAPI-NG local protection descriptor",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.76.3.1",
+        "szOID_WINDOWS_STORE_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.76.5.1",
+        "szOID_DYNAMIC_CODE_GEN_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.76.8.1",
+        "szOID_MICROSOFT_PUBLISHER_SIGNER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.4.1.311.96.1.1",
+        "SPC_NATURAL_AUTH_PLUGIN_OBJID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.31103.1.1", "fedfsUuid", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.10", "fedfsFslPort", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.100", "fedfsNfsPath", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.1002", "fedfsFsn", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.1003", "fedfsFsl", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.1004", "fedfsNfsFsl", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.101", "fedfsNfsMajorVer", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.102", "fedfsNfsMinorVer", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.103", "fedfsNfsCurrency", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.105", "fedfsNfsGenFlagGoing", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.106", "fedfsNfsGenFlagSplit", "Defined in RFC 7532"),
+    OidInfo::new(
+        "1.3.6.1.4.1.31103.1.107",
+        "fedfsNfsTransFlagRdma",
+        "Defined in RFC 7532",
+    ),
+    OidInfo::new("1.3.6.1.4.1.31103.1.108", "fedfsNfsClassSimul", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.109", "fedfsNfsClassHandle", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.11", "fedfsFslTTL", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.110", "fedfsNfsClassFileid", "Defined in RFC 7532"),
+    OidInfo::new(
+        "1.3.6.1.4.1.31103.1.111",
+        "fedfsNfsClassWritever",
+        "Defined in RFC 7532",
+    ),
+    OidInfo::new("1.3.6.1.4.1.31103.1.112", "fedfsNfsClassChange", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.113", "fedfsNfsClassReaddir", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.114", "fedfsNfsReadRank", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.115", "fedfsNfsReadOrder", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.116", "fedfsNfsWriteRank", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.117", "fedfsNfsWriteOrder", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.118", "fedfsNfsVarSub", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.119", "fedfsNfsValidFor", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.12", "fedfsAnnotation", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.120", "fedfsNfsURI", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.13", "fedfsDescr", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.14", "fedfsNceDN", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.15", "fedfsFsnTTL", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.2", "fedfsNetAddr", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.3", "fedfsNetPort", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.4", "fedfsFsnUuid", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.5", "fedfsNsdbName", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.6", "fedfsNsdbPort", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.7", "fedfsNcePrefix", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.8", "fedfsFslUuid", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.31103.1.9", "fedfsFslHost", "Defined in RFC 7532"),
+    OidInfo::new("1.3.6.1.4.1.41482.3.10", "yubikeyFips", "FIPS certified YubiKey"),
+    OidInfo::new("1.3.6.1.4.1.41482.3.11", "yubikeyCspn", "CSPN certified YubiKey"),
+    OidInfo::new(
+        "1.3.6.1.4.1.41482.3.3",
+        "yubikeyFirmwareVersion",
+        "YubiKey firmware version",
+    ),
+    OidInfo::new("1.3.6.1.4.1.41482.3.7", "yubikeySerialNumber", "YubiKey serial number"),
+    OidInfo::new(
+        "1.3.6.1.4.1.41482.3.8",
+        "yubikeyPolicy",
+        "YubiKey PIN policy and touch policy",
+    ),
+    OidInfo::new("1.3.6.1.4.1.41482.3.9", "yubikeyFormFactor", "YubiKey form factor"),
+    OidInfo::new(
+        "1.3.6.1.4.1.4203.1.11.3",
+        "LDAP_SERVER_WHO_AM_I_OID",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.4.1.4203.1.3.5", "supportedFeatures", "Defined in RFC 4512"),
+    OidInfo::new(
+        "1.3.6.1.4.1.44947.1.1.1",
+        "isrgDomainValidated",
+        "ISRG domain validated policy",
+    ),
+    OidInfo::new("1.3.6.1.4.1.453.7.1.1", "rFC822ToX400Mapping", "Defined in RFC 2164"),
+    OidInfo::new("1.3.6.1.4.1.453.7.1.2", "x400ToRFC822Mapping", "Defined in RFC 2164"),
+    OidInfo::new("1.3.6.1.4.1.453.7.1.3", "tableEntry", "Defined in RFC 2293"),
+    OidInfo::new("1.3.6.1.4.1.453.7.1.4", "mixerGateway", "Defined in RFC 2164"),
+    OidInfo::new("1.3.6.1.4.1.453.7.2.1", "textTableKey", "Defined in RFC 2293"),
+    OidInfo::new("1.3.6.1.4.1.453.7.2.2", "textTableValue", "Defined in RFC 2293"),
+    OidInfo::new("1.3.6.1.4.1.453.7.2.3", "associatedX400Gateway", "Defined in RFC 2164"),
+    OidInfo::new("1.3.6.1.4.1.453.7.2.6", "associatedORAddress", "Defined in RFC 2164"),
+    OidInfo::new("1.3.6.1.4.1.453.7.2.9", "mcgamTables", "Defined in RFC 2164"),
+    OidInfo::new(
+        "1.3.6.1.4.1.45605.1",
+        "id-kp-wisun-fan-device",
+        "Wi-SUN Alliance Field Area Network (FAN)",
+    ),
+    OidInfo::new("1.3.6.1.5", "security", "Security"),
+    OidInfo::new(
+        "1.3.6.1.5.2.2",
+        "id-pkinit-san",
+        "PKINIT Kerberos principal name (KRB5PrincipalName)",
+    ),
+    OidInfo::new("1.3.6.1.5.2.3", "id-pkinit", "id-pkinit"),
+    OidInfo::new("1.3.6.1.5.2.3.1", "id-pkinit-authData", "PKINIT authentication data"),
+    OidInfo::new(
+        "1.3.6.1.5.2.3.2",
+        "id-pkinit-DHKeyData",
+        "PKINIT Diffie-Hellman key data",
+    ),
+    OidInfo::new("1.3.6.1.5.2.3.3", "id-pkinit-rkeyData", "PKINIT reply key data"),
+    OidInfo::new(
+        "1.3.6.1.5.2.3.4",
+        "id-pkinit-KPClientAuth",
+        "PKINIT client authentication",
+    ),
+    OidInfo::new("1.3.6.1.5.2.3.5", "id-pkinit-KPKdc", "PKINIT KDC"),
+    OidInfo::new("1.3.6.1.5.2.3.6", "id-pkinit-kdf", "PKINIT key derivation functions"),
+    OidInfo::new(
+        "1.3.6.1.5.2.3.6.1",
+        "id-pkinit-kdf-ah-sha1",
+        "PKINIT key derivation with SHA-1",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.2.3.6.2",
+        "id-pkinit-kdf-ah-sha256",
+        "PKINIT key derivation with SHA-256",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.2.3.6.3",
+        "id-pkinit-kdf-ah-sha512",
+        "PKINIT key derivation with SHA-512",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.2.3.6.4",
+        "id-pkinit-kdf-ah-sha384",
+        "PKINIT key derivation with SHA-384",
+    ),
+    OidInfo::new("1.3.6.1.5.2.5", "iakerb", "IAKERB GSS-API mechanism"),
+    OidInfo::new("1.3.6.1.5.2.7", "pku2u", "PKU2U GSS-API mechanism"),
+    OidInfo::new("1.3.6.1.5.5.11", "ltans", "Defined in RFC 5911"),
+    OidInfo::new(
+        "1.3.6.1.5.5.2",
+        "spnego",
+        "Simple and protected GSS-API negotiation mechanism",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7", "PKIX", "PKIX"),
+    OidInfo::new("1.3.6.1.5.5.7.0", "id-pkix-mod", "id-pkix-mod"),
+    OidInfo::new("1.3.6.1.5.5.7.0.1", "id-pkix1-explicit-88", "id-pkix1-explicit-88"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.0.10",
+        "id-mod-qualified-cert-88",
+        "id-mod-qualified-cert-88",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.0.100", "id-mod-cmp2021-02", "id-mod-cmp2021-02"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.0.11",
+        "id-mod-qualified-cert-93",
+        "id-mod-qualified-cert-93",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.0.12", "id-mod-attribute-cert", "id-mod-attribute-cert"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.0.13",
+        "id-mod-timestamp-protocol",
+        "id-mod-timestamp-protocol",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.0.14", "id-mod-ocsp", "id-mod-ocsp"),
+    OidInfo::new("1.3.6.1.5.5.7.0.15", "id-mod-dvcs", "id-mod-dvcs"),
+    OidInfo::new("1.3.6.1.5.5.7.0.16", "id-mod-cmp2000", "id-mod-cmp2000"),
+    OidInfo::new("1.3.6.1.5.5.7.0.2", "id-pkix1-implicit-88", "id-pkix1-implicit-88"),
+    OidInfo::new("1.3.6.1.5.5.7.0.3", "id-pkix1-explicit-93", "id-pkix1-explicit-93"),
+    OidInfo::new("1.3.6.1.5.5.7.0.4", "id-pkix1-implicit-93", "id-pkix1-implicit-93"),
+    OidInfo::new("1.3.6.1.5.5.7.0.5", "id-mod-crmf", "id-mod-crmf"),
+    OidInfo::new("1.3.6.1.5.5.7.0.50", "id-mod-cmp2000-02", "id-mod-cmp2000-02"),
+    OidInfo::new("1.3.6.1.5.5.7.0.6", "id-mod-cmc", "id-mod-cmc"),
+    OidInfo::new("1.3.6.1.5.5.7.0.7", "id-mod-kea-profile-88", "id-mod-kea-profile-88"),
+    OidInfo::new("1.3.6.1.5.5.7.0.8", "id-mod-kea-profile-93", "id-mod-kea-profile-93"),
+    OidInfo::new("1.3.6.1.5.5.7.0.9", "id-mod-cmp", "id-mod-cmp"),
+    OidInfo::new("1.3.6.1.5.5.7.0.99", "id-mod-cmp2021-88", "id-mod-cmp2021-88"),
+    OidInfo::new("1.3.6.1.5.5.7.1", "id-pe", "id-pe"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.1",
+        "id-pe-authorityInfoAccess",
+        "Authority information access extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.10",
+        "id-pe-ac-proxying",
+        "Attribute certificate proxying extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.11",
+        "id-pe-subjectInfoAccess",
+        "Subject information access extension",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.1.12", "id-pe-logotype", "Logotype extension"),
+    OidInfo::new("1.3.6.1.5.5.7.1.14", "proxyCertInfo", "Proxy Certificate Information"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.2",
+        "id-pe-biometricInfo",
+        "Biometric information extension",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.1.24", "id-pe-tlsfeature", "TLS feature extension"),
+    OidInfo::new("1.3.6.1.5.5.7.1.26", "PKIX.TNAuthList", "PKIX.TNAuthList (pkix)"),
+    OidInfo::new("1.3.6.1.5.5.7.1.28", "sbgp-ipAddrBlockv2", "sbgp-ipAddrBlockv2"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.29",
+        "sbgp-autonomousSysNumv2",
+        "sbgp-autonomousSysNumv2",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.3",
+        "id-pe-qcStatements",
+        "Qualified certificate statements extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.4",
+        "id-pe-ac-auditIdentity",
+        "Attribute certificate audit identity extension",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.1.5", "ac-targeting", "ac-targeting"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.1.6",
+        "id-pe-aaControls",
+        "Attribute authority controls extension",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.1.7", "sbgp-ipAddrBlock", "sbgp-ipAddrBlock"),
+    OidInfo::new("1.3.6.1.5.5.7.1.8", "sbgp-autonomousSysNum", "sbgp-autonomousSysNum"),
+    OidInfo::new("1.3.6.1.5.5.7.1.9", "sbgp-routerIdentifier", "sbgp-routerIdentifier"),
+    OidInfo::new("1.3.6.1.5.5.7.10", "id-aca", "id-aca"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.10.1",
+        "id-aca-authenticationInfo",
+        "id-aca-authenticationInfo",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.10.2", "id-aca-accessIdentity", "id-aca-accessIdentity"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.10.3",
+        "id-aca-chargingIdentity",
+        "id-aca-chargingIdentity",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.10.4", "id-aca-group", "id-aca-group"),
+    OidInfo::new("1.3.6.1.5.5.7.10.5", "id-aca-role", "id-aca-role"),
+    OidInfo::new("1.3.6.1.5.5.7.10.6", "id-aca-encAttrs", "id-aca-encAttrs"),
+    OidInfo::new("1.3.6.1.5.5.7.11", "id-qcs", "id-qcs"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.11.1",
+        "id-qcs-pkixQCSyntax-v1",
+        "Qualified certificate syntax v1",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.11.2",
+        "id-qcs-pkixQCSyntax-v2",
+        "Qualified certificate syntax v2",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.12", "id-cct", "id-cct"),
+    OidInfo::new("1.3.6.1.5.5.7.12.1", "id-cct-crs", "id-cct-crs"),
+    OidInfo::new("1.3.6.1.5.5.7.12.2", "id-cct-PKIData", "id-cct-PKIData"),
+    OidInfo::new("1.3.6.1.5.5.7.12.3", "id-cct-PKIResponse", "id-cct-PKIResponse"),
+    OidInfo::new("1.3.6.1.5.5.7.13", "id-TEST", "Defined in RFC 7299"),
+    OidInfo::new("1.3.6.1.5.5.7.14", "id-cp", "id-cp"),
+    OidInfo::new("1.3.6.1.5.5.7.14.2", "ipAddr-asNumber", "ipAddr-asNumber"),
+    OidInfo::new("1.3.6.1.5.5.7.14.3", "ipAddr-asNumberv2", "ipAddr-asNumberv2"),
+    OidInfo::new("1.3.6.1.5.5.7.15", "id-cet", "Defined in RFC 7299"),
+    OidInfo::new("1.3.6.1.5.5.7.15.1", "id-cet-skdFailInfo", "Defined in RFC 5911"),
+    OidInfo::new("1.3.6.1.5.5.7.16", "id-ri", "Defined in RFC 7299"),
+    OidInfo::new("1.3.6.1.5.5.7.17", "id-stc", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.17.1", "id-stc-build-pkc-path", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.17.4", "id-stc-build-aa-path", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.18", "id-swb", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.18.10", "id-swb-pkc-cert", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.18.11", "id-swb-ac-cert", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.18.5", "id-swb-aa-cert-path", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.19", "id-svp", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.19.2", "id-svp-nameValAlg", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.19.3", "id-svp-basicValAlg", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.19.4", "NameCompAlgSet", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.2", "id-qt", "id-qt"),
+    OidInfo::new("1.3.6.1.5.5.7.2.1", "id-qt-cps", "CPS pointer qualifier"),
+    OidInfo::new("1.3.6.1.5.5.7.2.2", "id-qt-unotice", "User notice qualifier"),
+    OidInfo::new("1.3.6.1.5.5.7.2.3", "textNotice", "textNotice"),
+    OidInfo::new("1.3.6.1.5.5.7.20", "id-logo", "Defined in RFC 7299"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.20.1",
+        "szOID_LOYALTY_OTHER_LOGOTYPE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.20.2",
+        "szOID_BACKGROUND_OTHER_LOGOTYPE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.21", "id-ppl", "id-ppl"),
+    OidInfo::new("1.3.6.1.5.5.7.21.0", "id-ppl-anyLanguage", "Any language"),
+    OidInfo::new("1.3.6.1.5.5.7.21.1", "id-ppl-inheritAll", "Inherit all"),
+    OidInfo::new("1.3.6.1.5.5.7.21.2", "id-ppl-independent", "Independent"),
+    OidInfo::new("1.3.6.1.5.5.7.22", "id-mr", "Defined in RFC 7299"),
+    OidInfo::new("1.3.6.1.5.5.7.23", "id-skis", "Defined in RFC 7299"),
+    OidInfo::new("1.3.6.1.5.5.7.24", "id-scep", "Defined in RFC 8894"),
+    OidInfo::new("1.3.6.1.5.5.7.24.1", "id-scep-failInfoText", "Defined in RFC 8894"),
+    OidInfo::new("1.3.6.1.5.5.7.3", "id-kp", "id-kp"),
+    OidInfo::new("1.3.6.1.5.5.7.3.1", "id-kp-serverAuth", "TLS web server authentication"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.3.10",
+        "id-kp-dvcs",
+        "Data validation and certification server",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.3.13", "id-kp-eapOverPPP", "EAP over PPP"),
+    OidInfo::new("1.3.6.1.5.5.7.3.14", "id-kp-eapOverLAN", "EAP over LAN"),
+    OidInfo::new("1.3.6.1.5.5.7.3.15", "id-kp-scvpServer", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.3.16", "id-kp-scvpClient", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.3.17", "id-kp-ipsecIKE", "IPsec IKE"),
+    OidInfo::new("1.3.6.1.5.5.7.3.18", "capwapAC", "Ctrl/provision WAP Access"),
+    OidInfo::new("1.3.6.1.5.5.7.3.19", "capwapWTP", "Ctrl/Provision WAP Termination"),
+    OidInfo::new("1.3.6.1.5.5.7.3.2", "id-kp-clientAuth", "TLS web client authentication"),
+    OidInfo::new("1.3.6.1.5.5.7.3.21", "id-kp-secureShellClient", "SSH client"),
+    OidInfo::new("1.3.6.1.5.5.7.3.22", "id-kp-secureShellServer", "SSH server"),
+    OidInfo::new("1.3.6.1.5.5.7.3.23", "sendRouter", "Send Router"),
+    OidInfo::new("1.3.6.1.5.5.7.3.24", "sendProxiedRouter", "Send Proxied Router"),
+    OidInfo::new("1.3.6.1.5.5.7.3.25", "sendOwner", "Send Owner"),
+    OidInfo::new("1.3.6.1.5.5.7.3.26", "sendProxiedOwner", "Send Proxied Owner"),
+    OidInfo::new("1.3.6.1.5.5.7.3.27", "cmcCA", "CMC Certificate Authority"),
+    OidInfo::new("1.3.6.1.5.5.7.3.28", "cmcRA", "CMC Registration Authority"),
+    OidInfo::new("1.3.6.1.5.5.7.3.29", "cmcArchive", "CMC Archive Server"),
+    OidInfo::new("1.3.6.1.5.5.7.3.3", "id-kp-codeSigning", "Code signing"),
+    OidInfo::new("1.3.6.1.5.5.7.3.30", "id-kp-bgpsec-router", "BGPsec Router"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.3.31",
+        "id-kp-BrandIndicatorforMessageIdentification",
+        "Brand Indicator for Message Identification",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.3.32",
+        "cmKGA",
+        "Certificate Management Key Generation Authority",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.3.4", "id-kp-emailProtection", "E-mail protection"),
+    OidInfo::new("1.3.6.1.5.5.7.3.5", "id-kp-ipsecEndSystem", "IPsec end system"),
+    OidInfo::new("1.3.6.1.5.5.7.3.6", "id-kp-ipsecTunnel", "IPsec tunnel"),
+    OidInfo::new("1.3.6.1.5.5.7.3.7", "id-kp-ipsecUser", "IPsec user"),
+    OidInfo::new("1.3.6.1.5.5.7.3.8", "id-kp-timeStamping", "Time stamping"),
+    OidInfo::new("1.3.6.1.5.5.7.3.9", "id-kp-OCSPSigning", "OCSP signing"),
+    OidInfo::new("1.3.6.1.5.5.7.4", "id-it", "id-it"),
+    OidInfo::new("1.3.6.1.5.5.7.4.1", "id-it-caProtEncCert", "id-it-caProtEncCert"),
+    OidInfo::new("1.3.6.1.5.5.7.4.10", "id-it-keyPairParamReq", "id-it-keyPairParamReq"),
+    OidInfo::new("1.3.6.1.5.5.7.4.11", "id-it-keyPairParamRep", "id-it-keyPairParamRep"),
+    OidInfo::new("1.3.6.1.5.5.7.4.12", "id-it-revPassphrase", "id-it-revPassphrase"),
+    OidInfo::new("1.3.6.1.5.5.7.4.13", "id-it-implicitConfirm", "id-it-implicitConfirm"),
+    OidInfo::new("1.3.6.1.5.5.7.4.14", "id-it-confirmWaitTime", "id-it-confirmWaitTime"),
+    OidInfo::new("1.3.6.1.5.5.7.4.15", "id-it-origPKIMessage", "id-it-origPKIMessage"),
+    OidInfo::new("1.3.6.1.5.5.7.4.16", "id-it-suppLangTags", "id-it-suppLangTags"),
+    OidInfo::new("1.3.6.1.5.5.7.4.17", "id-it-caCerts", "id-it-caCerts"),
+    OidInfo::new("1.3.6.1.5.5.7.4.18", "id-it-rootCaKeyUpdate", "id-it-rootCaKeyUpdate"),
+    OidInfo::new("1.3.6.1.5.5.7.4.19", "id-it-certReqTemplate", "id-it-certReqTemplate"),
+    OidInfo::new("1.3.6.1.5.5.7.4.2", "id-it-signKeyPairTypes", "id-it-signKeyPairTypes"),
+    OidInfo::new("1.3.6.1.5.5.7.4.20", "id-it-rootCaCert", "id-it-rootCaCert"),
+    OidInfo::new("1.3.6.1.5.5.7.4.21", "id-it-certProfile", "id-it-certProfile"),
+    OidInfo::new("1.3.6.1.5.5.7.4.22", "id-it-crlStatusList", "id-it-crlStatusList"),
+    OidInfo::new("1.3.6.1.5.5.7.4.23", "id-it-crls", "id-it-crls"),
+    OidInfo::new("1.3.6.1.5.5.7.4.3", "id-it-encKeyPairTypes", "id-it-encKeyPairTypes"),
+    OidInfo::new("1.3.6.1.5.5.7.4.4", "id-it-preferredSymmAlg", "id-it-preferredSymmAlg"),
+    OidInfo::new("1.3.6.1.5.5.7.4.5", "id-it-caKeyUpdateInfo", "id-it-caKeyUpdateInfo"),
+    OidInfo::new("1.3.6.1.5.5.7.4.6", "id-it-currentCRL", "id-it-currentCRL"),
+    OidInfo::new("1.3.6.1.5.5.7.4.7", "id-it-unsupportedOIDs", "id-it-unsupportedOIDs"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.4.8",
+        "id-it-subscriptionRequest",
+        "id-it-subscriptionRequest",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.4.9",
+        "id-it-subscriptionResponse",
+        "id-it-subscriptionResponse",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.48", "id-ad", "id-ad"),
+    OidInfo::new("1.3.6.1.5.5.7.48.1", "id-ad-ocsp", "OCSP access method"),
+    OidInfo::new("1.3.6.1.5.5.7.48.1.1", "id-pkix-ocsp-basic", "Basic OCSP response"),
+    OidInfo::new("1.3.6.1.5.5.7.48.1.10", "path", "path"),
+    OidInfo::new("1.3.6.1.5.5.7.48.1.11", "trustRoot", "Trust Root"),
+    OidInfo::new("1.3.6.1.5.5.7.48.1.2", "id-pkix-ocsp-nonce", "OCSP nonce extension"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.1.3",
+        "id-pkix-ocsp-crl",
+        "OCSP CRL references extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.1.4",
+        "id-pkix-ocsp-response",
+        "OCSP acceptable responses extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.1.5",
+        "id-pkix-ocsp-nocheck",
+        "OCSP no check extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.1.6",
+        "id-pkix-ocsp-archive-cutoff",
+        "OCSP archive cutoff extension",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.1.7",
+        "id-pkix-ocsp-service-locator",
+        "OCSP service locator extension",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.48.1.8", "extendedStatus", "Extended OCSP Status"),
+    OidInfo::new("1.3.6.1.5.5.7.48.1.9", "valid", "valid"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.10",
+        "id-ad-rpki-manifest",
+        "PKIX Access Descriptor RPKI Manifest",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.11",
+        "id-ad-signed-object",
+        "PKIX Access Descriptor Signed Object",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.48.12", "id-ad-cmc", "PKIX Access Descriptor CMC"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.13",
+        "id-ad-rpki-notify",
+        "PKIX Access Descriptor RPKI Notify",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.14",
+        "id-ad-stirTNList",
+        "PKIX Access Descriptor STIRTNLIST",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.48.2", "id-ad-caIssuers", "CA issuers access method"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.3",
+        "id-ad-timeStamping",
+        "Time stamping access method",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.48.4", "id-ad-dvcs", "DVCS access method"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.5",
+        "id-ad-caRepository",
+        "CA repository access method",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.6",
+        "id-ad-http-certs",
+        "PKIX Access Descriptor HTTP Certificates",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.48.7",
+        "id-ad-http-crls",
+        "PKIX Access Descriptor HTTP Certificate Revocation Lists",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.5", "id-pkip", "id-pkip"),
+    OidInfo::new("1.3.6.1.5.5.7.5.1", "id-regCtrl", "id-regCtrl"),
+    OidInfo::new("1.3.6.1.5.5.7.5.1.1", "id-regCtrl-regToken", "id-regCtrl-regToken"),
+    OidInfo::new("1.3.6.1.5.5.7.5.1.11", "id-regCtrl-algId", "id-regCtrl-algId"),
+    OidInfo::new("1.3.6.1.5.5.7.5.1.12", "id-regCtrl-rsaKeyLen", "id-regCtrl-rsaKeyLen"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.5.1.2",
+        "id-regCtrl-authenticator",
+        "id-regCtrl-authenticator",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.5.1.3",
+        "id-regCtrl-pkiPublicationInfo",
+        "id-regCtrl-pkiPublicationInfo",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.5.1.4",
+        "id-regCtrl-pkiArchiveOptions",
+        "id-regCtrl-pkiArchiveOptions",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.5.1.5", "id-regCtrl-oldCertID", "id-regCtrl-oldCertID"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.5.1.6",
+        "id-regCtrl-protocolEncrKey",
+        "id-regCtrl-protocolEncrKey",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.5.1.7",
+        "id-regCtrl-altCertTemplate",
+        "id-regCtrl-altCertTemplate",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.5.2", "id-regInfo", "id-regInfo"),
+    OidInfo::new("1.3.6.1.5.5.7.5.2.1", "id-regInfo-utf8Pairs", "id-regInfo-utf8Pairs"),
+    OidInfo::new("1.3.6.1.5.5.7.5.2.2", "id-regInfo-certReq", "id-regInfo-certReq"),
+    OidInfo::new("1.3.6.1.5.5.7.6", "id-alg", "id-alg"),
+    OidInfo::new("1.3.6.1.5.5.7.6.1", "id-alg-des40", "id-alg-des40"),
+    OidInfo::new("1.3.6.1.5.5.7.6.2", "id-alg-noSignature", "id-alg-noSignature"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.6.3",
+        "id-alg-dh-sig-hmac-sha1",
+        "id-alg-dh-sig-hmac-sha1",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.6.4", "id-alg-dh-pop", "id-alg-dh-pop"),
+    OidInfo::new("1.3.6.1.5.5.7.7", "id-cmc", "id-cmc"),
+    OidInfo::new("1.3.6.1.5.5.7.7.1", "id-cmc-statusInfo", "id-cmc-statusInfo"),
+    OidInfo::new("1.3.6.1.5.5.7.7.10", "id-cmc-decryptedPOP", "id-cmc-decryptedPOP"),
+    OidInfo::new("1.3.6.1.5.5.7.7.11", "id-cmc-lraPOPWitness", "id-cmc-lraPOPWitness"),
+    OidInfo::new("1.3.6.1.5.5.7.7.15", "id-cmc-getCert", "id-cmc-getCert"),
+    OidInfo::new("1.3.6.1.5.5.7.7.16", "id-cmc-getCRL", "id-cmc-getCRL"),
+    OidInfo::new("1.3.6.1.5.5.7.7.17", "id-cmc-revokeRequest", "id-cmc-revokeRequest"),
+    OidInfo::new("1.3.6.1.5.5.7.7.18", "id-cmc-regInfo", "id-cmc-regInfo"),
+    OidInfo::new("1.3.6.1.5.5.7.7.19", "id-cmc-responseInfo", "id-cmc-responseInfo"),
+    OidInfo::new("1.3.6.1.5.5.7.7.2", "id-cmc-identification", "id-cmc-identification"),
+    OidInfo::new("1.3.6.1.5.5.7.7.21", "id-cmc-queryPending", "id-cmc-queryPending"),
+    OidInfo::new("1.3.6.1.5.5.7.7.22", "id-cmc-popLinkRandom", "id-cmc-popLinkRandom"),
+    OidInfo::new("1.3.6.1.5.5.7.7.23", "id-cmc-popLinkWitness", "id-cmc-popLinkWitness"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.7.24",
+        "id-cmc-confirmCertAcceptance",
+        "id-cmc-confirmCertAcceptance",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.7.25", "id-cmc-statusInfoV2", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.26", "id-cmc-trustedAnchors", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.27", "id-cmc-authData", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.28", "id-cmc-batchRequests", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.29", "id-cmc-batchResponses", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.3", "id-cmc-identityProof", "id-cmc-identityProof"),
+    OidInfo::new("1.3.6.1.5.5.7.7.30", "id-cmc-publishCert", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.31", "id-cmc-modCertTemplate", "Defined in RFC 5912"),
+    OidInfo::new("1.3.6.1.5.5.7.7.4", "id-cmc-dataReturn", "id-cmc-dataReturn"),
+    OidInfo::new("1.3.6.1.5.5.7.7.5", "id-cmc-transactionId", "id-cmc-transactionId"),
+    OidInfo::new("1.3.6.1.5.5.7.7.6", "id-cmc-senderNonce", "id-cmc-senderNonce"),
+    OidInfo::new("1.3.6.1.5.5.7.7.7", "id-cmc-recipientNonce", "id-cmc-recipientNonce"),
+    OidInfo::new("1.3.6.1.5.5.7.7.8", "id-cmc-addExtensions", "id-cmc-addExtensions"),
+    OidInfo::new("1.3.6.1.5.5.7.7.9", "id-cmc-encryptedPOP", "id-cmc-encryptedPOP"),
+    OidInfo::new("1.3.6.1.5.5.7.7.99", "id-cmc-glaRR", "Defined in RFC 5911"),
+    OidInfo::new("1.3.6.1.5.5.7.8", "id-on", "id-on"),
+    OidInfo::new("1.3.6.1.5.5.7.8.1", "id-on-personalData", "id-on-personalData"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.8.3",
+        "id-on-permanentIdentifier",
+        "Permanent identifier other name",
+    ),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.8.4",
+        "id-on-hardwareModuleName",
+        "Hardware module name other name",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.8.5", "id-on-xmppAddr", "XMPP address other name"),
+    OidInfo::new("1.3.6.1.5.5.7.8.7", "id-on-dnsSRV", "SRVName"),
+    OidInfo::new("1.3.6.1.5.5.7.8.8", "id-on-NAIRealm", "NAIRealm"),
+    OidInfo::new("1.3.6.1.5.5.7.8.9", "id-on-SmtpUTF8Mailbox", "Smtp UTF8 Mailbox"),
+    OidInfo::new("1.3.6.1.5.5.7.9", "id-pda", "id-pda"),
+    OidInfo::new("1.3.6.1.5.5.7.9.1", "id-pda-dateOfBirth", "Date of birth"),
+    OidInfo::new("1.3.6.1.5.5.7.9.2", "id-pda-placeOfBirth", "Place of birth"),
+    OidInfo::new("1.3.6.1.5.5.7.9.3", "id-pda-gender", "Gender"),
+    OidInfo::new(
+        "1.3.6.1.5.5.7.9.4",
+        "id-pda-countryOfCitizenship",
+        "Country of citizenship",
+    ),
+    OidInfo::new("1.3.6.1.5.5.7.9.5", "id-pda-countryOfResidence", "Country of residence"),
+    OidInfo::new("1.3.6.1.5.5.8.1.1", "hmac-md5", "HMAC with MD5"),
+    OidInfo::new("1.3.6.1.5.5.8.1.2", "hmac-sha1", "HMAC with SHA-1"),
+    OidInfo::new("1.3.6.1.5.5.8.2.2", "iKEIntermediate", "IKE intermediate"),
+    OidInfo::new(
+        "1.3.6.1.5.6.2",
+        "gss-nt-hostbased-service",
+        "GSS-API host-based service name type",
+    ),
+    OidInfo::new("1.3.6.1.5.6.3", "gss-nt-anonymous", "GSS-API anonymous name type"),
+    OidInfo::new("1.3.6.1.5.6.4", "gss-nt-export-name", "GSS-API exported name type"),
+    OidInfo::new("1.3.6.1.6", "snmpv2", "SNMPv2"),
+    OidInfo::new("1.3.6.1.7", "Mail", "Mail"),
+    OidInfo::new("1.3.6.1.7.1", "mime-mhs", "MIME MHS"),
+    OidInfo::new("1.3.6.1.7.1.1", "mime-mhs-headings", "mime-mhs-headings"),
+    OidInfo::new("1.3.6.1.7.1.1.1", "id-hex-partial-message", "id-hex-partial-message"),
+    OidInfo::new(
+        "1.3.6.1.7.1.1.2",
+        "id-hex-multipart-message",
+        "id-hex-multipart-message",
+    ),
+    OidInfo::new("1.3.6.1.7.1.2", "mime-mhs-bodies", "mime-mhs-bodies"),
+    OidInfo::new("2.16.840.1.101.2.1", "szOID_INFOSEC", "Windows SDK object identifier"),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.1",
+        "szOID_INFOSEC_sdnsSignature",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.10",
+        "szOID_INFOSEC_mosaicKeyManagement",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.11",
+        "szOID_INFOSEC_sdnsKMandSig",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.12",
+        "szOID_INFOSEC_mosaicKMandSig",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.13",
+        "szOID_INFOSEC_SuiteASignature",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.14",
+        "szOID_INFOSEC_SuiteAConfidentiality",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.15",
+        "szOID_INFOSEC_SuiteAIntegrity",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.16",
+        "szOID_INFOSEC_SuiteATokenProtection",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.17",
+        "szOID_INFOSEC_SuiteAKeyManagement",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.18",
+        "szOID_INFOSEC_SuiteAKMandSig",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.19",
+        "szOID_INFOSEC_mosaicUpdatedSig",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.2",
+        "szOID_INFOSEC_mosaicSignature",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.20",
+        "szOID_INFOSEC_mosaicKMandUpdSig",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.21",
+        "szOID_INFOSEC_mosaicUpdatedInteg",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.3",
+        "szOID_INFOSEC_sdnsConfidentiality",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.4",
+        "szOID_INFOSEC_mosaicConfidentiality",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.5",
+        "szOID_INFOSEC_sdnsIntegrity",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.6",
+        "szOID_INFOSEC_mosaicIntegrity",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.7",
+        "szOID_INFOSEC_sdnsTokenProtection",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.8",
+        "szOID_INFOSEC_mosaicTokenProtection",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.2.1.1.9",
+        "szOID_INFOSEC_sdnsKeyManagement",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4", "nistAlgorithms", "Defined in FIPS 202"),
+    OidInfo::new("2.16.840.1.101.3.4.1", "aes", "Defined in RFC 5911"),
+    OidInfo::new("2.16.840.1.101.3.4.1.1", "aes128-ECB", "AES-128 in ECB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.2", "aes128-CBC", "AES-128 in CBC mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.21", "aes192-ECB", "AES-192 in ECB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.22", "aes192-CBC", "AES-192 in CBC mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.23", "aes192-OFB", "AES-192 in OFB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.24", "aes192-CFB", "AES-192 in CFB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.25", "id-aes192-wrap", "AES-192 key wrap"),
+    OidInfo::new("2.16.840.1.101.3.4.1.26", "aes192-GCM", "AES-192 in GCM mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.27", "aes192-CCM", "AES-192 in CCM mode"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.1.28",
+        "id-aes192-wrap-pad",
+        "AES-192 key wrap with padding",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4.1.3", "aes128-OFB", "AES-128 in OFB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.4", "aes128-CFB", "AES-128 in CFB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.41", "aes256-ECB", "AES-256 in ECB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.42", "aes256-CBC", "AES-256 in CBC mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.43", "aes256-OFB", "AES-256 in OFB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.44", "aes256-CFB", "AES-256 in CFB mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.45", "id-aes256-wrap", "AES-256 key wrap"),
+    OidInfo::new("2.16.840.1.101.3.4.1.46", "aes256-GCM", "AES-256 in GCM mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.47", "aes256-CCM", "AES-256 in CCM mode"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.1.48",
+        "id-aes256-wrap-pad",
+        "AES-256 key wrap with padding",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4.1.5", "id-aes128-wrap", "AES-128 key wrap"),
+    OidInfo::new("2.16.840.1.101.3.4.1.6", "aes128-GCM", "AES-128 in GCM mode"),
+    OidInfo::new("2.16.840.1.101.3.4.1.7", "aes128-CCM", "AES-128 in CCM mode"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.1.8",
+        "id-aes128-wrap-pad",
+        "AES-128 key wrap with padding",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4.2", "hashAlgs", "Defined in FIPS 202"),
+    OidInfo::new("2.16.840.1.101.3.4.2.1", "sha256", "SHA-256 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.10", "sha3-512", "SHA3-512 hash"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.2.11",
+        "id-shake128",
+        "SHAKE128 extendable output function",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.2.12",
+        "id-shake256",
+        "SHAKE256 extendable output function",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4.2.13", "id-hmacWithSHA3-224", "HMAC with SHA3-224"),
+    OidInfo::new("2.16.840.1.101.3.4.2.14", "id-hmacWithSHA3-256", "HMAC with SHA3-256"),
+    OidInfo::new("2.16.840.1.101.3.4.2.15", "id-hmacWithSHA3-384", "HMAC with SHA3-384"),
+    OidInfo::new("2.16.840.1.101.3.4.2.16", "id-hmacWithSHA3-512", "HMAC with SHA3-512"),
+    OidInfo::new("2.16.840.1.101.3.4.2.19", "KMAC128", "kmac128"),
+    OidInfo::new("2.16.840.1.101.3.4.2.2", "sha384", "SHA-384 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.20", "KMAC256", "kmac256"),
+    OidInfo::new("2.16.840.1.101.3.4.2.21", "id-kmac128", "Defined in RFC 9688"),
+    OidInfo::new("2.16.840.1.101.3.4.2.22", "id-kmac256", "Defined in RFC 9688"),
+    OidInfo::new("2.16.840.1.101.3.4.2.3", "sha512", "SHA-512 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.4", "sha224", "SHA-224 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.5", "sha512-224", "SHA-512/224 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.6", "sha512-256", "SHA-512/256 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.7", "sha3-224", "SHA3-224 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.8", "sha3-256", "SHA3-256 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.2.9", "sha3-384", "SHA3-384 hash"),
+    OidInfo::new("2.16.840.1.101.3.4.3", "sigAlgs", "Defined in FIPS 204"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.1",
+        "id-dsa-with-sha224",
+        "DSA signature with SHA-224",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.10",
+        "id-ecdsa-with-sha3-256",
+        "ECDSA signature with SHA3-256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.11",
+        "id-ecdsa-with-sha3-384",
+        "ECDSA signature with SHA3-384",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.12",
+        "id-ecdsa-with-sha3-512",
+        "ECDSA signature with SHA3-512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.13",
+        "id-rsassa-pkcs1-v1_5-with-sha3-224",
+        "RSA PKCS #1 v1.5 signature with SHA3-224",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.14",
+        "id-rsassa-pkcs1-v1_5-with-sha3-256",
+        "RSA PKCS #1 v1.5 signature with SHA3-256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.15",
+        "id-rsassa-pkcs1-v1_5-with-sha3-384",
+        "RSA PKCS #1 v1.5 signature with SHA3-384",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.16",
+        "id-rsassa-pkcs1-v1_5-with-sha3-512",
+        "RSA PKCS #1 v1.5 signature with SHA3-512",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4.3.17", "id-ml-dsa-44", "ML-DSA-44 signature"),
+    OidInfo::new("2.16.840.1.101.3.4.3.18", "id-ml-dsa-65", "ML-DSA-65 signature"),
+    OidInfo::new("2.16.840.1.101.3.4.3.19", "id-ml-dsa-87", "ML-DSA-87 signature"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.2",
+        "id-dsa-with-sha256",
+        "DSA signature with SHA-256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.20",
+        "id-slh-dsa-sha2-128s",
+        "SLH-DSA-SHA2-128s signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.21",
+        "id-slh-dsa-sha2-128f",
+        "SLH-DSA-SHA2-128f signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.22",
+        "id-slh-dsa-sha2-192s",
+        "SLH-DSA-SHA2-192s signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.23",
+        "id-slh-dsa-sha2-192f",
+        "SLH-DSA-SHA2-192f signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.24",
+        "id-slh-dsa-sha2-256s",
+        "SLH-DSA-SHA2-256s signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.25",
+        "id-slh-dsa-sha2-256f",
+        "SLH-DSA-SHA2-256f signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.26",
+        "id-slh-dsa-shake-128s",
+        "SLH-DSA-SHAKE-128s signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.27",
+        "id-slh-dsa-shake-128f",
+        "SLH-DSA-SHAKE-128f signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.28",
+        "id-slh-dsa-shake-192s",
+        "SLH-DSA-SHAKE-192s signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.29",
+        "id-slh-dsa-shake-192f",
+        "SLH-DSA-SHAKE-192f signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.3",
+        "id-dsa-with-sha384",
+        "DSA signature with SHA-384",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.30",
+        "id-slh-dsa-shake-256s",
+        "SLH-DSA-SHAKE-256s signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.31",
+        "id-slh-dsa-shake-256f",
+        "SLH-DSA-SHAKE-256f signature",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.32",
+        "id-hash-ml-dsa-44-with-sha512",
+        "HASH-ML-DSA-44-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.33",
+        "id-hash-ml-dsa-65-with-sha512",
+        "HASH-ML-DSA-65-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.34",
+        "id-hash-ml-dsa-87-with-sha512",
+        "HASH-ML-DSA-87-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.35",
+        "id-hash-slh-dsa-sha2-128s-with-sha256",
+        "SLH-DSA-SHA2-128s-WITH-SHA256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.36",
+        "id-hash-slh-dsa-sha2-128f-with-sha256",
+        "SLH-DSA-SHA2-128f-WITH-SHA256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.37",
+        "id-hash-slh-dsa-sha2-192s-with-sha512",
+        "SLH-DSA-SHA2-192s-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.38",
+        "id-hash-slh-dsa-sha2-192f-with-sha512",
+        "SLH-DSA-SHA2-192f-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.39",
+        "id-hash-slh-dsa-sha2-256s-with-sha512",
+        "SLH-DSA-SHA2-256s-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.4",
+        "id-dsa-with-sha512",
+        "DSA signature with SHA-512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.40",
+        "id-hash-slh-dsa-sha2-256f-with-sha512",
+        "SLH-DSA-SHA2-256f-WITH-SHA512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.41",
+        "id-hash-slh-dsa-shake-128s-with-shake128",
+        "SLH-DSA-SHAKE-128s-WITH-SHAKE128",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.42",
+        "id-hash-slh-dsa-shake-128f-with-shake128",
+        "SLH-DSA-SHAKE-128f-WITH-SHAKE128",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.43",
+        "id-hash-slh-dsa-shake-192s-with-shake256",
+        "SLH-DSA-SHAKE-192s-WITH-SHAKE256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.44",
+        "id-hash-slh-dsa-shake-192f-with-shake256",
+        "SLH-DSA-SHAKE-192f-WITH-SHAKE256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.45",
+        "id-hash-slh-dsa-shake-256s-with-shake256",
+        "SLH-DSA-SHAKE-256s-WITH-SHAKE256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.46",
+        "id-hash-slh-dsa-shake-256f-with-shake256",
+        "SLH-DSA-SHAKE-256f-WITH-SHAKE256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.5",
+        "id-dsa-with-sha3-224",
+        "DSA signature with SHA3-224",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.6",
+        "id-dsa-with-sha3-256",
+        "DSA signature with SHA3-256",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.7",
+        "id-dsa-with-sha3-384",
+        "DSA signature with SHA3-384",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.8",
+        "id-dsa-with-sha3-512",
+        "DSA signature with SHA3-512",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.3.9",
+        "id-ecdsa-with-sha3-224",
+        "ECDSA signature with SHA3-224",
+    ),
+    OidInfo::new("2.16.840.1.101.3.4.4", "kems", "Defined in FIPS 203"),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.4.1",
+        "id-alg-ml-kem-512",
+        "ML-KEM-512 key encapsulation",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.4.2",
+        "id-alg-ml-kem-768",
+        "ML-KEM-768 key encapsulation",
+    ),
+    OidInfo::new(
+        "2.16.840.1.101.3.4.4.3",
+        "id-alg-ml-kem-1024",
+        "ML-KEM-1024 key encapsulation",
+    ),
+    OidInfo::new("2.16.840.1.113730", "Netscape", "Netscape Communications Corp."),
+    OidInfo::new("2.16.840.1.113730.1", "nsCertExt", "Netscape Certificate Extension"),
+    OidInfo::new(
+        "2.16.840.1.113730.1.1",
+        "netscape-cert-type",
+        "Netscape certificate type extension",
+    ),
+    OidInfo::new("2.16.840.1.113730.1.10", "nsEntityLogo", "Certificate Entity Logo"),
+    OidInfo::new("2.16.840.1.113730.1.11", "nsUserPicture", "Certificate User Picture"),
+    OidInfo::new(
+        "2.16.840.1.113730.1.12",
+        "netscape-ssl-server-name",
+        "Netscape SSL server name extension",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.1.13",
+        "netscape-comment",
+        "Netscape comment extension",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.1.2",
+        "netscape-base-url",
+        "Netscape base URL extension",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.1.3",
+        "netscape-revocation-url",
+        "Netscape revocation URL extension",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.1.4",
+        "netscape-ca-revocation-url",
+        "Netscape CA revocation URL extension",
+    ),
+    OidInfo::new("2.16.840.1.113730.1.5", "nsCACRLURL", "CA CRL URL"),
+    OidInfo::new("2.16.840.1.113730.1.6", "nsCACertURL", "CA Certificate URL"),
+    OidInfo::new(
+        "2.16.840.1.113730.1.7",
+        "netscape-cert-renewal-url",
+        "Netscape certificate renewal URL extension",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.1.8",
+        "netscape-ca-policy-url",
+        "Netscape CA policy URL extension",
+    ),
+    OidInfo::new("2.16.840.1.113730.1.9", "nsHomepageURL", "Certificate Homepage URL"),
+    OidInfo::new("2.16.840.1.113730.2", "nsDataType", "Netscape Data Type"),
+    OidInfo::new(
+        "2.16.840.1.113730.2.5",
+        "nsCertSequence",
+        "Netscape Certificate Sequence",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.3.1.1",
+        "carLicense",
+        "Vehicle license or registration plate",
+    ),
+    OidInfo::new("2.16.840.1.113730.3.1.2", "departmentNumber", "Department number"),
+    OidInfo::new("2.16.840.1.113730.3.1.216", "userPKCS12", "User PKCS #12 archive"),
+    OidInfo::new("2.16.840.1.113730.3.1.241", "displayName", "Display name"),
+    OidInfo::new("2.16.840.1.113730.3.1.3", "employeeNumber", "Employee number"),
+    OidInfo::new("2.16.840.1.113730.3.1.34", "ref", "Defined in RFC 3296"),
+    OidInfo::new(
+        "2.16.840.1.113730.3.1.39",
+        "preferredLanguage",
+        "Preferred written or spoken language",
+    ),
+    OidInfo::new("2.16.840.1.113730.3.1.4", "employeeType", "Employee type"),
+    OidInfo::new(
+        "2.16.840.1.113730.3.1.40",
+        "userSMIMECertificate",
+        "User S/MIME certificate",
+    ),
+    OidInfo::new("2.16.840.1.113730.3.2.2", "inetOrgPerson", "Defined in RFC 2798"),
+    OidInfo::new("2.16.840.1.113730.3.2.6", "referral", "Defined in RFC 3296"),
+    OidInfo::new(
+        "2.16.840.1.113730.3.4.10",
+        "LDAP_CONTROL_VLVRESPONSE",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.3.4.9",
+        "LDAP_CONTROL_VLVREQUEST",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113730.4.1",
+        "netscape-server-gated-crypto",
+        "Netscape server gated crypto",
+    ),
+    OidInfo::new("2.16.840.1.113733", "id-VeriSign", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1", "id-pki", "Defined in RFC 8894"),
+    OidInfo::new(
+        "2.16.840.1.113733.1.6.11",
+        "szOID_VERISIGN_ONSITE_JURISDICTION_HASH",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113733.1.6.13",
+        "szOID_VERISIGN_BITSTRING_6_13",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113733.1.6.9",
+        "szOID_VERISIGN_PRIVATE_6_9",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113733.1.7.1.1",
+        "szOID_CERT_POLICIES_95_QUALIFIER1",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.16.840.1.113733.1.8.1",
+        "szOID_VERISIGN_ISS_STRONG_CRYPTO",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("2.16.840.1.113733.1.9", "id-attributes", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1.9.2", "id-messageType", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1.9.3", "id-pkiStatus", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1.9.4", "id-failInfo", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1.9.5", "id-senderNonce", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1.9.6", "id-recipientNonce", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113733.1.9.7", "id-transactionID", "Defined in RFC 8894"),
+    OidInfo::new("2.16.840.1.113894", "oracle-organization", "Oracle organization"),
+    OidInfo::new(
+        "2.16.840.1.113894.746875.1.1",
+        "oracle-jdk-trustedkeyusage",
+        "Trusted key usage (Oracle)",
+    ),
+    OidInfo::new("2.2.840.10040.2", "holdInstruction", "Defined in RFC 5280"),
+    OidInfo::new("2.2.840.10040.2.1", "id-holdinstruction-none", "Defined in RFC 5280"),
+    OidInfo::new("2.23", "international-organizations", "International Organizations"),
+    OidInfo::new("2.23.133", "tcg", "Trusted Computing Group"),
+    OidInfo::new("2.23.133.1", "tcg-tcpaSpecVersion", "tcg-tcpaSpecVersion"),
+    OidInfo::new("2.23.133.1.0", "tcg-sv-tpm12", "Defined in TCG TPM"),
+    OidInfo::new("2.23.133.1.2", "tcg-sv-tpm20", "Defined in TCG TPM"),
+    OidInfo::new("2.23.133.11", "tcg-ca", "Trusted Computing Group Certificate Policies"),
+    OidInfo::new(
+        "2.23.133.11.4",
+        "tcg-cap-verifiedPlatformCertificate",
+        "TCG Verified Platform Certificate CA Policy",
+    ),
+    OidInfo::new("2.23.133.17", "tcg-address", "Trusted Computing Group Address Formats"),
+    OidInfo::new("2.23.133.17.1", "tcg-address-ethernetmac", "Ethernet MAC Address"),
+    OidInfo::new("2.23.133.17.2", "tcg-address-wlanmac", "WLAN MAC Address"),
+    OidInfo::new("2.23.133.17.3", "tcg-address-bluetoothmac", "Bluetooth MAC Address"),
+    OidInfo::new("2.23.133.18", "tcg-registry", "Trusted Computing Group Registry"),
+    OidInfo::new("2.23.133.18.3", "tcg-registry-componentClass", "TCG Component Class"),
+    OidInfo::new(
+        "2.23.133.18.3.1",
+        "tcg-registry-componentClass-tcg",
+        "Trusted Computed Group Registry",
+    ),
+    OidInfo::new(
+        "2.23.133.18.3.2",
+        "tcg-registry-componentClass-ietf",
+        "Internet Engineering Task Force Registry",
+    ),
+    OidInfo::new(
+        "2.23.133.18.3.3",
+        "tcg-registry-componentClass-dmtf",
+        "Distributed Management Task Force Registry",
+    ),
+    OidInfo::new(
+        "2.23.133.18.3.4",
+        "tcg-registry-componentClass-pcie",
+        "PCIE Component Class",
+    ),
+    OidInfo::new(
+        "2.23.133.18.3.5",
+        "tcg-registry-componentClass-disk",
+        "Disk Component Class",
+    ),
+    OidInfo::new("2.23.133.19", "tcg-traits", "Trusted Computing Group Traits"),
+    OidInfo::new("2.23.133.19.1", "tcg-tr-ID", "TCG Trait Identifiers"),
+    OidInfo::new("2.23.133.19.1.1", "tcg-tr-ID-Boolean", "Boolean Trait"),
+    OidInfo::new("2.23.133.19.1.10", "tcg-tr-ID-PEN", "Private Enterprise Number Trait"),
+    OidInfo::new(
+        "2.23.133.19.1.11",
+        "tcg-tr-ID-platformFirmwareCapabilities",
+        "Platform Firmware Capabilities Trait",
+    ),
+    OidInfo::new(
+        "2.23.133.19.1.12",
+        "tcg-tr-ID-platformFirmwareSignatureVerification",
+        "Platform Firmware Signature Verification Trait",
+    ),
+    OidInfo::new(
+        "2.23.133.19.1.13",
+        "tcg-tr-ID-platformFirmwareUpdateCompliance",
+        "Platform Firmware Update Compliance Trait",
+    ),
+    OidInfo::new(
+        "2.23.133.19.1.14",
+        "tcg-tr-ID-platformHardwareCapabilities",
+        "Platform Hardware Capabilities Trait",
+    ),
+    OidInfo::new(
+        "2.23.133.19.1.15",
+        "tcg-tr-ID-RTM",
+        "Root of Trust for Measurement Trait",
+    ),
+    OidInfo::new("2.23.133.19.1.16", "tcg-tr-ID-status", "Attribute Status Trait"),
+    OidInfo::new("2.23.133.19.1.17", "tcg-tr-ID-URI", "Uniform Resource Identifier Trait"),
+    OidInfo::new("2.23.133.19.1.18", "tcg-tr-ID-UTF8String", "UTF8String Trait"),
+    OidInfo::new("2.23.133.19.1.19", "tcg-tr-ID-IA5String", "IA5String Trait"),
+    OidInfo::new(
+        "2.23.133.19.1.2",
+        "tcg-tr-ID-CertificateIdentifier",
+        "Certificate Identifier Trait",
+    ),
+    OidInfo::new(
+        "2.23.133.19.1.20",
+        "tcg-tr-ID-PEMCertString",
+        "PEM-Encoded Certificate String Trait",
+    ),
+    OidInfo::new("2.23.133.19.1.21", "tcg-tr-ID-PublicKey", "Public Key Trait"),
+    OidInfo::new("2.23.133.19.1.3", "tcg-tr-ID-CommonCriteria", "Common Criteria Trait"),
+    OidInfo::new("2.23.133.19.1.4", "tcg-tr-ID-componentClass", "Component Class Trait"),
+    OidInfo::new(
+        "2.23.133.19.1.5",
+        "tcg-tr-ID-componentIdentifierV11",
+        "Component Identifier V1.1 Trait",
+    ),
+    OidInfo::new("2.23.133.19.1.6", "tcg-tr-ID-FIPSLevel", "FIPS Level Trait"),
+    OidInfo::new("2.23.133.19.1.7", "tcg-tr-ID-ISO9000Level", "ISO 9000 Level Trait"),
+    OidInfo::new("2.23.133.19.1.8", "tcg-tr-ID-networkMAC", "Network MAC Trait"),
+    OidInfo::new("2.23.133.19.1.9", "tcg-tr-ID-OID", "Object Identifier Trait"),
+    OidInfo::new("2.23.133.19.2", "tcg-tr-category", "TCG Trait Categories"),
+    OidInfo::new(
+        "2.23.133.19.2.1",
+        "tcg-tr-cat-platformManufacturer",
+        "Platform Manufacturer Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.10",
+        "tcg-tr-cat-componentSerial",
+        "Component Serial Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.11",
+        "tcg-tr-cat-componentStatus",
+        "Component Status Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.12",
+        "tcg-tr-cat-componentLocation",
+        "Component Location Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.13",
+        "tcg-tr-cat-componentRevision",
+        "Component Revision Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.14",
+        "tcg-tr-cat-componentFieldReplaceable",
+        "Component Field Replaceable Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.15",
+        "tcg-tr-cat-EKCertificate",
+        "EK Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.16",
+        "tcg-tr-cat-IAKCertificate",
+        "IAK Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.17",
+        "tcg-tr-cat-IDevIDCertificate",
+        "IDevID Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.18",
+        "tcg-tr-cat-DICECertificate",
+        "DICE Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.19",
+        "tcg-tr-cat-SPDMCertificate",
+        "SPDM Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.2",
+        "tcg-tr-cat-platformModel",
+        "Platform Model Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.20",
+        "tcg-tr-cat-PEMCertificate",
+        "PEM Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.21",
+        "tcg-tr-cat-PlatformCertificate",
+        "Platform Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.22",
+        "tcg-tr-cat-DeltaPlatformCertificate",
+        "Delta Platform Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.23",
+        "tcg-tr-cat-RebasePlatformCertificate",
+        "Rebase Platform Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.24",
+        "tcg-tr-cat-genericCertificate",
+        "Generic Certificate Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.25",
+        "tcg-tr-cat-CommonCriteria",
+        "Common Criteria Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.26",
+        "tcg-tr-cat-componentIdentifierV11",
+        "Component Identifier V1.1 Trait Category",
+    ),
+    OidInfo::new("2.23.133.19.2.27", "tcg-tr-cat-FIPSLevel", "FIPS Level Trait Category"),
+    OidInfo::new("2.23.133.19.2.28", "tcg-tr-cat-ISO9000", "ISO 9000 Trait Category"),
+    OidInfo::new(
+        "2.23.133.19.2.29",
+        "tcg-tr-cat-networkMAC",
+        "Network MAC Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.3",
+        "tcg-tr-cat-platformVersion",
+        "Platform Version Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.30",
+        "tcg-tr-cat-attestationProtocol",
+        "Attestation Protocol Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.31",
+        "tcg-tr-cat-PEN",
+        "Private Enterprise Number Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.32",
+        "tcg-tr-cat-platformFirmwareCapabilities",
+        "Platform Firmware Capabilities Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.33",
+        "tcg-tr-cat-platformHardwareCapabilities",
+        "Platform Hardware Capabilities Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.34",
+        "tcg-tr-cat-platformFirmwareSignatureVerification",
+        "Platform Firmware Signature Verification Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.35",
+        "tcg-tr-cat-platformFirmwareUpdateCompliance",
+        "Platform Firmware Update Compliance Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.36",
+        "tcg-tr-cat-RTM",
+        "Root of Trust of Measurement Trait Category",
+    ),
+    OidInfo::new("2.23.133.19.2.37", "tcg-tr-cat-PublicKey", "Public Key Trait Category"),
+    OidInfo::new(
+        "2.23.133.19.2.4",
+        "tcg-tr-cat-platformSerial",
+        "Platform Serial Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.5",
+        "tcg-tr-cat-platformManufacturerIdentifier",
+        "Platform Manufacturer Identifier Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.6",
+        "tcg-tr-cat-platformOwnership",
+        "Platform Ownership Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.7",
+        "tcg-tr-cat-componentClass",
+        "Component Class Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.8",
+        "tcg-tr-cat-componentManufacturer",
+        "Component Manufacturer Trait Category",
+    ),
+    OidInfo::new(
+        "2.23.133.19.2.9",
+        "tcg-tr-cat-componentModel",
+        "Component Model Trait Category",
+    ),
+    OidInfo::new("2.23.133.19.3", "tcg-tr-registry", "TCG Trait Registries"),
+    OidInfo::new("2.23.133.2", "tcg-attribute", "Trusted Computing Group Attributes"),
+    OidInfo::new("2.23.133.2.1", "tcg-at-tpmManufacturer", "TPM Manufacturer"),
+    OidInfo::new("2.23.133.2.10", "tcg-at-securityQualities", "Security Qualities"),
+    OidInfo::new("2.23.133.2.11", "tcg-at-tpmProtectionProfile", "TPM Protection Profile"),
+    OidInfo::new("2.23.133.2.12", "tcg-at-tpmSecurityTarget", "TPM Security Target"),
+    OidInfo::new("2.23.133.2.13", "tcg-at-tbbProtectionProfile", "TBB Protection Profile"),
+    OidInfo::new("2.23.133.2.14", "tcg-at-tbbSecurityTarget", "TBB Security Target"),
+    OidInfo::new("2.23.133.2.15", "tcg-at-tpmIdLabel", "TPM ID Label"),
+    OidInfo::new("2.23.133.2.16", "tcg-at-tpmSpecification", "TPM Specification"),
+    OidInfo::new(
+        "2.23.133.2.17",
+        "tcg-at-tcgPlatformSpecification",
+        "TPM Platform Specification",
+    ),
+    OidInfo::new(
+        "2.23.133.2.18",
+        "tcg-at-tpmSecurityAssertions",
+        "TPM Security Assertions",
+    ),
+    OidInfo::new(
+        "2.23.133.2.19",
+        "tcg-at-tbbSecurityAssertions",
+        "TBB Security Assertions",
+    ),
+    OidInfo::new("2.23.133.2.2", "tcg-at-tpmModel", "TPM Model"),
+    OidInfo::new(
+        "2.23.133.2.23",
+        "tcg-at-tcgCredentialSpecification",
+        "TCG Credential Specification",
+    ),
+    OidInfo::new("2.23.133.2.25", "tcg-at-tcgCredentialType", "TCG Credential Type"),
+    OidInfo::new(
+        "2.23.133.2.26",
+        "tcg-at-previousPlatformCertificates",
+        "TCG Previous Platform Certificates",
+    ),
+    OidInfo::new(
+        "2.23.133.2.27",
+        "tcg-at-tbbSecurityAssertions-v3",
+        "TCG TBB Security Assertions V3",
+    ),
+    OidInfo::new(
+        "2.23.133.2.28",
+        "tcg-at-cryptographicAnchors",
+        "TCG Cryptographic Anchors",
+    ),
+    OidInfo::new("2.23.133.2.3", "tcg-at-tpmVersion", "TPM Version"),
+    OidInfo::new(
+        "2.23.133.2.4",
+        "szOID_RDN_TCG_PLATFORM_MANUFACTURER",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.23.133.2.5",
+        "szOID_RDN_TCG_PLATFORM_MODEL",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new(
+        "2.23.133.2.6",
+        "szOID_RDN_TCG_PLATFORM_VERSION",
+        "Windows SDK object identifier",
+    ),
+    OidInfo::new("2.23.133.3", "tcg-protocol", "Trusted Computing Group Protocols"),
+    OidInfo::new("2.23.133.3.1", "tcg-prt-tpmIdProtocol", "TCG TPM Protocol"),
+    OidInfo::new("2.23.133.4", "tcg-algorithm", "Trusted Computing Group Algorithms"),
+    OidInfo::new("2.23.133.4.1", "tcg-algorithm-null", "TCG NULL Algorithm"),
+    OidInfo::new(
+        "2.23.133.5",
+        "tcg-platformClass",
+        "Trusted Computing Group Platform Classes",
+    ),
+    OidInfo::new("2.23.133.5.1", "tcg-common", "Trusted Computing Group Common"),
+    OidInfo::new(
+        "2.23.133.5.1.1",
+        "tcg-at-platformManufacturerStr",
+        "TCG Platform Manufacturer String",
+    ),
+    OidInfo::new(
+        "2.23.133.5.1.2",
+        "tcg-at-platformManufacturerId",
+        "TCG Platform Manufacturer ID",
+    ),
+    OidInfo::new(
+        "2.23.133.5.1.3",
+        "tcg-at-platformConfigUri",
+        "TCG Platform Configuration URI",
+    ),
+    OidInfo::new("2.23.133.5.1.4", "tcg-at-platformModel", "TCG Platform Model"),
+    OidInfo::new("2.23.133.5.1.5", "tcg-at-platformVersion", "TCG Platform Version"),
+    OidInfo::new("2.23.133.5.1.6", "tcg-at-platformSerial", "TCG Platform Serial Number"),
+    OidInfo::new(
+        "2.23.133.5.1.7",
+        "tcg-at-platformConfiguration",
+        "TCG Platform Configuration",
+    ),
+    OidInfo::new(
+        "2.23.133.5.1.7.1",
+        "tcg-at-platformConfiguration-v1",
+        "Platform Configuration Version 1",
+    ),
+    OidInfo::new(
+        "2.23.133.5.1.7.2",
+        "tcg-at-platformConfiguration-v2",
+        "Platform Configuration Version 2",
+    ),
+    OidInfo::new(
+        "2.23.133.5.1.7.3",
+        "tcg-at-platformConfiguration-v3",
+        "Platform Configuration Version 3",
+    ),
+    OidInfo::new(
+        "2.23.133.5.1.7.4",
+        "tcg-at-platformConfigUri-v3",
+        "Platform Configuration URI Version 3",
+    ),
+    OidInfo::new("2.23.133.5.1.8", "tcg-at-platformIdentifier", "TCG Platform Identifier"),
+    OidInfo::new("2.23.133.6", "tcg-ce", "Trusted Computing Group Certificate Extensions"),
+    OidInfo::new("2.23.133.6.2", "tcg-ce-relevantCredentials", "Relevant Credentials"),
+    OidInfo::new("2.23.133.6.3", "tcg-ce-relevantManifests", "Relevant Manifests"),
+    OidInfo::new(
+        "2.23.133.6.4",
+        "tcg-ce-virtualPlatformAttestationService",
+        "Virtual Platform Attestation Service",
+    ),
+    OidInfo::new(
+        "2.23.133.6.5",
+        "tcg-ce-migrationControllerAttestationService",
+        "Migration Controller Attestation Service",
+    ),
+    OidInfo::new(
+        "2.23.133.6.6",
+        "tcg-ce-migrationControllerRegistrationService",
+        "Migration Controller Registration Service",
+    ),
+    OidInfo::new(
+        "2.23.133.6.7",
+        "tcg-ce-virtualPlatformBackupService",
+        "Virtual Platform Backup Service",
+    ),
+    OidInfo::new("2.23.133.8", "tcg-kp", "Trusted Computing Group Key Purposes"),
+    OidInfo::new("2.23.133.8.1", "tcg-kp-EKCertificate", "Endorsement Key Certificate"),
+    OidInfo::new(
+        "2.23.133.8.2",
+        "tcg-kp-PlatformAttributeCertificate",
+        "Platform Attribute Certificate",
+    ),
+    OidInfo::new(
+        "2.23.133.8.3",
+        "tcg-kp-AIKCertificate",
+        "Attestation Identity Key Certificate",
+    ),
+    OidInfo::new(
+        "2.23.133.8.4",
+        "tcg-kp-PlatformKeyCertificate",
+        "Platform Key Certificate",
+    ),
+    OidInfo::new(
+        "2.23.133.8.5",
+        "tcg-kp-DeltaPlatformAttributeCertificate",
+        "Delta Platform Attribute Certificate",
+    ),
+    OidInfo::new(
+        "2.23.133.8.6",
+        "tcg-kp-DeltaPlatformKeyCertificate",
+        "Delta Platform Key Certificate",
+    ),
+    OidInfo::new(
+        "2.23.133.8.7",
+        "tcg-kp-AdditionalPlatformAttributeCertificate",
+        "Additional Platform Attribute Certificate",
+    ),
+    OidInfo::new(
+        "2.23.133.8.8",
+        "tcg-kp-AdditionalPlatformKeyCertificate",
+        "Additional Platform Key Certificate",
+    ),
+    OidInfo::new(
+        "2.23.140.1.1",
+        "ev-guidelines",
+        "Extended validation certificate policy",
+    ),
+    OidInfo::new(
+        "2.23.140.1.2.1",
+        "domain-validated",
+        "Domain validated certificate policy",
+    ),
+    OidInfo::new(
+        "2.23.140.1.2.2",
+        "organization-validated",
+        "Organization validated certificate policy",
+    ),
+    OidInfo::new(
+        "2.23.140.1.2.3",
+        "individual-validated",
+        "Individual validated certificate policy",
+    ),
+    OidInfo::new(
+        "2.23.140.1.3",
+        "extended-validation-codesigning",
+        "Extended validation code signing certificate policy",
+    ),
+    OidInfo::new("2.23.140.1.31", "onion-EV", "Onion EV certificate policy"),
+    OidInfo::new(
+        "2.23.140.1.4.1",
+        "codesigning-requirements",
+        "Code signing certificate policy",
+    ),
+    OidInfo::new("2.23.42", "id-set", "Secure Electronic Transactions"),
+    OidInfo::new("2.23.42.0", "set-ctype", "content types"),
+    OidInfo::new("2.23.42.0.0", "setct-PANData", "setct-PANData"),
+    OidInfo::new("2.23.42.0.1", "setct-PANToken", "setct-PANToken"),
+    OidInfo::new("2.23.42.0.10", "setct-AuthRevResBaggage", "setct-AuthRevResBaggage"),
+    OidInfo::new("2.23.42.0.11", "setct-CapTokenSeq", "setct-CapTokenSeq"),
+    OidInfo::new("2.23.42.0.12", "setct-PInitResData", "setct-PInitResData"),
+    OidInfo::new("2.23.42.0.13", "setct-PI-TBS", "setct-PI-TBS"),
+    OidInfo::new("2.23.42.0.14", "setct-PResData", "setct-PResData"),
+    OidInfo::new("2.23.42.0.16", "setct-AuthReqTBS", "setct-AuthReqTBS"),
+    OidInfo::new("2.23.42.0.17", "setct-AuthResTBS", "setct-AuthResTBS"),
+    OidInfo::new("2.23.42.0.18", "setct-AuthResTBSX", "setct-AuthResTBSX"),
+    OidInfo::new("2.23.42.0.19", "setct-AuthTokenTBS", "setct-AuthTokenTBS"),
+    OidInfo::new("2.23.42.0.2", "setct-PANOnly", "setct-PANOnly"),
+    OidInfo::new("2.23.42.0.20", "setct-CapTokenData", "setct-CapTokenData"),
+    OidInfo::new("2.23.42.0.21", "setct-CapTokenTBS", "setct-CapTokenTBS"),
+    OidInfo::new("2.23.42.0.22", "setct-AcqCardCodeMsg", "setct-AcqCardCodeMsg"),
+    OidInfo::new("2.23.42.0.23", "setct-AuthRevReqTBS", "setct-AuthRevReqTBS"),
+    OidInfo::new("2.23.42.0.24", "setct-AuthRevResData", "setct-AuthRevResData"),
+    OidInfo::new("2.23.42.0.25", "setct-AuthRevResTBS", "setct-AuthRevResTBS"),
+    OidInfo::new("2.23.42.0.26", "setct-CapReqTBS", "setct-CapReqTBS"),
+    OidInfo::new("2.23.42.0.27", "setct-CapReqTBSX", "setct-CapReqTBSX"),
+    OidInfo::new("2.23.42.0.28", "setct-CapResData", "setct-CapResData"),
+    OidInfo::new("2.23.42.0.29", "setct-CapRevReqTBS", "setct-CapRevReqTBS"),
+    OidInfo::new("2.23.42.0.3", "setct-OIData", "setct-OIData"),
+    OidInfo::new("2.23.42.0.30", "setct-CapRevReqTBSX", "setct-CapRevReqTBSX"),
+    OidInfo::new("2.23.42.0.31", "setct-CapRevResData", "setct-CapRevResData"),
+    OidInfo::new("2.23.42.0.32", "setct-CredReqTBS", "setct-CredReqTBS"),
+    OidInfo::new("2.23.42.0.33", "setct-CredReqTBSX", "setct-CredReqTBSX"),
+    OidInfo::new("2.23.42.0.34", "setct-CredResData", "setct-CredResData"),
+    OidInfo::new("2.23.42.0.35", "setct-CredRevReqTBS", "setct-CredRevReqTBS"),
+    OidInfo::new("2.23.42.0.36", "setct-CredRevReqTBSX", "setct-CredRevReqTBSX"),
+    OidInfo::new("2.23.42.0.37", "setct-CredRevResData", "setct-CredRevResData"),
+    OidInfo::new("2.23.42.0.38", "setct-PCertReqData", "setct-PCertReqData"),
+    OidInfo::new("2.23.42.0.39", "setct-PCertResTBS", "setct-PCertResTBS"),
+    OidInfo::new("2.23.42.0.4", "setct-PI", "setct-PI"),
+    OidInfo::new("2.23.42.0.40", "setct-BatchAdminReqData", "setct-BatchAdminReqData"),
+    OidInfo::new("2.23.42.0.41", "setct-BatchAdminResData", "setct-BatchAdminResData"),
+    OidInfo::new("2.23.42.0.42", "setct-CardCInitResTBS", "setct-CardCInitResTBS"),
+    OidInfo::new("2.23.42.0.43", "setct-MeAqCInitResTBS", "setct-MeAqCInitResTBS"),
+    OidInfo::new("2.23.42.0.44", "setct-RegFormResTBS", "setct-RegFormResTBS"),
+    OidInfo::new("2.23.42.0.45", "setct-CertReqData", "setct-CertReqData"),
+    OidInfo::new("2.23.42.0.46", "setct-CertReqTBS", "setct-CertReqTBS"),
+    OidInfo::new("2.23.42.0.47", "setct-CertResData", "setct-CertResData"),
+    OidInfo::new("2.23.42.0.48", "setct-CertInqReqTBS", "setct-CertInqReqTBS"),
+    OidInfo::new("2.23.42.0.49", "setct-ErrorTBS", "setct-ErrorTBS"),
+    OidInfo::new("2.23.42.0.5", "setct-PIData", "setct-PIData"),
+    OidInfo::new("2.23.42.0.50", "setct-PIDualSignedTBE", "setct-PIDualSignedTBE"),
+    OidInfo::new("2.23.42.0.51", "setct-PIUnsignedTBE", "setct-PIUnsignedTBE"),
+    OidInfo::new("2.23.42.0.52", "setct-AuthReqTBE", "setct-AuthReqTBE"),
+    OidInfo::new("2.23.42.0.53", "setct-AuthResTBE", "setct-AuthResTBE"),
+    OidInfo::new("2.23.42.0.54", "setct-AuthResTBEX", "setct-AuthResTBEX"),
+    OidInfo::new("2.23.42.0.55", "setct-AuthTokenTBE", "setct-AuthTokenTBE"),
+    OidInfo::new("2.23.42.0.56", "setct-CapTokenTBE", "setct-CapTokenTBE"),
+    OidInfo::new("2.23.42.0.57", "setct-CapTokenTBEX", "setct-CapTokenTBEX"),
+    OidInfo::new("2.23.42.0.58", "setct-AcqCardCodeMsgTBE", "setct-AcqCardCodeMsgTBE"),
+    OidInfo::new("2.23.42.0.59", "setct-AuthRevReqTBE", "setct-AuthRevReqTBE"),
+    OidInfo::new("2.23.42.0.6", "setct-PIDataUnsigned", "setct-PIDataUnsigned"),
+    OidInfo::new("2.23.42.0.60", "setct-AuthRevResTBE", "setct-AuthRevResTBE"),
+    OidInfo::new("2.23.42.0.61", "setct-AuthRevResTBEB", "setct-AuthRevResTBEB"),
+    OidInfo::new("2.23.42.0.62", "setct-CapReqTBE", "setct-CapReqTBE"),
+    OidInfo::new("2.23.42.0.63", "setct-CapReqTBEX", "setct-CapReqTBEX"),
+    OidInfo::new("2.23.42.0.64", "setct-CapResTBE", "setct-CapResTBE"),
+    OidInfo::new("2.23.42.0.65", "setct-CapRevReqTBE", "setct-CapRevReqTBE"),
+    OidInfo::new("2.23.42.0.66", "setct-CapRevReqTBEX", "setct-CapRevReqTBEX"),
+    OidInfo::new("2.23.42.0.67", "setct-CapRevResTBE", "setct-CapRevResTBE"),
+    OidInfo::new("2.23.42.0.68", "setct-CredReqTBE", "setct-CredReqTBE"),
+    OidInfo::new("2.23.42.0.69", "setct-CredReqTBEX", "setct-CredReqTBEX"),
+    OidInfo::new("2.23.42.0.7", "setct-HODInput", "setct-HODInput"),
+    OidInfo::new("2.23.42.0.70", "setct-CredResTBE", "setct-CredResTBE"),
+    OidInfo::new("2.23.42.0.71", "setct-CredRevReqTBE", "setct-CredRevReqTBE"),
+    OidInfo::new("2.23.42.0.72", "setct-CredRevReqTBEX", "setct-CredRevReqTBEX"),
+    OidInfo::new("2.23.42.0.73", "setct-CredRevResTBE", "setct-CredRevResTBE"),
+    OidInfo::new("2.23.42.0.74", "setct-BatchAdminReqTBE", "setct-BatchAdminReqTBE"),
+    OidInfo::new("2.23.42.0.75", "setct-BatchAdminResTBE", "setct-BatchAdminResTBE"),
+    OidInfo::new("2.23.42.0.76", "setct-RegFormReqTBE", "setct-RegFormReqTBE"),
+    OidInfo::new("2.23.42.0.77", "setct-CertReqTBE", "setct-CertReqTBE"),
+    OidInfo::new("2.23.42.0.78", "setct-CertReqTBEX", "setct-CertReqTBEX"),
+    OidInfo::new("2.23.42.0.79", "setct-CertResTBE", "setct-CertResTBE"),
+    OidInfo::new("2.23.42.0.8", "setct-AuthResBaggage", "setct-AuthResBaggage"),
+    OidInfo::new("2.23.42.0.80", "setct-CRLNotificationTBS", "setct-CRLNotificationTBS"),
+    OidInfo::new(
+        "2.23.42.0.81",
+        "setct-CRLNotificationResTBS",
+        "setct-CRLNotificationResTBS",
+    ),
+    OidInfo::new("2.23.42.0.82", "setct-BCIDistributionTBS", "setct-BCIDistributionTBS"),
+    OidInfo::new("2.23.42.0.9", "setct-AuthRevReqBaggage", "setct-AuthRevReqBaggage"),
+    OidInfo::new("2.23.42.1", "set-msgExt", "message extensions"),
+    OidInfo::new("2.23.42.1.1", "setext-genCrypt", "generic cryptogram"),
+    OidInfo::new("2.23.42.1.3", "setext-miAuth", "merchant initiated auth"),
+    OidInfo::new("2.23.42.1.4", "setext-pinSecure", "setext-pinSecure"),
+    OidInfo::new("2.23.42.1.5", "setext-pinAny", "setext-pinAny"),
+    OidInfo::new("2.23.42.1.7", "setext-track2", "setext-track2"),
+    OidInfo::new("2.23.42.1.8", "setext-cv", "additional verification"),
+    OidInfo::new("2.23.42.3", "set-attr", "set-attr"),
+    OidInfo::new("2.23.42.3.0", "setAttr-Cert", "setAttr-Cert"),
+    OidInfo::new("2.23.42.3.0.0", "set-rootKeyThumb", "set-rootKeyThumb"),
+    OidInfo::new("2.23.42.3.0.1", "set-addPolicy", "set-addPolicy"),
+    OidInfo::new("2.23.42.3.1", "setAttr-PGWYcap", "payment gateway capabilities"),
+    OidInfo::new("2.23.42.3.2", "setAttr-TokenType", "setAttr-TokenType"),
+    OidInfo::new("2.23.42.3.2.1", "setAttr-Token-EMV", "setAttr-Token-EMV"),
+    OidInfo::new("2.23.42.3.2.2", "setAttr-Token-B0Prime", "setAttr-Token-B0Prime"),
+    OidInfo::new("2.23.42.3.3", "setAttr-IssCap", "issuer capabilities"),
+    OidInfo::new("2.23.42.3.3.3", "setAttr-IssCap-CVM", "setAttr-IssCap-CVM"),
+    OidInfo::new("2.23.42.3.3.3.1", "setAttr-GenCryptgrm", "generate cryptogram"),
+    OidInfo::new("2.23.42.3.3.4", "setAttr-IssCap-T2", "setAttr-IssCap-T2"),
+    OidInfo::new("2.23.42.3.3.4.1", "setAttr-T2Enc", "encrypted track 2"),
+    OidInfo::new("2.23.42.3.3.4.2", "setAttr-T2cleartxt", "cleartext track 2"),
+    OidInfo::new("2.23.42.3.3.5", "setAttr-IssCap-Sig", "setAttr-IssCap-Sig"),
+    OidInfo::new("2.23.42.3.3.5.1", "setAttr-TokICCsig", "ICC or token signature"),
+    OidInfo::new("2.23.42.3.3.5.2", "setAttr-SecDevSig", "secure device signature"),
+    OidInfo::new("2.23.42.5", "set-policy", "set-policy"),
+    OidInfo::new("2.23.42.5.0", "set-policy-root", "set-policy-root"),
+    OidInfo::new("2.23.42.7", "set-certExt", "certificate extensions"),
+    OidInfo::new("2.23.42.7.0", "setCext-hashedRoot", "setCext-hashedRoot"),
+    OidInfo::new("2.23.42.7.1", "setCext-certType", "setCext-certType"),
+    OidInfo::new("2.23.42.7.10", "setCext-TokenType", "setCext-TokenType"),
+    OidInfo::new(
+        "2.23.42.7.11",
+        "setCext-IssuerCapabilities",
+        "setCext-IssuerCapabilities",
+    ),
+    OidInfo::new("2.23.42.7.2", "setCext-merchData", "setCext-merchData"),
+    OidInfo::new("2.23.42.7.3", "setCext-cCertRequired", "setCext-cCertRequired"),
+    OidInfo::new("2.23.42.7.4", "setCext-tunneling", "setCext-tunneling"),
+    OidInfo::new("2.23.42.7.5", "setCext-setExt", "setCext-setExt"),
+    OidInfo::new("2.23.42.7.6", "setCext-setQualf", "setCext-setQualf"),
+    OidInfo::new("2.23.42.7.7", "setCext-PGWYcapabilities", "setCext-PGWYcapabilities"),
+    OidInfo::new("2.23.42.7.8", "setCext-TokenIdentifier", "setCext-TokenIdentifier"),
+    OidInfo::new("2.23.42.7.9", "setCext-Track2Data", "setCext-Track2Data"),
+    OidInfo::new("2.23.42.8", "set-brand", "set-brand"),
+    OidInfo::new("2.23.42.8.1", "set-brand-IATA-ATA", "set-brand-IATA-ATA"),
+    OidInfo::new("2.23.42.8.30", "set-brand-Diners", "set-brand-Diners"),
+    OidInfo::new("2.23.42.8.34", "set-brand-AmericanExpress", "set-brand-AmericanExpress"),
+    OidInfo::new("2.23.42.8.35", "set-brand-JCB", "set-brand-JCB"),
+    OidInfo::new("2.23.42.8.4", "set-brand-Visa", "set-brand-Visa"),
+    OidInfo::new("2.23.42.8.5", "set-brand-MasterCard", "set-brand-MasterCard"),
+    OidInfo::new("2.23.42.8.6011", "set-brand-Novus", "set-brand-Novus"),
+    OidInfo::new("2.23.43", "wap", "wap"),
+    OidInfo::new("2.23.43.1", "wap-wsg", "wap-wsg"),
+    OidInfo::new("2.23.43.1.4.1", "wap-wsg-idm-ecid-wtls1", "wap-wsg-idm-ecid-wtls1"),
+    OidInfo::new("2.23.43.1.4.10", "wap-wsg-idm-ecid-wtls10", "wap-wsg-idm-ecid-wtls10"),
+    OidInfo::new("2.23.43.1.4.11", "wap-wsg-idm-ecid-wtls11", "wap-wsg-idm-ecid-wtls11"),
+    OidInfo::new("2.23.43.1.4.12", "wap-wsg-idm-ecid-wtls12", "wap-wsg-idm-ecid-wtls12"),
+    OidInfo::new("2.23.43.1.4.3", "wap-wsg-idm-ecid-wtls3", "wap-wsg-idm-ecid-wtls3"),
+    OidInfo::new("2.23.43.1.4.4", "wap-wsg-idm-ecid-wtls4", "wap-wsg-idm-ecid-wtls4"),
+    OidInfo::new("2.23.43.1.4.5", "wap-wsg-idm-ecid-wtls5", "wap-wsg-idm-ecid-wtls5"),
+    OidInfo::new("2.23.43.1.4.6", "wap-wsg-idm-ecid-wtls6", "wap-wsg-idm-ecid-wtls6"),
+    OidInfo::new("2.23.43.1.4.7", "wap-wsg-idm-ecid-wtls7", "wap-wsg-idm-ecid-wtls7"),
+    OidInfo::new("2.23.43.1.4.8", "wap-wsg-idm-ecid-wtls8", "wap-wsg-idm-ecid-wtls8"),
+    OidInfo::new("2.23.43.1.4.9", "wap-wsg-idm-ecid-wtls9", "wap-wsg-idm-ecid-wtls9"),
+    OidInfo::new("2.5", "x500", "X.500"),
+    OidInfo::new("2.5.1.5", "selected-attribute-types", "Selected Attribute Types"),
+    OidInfo::new("2.5.1.5.55", "clearance", "clearance"),
+    OidInfo::new("2.5.13.0", "objectIdentifierMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.1", "distinguishedNameMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.11", "caseIgnoreListMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.13", "booleanMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.14", "integerMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.15", "integerOrderingMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.16", "bitStringMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.17", "octetStringMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.2", "caseIgnoreMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.20", "telephoneNumberMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.23", "uniqueMemberMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.27", "generalizedTimeMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.32", "wordMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.33", "keywordMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.34", "certificateExactMatch", "Defined in RFC 4523"),
+    OidInfo::new("2.5.13.35", "certificateMatch", "Defined in RFC 4523"),
+    OidInfo::new("2.5.13.37", "certificatePairMatch", "Defined in RFC 4523"),
+    OidInfo::new("2.5.13.39", "certificateListMatch", "Defined in RFC 4523"),
+    OidInfo::new("2.5.13.41", "storedPrefixMatch", "Defined in RFC 3698"),
+    OidInfo::new("2.5.13.5", "caseExactMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.13.8", "numericStringMatch", "Defined in RFC 4517"),
+    OidInfo::new("2.5.17.0", "subentry", "Defined in RFC 3672"),
+    OidInfo::new("2.5.18.1", "createTimestamp", "Defined in RFC 4512"),
+    OidInfo::new("2.5.18.10", "subschemaSubentry", "Defined in RFC 4512"),
+    OidInfo::new("2.5.18.2", "modifyTimestamp", "Defined in RFC 4512"),
+    OidInfo::new("2.5.18.3", "creatorsName", "Defined in RFC 4512"),
+    OidInfo::new("2.5.18.4", "modifiersName", "Defined in RFC 4512"),
+    OidInfo::new("2.5.18.5", "administrativeRole", "Defined in RFC 3672"),
+    OidInfo::new("2.5.18.6", "subtreeSpecification", "Defined in RFC 3672"),
+    OidInfo::new("2.5.18.7", "collectiveExclusions", "Defined in RFC 3671"),
+    OidInfo::new("2.5.20.1", "subschema", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.1", "dITStructureRules", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.10", "governingStructureRule", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.2", "dITContentRules", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.4", "matchingRules", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.5", "attributeTypes", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.6", "objectClasses", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.7", "nameForms", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.8", "matchingRuleUse", "Defined in RFC 4512"),
+    OidInfo::new("2.5.21.9", "structuralObjectClass", "Defined in RFC 4512"),
+    OidInfo::new("2.5.23.1", "autonomousArea", "Defined in RFC 3672"),
+    OidInfo::new("2.5.29", "id-ce", "id-ce"),
+    OidInfo::new(
+        "2.5.29.1",
+        "authorityKeyIdentifier (obsolete)",
+        "Obsolete authority key identifier extension",
+    ),
+    OidInfo::new(
+        "2.5.29.10",
+        "basicConstraints (obsolete)",
+        "Obsolete basic constraints extension",
+    ),
+    OidInfo::new("2.5.29.14", "subjectKeyIdentifier", "Subject key identifier extension"),
+    OidInfo::new("2.5.29.15", "keyUsage", "Key usage extension"),
+    OidInfo::new(
+        "2.5.29.16",
+        "privateKeyUsagePeriod",
+        "Private key usage period extension",
+    ),
+    OidInfo::new("2.5.29.17", "subjectAltName", "Subject alternative name extension"),
+    OidInfo::new("2.5.29.18", "issuerAltName", "Issuer alternative name extension"),
+    OidInfo::new("2.5.29.19", "basicConstraints", "Basic constraints extension"),
+    OidInfo::new("2.5.29.2", "keyAttributes", "Obsolete key attributes extension"),
+    OidInfo::new("2.5.29.20", "cRLNumber", "CRL number extension"),
+    OidInfo::new("2.5.29.21", "cRLReason", "CRL reason code extension"),
+    OidInfo::new("2.5.29.23", "holdInstructionCode", "Hold instruction code extension"),
+    OidInfo::new("2.5.29.24", "invalidityDate", "Invalidity date extension"),
+    OidInfo::new("2.5.29.27", "deltaCRLIndicator", "Delta CRL indicator extension"),
+    OidInfo::new(
+        "2.5.29.28",
+        "issuingDistributionPoint",
+        "Issuing distribution point extension",
+    ),
+    OidInfo::new("2.5.29.29", "certificateIssuer", "Certificate issuer extension"),
+    OidInfo::new(
+        "2.5.29.3",
+        "certificatePolicies (obsolete)",
+        "Obsolete certificate policies extension",
+    ),
+    OidInfo::new("2.5.29.30", "nameConstraints", "Name constraints extension"),
+    OidInfo::new(
+        "2.5.29.31",
+        "cRLDistributionPoints",
+        "CRL distribution points extension",
+    ),
+    OidInfo::new("2.5.29.32", "certificatePolicies", "Certificate policies extension"),
+    OidInfo::new("2.5.29.32.0", "anyPolicy", "Any certificate policy"),
+    OidInfo::new("2.5.29.33", "policyMappings", "Policy mappings extension"),
+    OidInfo::new(
+        "2.5.29.35",
+        "authorityKeyIdentifier",
+        "Authority key identifier extension",
+    ),
+    OidInfo::new("2.5.29.36", "policyConstraints", "Policy constraints extension"),
+    OidInfo::new("2.5.29.37", "extKeyUsage", "Extended key usage extension"),
+    OidInfo::new("2.5.29.37.0", "anyExtendedKeyUsage", "Any extended key usage"),
+    OidInfo::new(
+        "2.5.29.38",
+        "authorityAttributeIdentifier",
+        "X509v3 Authority Attribute Identifier",
+    ),
+    OidInfo::new(
+        "2.5.29.39",
+        "roleSpecCertIdentifier",
+        "X509v3 Role Specification Certificate Identifier",
+    ),
+    OidInfo::new(
+        "2.5.29.4",
+        "keyUsageRestriction",
+        "Obsolete key usage restriction extension",
+    ),
+    OidInfo::new(
+        "2.5.29.41",
+        "basicAttConstraints",
+        "X509v3 Basic Attribute Certificate Constraints",
+    ),
+    OidInfo::new(
+        "2.5.29.42",
+        "delegatedNameConstraints",
+        "X509v3 Delegated Name Constraints",
+    ),
+    OidInfo::new("2.5.29.43", "timeSpecification", "X509v3 Time Specification"),
+    OidInfo::new("2.5.29.46", "freshestCRL", "Freshest CRL extension"),
+    OidInfo::new("2.5.29.48", "attributeDescriptor", "X509v3 Attribute Descriptor"),
+    OidInfo::new("2.5.29.49", "userNotice", "X509v3 User Notice"),
+    OidInfo::new(
+        "2.5.29.5",
+        "policyMapping (obsolete)",
+        "Obsolete policy mapping extension",
+    ),
+    OidInfo::new("2.5.29.50", "sOAIdentifier", "X509v3 Source of Authority Identifier"),
+    OidInfo::new(
+        "2.5.29.52",
+        "acceptableCertPolicies",
+        "X509v3 Acceptable Certification Policies",
+    ),
+    OidInfo::new("2.5.29.54", "inhibitAnyPolicy", "Inhibit any policy extension"),
+    OidInfo::new("2.5.29.55", "targetInformation", "Target information extension"),
+    OidInfo::new("2.5.29.56", "noRevAvail", "No revocation available extension"),
+    OidInfo::new(
+        "2.5.29.57",
+        "acceptablePrivPolicies",
+        "X509v3 Acceptable Privilege Policies",
+    ),
+    OidInfo::new(
+        "2.5.29.6",
+        "subtreesConstraint",
+        "Obsolete subtrees constraint extension",
+    ),
+    OidInfo::new("2.5.29.61", "indirectIssuer", "X509v3 Indirect Issuer"),
+    OidInfo::new("2.5.29.62", "noAssertion", "X509v3 No Assertion"),
+    OidInfo::new(
+        "2.5.29.63",
+        "aAissuingDistributionPoint",
+        "X509v3 Attribute Authority Issuing Distribution Point",
+    ),
+    OidInfo::new("2.5.29.64", "issuedOnBehalfOf", "X509v3 Issued On Behalf Of"),
+    OidInfo::new("2.5.29.65", "singleUse", "X509v3 Single Use"),
+    OidInfo::new("2.5.29.66", "groupAC", "X509v3 Group Attribute Certificate"),
+    OidInfo::new(
+        "2.5.29.67",
+        "allowedAttributeAssignments",
+        "X509v3 Allowed Attribute Assignments",
+    ),
+    OidInfo::new("2.5.29.68", "attributeMappings", "X509v3 Attribute Mappings"),
+    OidInfo::new("2.5.29.69", "holderNameConstraints", "X509v3 Holder Name Constraints"),
+    OidInfo::new(
+        "2.5.29.7",
+        "subjectAltName (obsolete)",
+        "Obsolete subject alternative name extension",
+    ),
+    OidInfo::new(
+        "2.5.29.70",
+        "authorizationValidation",
+        "X509v3 Authorization Validation",
+    ),
+    OidInfo::new("2.5.29.71", "protRestrict", "X509v3 Protocol Restriction"),
+    OidInfo::new(
+        "2.5.29.72",
+        "subjectAltPublicKeyInfo",
+        "X509v3 Subject Alternative Public Key Info",
+    ),
+    OidInfo::new(
+        "2.5.29.73",
+        "altSignatureAlgorithm",
+        "X509v3 Alternative Signature Algorithm",
+    ),
+    OidInfo::new("2.5.29.74", "altSignatureValue", "X509v3 Alternative Signature Value"),
+    OidInfo::new("2.5.29.75", "associatedInformation", "X509v3 Associated Information"),
+    OidInfo::new(
+        "2.5.29.8",
+        "issuerAltName (obsolete)",
+        "Obsolete issuer alternative name extension",
+    ),
+    OidInfo::new(
+        "2.5.29.9",
+        "subjectDirectoryAttributes",
+        "Subject directory attributes extension",
+    ),
+    OidInfo::new("2.5.4", "x509", "X.509"),
+    OidInfo::new("2.5.4.0", "objectClass", "Object class"),
+    OidInfo::new("2.5.4.1", "aliasedEntryName", "Aliased entry name"),
+    OidInfo::new("2.5.4.10", "organizationName", "Organization name (O)"),
+    OidInfo::new("2.5.4.10.1", "c-o", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.100", "dnsName", "dnsName"),
+    OidInfo::new("2.5.4.11", "organizationalUnitName", "Organizational unit name (OU)"),
+    OidInfo::new("2.5.4.11.1", "c-ou", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.12", "title", "Title"),
+    OidInfo::new("2.5.4.13", "description", "Description"),
+    OidInfo::new("2.5.4.14", "searchGuide", "Search guide"),
+    OidInfo::new("2.5.4.15", "businessCategory", "Business category"),
+    OidInfo::new("2.5.4.16", "postalAddress", "Postal address"),
+    OidInfo::new("2.5.4.16.1", "c-PostalAddress", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.17", "postalCode", "Postal code"),
+    OidInfo::new("2.5.4.17.1", "c-PostalCode", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.18", "postOfficeBox", "Post office box"),
+    OidInfo::new("2.5.4.18.1", "c-PostOfficeBox", "Defined in RFC 3671"),
+    OidInfo::new(
+        "2.5.4.19",
+        "physicalDeliveryOfficeName",
+        "Physical delivery office name",
+    ),
+    OidInfo::new("2.5.4.2", "knowledgeInformation", "Knowledge information"),
+    OidInfo::new("2.5.4.20", "telephoneNumber", "Telephone number"),
+    OidInfo::new("2.5.4.20.1", "c-TelephoneNumber", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.21", "telexNumber", "Telex number"),
+    OidInfo::new("2.5.4.21.1", "c-TelexNumber", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.22", "teletexTerminalIdentifier", "Teletex terminal identifier"),
+    OidInfo::new("2.5.4.23", "facsimileTelephoneNumber", "Facsimile telephone number"),
+    OidInfo::new("2.5.4.24", "x121Address", "X.121 address"),
+    OidInfo::new("2.5.4.25", "internationalISDNNumber", "International ISDN number"),
+    OidInfo::new("2.5.4.26", "registeredAddress", "Registered address"),
+    OidInfo::new("2.5.4.27", "destinationIndicator", "Destination indicator"),
+    OidInfo::new("2.5.4.28", "preferredDeliveryMethod", "Preferred delivery method"),
+    OidInfo::new("2.5.4.29", "presentationAddress", "Presentation address"),
+    OidInfo::new("2.5.4.3", "commonName", "Common name (CN)"),
+    OidInfo::new(
+        "2.5.4.30",
+        "supportedApplicationContext",
+        "Supported application context",
+    ),
+    OidInfo::new("2.5.4.31", "member", "Member"),
+    OidInfo::new("2.5.4.32", "owner", "Owner"),
+    OidInfo::new("2.5.4.33", "roleOccupant", "Role occupant"),
+    OidInfo::new("2.5.4.34", "seeAlso", "See also"),
+    OidInfo::new("2.5.4.35", "userPassword", "User password"),
+    OidInfo::new("2.5.4.36", "userCertificate", "User certificate"),
+    OidInfo::new("2.5.4.37", "cACertificate", "CA certificate"),
+    OidInfo::new("2.5.4.38", "authorityRevocationList", "Authority revocation list"),
+    OidInfo::new("2.5.4.39", "certificateRevocationList", "Certificate revocation list"),
+    OidInfo::new("2.5.4.4", "surname", "Surname (SN)"),
+    OidInfo::new("2.5.4.40", "crossCertificatePair", "Cross certificate pair"),
+    OidInfo::new("2.5.4.41", "name", "Name"),
+    OidInfo::new("2.5.4.42", "givenName", "Given name (GN)"),
+    OidInfo::new("2.5.4.43", "initials", "Initials"),
+    OidInfo::new("2.5.4.44", "generationQualifier", "Generation qualifier"),
+    OidInfo::new("2.5.4.45", "x500UniqueIdentifier", "X.500 unique identifier"),
+    OidInfo::new("2.5.4.46", "dnQualifier", "Distinguished name qualifier"),
+    OidInfo::new("2.5.4.47", "enhancedSearchGuide", "Enhanced search guide"),
+    OidInfo::new("2.5.4.48", "protocolInformation", "Protocol information"),
+    OidInfo::new("2.5.4.49", "distinguishedName", "Distinguished name"),
+    OidInfo::new("2.5.4.5", "serialNumber", "Serial number"),
+    OidInfo::new("2.5.4.50", "uniqueMember", "Unique member"),
+    OidInfo::new("2.5.4.51", "houseIdentifier", "House identifier"),
+    OidInfo::new("2.5.4.52", "supportedAlgorithms", "Supported algorithms"),
+    OidInfo::new("2.5.4.53", "deltaRevocationList", "Delta revocation list"),
+    OidInfo::new("2.5.4.54", "dmdName", "DMD name"),
+    OidInfo::new("2.5.4.6", "countryName", "Country name (C)"),
+    OidInfo::new("2.5.4.65", "pseudonym", "Pseudonym"),
+    OidInfo::new("2.5.4.7", "localityName", "Locality name (L)"),
+    OidInfo::new("2.5.4.7.1", "c-l", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.72", "role", "Role"),
+    OidInfo::new("2.5.4.8", "stateOrProvinceName", "State or province name (ST)"),
+    OidInfo::new("2.5.4.8.1", "c-st", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.9", "streetAddress", "Street address"),
+    OidInfo::new("2.5.4.9.1", "c-street", "Defined in RFC 3671"),
+    OidInfo::new("2.5.4.97", "organizationIdentifier", "Organization identifier"),
+    OidInfo::new("2.5.4.98", "c3", "countryCode3c"),
+    OidInfo::new("2.5.4.99", "n3", "countryCode3n"),
+    OidInfo::new("2.5.6.0", "top", "Top object class"),
+    OidInfo::new("2.5.6.1", "alias", "Alias object class"),
+    OidInfo::new("2.5.6.10", "residentialPerson", "Residential person object class"),
+    OidInfo::new("2.5.6.11", "applicationProcess", "Application process object class"),
+    OidInfo::new("2.5.6.12", "applicationEntity", "Application entity object class"),
+    OidInfo::new("2.5.6.13", "dSA", "Directory system agent object class"),
+    OidInfo::new("2.5.6.14", "device", "Device object class"),
+    OidInfo::new(
+        "2.5.6.15",
+        "strongAuthenticationUser",
+        "Strong authentication user object class",
+    ),
+    OidInfo::new(
+        "2.5.6.16",
+        "certificationAuthority",
+        "Certification authority object class",
+    ),
+    OidInfo::new("2.5.6.17", "groupOfUniqueNames", "Group of unique names object class"),
+    OidInfo::new(
+        "2.5.6.18",
+        "userSecurityInformation",
+        "User security information object class",
+    ),
+    OidInfo::new(
+        "2.5.6.19",
+        "cRLDistributionPoint",
+        "CRL distribution point object class",
+    ),
+    OidInfo::new("2.5.6.2", "country", "Country object class"),
+    OidInfo::new("2.5.6.20", "dmd", "Defined in RFC 2256"),
+    OidInfo::new("2.5.6.21", "pkiUser", "Defined in RFC 4523"),
+    OidInfo::new("2.5.6.22", "pkiCA", "Defined in RFC 4523"),
+    OidInfo::new("2.5.6.23", "deltaCRL", "Defined in RFC 4523"),
+    OidInfo::new("2.5.6.3", "locality", "Locality object class"),
+    OidInfo::new("2.5.6.4", "organization", "Organization object class"),
+    OidInfo::new("2.5.6.5", "organizationalUnit", "Organizational unit object class"),
+    OidInfo::new("2.5.6.6", "person", "Person object class"),
+    OidInfo::new("2.5.6.7", "organizationalPerson", "Organizational person object class"),
+    OidInfo::new("2.5.6.8", "organizationalRole", "Organizational role object class"),
+    OidInfo::new("2.5.6.9", "groupOfNames", "Group of names object class"),
+    OidInfo::new("2.5.8", "X500algorithms", "directory services - algorithms"),
+    OidInfo::new("2.5.8.1", "szOID_DSALG_CRPT", "Windows SDK object identifier"),
+    OidInfo::new("2.5.8.1.1", "id-ea-rsa", "X.500 RSA encryption algorithm"),
+    OidInfo::new("2.5.8.2", "szOID_DSALG_HASH", "Windows SDK object identifier"),
+    OidInfo::new("2.5.8.3", "szOID_DSALG_SIGN", "Windows SDK object identifier"),
+    OidInfo::new("2.5.8.3.100", "RSA-MDC2", "mdc2WithRSA"),
+    OidInfo::new("2.5.8.3.101", "MDC2", "mdc2"),
+];
@@ -0,0 +1,120 @@
+//! Object identifiers registry: names, descriptions, and reverse lookup by name.
+//!
+//! The built-in entries cover PKCS, PKIX, X.520 attributes, X.509 extensions, elliptic curves, Kerberos,
+//! and Microsoft object identifiers. They are generated from public OID datasets by `scripts/generate_oids.py`.
+//! Custom entries can be registered in the [OidRegistry].
+
+mod entries;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use entries::OIDS;
+
+/// Object identifier name and description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OidInfo<'a> {
+    oid: &'a str,
+    name: &'a str,
+    description: &'a str,
+}
+
+impl<'a> OidInfo<'a> {
+    pub const fn new(oid: &'a str, name: &'a str, description: &'a str) -> Self {
+        Self { oid, name, description }
+    }
+
+    /// Dotted representation of the object identifier.
+    pub fn oid(&self) -> &'a str {
+        self.oid
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn description(&self) -> &'a str {
+        self.description
+    }
+}
+
+/// Returns all built-in object identifiers sorted by their dotted representation.
+pub fn builtin_oids() -> &'static [OidInfo<'static>] {
+    OIDS
+}
+
+/// Looks up the built-in object identifier by its dotted representation.
+pub fn oid_info(oid: &str) -> Option<&'static OidInfo<'static>> {
+    OIDS.binary_search_by(|info| info.oid.cmp(oid))
+        .ok()
+        .map(|index| &OIDS[index])
+}
+
+/// Looks up the built-in object identifier by its name. The comparison is case-insensitive.
+pub fn oid_by_name(name: &str) -> Option<&'static OidInfo<'static>> {
+    OIDS.iter().find(|info| info.name.eq_ignore_ascii_case(name))
+}
+
+/// User-defined object identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomOid {
+    pub oid: String,
+    pub name: String,
+    pub description: String,
+}
+
+impl CustomOid {
+    pub fn info(&self) -> OidInfo<'_> {
+        OidInfo::new(&self.oid, &self.name, &self.description)
+    }
+}
+
+/// Object identifiers registry. Custom entries take precedence over the built-in ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OidRegistry {
+    custom: Vec<CustomOid>,
+}
+
+impl OidRegistry {
+    pub fn new(custom: Vec<CustomOid>) -> Self {
+        Self { custom }
+    }
+
+    pub fn custom(&self) -> &[CustomOid] {
+        &self.custom
+    }
+
+    /// Registers the custom object identifier. The previous custom entry with the same oid is replaced.
+    pub fn register(&mut self, custom: CustomOid) {
+        match self.custom.iter_mut().find(|entry| entry.oid == custom.oid) {
+            Some(entry) => *entry = custom,
+            None => self.custom.push(custom),
+        }
+    }
+
+    /// Removes the custom object identifier. Returns `true` if it was registered.
+    pub fn unregister(&mut self, oid: &str) -> bool {
+        let len = self.custom.len();
+        self.custom.retain(|entry| entry.oid != oid);
+
+        len != self.custom.len()
+    }
+
+    /// Looks up the object identifier by its dotted representation.
+    pub fn lookup(&self, oid: &str) -> Option<OidInfo<'_>> {
+        self.custom
+            .iter()
+            .find(|entry| entry.oid == oid)
+            .map(CustomOid::info)
+            .or_else(|| oid_info(oid).copied())
+    }
+
+    /// Looks up the object identifier by its name. The comparison is case-insensitive.
+    pub fn lookup_by_name(&self, name: &str) -> Option<OidInfo<'_>> {
+        self.custom
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+            .map(CustomOid::info)
+            .or_else(|| oid_by_name(name).copied())
+    }
+}
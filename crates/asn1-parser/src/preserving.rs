@@ -0,0 +1,175 @@
+//! Byte-exact encoding that preserves the original (possibly non-canonical) encoding of the unmodified nodes.
+//!
+//! [Asn1Encoder] always produces the minimal encoding: long-form lengths and other BER quirks are silently
+//! "fixed" in the whole tree after any edit. [encode_preserving] reuses the original node bytes
+//! ([RawAsn1EntityData::raw_data]) for every unmodified subtree and re-encodes only the modified nodes
+//! and the headers of their ancestors.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::length::{len_size, write_len};
+use crate::writer::Writer;
+use crate::{Asn1, Asn1Encoder, Asn1Entity, Asn1Result, Asn1Type, RawAsn1EntityData};
+
+/// Calls the provided function with the node children if the node is constructed.
+fn with_children<R>(asn1: &Asn1Type, f: impl FnOnce(&[Asn1]) -> R) -> Option<R> {
+    match asn1 {
+        Asn1Type::Sequence(sequence) => Some(f(sequence.get().fields())),
+        Asn1Type::Set(set) => Some(f(set.get().fields())),
+        Asn1Type::ExplicitTag(explicit) => Some(f(explicit.get().inner())),
+        Asn1Type::ApplicationTag(application) => Some(f(application.get().inner())),
+        _ => None,
+    }
+}
+
+fn has_original_bytes(meta: &RawAsn1EntityData) -> bool {
+    !meta.raw_data.is_empty()
+}
+
+/// Returns `true` if the original node header (tag and length bytes) can be reused.
+fn reuse_header(tree: &Asn1, data_len: usize) -> bool {
+    has_original_bytes(tree.meta()) && !tree.inner_asn1().is_modified() && tree.meta().data_range().len() == data_len
+}
+
+/// Precomputed dirtiness and preserved length of the node and its descendants.
+///
+/// It is built bottom-up in one pass: a dirty node marks all its ancestors dirty, so neither the dirtiness nor
+/// the length of any subtree is recomputed during the encoding.
+struct Preserved {
+    /// The node or any of its descendants is modified or does not have the original bytes.
+    dirty: bool,
+    len: usize,
+    /// Empty for the primitive nodes.
+    children: Vec<Preserved>,
+}
+
+impl Preserved {
+    fn new(tree: &Asn1) -> Self {
+        let meta = tree.meta();
+        let children = with_children(tree.inner_asn1(), |children| {
+            children.iter().map(Preserved::new).collect::<Vec<_>>()
+        });
+
+        let dirty = !has_original_bytes(meta)
+            || tree.inner_asn1().is_modified()
+            || children.iter().flatten().any(|child| child.dirty);
+
+        let len = match &children {
+            _ if !dirty => meta.raw_bytes().len(),
+            Some(children) => {
+                let data_len = children_len(children);
+
+                if reuse_header(tree, data_len) {
+                    // Indefinite-length nodes keep their end-of-contents octets after the data.
+                    meta.raw_bytes().len() - meta.data_range().len() + data_len
+                } else {
+                    1 /* tag */ + len_size(data_len) + data_len
+                }
+            }
+            None => tree.needed_buf_size(),
+        };
+
+        Self {
+            dirty,
+            len,
+            children: children.unwrap_or_default(),
+        }
+    }
+}
+
+fn children_len(children: &[Preserved]) -> usize {
+    children.iter().map(|child| child.len).sum()
+}
+
+fn encode_node(tree: &Asn1, preserved: &Preserved, writer: &mut Writer) -> Asn1Result<()> {
+    let meta = tree.meta();
+
+    if !preserved.dirty {
+        return writer.write_slice(meta.raw_bytes());
+    }
+
+    with_children(tree.inner_asn1(), |children| {
+        let data_len = children_len(&preserved.children);
+        let reuse_header = reuse_header(tree, data_len);
+
+        if reuse_header {
+            writer.write_slice(&meta.raw_bytes()[..meta.data_range().start])?;
+        } else {
+            writer.write_byte(tree.tag().into())?;
+            write_len(data_len, writer)?;
+        }
+
+        children
+            .iter()
+            .zip(&preserved.children)
+            .try_for_each(|(child, preserved)| encode_node(child, preserved, writer))?;
+
+        if reuse_header {
+            writer.write_slice(&meta.raw_bytes()[meta.data_range().end..])?;
+        }
+
+        Ok(())
+    })
+    .unwrap_or_else(|| tree.encode(writer))
+}
+
+/// Returns `true` if the node or any of its descendants is modified or does not have the original bytes.
+pub fn is_dirty(tree: &Asn1) -> bool {
+    Preserved::new(tree).dirty
+}
+
+/// Returns the length of the node encoded using [encode_preserving].
+pub fn preserved_len(tree: &Asn1) -> usize {
+    Preserved::new(tree).len
+}
+
+/// Encodes the trees reusing the original bytes of all unmodified subtrees.
+///
+/// Modified primitive nodes are re-encoded using the minimal encoding. Constructed nodes on the path from
+/// the modified nodes to the root keep their original header if the data length did not change.
+pub fn encode_preserving(trees: &[Asn1]) -> Asn1Result<Vec<u8>> {
+    let preserved = trees.iter().map(Preserved::new).collect::<Vec<_>>();
+    let mut buf = vec![0; children_len(&preserved)];
+
+    let mut writer = Writer::new(&mut buf);
+    trees
+        .iter()
+        .zip(&preserved)
+        .try_for_each(|(tree, preserved)| encode_node(tree, preserved, &mut writer))?;
+
+    Ok(buf)
+}
+
+fn collect_changed_lengths(old: &[Asn1], new: &[Asn1], changed: &mut Vec<u64>) {
+    for (old, new) in old.iter().zip(new) {
+        if old.tag() != new.tag() {
+            continue;
+        }
+
+        if old.meta().length_bytes() != new.meta().length_bytes() && !old.inner_asn1().is_modified() {
+            changed.push(new.id());
+        }
+
+        with_children(old.inner_asn1(), |old_children| {
+            with_children(new.inner_asn1(), |new_children| {
+                if old_children.len() == new_children.len() {
+                    collect_changed_lengths(old_children, new_children, changed);
+                }
+            })
+        });
+    }
+}
+
+/// Compares the trees before and after the re-encoding and returns ids of the nodes (in the new trees) which
+/// length bytes have changed even though the nodes themselves were not modified. Usually, they are the
+/// ancestors of the modified nodes.
+pub fn changed_lengths(old: &[Asn1], new: &[Asn1]) -> Vec<u64> {
+    let mut changed = Vec::new();
+
+    if old.len() == new.len() {
+        collect_changed_lengths(old, new, &mut changed);
+    }
+
+    changed
+}
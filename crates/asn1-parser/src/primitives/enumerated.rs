@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use num_bigint_dig::BigUint;
+use num_bigint_dig::{BigInt, BigUint};
 
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
@@ -27,6 +27,11 @@ impl Enumerated {
         })
     }
 
+    /// Interprets the enumerated bytes as a two's-complement signed number.
+    pub fn as_big_int(&self) -> BigInt {
+        BigInt::from_signed_bytes_be(&self.0)
+    }
+
     pub fn set(&mut self, bytes: Vec<u8>) {
         self.0 = bytes;
     }
@@ -1,11 +1,11 @@
 use alloc::vec::Vec;
 
-use num_bigint_dig::BigUint;
+use num_bigint_dig::{BigInt, BigUint};
 
 use crate::length::{len_size, write_len};
 use crate::reader::Reader;
 use crate::writer::Writer;
-use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Tag, Taggable};
+use crate::{Asn1Encoder, Asn1Result, Asn1ValueDecoder, Error, Tag, Taggable};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Integer(Vec<u8>);
@@ -17,6 +17,9 @@ impl Integer {
         self.0.as_ref()
     }
 
+    /// Interprets the integer bytes as an unsigned number. The leading zero byte is skipped.
+    ///
+    /// Use [Integer::as_big_int] to get the actual (two's-complement) value of the integer.
     pub fn as_big_uint(&self) -> BigUint {
         BigUint::from_bytes_be(if self.0.len() > 1 {
             if self.0[0] == 0x00 { &self.0[1..] } else { &self.0 }
@@ -27,11 +30,87 @@ impl Integer {
         })
     }
 
+    /// Interprets the integer bytes as a two's-complement signed number.
+    pub fn as_big_int(&self) -> BigInt {
+        BigInt::from_signed_bytes_be(&self.0)
+    }
+
+    /// Creates a new integer from the signed number using the minimal DER encoding.
+    pub fn from_big_int(value: &BigInt) -> Self {
+        Self(value.to_signed_bytes_be())
+    }
+
     pub fn set(&mut self, bytes: Vec<u8>) {
         self.0 = bytes;
     }
 }
 
+/// Returns the minimal two's-complement encoding of the number.
+fn minimal_signed_bytes(value: i128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let redundant = bytes
+        .windows(2)
+        .take_while(|pair| (pair[0] == 0x00 && pair[1] & 0x80 == 0) || (pair[0] == 0xff && pair[1] & 0x80 != 0))
+        .count();
+
+    bytes[redundant..].to_vec()
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Integer {
+                fn from(value: $number) -> Self {
+                    Self(minimal_signed_bytes(i128::from(value)))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl TryFrom<&Integer> for i128 {
+    type Error = Error;
+
+    fn try_from(integer: &Integer) -> Result<Self, Self::Error> {
+        let bytes = integer.raw_data();
+        let sign_byte = if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+            0xff
+        } else {
+            0x00
+        };
+
+        // Non-minimal encodings can contain redundant leading sign bytes.
+        let extra = bytes.len().saturating_sub(size_of::<i128>());
+        if bytes[..extra].iter().any(|byte| *byte != sign_byte) {
+            return Err("integer value is too big".into());
+        }
+
+        let mut value = [sign_byte; size_of::<i128>()];
+        let bytes = &bytes[extra..];
+        value[size_of::<i128>() - bytes.len()..].copy_from_slice(bytes);
+
+        Ok(i128::from_be_bytes(value))
+    }
+}
+
+impl TryFrom<&Integer> for i64 {
+    type Error = Error;
+
+    fn try_from(integer: &Integer) -> Result<Self, Self::Error> {
+        i64::try_from(i128::try_from(integer)?).map_err(|_| Error::from("integer value does not fit into i64"))
+    }
+}
+
+impl TryFrom<&Integer> for u64 {
+    type Error = Error;
+
+    fn try_from(integer: &Integer) -> Result<Self, Self::Error> {
+        u64::try_from(i128::try_from(integer)?).map_err(|_| Error::from("integer value does not fit into u64"))
+    }
+}
+
 impl From<Vec<u8>> for Integer {
     fn from(bytes: Vec<u8>) -> Self {
         Self(bytes)
@@ -4,7 +4,8 @@ use crate::{Asn1Result, Error};
 
 #[derive(Debug)]
 pub struct Reader<'data> {
-    next_node_id: u64,
+    /// The last assigned node id. Zero means that no ids are assigned yet: node ids start from one.
+    last_node_id: u64,
     offset: usize,
     position: usize,
     inner: &'data [u8],
@@ -14,7 +15,7 @@ impl<'data> Reader<'data> {
     pub fn new(data: &'data [u8]) -> Self {
         Self {
             offset: 0,
-            next_node_id: 0,
+            last_node_id: 0,
             position: 0,
             inner: data,
         }
@@ -24,13 +25,20 @@ impl<'data> Reader<'data> {
         self.offset = offset;
     }
 
+    /// Assigns the next node id.
     pub fn next_id(&mut self) -> u64 {
-        self.next_node_id += 1;
-        self.next_node_id
+        self.last_node_id += 1;
+        self.last_node_id
     }
 
-    pub fn set_next_id(&mut self, next_node_id: u64) {
-        self.next_node_id = next_node_id;
+    /// Sets the last assigned node id: the following nodes get bigger ids.
+    pub fn set_next_id(&mut self, last_node_id: u64) {
+        self.last_node_id = last_node_id;
+    }
+
+    /// Returns the last assigned node id (zero if no ids are assigned yet).
+    pub fn last_id(&self) -> u64 {
+        self.last_node_id
     }
 
     pub fn position(&self) -> usize {
@@ -64,7 +72,7 @@ impl<'data> Reader<'data> {
     }
 
     pub fn read(&mut self, len: usize) -> Asn1Result<&'data [u8]> {
-        if self.position + len > self.inner.len() {
+        if len > self.inner.len() - self.position {
             return Err(Error::from("Outside"));
         }
 
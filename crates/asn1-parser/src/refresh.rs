@@ -0,0 +1,223 @@
+//! Metadata refreshing after edits.
+//!
+//! After an edit, the node offsets and lengths stored in [RawAsn1EntityData] are outdated. Re-parsing the encoded
+//! trees from scratch fixes them but assigns new node ids. [refresh_meta] walks the encoded data along with the
+//! edited trees instead: it updates the metadata and keeps the ids of all existing nodes.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::length::read_len;
+use crate::reader::{Reader, read_data};
+use crate::{
+    ApplicationTag, Asn1, Asn1Entity, Asn1Result, Asn1Type, Error, ExplicitTag, Mutable, RawAsn1EntityData, Sequence,
+    Set, Tag,
+};
+
+/// Trees with the refreshed metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshedTrees {
+    pub trees: Vec<Asn1>,
+    /// Ids of the unmodified nodes which length bytes have changed. Usually, they are the ancestors
+    /// of the modified nodes.
+    pub changed_lengths: Vec<u64>,
+}
+
+fn max_id(trees: &[Asn1]) -> u64 {
+    trees
+        .iter()
+        .map(|tree| {
+            let inner_max_id = match tree.inner_asn1() {
+                Asn1Type::Sequence(sequence) => max_id(sequence.get().fields()),
+                Asn1Type::Set(set) => max_id(set.get().fields()),
+                Asn1Type::ExplicitTag(explicit) => max_id(explicit.get().inner()),
+                Asn1Type::ApplicationTag(application) => max_id(application.get().inner()),
+                Asn1Type::OctetString(octet_string) => octet_string
+                    .get()
+                    .inner()
+                    .map(|inner| max_id(&inner.get()))
+                    .unwrap_or_default(),
+                Asn1Type::BitString(bit_string) => bit_string
+                    .get()
+                    .inner()
+                    .map(|inner| max_id(&inner.get()))
+                    .unwrap_or_default(),
+                Asn1Type::ImplicitTag(implicit) => implicit
+                    .get()
+                    .inner_asn1()
+                    .map(|inner| max_id(core::slice::from_ref(inner)))
+                    .unwrap_or_default(),
+                _ => 0,
+            };
+
+            tree.id().max(inner_max_id)
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+struct Refresher {
+    next_id: u64,
+    changed_lengths: Vec<u64>,
+}
+
+impl Refresher {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn refresh_trees(&mut self, trees: &[Asn1], reader: &mut Reader, fresh: bool) -> Asn1Result<Vec<Asn1>> {
+        let trees = trees
+            .iter()
+            .map(|tree| self.refresh_tree(tree, reader, fresh))
+            .collect::<Asn1Result<Vec<_>>>()?;
+
+        if !reader.empty() {
+            return Err(Error::from(
+                "encoded data does not match the trees: unexpected trailing data",
+            ));
+        }
+
+        Ok(trees)
+    }
+
+    /// Refreshes the node metadata. The `fresh` node (and all its descendants) gets a new id.
+    fn refresh_tree(&mut self, tree: &Asn1, reader: &mut Reader, fresh: bool) -> Asn1Result<Asn1> {
+        // Nodes without id are created by the user and are not decoded yet.
+        let fresh = fresh || tree.id() == 0;
+        let modified = tree.inner_asn1().is_modified();
+
+        let tag_position = reader.full_offset();
+        let node_start = reader.position();
+
+        let tag = Tag(reader.read_byte()?);
+        if tag != tree.tag() {
+            return Err(Error::from("encoded data does not match the trees: unexpected tag"));
+        }
+
+        let (len, len_range) = read_len(reader)?;
+        let (data, data_range) = read_data(reader, len)?;
+
+        let mut inner_reader = Reader::new(data);
+        inner_reader.set_offset(reader.full_offset() - data.len());
+
+        // Inner trees of the modified encapsulating nodes (octet string, bit string, implicit tag) are re-parsed
+        // from the new bytes, so they get new ids.
+        let asn1 = match tree.inner_asn1() {
+            Asn1Type::Sequence(sequence) => Asn1Type::Sequence(Mutable::new(Sequence::new(self.refresh_trees(
+                sequence.get().fields(),
+                &mut inner_reader,
+                fresh,
+            )?))),
+            Asn1Type::Set(set) => Asn1Type::Set(Mutable::new(Set::new(self.refresh_trees(
+                set.get().fields(),
+                &mut inner_reader,
+                fresh,
+            )?))),
+            Asn1Type::ExplicitTag(explicit) => {
+                let explicit = explicit.get();
+                let inner = self.refresh_trees(explicit.inner(), &mut inner_reader, fresh)?;
+
+                Asn1Type::ExplicitTag(Mutable::new(ExplicitTag::new(explicit.tag_number(), inner)))
+            }
+            Asn1Type::ApplicationTag(application) => {
+                let application = application.get();
+                let inner = self.refresh_trees(application.inner(), &mut inner_reader, fresh)?;
+
+                Asn1Type::ApplicationTag(Mutable::new(ApplicationTag::new(application.tag_number(), inner)))
+            }
+            Asn1Type::OctetString(octet_string) => {
+                let octet_string = octet_string.get();
+                let inner = octet_string
+                    .inner()
+                    .map(|inner| self.refresh_trees(&inner.get(), &mut inner_reader, fresh || modified))
+                    .transpose()?
+                    .map(Mutable::new);
+
+                Asn1Type::OctetString(Mutable::new(octet_string.with_inner(inner)))
+            }
+            Asn1Type::BitString(bit_string) => {
+                let bit_string = bit_string.get();
+                let inner = match bit_string.inner() {
+                    Some(inner) => {
+                        // The first byte is the number of unused bits.
+                        let mut inner_reader = Reader::new(data.get(1..).unwrap_or_default());
+                        inner_reader.set_offset(reader.full_offset() - data.len() + 1);
+
+                        Some(Mutable::new(self.refresh_trees(
+                            &inner.get(),
+                            &mut inner_reader,
+                            fresh || modified,
+                        )?))
+                    }
+                    None => None,
+                };
+
+                Asn1Type::BitString(Mutable::new(bit_string.with_inner(inner)))
+            }
+            Asn1Type::ImplicitTag(implicit) => {
+                let implicit = implicit.get();
+                let inner = match implicit.inner_asn1() {
+                    Some(inner) => {
+                        let inner = self.refresh_tree(inner, &mut inner_reader, fresh || modified)?;
+
+                        if !inner_reader.empty() {
+                            return Err(Error::from(
+                                "encoded data does not match the trees: unexpected trailing data",
+                            ));
+                        }
+
+                        Some(Box::new(inner))
+                    }
+                    None => None,
+                };
+
+                Asn1Type::ImplicitTag(Mutable::new(implicit.with_inner(inner)))
+            }
+            asn1 => {
+                asn1.clear_modified();
+
+                asn1.clone()
+            }
+        };
+
+        let meta = RawAsn1EntityData {
+            raw_data: reader.data_in_range(node_start..data_range.end)?.to_vec(),
+            tag: tag_position,
+            length: (len_range.start - node_start)..(len_range.end - node_start),
+            data: (data_range.start - node_start)..(data_range.end - node_start),
+        };
+
+        let id = if fresh {
+            self.next_id()
+        } else {
+            if !modified && !tree.meta().raw_data.is_empty() && tree.meta().length_bytes() != meta.length_bytes() {
+                self.changed_lengths.push(tree.id());
+            }
+
+            tree.id()
+        };
+
+        Ok(Asn1::new(id, meta, asn1))
+    }
+}
+
+/// Refreshes the trees metadata using their encoded representation.
+///
+/// The `encoded` data must be the result of the trees encoding (see [Asn1Encoder](crate::Asn1Encoder) and
+/// [encode_preserving](crate::preserving::encode_preserving)). Existing nodes keep their ids, the new nodes
+/// get new unique ids. All modification flags are cleared.
+pub fn refresh_meta(trees: &[Asn1], encoded: &[u8]) -> Asn1Result<RefreshedTrees> {
+    let mut refresher = Refresher {
+        next_id: max_id(trees),
+        changed_lengths: Vec::new(),
+    };
+
+    let trees = refresher.refresh_trees(trees, &mut Reader::new(encoded), false)?;
+
+    Ok(RefreshedTrees {
+        trees,
+        changed_lengths: refresher.changed_lengths,
+    })
+}
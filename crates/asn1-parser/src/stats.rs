@@ -0,0 +1,159 @@
+//! Size and structure statistics of the asn1 trees.
+//!
+//! [tree_stats] walks the trees (including the trees encapsulated in octet strings, bit strings, and implicit tags)
+//! and collects the encoded size of every subtree, node counts per type, and the maximum depth.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::{Asn1, Asn1Encoder, Asn1Entity, Asn1Type};
+
+/// Statistics of one node and its subtree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeStats {
+    pub id: u64,
+    pub type_name: &'static str,
+    pub depth: usize,
+    /// Encoded size of the whole subtree (tag, length, and data bytes).
+    pub bytes: usize,
+    /// Amount of nodes in the subtree, excluding the node itself.
+    pub descendants: usize,
+}
+
+impl NodeStats {
+    /// Returns the subtree share of the `total` bytes in percents.
+    pub fn percentage(&self, total: usize) -> f64 {
+        if total == 0 {
+            return 0.0;
+        }
+
+        self.bytes as f64 * 100.0 / total as f64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TreeStats {
+    /// Node statistics in the pre-order (the same order as the nodes appear in the encoding).
+    pub nodes: Vec<NodeStats>,
+    /// Node counts per type sorted by the count in descending order.
+    pub type_counts: Vec<(&'static str, usize)>,
+    pub max_depth: usize,
+    /// Encoded size of all trees.
+    pub total_bytes: usize,
+}
+
+pub fn type_name(asn1: &Asn1Type) -> &'static str {
+    match asn1 {
+        Asn1Type::Sequence(_) => "SEQUENCE",
+        Asn1Type::Set(_) => "SET",
+        Asn1Type::OctetString(_) => "OCTET STRING",
+        Asn1Type::Utf8String(_) => "UTF8String",
+        Asn1Type::BitString(_) => "BIT STRING",
+        Asn1Type::BmpString(_) => "BMPString",
+        Asn1Type::IA5String(_) => "IA5String",
+        Asn1Type::PrintableString(_) => "PrintableString",
+        Asn1Type::GeneralString(_) => "GeneralString",
+        Asn1Type::NumericString(_) => "NumericString",
+        Asn1Type::VisibleString(_) => "VisibleString",
+        Asn1Type::Bool(_) => "BOOLEAN",
+        Asn1Type::Null(_) => "NULL",
+        Asn1Type::Integer(_) => "INTEGER",
+        Asn1Type::Enumerated(_) => "ENUMERATED",
+        Asn1Type::ObjectIdentifier(_) => "OBJECT IDENTIFIER",
+        Asn1Type::UtcTime(_) => "UTCTime",
+        Asn1Type::GeneralizedTime(_) => "GeneralizedTime",
+        Asn1Type::ExplicitTag(_) => "EXPLICIT",
+        Asn1Type::ImplicitTag(_) => "IMPLICIT",
+        Asn1Type::ApplicationTag(_) => "APPLICATION",
+    }
+}
+
+/// Encoded size of the node. Nodes without the original bytes (e.g. just created) are measured by their
+/// minimal encoding.
+fn node_bytes(tree: &Asn1) -> usize {
+    let raw_bytes = tree.meta().raw_bytes();
+
+    if raw_bytes.is_empty() {
+        tree.needed_buf_size()
+    } else {
+        raw_bytes.len()
+    }
+}
+
+struct Collector {
+    nodes: Vec<NodeStats>,
+    type_counts: BTreeMap<&'static str, usize>,
+    max_depth: usize,
+}
+
+impl Collector {
+    /// Collects the statistics of the trees and returns the amount of collected nodes.
+    fn collect_trees(&mut self, trees: &[Asn1], depth: usize) -> usize {
+        trees.iter().map(|tree| self.collect_tree(tree, depth)).sum()
+    }
+
+    fn collect_tree(&mut self, tree: &Asn1, depth: usize) -> usize {
+        let asn1 = tree.inner_asn1();
+        let type_name = type_name(asn1);
+
+        *self.type_counts.entry(type_name).or_default() += 1;
+        self.max_depth = self.max_depth.max(depth);
+
+        let index = self.nodes.len();
+        self.nodes.push(NodeStats {
+            id: tree.id(),
+            type_name,
+            depth,
+            bytes: node_bytes(tree),
+            descendants: 0,
+        });
+
+        let descendants = match asn1 {
+            Asn1Type::Sequence(sequence) => self.collect_trees(sequence.get().fields(), depth + 1),
+            Asn1Type::Set(set) => self.collect_trees(set.get().fields(), depth + 1),
+            Asn1Type::ExplicitTag(explicit) => self.collect_trees(explicit.get().inner(), depth + 1),
+            Asn1Type::ApplicationTag(application) => self.collect_trees(application.get().inner(), depth + 1),
+            Asn1Type::OctetString(octet_string) => octet_string
+                .get()
+                .inner()
+                .map(|inner| self.collect_trees(&inner.get(), depth + 1))
+                .unwrap_or_default(),
+            Asn1Type::BitString(bit_string) => bit_string
+                .get()
+                .inner()
+                .map(|inner| self.collect_trees(&inner.get(), depth + 1))
+                .unwrap_or_default(),
+            Asn1Type::ImplicitTag(implicit) => implicit
+                .get()
+                .inner_asn1()
+                .map(|inner| self.collect_tree(inner, depth + 1))
+                .unwrap_or_default(),
+            _ => 0,
+        };
+
+        self.nodes[index].descendants = descendants;
+
+        descendants + 1
+    }
+}
+
+/// Collects the statistics of the trees.
+pub fn tree_stats(trees: &[Asn1]) -> TreeStats {
+    let mut collector = Collector {
+        nodes: Vec::new(),
+        type_counts: BTreeMap::new(),
+        max_depth: 0,
+    };
+    collector.collect_trees(trees, 0);
+
+    let mut type_counts = collector.type_counts.into_iter().collect::<Vec<_>>();
+    // The sort is stable, so the types with the same count stay sorted by name.
+    type_counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    TreeStats {
+        nodes: collector.nodes,
+        type_counts,
+        max_depth: collector.max_depth,
+        total_bytes: trees.iter().map(node_bytes).sum(),
+    }
+}
@@ -30,6 +30,14 @@ impl BitString {
         self.inner.clone()
     }
 
+    /// Returns a copy of the value with the provided inner trees.
+    pub(crate) fn with_inner(&self, inner: Option<Mutable<Vec<Asn1>>>) -> Self {
+        Self {
+            octets: self.octets.clone(),
+            inner,
+        }
+    }
+
     pub fn bits_amount(&self) -> usize {
         let data_len = self.octets.len() - 1;
         let padding = usize::from(self.octets[0]);
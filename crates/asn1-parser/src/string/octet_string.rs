@@ -30,6 +30,14 @@ impl OctetString {
         self.inner.clone()
     }
 
+    /// Returns a copy of the value with the provided inner trees.
+    pub(crate) fn with_inner(&self, inner: Option<Mutable<Vec<Asn1>>>) -> Self {
+        Self {
+            octets: self.octets.clone(),
+            inner,
+        }
+    }
+
     pub fn new(octets: Vec<u8>) -> OctetString {
         let inner = decode_buff_vec(&octets).ok().map(|mut asn1| {
             asn1.iter_mut().for_each(|tree| tree.clear_meta());
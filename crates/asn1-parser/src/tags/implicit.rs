@@ -36,6 +36,15 @@ impl ImplicitTag {
         self.octets.as_ref()
     }
 
+    /// Returns a copy of the value with the provided inner tree.
+    pub(crate) fn with_inner(&self, inner: Option<Box<Asn1>>) -> Self {
+        Self {
+            tag: self.tag,
+            octets: self.octets.clone(),
+            inner,
+        }
+    }
+
     pub fn set_octets(&mut self, octets: Vec<u8>) {
         self.octets = octets;
         self.inner = Asn1::decode_buff(&self.octets).ok().map(Box::new);
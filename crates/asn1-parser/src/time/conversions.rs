@@ -0,0 +1,149 @@
+//! Conversions between asn1 time types and [OffsetDateTime].
+
+use ::time::{Date, Month as CalendarMonth, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use super::{
+    Day, GeneralizedTime, GtSecond, GtYear, Hour, LocalTimeDiffFactor, LocalTimeDirection, Minute, Month, Second,
+    UtcTime, Year,
+};
+use crate::{Asn1Result, Error};
+
+/// UtcTime years less than this value belong to the 21st century (RFC 5280 4.1.2.5.1).
+const UTC_TIME_PIVOT_YEAR: u8 = 50;
+
+const NANOSECONDS_IN_MILLISECOND: u32 = 1_000_000;
+
+fn date_time(year: i32, month: Month, day: Day, hour: Hour, minute: Minute) -> Asn1Result<PrimitiveDateTime> {
+    let month = CalendarMonth::try_from(u8::from(month)).map_err(|_| Error::from("invalid month"))?;
+    let date = Date::from_calendar_date(year, month, day.into()).map_err(|_| Error::from("invalid date"))?;
+    let time = Time::from_hms(hour.into(), minute.into(), 0).map_err(|_| Error::from("invalid time"))?;
+
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+impl TryFrom<&UtcTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(utc_time: &UtcTime) -> Result<Self, Self::Error> {
+        let year = u8::from(utc_time.year);
+        let year = if year < UTC_TIME_PIVOT_YEAR {
+            2000 + i32::from(year)
+        } else {
+            1900 + i32::from(year)
+        };
+
+        let date_time = date_time(year, utc_time.month, utc_time.day, utc_time.hour, utc_time.minute)?
+            .replace_second(utc_time.second.map(u8::from).unwrap_or_default())
+            .map_err(|_| Error::from("invalid second"))?;
+
+        Ok(date_time.assume_utc())
+    }
+}
+
+impl TryFrom<OffsetDateTime> for UtcTime {
+    type Error = Error;
+
+    /// Converts the date time to UTC. Only years in the 1950..=2049 range can be represented.
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        let date_time = date_time.to_offset(UtcOffset::UTC);
+
+        if !(1950..=2049).contains(&date_time.year()) {
+            return Err("UtcTime year must be in the 1950..=2049 range".into());
+        }
+
+        Ok(UtcTime {
+            year: Year::try_from((date_time.year() % 100) as u8)?,
+            month: Month::try_from(u8::from(date_time.month()))?,
+            day: Day::try_from(date_time.day())?,
+            hour: Hour::try_from(date_time.hour())?,
+            minute: Minute::try_from(date_time.minute())?,
+            second: Some(Second::try_from(date_time.second())?),
+        })
+    }
+}
+
+impl TryFrom<&GeneralizedTime> for OffsetDateTime {
+    type Error = Error;
+
+    /// The time without the local time difference is treated as UTC.
+    fn try_from(generalized_time: &GeneralizedTime) -> Result<Self, Self::Error> {
+        let second = f32::from(generalized_time.second);
+        let whole_seconds = second as u8;
+        // GeneralizedTime fraction is encoded with millisecond precision.
+        let milliseconds = ((second - f32::from(whole_seconds)) * 1000.0 + 0.5) as u32;
+
+        let date_time = date_time(
+            i32::from(*generalized_time.year.as_ref()),
+            generalized_time.month,
+            generalized_time.day,
+            generalized_time.hour,
+            generalized_time.minute,
+        )?
+        .replace_second(whole_seconds)
+        .and_then(|date_time| date_time.replace_nanosecond(milliseconds.min(999) * NANOSECONDS_IN_MILLISECOND))
+        .map_err(|_| Error::from("invalid second"))?;
+
+        let offset = match generalized_time.local_time {
+            Some(LocalTimeDiffFactor {
+                time_direction,
+                hour,
+                minute,
+            }) => {
+                let (hour, minute) = (u8::from(hour) as i8, u8::from(minute) as i8);
+                let (hour, minute) = match time_direction {
+                    LocalTimeDirection::Plus => (hour, minute),
+                    LocalTimeDirection::Minus => (-hour, -minute),
+                };
+
+                UtcOffset::from_hms(hour, minute, 0).map_err(|_| Error::from("invalid local time difference"))?
+            }
+            None => UtcOffset::UTC,
+        };
+
+        Ok(date_time.assume_offset(offset))
+    }
+}
+
+impl TryFrom<OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    /// Keeps the date time offset as the local time difference. Seconds are rounded down to milliseconds.
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        let year = u16::try_from(date_time.year()).map_err(|_| Error::from("GeneralizedTime year must be positive"))?;
+        if year > 9999 {
+            return Err("GeneralizedTime year must have at most four digits".into());
+        }
+
+        let milliseconds = date_time.nanosecond() / NANOSECONDS_IN_MILLISECOND;
+        let second = f32::from(date_time.second()) + milliseconds as f32 / 1000.0;
+
+        let offset = date_time.offset();
+        let local_time = if offset.is_utc() {
+            None
+        } else {
+            if offset.seconds_past_minute() != 0 {
+                return Err("GeneralizedTime local time difference can not contain seconds".into());
+            }
+
+            Some(LocalTimeDiffFactor {
+                time_direction: if offset.is_negative() {
+                    LocalTimeDirection::Minus
+                } else {
+                    LocalTimeDirection::Plus
+                },
+                hour: Hour::try_from(offset.whole_hours().unsigned_abs())?,
+                minute: Minute::try_from(offset.minutes_past_hour().unsigned_abs())?,
+            })
+        };
+
+        Ok(GeneralizedTime {
+            year: GtYear::new(year),
+            month: Month::try_from(u8::from(date_time.month()))?,
+            day: Day::try_from(date_time.day())?,
+            hour: Hour::try_from(date_time.hour())?,
+            minute: Minute::try_from(date_time.minute())?,
+            second: GtSecond::try_from(second)?,
+            local_time,
+        })
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "time")]
+mod conversions;
 mod generalized_time;
 mod utc_time;
 
@@ -1,6 +1,6 @@
 use alloc::fmt::Debug;
 
-use crate::length::read_len;
+use crate::length::{INDEFINITE_LEN, indefinite_len, read_len};
 use crate::reader::{Reader, read_data};
 use crate::writer::Writer;
 use crate::{
@@ -70,9 +70,21 @@ impl<'data, A: Asn1ValueDecoder<'data> + Debug> Asn1Decoder<'data> for Tlv<A> {
 
         let tag = Tag(reader.read_byte()?);
 
-        let (len, len_range) = read_len(reader)?;
+        let indefinite = tag.is_constructed() && reader.peek_byte()? == INDEFINITE_LEN;
+        let (len, len_range) = if indefinite {
+            let before = reader.position();
+            reader.read_byte()?;
+
+            (indefinite_len(reader.remaining())?, before..reader.position())
+        } else {
+            read_len(reader)?
+        };
 
         let (data, data_range) = read_data(reader, len)?;
+        if indefinite {
+            // End-of-contents octets.
+            reader.read(2)?;
+        }
 
         trace!(?tag, ?len, ?data);
 
@@ -83,7 +95,7 @@ impl<'data, A: Asn1ValueDecoder<'data> + Debug> Asn1Decoder<'data> for Tlv<A> {
 
         reader.set_next_id(inner_reader.next_id());
 
-        let raw_data = reader.data_in_range(data_start..data_range.end)?.to_vec();
+        let raw_data = reader.data_in_range(data_start..reader.position())?.to_vec();
         let length = (len_range.start - data_start)..(len_range.end - data_start);
         let data = (data_range.start - data_start)..(data_range.end - data_start);
 
@@ -0,0 +1,384 @@
+//! Typed asn1 encoding and decoding on top of the [Asn1] trees.
+//!
+//! The [Asn1Decode] and [Asn1Encode] traits convert Rust types from/into the [Asn1] trees.
+//! Usually, they are implemented using the `#[derive(Asn1Decode, Asn1Encode)]` macros (the `derive` feature).
+//! Helpers from this module ([Tagging], [FieldsDecoder], etc) are used by the generated code.
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    Asn1, Asn1Decoder, Asn1Encoder, Asn1Entity, Asn1Result, Asn1Type, BitString, BmpString, Bool, Enumerated, Error,
+    GeneralString, GeneralizedTime, IA5String, Integer, MetaInfo, Mutable, Null, NumericString, ObjectIdentifier,
+    OctetString, PrintableString, Sequence, Set, Tag, UtcTime, Utf8String, VisibleString,
+};
+
+/// Decodes the Rust type from the [Asn1] tree.
+pub trait Asn1Decode: Sized {
+    /// Universal tag of the type. It is `None` for the `CHOICE` and `ANY` types.
+    const ASN1_TAG: Option<Tag>;
+
+    /// Checks if the asn1 node with the provided tag can be decoded into this type.
+    fn compare_tags(tag: Tag) -> bool {
+        Self::ASN1_TAG == Some(tag)
+    }
+
+    /// Decodes the type from the asn1 node.
+    fn decode_asn1(asn1: &Asn1) -> Asn1Result<Self>;
+
+    /// Decodes the type from the DER-encoded buffer.
+    fn from_der(buff: &[u8]) -> Asn1Result<Self> {
+        Self::decode_asn1(&Asn1::decode_buff(buff)?)
+    }
+}
+
+/// Encodes the Rust type into the [Asn1] tree.
+pub trait Asn1Encode {
+    /// `true` for the `CHOICE` and `ANY` types. Their tag depends on the value, so they can not be implicitly tagged.
+    const IS_CHOICE: bool = false;
+
+    /// Encodes the type into the asn1 node.
+    fn encode_asn1(&self) -> Asn1Result<Asn1>;
+
+    /// Encodes the type into the DER-encoded buffer.
+    fn to_der(&self) -> Asn1Result<Vec<u8>> {
+        let asn1 = self.encode_asn1()?;
+
+        let mut buff = vec![0; asn1.needed_buf_size()];
+        asn1.encode_buff(&mut buff)?;
+
+        Ok(buff)
+    }
+}
+
+macro_rules! impl_typed_asn1 {
+    ($($name:ident),*) => {
+        $(
+            impl Asn1Decode for $name {
+                const ASN1_TAG: Option<Tag> = Some($name::TAG);
+
+                fn decode_asn1(asn1: &Asn1) -> Asn1Result<Self> {
+                    match asn1.inner_asn1() {
+                        Asn1Type::$name(value) => Ok(value.get().clone()),
+                        _ => Err(concat!("Expected ", stringify!($name)).into()),
+                    }
+                }
+            }
+
+            impl Asn1Encode for $name {
+                fn encode_asn1(&self) -> Asn1Result<Asn1> {
+                    Ok(Asn1::from_asn1_type(Asn1Type::$name(Mutable::new(self.clone()))))
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_asn1!(
+    Sequence,
+    Set,
+    OctetString,
+    Utf8String,
+    BitString,
+    BmpString,
+    IA5String,
+    PrintableString,
+    GeneralString,
+    NumericString,
+    VisibleString,
+    UtcTime,
+    GeneralizedTime,
+    Bool,
+    Null,
+    Integer,
+    Enumerated,
+    ObjectIdentifier
+);
+
+impl Asn1Decode for bool {
+    const ASN1_TAG: Option<Tag> = Some(Bool::TAG);
+
+    fn decode_asn1(asn1: &Asn1) -> Asn1Result<Self> {
+        Bool::decode_asn1(asn1).map(|value| value.value())
+    }
+}
+
+impl Asn1Encode for bool {
+    fn encode_asn1(&self) -> Asn1Result<Asn1> {
+        Bool::from(*self).encode_asn1()
+    }
+}
+
+macro_rules! impl_typed_number {
+    ($($number:ty),*) => {
+        $(
+            impl Asn1Decode for $number {
+                const ASN1_TAG: Option<Tag> = Some(Integer::TAG);
+
+                fn decode_asn1(asn1: &Asn1) -> Asn1Result<Self> {
+                    <$number>::try_from(&Integer::decode_asn1(asn1)?)
+                }
+            }
+
+            impl Asn1Encode for $number {
+                fn encode_asn1(&self) -> Asn1Result<Asn1> {
+                    Integer::from(*self).encode_asn1()
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_number!(i64, u64, i128);
+
+impl Asn1Decode for String {
+    const ASN1_TAG: Option<Tag> = Some(Utf8String::TAG);
+
+    fn decode_asn1(asn1: &Asn1) -> Asn1Result<Self> {
+        Utf8String::decode_asn1(asn1).map(|value| value.string().to_owned())
+    }
+}
+
+impl Asn1Encode for String {
+    fn encode_asn1(&self) -> Asn1Result<Asn1> {
+        Utf8String::from(self.as_str()).encode_asn1()
+    }
+}
+
+/// `ANY` type: any asn1 node is accepted as it is.
+impl Asn1Decode for Asn1 {
+    const ASN1_TAG: Option<Tag> = None;
+
+    fn compare_tags(_tag: Tag) -> bool {
+        true
+    }
+
+    fn decode_asn1(asn1: &Asn1) -> Asn1Result<Self> {
+        Ok(asn1.clone())
+    }
+}
+
+impl Asn1Encode for Asn1 {
+    const IS_CHOICE: bool = true;
+
+    fn encode_asn1(&self) -> Asn1Result<Asn1> {
+        Ok(self.clone())
+    }
+}
+
+/// Tagging of the field or `CHOICE` alternative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tagging {
+    /// The field is encoded using its own universal tag.
+    Universal,
+    /// `[n] EXPLICIT`: the encoded field is wrapped into the context-specific constructed tag.
+    Explicit(u8),
+    /// `[n] IMPLICIT`: the field tag is replaced with the context-specific tag.
+    Implicit(u8),
+}
+
+impl Tagging {
+    /// Checks if the node tag matches the tagged field.
+    ///
+    /// `compare_tags` is used to check the untagged (universal) tag. `universal_tag` is the tag of the untagged field:
+    /// the implicit tag keeps its primitive/constructed bit.
+    pub fn compare_tags(self, tag: Tag, universal_tag: Option<Tag>, compare_tags: impl Fn(Tag) -> bool) -> bool {
+        match self {
+            Tagging::Universal => compare_tags(tag),
+            Tagging::Explicit(number) => tag.0 == 0xa0 | number,
+            Tagging::Implicit(number) => {
+                universal_tag.is_some_and(|universal_tag| tag == implicit_tag(number, universal_tag))
+            }
+        }
+    }
+
+    /// Applies the tagging to the encoded field.
+    pub fn wrap(self, asn1: Asn1) -> Asn1Result<Asn1> {
+        match self {
+            Tagging::Universal => Ok(asn1),
+            Tagging::Explicit(number) => Ok(Asn1::from_asn1_type(Asn1Type::ExplicitTag(Mutable::new(
+                crate::ExplicitTag::new(number, vec![asn1]),
+            )))),
+            Tagging::Implicit(number) => {
+                let tag = implicit_tag(number, asn1.tag());
+                retag(&asn1, tag)
+            }
+        }
+    }
+
+    /// Removes the tagging from the node.
+    ///
+    /// `universal_tag` is the tag of the untagged field. It is needed to restore the implicitly tagged values.
+    pub fn unwrap(self, asn1: &Asn1, universal_tag: Option<Tag>) -> Asn1Result<Asn1> {
+        match self {
+            Tagging::Universal => Ok(asn1.clone()),
+            Tagging::Explicit(number) => {
+                let Asn1Type::ExplicitTag(tag) = asn1.inner_asn1() else {
+                    return Err("Expected explicit tag".into());
+                };
+                let tag = tag.get();
+
+                if tag.tag_number() != number {
+                    return Err("Invalid explicit tag number".into());
+                }
+
+                match tag.inner() {
+                    [inner] => Ok(inner.clone()),
+                    _ => Err("Explicit tag must contain exactly one inner node".into()),
+                }
+            }
+            Tagging::Implicit(number) => {
+                let universal_tag =
+                    universal_tag.ok_or(Error::from("CHOICE and ANY types can not be implicitly tagged"))?;
+
+                if asn1.tag() != implicit_tag(number, universal_tag) {
+                    return Err("Invalid implicit tag".into());
+                }
+
+                retag(asn1, universal_tag)
+            }
+        }
+    }
+}
+
+/// Context-specific tag that replaces the universal one. The primitive/constructed bit is taken from the universal tag.
+fn implicit_tag(number: u8, universal_tag: Tag) -> Tag {
+    Tag(0x80 | (universal_tag.0 & 0x20) | number)
+}
+
+/// Re-encodes the node with another tag.
+fn retag(asn1: &Asn1, tag: Tag) -> Asn1Result<Asn1> {
+    let mut buff = vec![0; asn1.needed_buf_size()];
+    asn1.encode_buff(&mut buff)?;
+    buff[0] = tag.0;
+
+    let mut asn1 = Asn1::decode_buff(&buff)?;
+    asn1.clear_meta();
+
+    Ok(asn1)
+}
+
+/// Walks over `SEQUENCE` or `SET` fields during the typed decoding.
+///
+/// `SEQUENCE` fields are taken one by one in order. `SET` fields are looked up by tag regardless of their order.
+pub struct FieldsDecoder<'a> {
+    fields: &'a [Asn1],
+    used: Vec<bool>,
+    position: usize,
+    ordered: bool,
+}
+
+impl<'a> FieldsDecoder<'a> {
+    pub fn sequence(fields: &'a [Asn1]) -> Self {
+        Self {
+            fields,
+            used: vec![false; fields.len()],
+            position: 0,
+            ordered: true,
+        }
+    }
+
+    pub fn set(fields: &'a [Asn1]) -> Self {
+        Self {
+            ordered: false,
+            ..Self::sequence(fields)
+        }
+    }
+
+    /// Returns the next field that matches the provided tags (if any).
+    pub fn next_optional(&mut self, compare_tags: impl Fn(Tag) -> bool) -> Option<&'a Asn1> {
+        let index = if self.ordered {
+            let index = self.position;
+            compare_tags(self.fields.get(index)?.tag()).then_some(index)?
+        } else {
+            (0..self.fields.len()).find(|&i| !self.used[i] && compare_tags(self.fields[i].tag()))?
+        };
+
+        self.used[index] = true;
+        self.position = index + 1;
+
+        Some(&self.fields[index])
+    }
+
+    /// Returns the next field that must match the provided tags.
+    pub fn next_required(&mut self, compare_tags: impl Fn(Tag) -> bool) -> Asn1Result<&'a Asn1> {
+        match self.next_optional(compare_tags) {
+            Some(field) => Ok(field),
+            None if self.ordered && self.position < self.fields.len() => Err("Unexpected field tag".into()),
+            None => Err("Missing required field".into()),
+        }
+    }
+
+    /// Checks that all fields have been decoded.
+    pub fn finish(self) -> Asn1Result<()> {
+        if self.used.iter().all(|used| *used) {
+            Ok(())
+        } else {
+            Err("Unexpected extra fields".into())
+        }
+    }
+}
+
+/// Builds the `SEQUENCE` or `SET` node from the encoded fields. Absent (`None`) fields are skipped.
+///
+/// `SET` fields are sorted by their tags as DER requires.
+pub fn encode_fields(fields: impl IntoIterator<Item = Option<Asn1>>, set: bool) -> Asn1Result<Asn1> {
+    let mut fields = fields.into_iter().flatten().collect::<Vec<_>>();
+
+    if set {
+        fields.sort_by_key(|field| field.tag().0);
+        Ok(Asn1::from_asn1_type(Asn1Type::Set(Mutable::new(Set::new(fields)))))
+    } else {
+        Ok(Asn1::from_asn1_type(Asn1Type::Sequence(Mutable::new(Sequence::new(
+            fields,
+        )))))
+    }
+}
+
+/// Decodes `SEQUENCE OF T`.
+pub fn decode_sequence_of<T: Asn1Decode>(asn1: &Asn1) -> Asn1Result<Vec<T>> {
+    let Asn1Type::Sequence(sequence) = asn1.inner_asn1() else {
+        return Err("Expected SEQUENCE OF".into());
+    };
+
+    sequence.get().fields().iter().map(T::decode_asn1).collect()
+}
+
+/// Decodes `SET OF T`.
+pub fn decode_set_of<T: Asn1Decode>(asn1: &Asn1) -> Asn1Result<Vec<T>> {
+    let Asn1Type::Set(set) = asn1.inner_asn1() else {
+        return Err("Expected SET OF".into());
+    };
+
+    set.get().fields().iter().map(T::decode_asn1).collect()
+}
+
+/// Encodes `SEQUENCE OF T`.
+pub fn encode_sequence_of<T: Asn1Encode>(values: &[T]) -> Asn1Result<Asn1> {
+    let fields = values.iter().map(T::encode_asn1).collect::<Asn1Result<Vec<_>>>()?;
+
+    encode_fields(fields.into_iter().map(Some), false)
+}
+
+/// Encodes `SET OF T`.
+pub fn encode_set_of<T: Asn1Encode>(values: &[T]) -> Asn1Result<Asn1> {
+    let mut fields = values
+        .iter()
+        .map(|value| value.to_der())
+        .collect::<Asn1Result<Vec<_>>>()?;
+    // DER: SET OF elements are sorted by their encodings.
+    fields.sort();
+
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let mut asn1 = Asn1::decode_buff(field)?;
+            asn1.clear_meta();
+            Ok(asn1)
+        })
+        .collect::<Asn1Result<_>>()?;
+
+    Ok(Asn1::from_asn1_type(Asn1Type::Set(Mutable::new(Set::new(fields)))))
+}
@@ -0,0 +1,47 @@
+use asn1_parser::builder::{encode, int, seq, utf8};
+use asn1_parser::{Asn1Entity, Asn1Type, Integer, asn1};
+
+#[test]
+fn build_tree() {
+    let tree = asn1! {
+        seq {
+            int(5),
+            explicit(0, utf8("x")),
+            oid("1.2.3"),
+            set {},
+            application(1, octets(vec![1, 2]), null(),),
+            bool(true),
+            int(Integer::from(-129_i64)),
+        }
+    }
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            0x30, 0x1d, 0x02, 0x01, 0x05, 0xa0, 0x03, 0x0c, 0x01, 0x78, 0x06, 0x02, 0x2a, 0x03, 0x31, 0x00, 0x61, 0x06,
+            0x04, 0x02, 0x01, 0x02, 0x05, 0x00, 0x01, 0x01, 0xff, 0x02, 0x02, 0xff, 0x7f,
+        ],
+        encode(&tree).unwrap()
+    );
+}
+
+#[test]
+fn valid_metadata() {
+    let tree = asn1! { seq { utf8("TheBestTvarynka"), tree(int(1_u8)) } }.unwrap();
+
+    assert_eq!(encode(&tree).unwrap(), tree.meta().raw_bytes());
+
+    let Asn1Type::Sequence(sequence) = tree.inner_asn1() else {
+        panic!("expected Sequence");
+    };
+    let fields = sequence.get().fields().to_vec();
+    assert_eq!(2, fields.len());
+    assert_eq!(&[1], fields[1].meta().data_bytes());
+    assert_ne!(fields[0].id(), fields[1].id());
+    assert_ne!(tree.id(), fields[0].id());
+
+    assert_eq!(
+        encode(&seq([utf8("TheBestTvarynka"), int(1_u8)])).unwrap(),
+        encode(&tree).unwrap()
+    );
+}
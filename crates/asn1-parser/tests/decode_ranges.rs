@@ -0,0 +1,23 @@
+use asn1_parser::{Asn1Entity, decode_buff_ranges};
+
+#[test]
+fn offsets_and_ids_across_ranges() {
+    // Two Kerberos-over-TCP-like frames: 4-byte big-endian length followed by the asn1 message.
+    let data = [
+        0x00, 0x00, 0x00, 0x05, 0x30, 0x03, 0x02, 0x01, 0x07, // frame 1: SEQUENCE { INTEGER 7 }
+        0x00, 0x00, 0x00, 0x02, 0x05, 0x00, // frame 2: NULL
+    ];
+
+    let frames = decode_buff_ranges(&data, &[4..9, 13..15]).unwrap();
+
+    assert_eq!(2, frames.len());
+    assert_eq!(4, frames[0][0].meta().tag_position());
+    assert_eq!(13, frames[1][0].meta().tag_position());
+    assert_eq!(&data[4..9], frames[0][0].meta().raw_bytes());
+
+    // SEQUENCE and INTEGER take the first ids.
+    assert!(frames[1][0].id() > frames[0][0].id());
+    assert_ne!(0, frames[0][0].id());
+
+    assert!(decode_buff_ranges(&data, &[4..9, 13..20]).is_err());
+}
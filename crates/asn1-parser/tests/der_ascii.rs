@@ -0,0 +1,129 @@
+use asn1_parser::decode_buff_vec;
+use asn1_parser::der_ascii::{DerAscii, compile, decompile};
+
+#[test]
+fn compile_simple() {
+    let bytes = compile(r#"SEQUENCE { INTEGER { 5 } [0] { UTF8String { "x" } } }"#).unwrap();
+
+    assert_eq!(bytes, [48, 8, 2, 1, 5, 160, 3, 12, 1, 120]);
+}
+
+#[test]
+fn compile_literals() {
+    let source = r#"
+        # All supported literals.
+        SEQUENCE {
+            INTEGER { -129 }
+            INTEGER { 128 }
+            OBJECT_IDENTIFIER { 1.2.840.113549 }
+            BOOLEAN { TRUE }
+            OCTET_STRING { `01 02ff` }
+            IA5String { "a\"b\x00" }
+            [APPLICATION 1 PRIMITIVE] { `aa` }
+            [31] {}
+        }
+    "#;
+
+    let bytes = compile(source).unwrap();
+
+    assert_eq!(
+        bytes,
+        [
+            48, 36, 2, 2, 255, 127, 2, 2, 0, 128, 6, 6, 42, 134, 72, 134, 247, 13, 1, 1, 255, 4, 3, 1, 2, 255, 22, 4,
+            97, 34, 98, 0, 65, 1, 170, 191, 31, 0
+        ]
+    );
+}
+
+#[test]
+fn compile_length_overrides() {
+    assert_eq!(compile("INTEGER length:5 { 1 }").unwrap(), [2, 5, 1]);
+    assert_eq!(compile("INTEGER long-form:2 { 1 }").unwrap(), [2, 130, 0, 1, 1]);
+    assert_eq!(
+        compile("SEQUENCE indefinite { NULL {} }").unwrap(),
+        [48, 128, 5, 0, 0, 0]
+    );
+    assert!(compile("INTEGER long-form:0 { 1 }").is_err());
+}
+
+#[test]
+fn syntax_error_position() {
+    let error = DerAscii::parse("SEQUENCE {\n  INTEGR { 1 }\n}").unwrap_err();
+
+    assert_eq!(error.line(), 2);
+    assert_eq!(error.to_string(), "2:9: Unknown keyword");
+
+    let error = DerAscii::parse("SEQUENCE { INTEGER { 1 }").unwrap_err();
+
+    assert_eq!(error.message(), "Unclosed `{`");
+}
+
+#[test]
+fn decompile_round_trip() {
+    let source = "SEQUENCE {
+  INTEGER { -129 }
+  [0] {
+    UTF8String { \"x\\\"y\" }
+  }
+  OBJECT_IDENTIFIER { 1.2.840.113549 }
+  BOOLEAN { TRUE }
+  OCTET_STRING {
+    SEQUENCE {
+      NULL {}
+    }
+  }
+  BIT_STRING { `0480` }
+  UTCTime { \"230101120000Z\" }
+  [1 PRIMITIVE] { `0102` }
+  SET {}
+}
+";
+
+    let bytes = compile(source).unwrap();
+    let trees = decode_buff_vec(&bytes).unwrap();
+
+    assert_eq!(decompile(&trees), source);
+}
+
+#[test]
+fn decompile_malformed_oid() {
+    // The last byte has the continuation bit set.
+    let bytes = [6, 3, 0x55, 0x1d, 0xc1];
+    let trees = decode_buff_vec(&bytes).unwrap();
+
+    let source = decompile(&trees);
+
+    assert_eq!(source, "OBJECT_IDENTIFIER { `551dc1` }\n");
+    assert_eq!(compile(&source).unwrap(), bytes);
+}
+
+#[test]
+fn decompile_exact_bytes() {
+    let cases: [&[u8]; 7] = [
+        // Non-minimal integers.
+        &[0x02, 0x02, 0x00, 0x05],
+        &[0x0a, 0x02, 0xff, 0x80],
+        // Long-form lengths.
+        &[0x30, 0x82, 0x00, 0x03, 0x02, 0x01, 0x05],
+        &[0x30, 0x04, 0x02, 0x81, 0x01, 0x05],
+        // Indefinite lengths.
+        &[0x30, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00],
+        &[0x30, 0x80, 0xa0, 0x80, 0x02, 0x01, 0x05, 0x00, 0x00, 0x00, 0x00],
+        &[0x31, 0x80, 0x00, 0x00],
+    ];
+
+    for bytes in cases {
+        let trees = decode_buff_vec(bytes).unwrap();
+
+        assert_eq!(compile(&decompile(&trees)).unwrap(), bytes, "{bytes:02x?}");
+    }
+}
+
+#[test]
+fn decompile_length_modifiers() {
+    let trees = decode_buff_vec(&[0x30, 0x82, 0x00, 0x03, 0x02, 0x01, 0x05]).unwrap();
+    assert_eq!(decompile(&trees), "SEQUENCE long-form:2 {\n  INTEGER { 5 }\n}\n");
+
+    let trees = decode_buff_vec(&[0x30, 0x80, 0x02, 0x02, 0x00, 0x05, 0x00, 0x00]).unwrap();
+    assert_eq!(decompile(&trees), "SEQUENCE indefinite {\n  INTEGER { `0005` }\n}\n");
+}
@@ -0,0 +1,80 @@
+use asn1_parser::diff::{ChangeKind, diff_trees};
+use asn1_parser::{asn1, builder};
+
+#[test]
+fn changed_added_and_removed_nodes() {
+    let original = asn1! {
+        seq {
+            int(5),
+            explicit(1, utf8("crypto")),
+            bool(true),
+        }
+    }
+    .unwrap();
+    let changed = asn1! {
+        seq {
+            int(5),
+            null(),
+            explicit(1, utf8("helper")),
+        }
+    }
+    .unwrap();
+
+    let changes = diff_trees(&[original], &[changed]);
+    let summary = changes
+        .iter()
+        .map(|change| (change.kind, change.path.as_str()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            (ChangeKind::Added, "0:SEQUENCE/1:NULL"),
+            (ChangeKind::Changed, "0:SEQUENCE/1:[1]/0:UTF8String"),
+            (ChangeKind::Removed, "0:SEQUENCE/2:BOOLEAN"),
+        ],
+        summary
+    );
+    assert_eq!(Some("crypto"), changes[1].original_value.as_deref());
+    assert_eq!(Some("helper"), changes[1].changed_value.as_deref());
+}
+
+#[test]
+fn moved_set_elements() {
+    let original = asn1! { set { int(1), int(2), int(3) } }.unwrap();
+    let changed = asn1! { set { int(3), int(1), int(2) } }.unwrap();
+
+    let changes = diff_trees(&[original], &[changed]);
+
+    assert_eq!(1, changes.len());
+    assert_eq!(ChangeKind::Moved, changes[0].kind);
+    assert_eq!("0:SET/2:INTEGER", changes[0].path);
+}
+
+#[test]
+fn equal_trees() {
+    let tree = asn1! { seq { int(1), octets(vec![0x05, 0x00]) } }.unwrap();
+
+    assert!(diff_trees(std::slice::from_ref(&tree), std::slice::from_ref(&tree)).is_empty());
+}
+
+#[test]
+fn long_sequences() {
+    let original = (0..300_i64).map(builder::int).collect::<Vec<_>>();
+    let mut changed = original.clone();
+    changed.remove(250);
+    changed.insert(100, builder::null());
+
+    let changes = diff_trees(&[builder::seq(original)], &[builder::seq(changed)]);
+    let summary = changes
+        .iter()
+        .map(|change| (change.kind, change.path.as_str()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            (ChangeKind::Added, "0:SEQUENCE/100:NULL"),
+            (ChangeKind::Removed, "0:SEQUENCE/250:INTEGER"),
+        ],
+        summary
+    );
+}
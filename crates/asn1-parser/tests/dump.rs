@@ -0,0 +1,54 @@
+use asn1_parser::decode_buff_vec;
+use asn1_parser::dump::{DumpAsn1, OpensslDump};
+
+const DATA: &[u8] = &[
+    48, 46, 2, 1, 5, 160, 8, 12, 6, 99, 114, 121, 112, 116, 111, 2, 2, 255, 127, 6, 3, 85, 29, 19, 1, 1, 255, 4, 4, 48,
+    2, 5, 0, 23, 13, 50, 51, 48, 49, 48, 49, 49, 50, 48, 48, 48, 48, 90,
+];
+
+#[test]
+fn openssl_asn1parse() {
+    let trees = decode_buff_vec(DATA).unwrap();
+
+    assert_eq!(
+        OpensslDump::new(&trees).to_string(),
+        [
+            "    0:d=0  hl=2 l=  46 cons: SEQUENCE          ",
+            "    2:d=1  hl=2 l=   1 prim:  INTEGER           :05",
+            "    5:d=1  hl=2 l=   8 cons:  cont [ 0 ]        ",
+            "    7:d=2  hl=2 l=   6 prim:   UTF8STRING        :crypto",
+            "   15:d=1  hl=2 l=   2 prim:  INTEGER           :-81",
+            "   19:d=1  hl=2 l=   3 prim:  OBJECT            :2.5.29.19",
+            "   24:d=1  hl=2 l=   1 prim:  BOOLEAN           :255",
+            "   27:d=1  hl=2 l=   4 prim:  OCTET STRING      [HEX DUMP]:30020500",
+            "   33:d=1  hl=2 l=  13 prim:  UTCTIME           :230101120000Z",
+            "",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn dumpasn1() {
+    let trees = decode_buff_vec(DATA).unwrap();
+
+    assert_eq!(
+        DumpAsn1::new(&trees).to_string(),
+        "   0   46: SEQUENCE {
+   2    1:   INTEGER 5
+   5    8:   [0] {
+   7    6:     UTF8String 'crypto'
+         :     }
+  15    2:   INTEGER -129
+  19    3:   OBJECT IDENTIFIER '2 5 29 19'
+  24    1:   BOOLEAN TRUE
+  27    4:   OCTET STRING, encapsulates {
+  29    2:     SEQUENCE {
+  31    0:       NULL
+         :       }
+         :     }
+  33   13:   UTCTime 01/01/2023 12:00:00 GMT
+         :   }
+"
+    );
+}
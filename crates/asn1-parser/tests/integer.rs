@@ -0,0 +1,61 @@
+use asn1_parser::{Asn1Decode, Asn1Encode, Integer};
+
+#[test]
+fn signed_value() {
+    assert_eq!("-128", Integer::from(vec![0x80]).as_big_int().to_string());
+    assert_eq!("128", Integer::from(vec![0x00, 0x80]).as_big_int().to_string());
+    assert_eq!("128", Integer::from(vec![0x80]).as_big_uint().to_string());
+    assert_eq!("-1", Integer::from(vec![0xff, 0xff]).as_big_int().to_string());
+    assert_eq!("0", Integer::from(Vec::new()).as_big_int().to_string());
+}
+
+#[test]
+fn minimal_encoding_from_numbers() {
+    assert_eq!(&[0x00], Integer::from(0_i64).raw_data());
+    assert_eq!(&[0x7f], Integer::from(127_u8).raw_data());
+    assert_eq!(&[0x00, 0x80], Integer::from(128_u64).raw_data());
+    assert_eq!(&[0x80], Integer::from(-128_i32).raw_data());
+    assert_eq!(&[0xff, 0x7f], Integer::from(-129_i64).raw_data());
+    assert_eq!(&[0xff], Integer::from(-1_i128).raw_data());
+    assert_eq!(
+        &[0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        Integer::from(u64::MAX).raw_data()
+    );
+
+    let value = "-123456789012345678901234567890".parse().unwrap();
+    let integer = Integer::from_big_int(&value);
+    assert_eq!(value, integer.as_big_int());
+}
+
+#[test]
+fn checked_conversions() {
+    for value in [0, 1, -1, 127, 128, -128, -129, i64::MIN, i64::MAX] {
+        let integer = Integer::from(value);
+
+        assert_eq!(value, i64::try_from(&integer).unwrap());
+        assert_eq!(i128::from(value), i128::try_from(&integer).unwrap());
+    }
+
+    assert_eq!(u64::MAX, u64::try_from(&Integer::from(u64::MAX)).unwrap());
+    assert!(i64::try_from(&Integer::from(u64::MAX)).is_err());
+    assert!(u64::try_from(&Integer::from(-1_i8)).is_err());
+    assert_eq!(i128::MIN, i128::try_from(&Integer::from(i128::MIN)).unwrap());
+
+    // Redundant leading sign bytes.
+    assert_eq!(
+        -2,
+        i64::try_from(&Integer::from(
+            vec![0xff; 20].into_iter().chain([0xfe]).collect::<Vec<_>>()
+        ))
+        .unwrap()
+    );
+    assert!(i128::try_from(&Integer::from(vec![0x01; 17])).is_err());
+}
+
+#[test]
+fn typed_numbers() {
+    let asn1 = (-300_i64).encode_asn1().unwrap();
+
+    assert_eq!(-300, i64::decode_asn1(&asn1).unwrap());
+    assert!(u64::decode_asn1(&asn1).is_err());
+}
@@ -0,0 +1,60 @@
+use asn1_parser::oid_registry::{CustomOid, OidRegistry, builtin_oids, oid_by_name, oid_info};
+
+#[test]
+fn builtin_oids_are_sorted_and_valid() {
+    let oids = builtin_oids();
+
+    assert!(oids.len() > 2000);
+    assert!(oids.windows(2).all(|pair| pair[0].oid() < pair[1].oid()));
+
+    for info in oids {
+        assert!(oid::ObjectIdentifier::try_from(info.oid()).is_ok(), "{}", info.oid());
+        assert!(!info.name().is_empty());
+        assert!(!info.description().is_empty());
+    }
+}
+
+#[test]
+fn lookup() {
+    let info = oid_info("1.2.840.113549.1.1.11").unwrap();
+    assert_eq!("sha256WithRSAEncryption", info.name());
+
+    assert_eq!("2.5.4.3", oid_by_name("COMMONNAME").unwrap().oid());
+
+    // Entries generated from the Windows SDK.
+    assert_eq!(
+        "szOID_CMC_ADD_ATTRIBUTES",
+        oid_info("1.3.6.1.4.1.311.10.10.1").unwrap().name()
+    );
+    assert_eq!(
+        "1.3.6.1.4.1.311.10.10.1",
+        oid_by_name("szOID_CMC_ADD_ATTRIBUTES").unwrap().oid()
+    );
+
+    assert!(oid_info("1.2.3.4.5.6.7.8.9").is_none());
+}
+
+#[test]
+fn custom_oids() {
+    let mut registry = OidRegistry::default();
+
+    registry.register(CustomOid {
+        oid: "1.2.3.4".to_owned(),
+        name: "myOid".to_owned(),
+        description: "My oid".to_owned(),
+    });
+    registry.register(CustomOid {
+        oid: "2.5.4.3".to_owned(),
+        name: "cn".to_owned(),
+        description: "Overridden common name".to_owned(),
+    });
+
+    assert_eq!("myOid", registry.lookup("1.2.3.4").unwrap().name());
+    assert_eq!("1.2.3.4", registry.lookup_by_name("myoid").unwrap().oid());
+    assert_eq!("cn", registry.lookup("2.5.4.3").unwrap().name());
+    assert_eq!("2.5.4.6", registry.lookup_by_name("countryName").unwrap().oid());
+
+    assert!(registry.unregister("2.5.4.3"));
+    assert!(!registry.unregister("2.5.4.3"));
+    assert_eq!("commonName", registry.lookup("2.5.4.3").unwrap().name());
+}
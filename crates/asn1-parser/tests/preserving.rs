@@ -0,0 +1,74 @@
+use asn1_parser::preserving::{changed_lengths, encode_preserving};
+use asn1_parser::{Asn1, Asn1Entity, Asn1Type, decode_buff_vec};
+
+// SEQUENCE (long-form length) { INTEGER (long-form length) 5, UTF8String "ab", OCTET STRING 00 }
+const NON_CANONICAL: &[u8] = &[
+    0x30, 0x81, 0x0b, 0x02, 0x81, 0x01, 0x05, 0x0c, 0x02, 0x61, 0x62, 0x04, 0x01, 0x00,
+];
+
+fn set_utf8(tree: &Asn1, value: &str) {
+    let Asn1Type::Sequence(sequence) = tree.inner_asn1() else {
+        panic!("expected Sequence");
+    };
+    let Asn1Type::Utf8String(utf8) = sequence.get().fields()[1].inner_asn1().clone() else {
+        panic!("expected Utf8String");
+    };
+
+    utf8.get_mut().set_string(value.to_owned());
+}
+
+#[test]
+fn unmodified_trees() {
+    let trees = decode_buff_vec(NON_CANONICAL).unwrap();
+
+    assert_eq!(NON_CANONICAL, encode_preserving(&trees).unwrap());
+}
+
+#[test]
+fn same_length_edit() {
+    let trees = decode_buff_vec(NON_CANONICAL).unwrap();
+    set_utf8(&trees[0], "xy");
+
+    assert_eq!(
+        &[
+            0x30, 0x81, 0x0b, 0x02, 0x81, 0x01, 0x05, 0x0c, 0x02, 0x78, 0x79, 0x04, 0x01, 0x00
+        ],
+        encode_preserving(&trees).unwrap().as_slice()
+    );
+}
+
+#[test]
+fn length_changing_edit() {
+    let trees = decode_buff_vec(NON_CANONICAL).unwrap();
+    set_utf8(&trees[0], "abc");
+
+    let encoded = encode_preserving(&trees).unwrap();
+    assert_eq!(
+        &[
+            0x30, 0x0c, 0x02, 0x81, 0x01, 0x05, 0x0c, 0x03, 0x61, 0x62, 0x63, 0x04, 0x01, 0x00
+        ],
+        encoded.as_slice()
+    );
+
+    let new_trees = decode_buff_vec(&encoded).unwrap();
+    assert_eq!(vec![new_trees[0].id()], changed_lengths(&trees, &new_trees));
+}
+
+#[test]
+fn indefinite_length_edit() {
+    // SEQUENCE (indefinite length) { INTEGER 5, UTF8String "ab", OCTET STRING 00 }
+    let data = [
+        0x30, 0x80, 0x02, 0x01, 0x05, 0x0c, 0x02, 0x61, 0x62, 0x04, 0x01, 0x00, 0x00, 0x00,
+    ];
+    let trees = decode_buff_vec(&data).unwrap();
+    assert_eq!(data, encode_preserving(&trees).unwrap().as_slice());
+
+    set_utf8(&trees[0], "xy");
+
+    assert_eq!(
+        &[
+            0x30, 0x80, 0x02, 0x01, 0x05, 0x0c, 0x02, 0x78, 0x79, 0x04, 0x01, 0x00, 0x00, 0x00
+        ],
+        encode_preserving(&trees).unwrap().as_slice()
+    );
+}
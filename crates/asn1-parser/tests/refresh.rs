@@ -0,0 +1,92 @@
+use asn1_parser::builder::int;
+use asn1_parser::preserving::encode_preserving;
+use asn1_parser::refresh::refresh_meta;
+use asn1_parser::{Asn1, Asn1Entity, Asn1Type, decode_buff_vec};
+
+// SEQUENCE (long-form length) { INTEGER 5, OCTET STRING { UTF8String "ab" }, NULL }
+const DATA: &[u8] = &[
+    0x30, 0x81, 0x0b, 0x02, 0x01, 0x05, 0x04, 0x04, 0x0c, 0x02, 0x61, 0x62, 0x05, 0x00,
+];
+
+fn ids(trees: &[Asn1], out: &mut Vec<u64>) {
+    for tree in trees {
+        out.push(tree.id());
+
+        match tree.inner_asn1() {
+            Asn1Type::Sequence(sequence) => ids(sequence.get().fields(), out),
+            Asn1Type::OctetString(octet_string) => {
+                if let Some(inner) = octet_string.get().inner() {
+                    ids(&inner.get(), out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn all_ids(trees: &[Asn1]) -> Vec<u64> {
+    let mut out = Vec::new();
+    ids(trees, &mut out);
+    out
+}
+
+fn fields(tree: &Asn1) -> Vec<Asn1> {
+    let Asn1Type::Sequence(sequence) = tree.inner_asn1() else {
+        panic!("expected Sequence");
+    };
+
+    sequence.get().fields().to_vec()
+}
+
+#[test]
+fn refresh_keeps_ids() {
+    let trees = decode_buff_vec(DATA).unwrap();
+    let Asn1Type::Integer(integer) = fields(&trees[0])[0].inner_asn1().clone() else {
+        panic!("expected Integer");
+    };
+    integer.get_mut().set(vec![0x01, 0x00]);
+
+    let encoded = encode_preserving(&trees).unwrap();
+    let refreshed = refresh_meta(&trees, &encoded).unwrap();
+
+    assert_eq!(all_ids(&trees), all_ids(&refreshed.trees));
+    assert_eq!(vec![trees[0].id()], refreshed.changed_lengths);
+    assert!(!integer.is_modified());
+
+    // Metadata is the same as after the full re-parse.
+    let reparsed = decode_buff_vec(&encoded).unwrap();
+    assert_eq!(reparsed[0].meta(), refreshed.trees[0].meta());
+    for (reparsed, refreshed) in fields(&reparsed[0]).iter().zip(fields(&refreshed.trees[0]).iter()) {
+        assert_eq!(reparsed.meta(), refreshed.meta());
+    }
+}
+
+#[test]
+fn new_nodes_get_unique_ids() {
+    let trees = decode_buff_vec(DATA).unwrap();
+    let Asn1Type::Sequence(sequence) = trees[0].inner_asn1().clone() else {
+        panic!("expected Sequence");
+    };
+    sequence.get_mut().fields_mut_vec().push(int(7));
+
+    let encoded = encode_preserving(&trees).unwrap();
+    let refreshed = refresh_meta(&trees, &encoded).unwrap();
+
+    let old_ids = all_ids(&trees);
+    let mut new_ids = all_ids(&refreshed.trees);
+    assert_eq!(old_ids.len(), new_ids.len());
+    assert!(!old_ids.contains(&fields(&refreshed.trees[0])[3].id()));
+
+    new_ids.sort();
+    new_ids.dedup();
+    assert_eq!(old_ids.len(), new_ids.len());
+    assert!(refreshed.changed_lengths.is_empty());
+}
+
+#[test]
+fn mismatched_data() {
+    let trees = decode_buff_vec(DATA).unwrap();
+
+    assert!(refresh_meta(&trees, &DATA[..DATA.len() - 2]).is_err());
+    assert!(refresh_meta(&trees, &[0x02, 0x01, 0x00]).is_err());
+}
@@ -0,0 +1,50 @@
+use asn1_parser::asn1;
+use asn1_parser::stats::tree_stats;
+
+#[test]
+fn sizes_depth_and_type_counts() {
+    let tree = asn1! {
+        seq {
+            int(5),
+            octets(vec![0x30, 0x03, 0x02, 0x01, 0x07]),
+            explicit(0, utf8("crypto")),
+        }
+    }
+    .unwrap();
+
+    let stats = tree_stats(&[tree]);
+
+    // SEQUENCE { INTEGER, OCTET STRING { SEQUENCE { INTEGER } }, [0] { UTF8String } }
+    assert_eq!(22, stats.total_bytes);
+    assert_eq!(3, stats.max_depth);
+    assert_eq!(
+        vec![
+            ("INTEGER", 2),
+            ("SEQUENCE", 2),
+            ("EXPLICIT", 1),
+            ("OCTET STRING", 1),
+            ("UTF8String", 1)
+        ],
+        stats.type_counts
+    );
+
+    let nodes = stats
+        .nodes
+        .iter()
+        .map(|node| (node.type_name, node.depth, node.bytes, node.descendants))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("SEQUENCE", 0, 22, 6),
+            ("INTEGER", 1, 3, 0),
+            ("OCTET STRING", 1, 7, 2),
+            ("SEQUENCE", 2, 5, 1),
+            ("INTEGER", 3, 3, 0),
+            ("EXPLICIT", 1, 10, 1),
+            ("UTF8String", 2, 8, 0),
+        ],
+        nodes
+    );
+    assert_eq!(100.0, stats.nodes[0].percentage(stats.total_bytes));
+    assert_eq!(50.0, stats.nodes[1].percentage(6));
+}
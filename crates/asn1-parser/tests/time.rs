@@ -0,0 +1,94 @@
+use asn1_parser::{
+    Day, GeneralizedTime, GtSecond, GtYear, Hour, LocalTimeDiffFactor, LocalTimeDirection, Minute, Month, Second,
+    UtcTime, Year,
+};
+use time::{Date, Month as CalendarMonth, OffsetDateTime, Time, UtcOffset};
+
+fn date_time(year: i32, month: u8, day: u8, time: (u8, u8, u8, u16), offset: (i8, i8)) -> OffsetDateTime {
+    let (hour, minute, second, millisecond) = time;
+
+    Date::from_calendar_date(year, CalendarMonth::try_from(month).unwrap(), day)
+        .unwrap()
+        .with_time(Time::from_hms_milli(hour, minute, second, millisecond).unwrap())
+        .assume_offset(UtcOffset::from_hms(offset.0, offset.1, 0).unwrap())
+}
+
+fn utc_time(year: u8) -> UtcTime {
+    UtcTime::new(
+        Year::try_from(year).unwrap(),
+        Month::try_from(2).unwrap(),
+        Day::try_from(28).unwrap(),
+        Hour::try_from(13).unwrap(),
+        Minute::try_from(5).unwrap(),
+        Some(Second::try_from(9).unwrap()),
+    )
+}
+
+#[test]
+fn utc_time_pivot() {
+    assert_eq!(
+        date_time(2049, 2, 28, (13, 5, 9, 0), (0, 0)),
+        OffsetDateTime::try_from(&utc_time(49)).unwrap()
+    );
+    assert_eq!(
+        date_time(1950, 2, 28, (13, 5, 9, 0), (0, 0)),
+        OffsetDateTime::try_from(&utc_time(50)).unwrap()
+    );
+
+    assert_eq!(
+        utc_time(49),
+        UtcTime::try_from(date_time(2049, 2, 28, (13, 5, 9, 0), (0, 0))).unwrap()
+    );
+    assert_eq!(
+        utc_time(50),
+        UtcTime::try_from(date_time(1950, 2, 28, (13, 5, 9, 0), (0, 0))).unwrap()
+    );
+    assert!(UtcTime::try_from(date_time(2050, 1, 1, (0, 0, 0, 0), (0, 0))).is_err());
+    assert!(UtcTime::try_from(date_time(1949, 12, 31, (23, 59, 59, 0), (0, 0))).is_err());
+
+    // UtcTime is always in UTC.
+    assert_eq!(
+        utc_time(49),
+        UtcTime::try_from(date_time(2049, 2, 28, (15, 5, 9, 0), (2, 0))).unwrap()
+    );
+}
+
+#[test]
+fn invalid_date() {
+    let mut time = utc_time(23);
+    time.day = Day::try_from(29).unwrap();
+
+    assert!(OffsetDateTime::try_from(&time).is_err());
+}
+
+#[test]
+fn generalized_time_with_offset_and_fraction() {
+    let generalized_time = GeneralizedTime::new(
+        GtYear::new(2024),
+        Month::try_from(2).unwrap(),
+        Day::try_from(29).unwrap(),
+        Hour::try_from(23).unwrap(),
+        Minute::try_from(30).unwrap(),
+        GtSecond::try_from(15.25).unwrap(),
+        Some(LocalTimeDiffFactor {
+            time_direction: LocalTimeDirection::Minus,
+            hour: Hour::try_from(5).unwrap(),
+            minute: Minute::try_from(30).unwrap(),
+        }),
+    );
+
+    let converted = OffsetDateTime::try_from(&generalized_time).unwrap();
+    assert_eq!(date_time(2024, 2, 29, (23, 30, 15, 250), (-5, -30)), converted);
+    assert_eq!(date_time(2024, 3, 1, (5, 0, 15, 250), (0, 0)), converted);
+
+    assert_eq!(generalized_time, GeneralizedTime::try_from(converted).unwrap());
+}
+
+#[test]
+fn generalized_time_in_utc() {
+    let expected = date_time(1812, 6, 24, (4, 0, 0, 0), (0, 0));
+
+    let generalized_time = GeneralizedTime::try_from(expected).unwrap();
+    assert_eq!(None, generalized_time.local_time);
+    assert_eq!(expected, OffsetDateTime::try_from(&generalized_time).unwrap());
+}
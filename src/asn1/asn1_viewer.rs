@@ -8,6 +8,9 @@ use crate::asn1::scheme::{AddNodeButton, build_asn1_schema};
 pub struct Asn1ViewerProps {
     pub structures: Mutable<Vec<Asn1>>,
     pub re_encode: Callback<()>,
+    /// Captions of the top-level trees (e.g. PEM labels).
+    #[prop_or_default]
+    pub captions: Vec<Option<String>>,
 
     pub cur_node: Option<u64>,
     pub set_cur_node: Callback<HighlightAction>,
@@ -48,14 +51,24 @@ pub fn asn1_viewer(props: &Asn1ViewerProps) -> Html {
                 re_encode.emit(());
             });
 
-            build_asn1_schema(
+            let tree = build_asn1_schema(
                 structure,
                 &props.cur_node,
                 set_cur_node,
                 props.re_encode.clone(),
                 add_node,
                 remove_node,
-            )
+            );
+
+            match props.captions.get(i) {
+                Some(Some(caption)) => html! {
+                    <>
+                        <span class="asn1-tree-caption">{caption}</span>
+                        {tree}
+                    </>
+                },
+                _ => tree,
+            }
         })
         .fold(trees, |mut trees, component| {
             trees.push(component);
@@ -0,0 +1,139 @@
+use std::rc::Rc;
+
+use asn1_parser::oid_registry::{CustomOid, OidRegistry};
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::{Callback, Html, InputEvent, Properties, TargetCast, UseStateHandle, function_component, html, use_state};
+use yew_notifications::{Notification, NotificationType, use_notification};
+
+use crate::common::Checkbox;
+
+pub const CUSTOM_OIDS_LOCAL_STORAGE_KEY: &str = "ASN1_CUSTOM_OIDS";
+
+#[derive(Serialize, Deserialize)]
+struct StoredOid {
+    oid: String,
+    name: String,
+    description: String,
+}
+
+pub fn load_custom_oids(raw: &str) -> Vec<CustomOid> {
+    match serde_json::from_str::<Vec<StoredOid>>(raw) {
+        Ok(oids) => oids
+            .into_iter()
+            .map(|StoredOid { oid, name, description }| CustomOid { oid, name, description })
+            .collect(),
+        Err(err) => {
+            error!(?err, "Can not parse custom OIDs.");
+            Vec::new()
+        }
+    }
+}
+
+pub fn save_custom_oids(oids: &[CustomOid]) -> String {
+    let oids = oids
+        .iter()
+        .map(|CustomOid { oid, name, description }| StoredOid {
+            oid: oid.clone(),
+            name: name.clone(),
+            description: description.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string(&oids).expect("custom OIDs serialization should not fail")
+}
+
+fn input_setter(state: &UseStateHandle<String>) -> Callback<InputEvent> {
+    let setter = state.setter();
+
+    Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        setter.set(input.value());
+    })
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct CustomOidsProps {
+    pub registry: Rc<OidRegistry>,
+    pub set_registry: Callback<OidRegistry>,
+}
+
+#[function_component(CustomOids)]
+pub fn custom_oids(props: &CustomOidsProps) -> Html {
+    let opened = use_state(|| false);
+    let oid = use_state(String::new);
+    let name = use_state(String::new);
+    let description = use_state(String::new);
+
+    let notifications = use_notification::<Notification>();
+    let registry = props.registry.clone();
+    let set_registry = props.set_registry.clone();
+    let new_oid = CustomOid {
+        oid: oid.trim().to_owned(),
+        name: name.trim().to_owned(),
+        description: description.trim().to_owned(),
+    };
+    let add = Callback::from(move |_| {
+        if oid::ObjectIdentifier::try_from(new_oid.oid.as_str()).is_err() || new_oid.name.is_empty() {
+            notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Invalid custom OID",
+                "Custom OID must have a valid dotted representation and a non-empty name.",
+                Notification::NOTIFICATION_LIFETIME,
+            ));
+            return;
+        }
+
+        let mut registry = registry.as_ref().clone();
+        registry.register(new_oid.clone());
+        set_registry.emit(registry);
+    });
+
+    let opened_setter = opened.setter();
+
+    html! {
+        <div class="vertical">
+            <Checkbox
+                id={"custom-oids".to_owned()}
+                name={"custom OIDs".to_owned()}
+                checked={*opened}
+                set_checked={Callback::from(move |checked| opened_setter.set(checked))}
+            />
+            {if *opened {
+                html! {
+                    <div class="vertical">
+                        <div class="horizontal">
+                            <input class="base-input" placeholder="1.2.3.4" value={(*oid).clone()} oninput={input_setter(&oid)} />
+                            <input class="base-input" placeholder="name" value={(*name).clone()} oninput={input_setter(&name)} />
+                            <input class="base-input" placeholder="description" value={(*description).clone()} oninput={input_setter(&description)} />
+                            <button class="action-button" onclick={add}>{"Add"}</button>
+                        </div>
+                        {for props.registry.custom().iter().map(|custom| {
+                            let registry = props.registry.clone();
+                            let set_registry = props.set_registry.clone();
+                            let custom_oid = custom.oid.clone();
+                            let remove = Callback::from(move |_| {
+                                let mut registry = registry.as_ref().clone();
+                                registry.unregister(&custom_oid);
+                                set_registry.emit(registry);
+                            });
+
+                            html! {
+                                <div class="horizontal">
+                                    <span class="asn-simple-value">{&custom.oid}</span>
+                                    <span>{&custom.name}</span>
+                                    <span class="total">{&custom.description}</span>
+                                    <button class="asn1-button-with-icon" onclick={remove} title="Remove">
+                                        <img src="/public/img/icons/close.png" />
+                                    </button>
+                                </div>
+                            }
+                        })}
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,94 @@
+use asn1_parser::der_ascii::{DerAscii, decompile};
+use asn1_parser::{Asn1, Asn1Encoder, Mutable};
+use web_sys::HtmlInputElement;
+use yew::{Callback, Html, InputEvent, Properties, TargetCast, function_component, html, use_state};
+use yew_notifications::{Notification, NotificationType, use_notification};
+
+use crate::common::Checkbox;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct DerAsciiEditorProps {
+    pub structures: Mutable<Vec<Asn1>>,
+    /// Called with the compiled bytes.
+    pub set_bytes: Callback<Vec<u8>>,
+}
+
+#[function_component(DerAsciiEditor)]
+pub fn der_ascii_editor(props: &DerAsciiEditorProps) -> Html {
+    let opened = use_state(|| false);
+    let structures = props.structures.clone();
+    let text = use_state(|| decompile(&structures.get()));
+
+    let text_setter = text.setter();
+    let oninput = Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        text_setter.set(input.value());
+    });
+
+    let notifications = use_notification::<Notification>();
+    let source = (*text).clone();
+    let set_bytes = props.set_bytes.clone();
+    let compile = Callback::from(move |_| {
+        let program = match DerAscii::parse(&source) {
+            Ok(program) => program,
+            Err(err) => {
+                notifications.spawn(Notification::new(
+                    NotificationType::Error,
+                    "Invalid der-ascii",
+                    err.to_string(),
+                    Notification::NOTIFICATION_LIFETIME,
+                ));
+                return;
+            }
+        };
+
+        let mut bytes = vec![0; program.needed_buf_size()];
+        match program.encode_buff(&mut bytes) {
+            Ok(()) => set_bytes.emit(bytes),
+            Err(err) => notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Can not compile der-ascii",
+                err.message(),
+                Notification::NOTIFICATION_LIFETIME,
+            )),
+        }
+    });
+
+    let text_setter = text.setter();
+    let from_tree = Callback::from(move |_| {
+        text_setter.set(decompile(&structures.get()));
+    });
+
+    let opened_setter = opened.setter();
+
+    html! {
+        <div class="vertical">
+            <Checkbox
+                id={"der-ascii-editor".to_owned()}
+                name={"der-ascii editor".to_owned()}
+                checked={*opened}
+                set_checked={Callback::from(move |checked| opened_setter.set(checked))}
+            />
+            {if *opened {
+                html! {
+                    <div class="vertical">
+                        <textarea
+                            rows="12"
+                            class="base-input der-ascii-editor"
+                            placeholder={"SEQUENCE { INTEGER { 5 } [0] { UTF8String { \"x\" } } }"}
+                            value={(*text).clone()}
+                            {oninput}
+                        />
+                        <div class="horizontal">
+                            <button class="action-button" onclick={compile}>{"Compile"}</button>
+                            <button class="action-button" onclick={from_tree}>{"Decompile tree"}</button>
+                            <span class="total">{"# comments, `hex`, length:N, long-form:N, and indefinite are supported"}</span>
+                        </div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
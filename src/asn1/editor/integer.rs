@@ -1,5 +1,5 @@
 use base64::Engine;
-use rsa::BigUint;
+use num_bigint_dig::BigInt;
 use web_sys::HtmlInputElement;
 use yew::{
     Callback, Html, MouseEvent, Properties, TargetCast, UseStateSetter, function_component, html, use_effect_with,
@@ -130,18 +130,8 @@ fn encode_bytes(integer: impl AsRef<[u8]>, format: IntegerFormat) -> String {
             .map(|byte| byte.to_string())
             .collect::<Vec<String>>()
             .join(" "),
-        IntegerFormat::DecimalInteger => {
-            let bytes = integer.as_ref();
-
-            BigUint::from_bytes_be(if bytes.len() > 1 {
-                if bytes[0] == 0x00 { &bytes[1..] } else { bytes }
-            } else if bytes.is_empty() {
-                &[0]
-            } else {
-                bytes
-            })
-            .to_string()
-        }
+        // INTEGER value is encoded as a two's-complement number.
+        IntegerFormat::DecimalInteger => BigInt::from_signed_bytes_be(integer.as_ref()).to_string(),
     }
 }
 
@@ -173,8 +163,8 @@ fn parse_integer(raw: &str, format: IntegerFormat) -> Result<Vec<u8>, String> {
             decode_base64(&raw)
         }
         IntegerFormat::DecimalBytes => decode_decimal(raw),
-        IntegerFormat::DecimalInteger => BigUint::parse_bytes(raw.as_bytes(), 10)
+        IntegerFormat::DecimalInteger => BigInt::parse_bytes(raw.trim().as_bytes(), 10)
             .ok_or_else(|| "invalid decimal integer input".to_string())
-            .map(|integer| integer.to_bytes_be()),
+            .map(|integer| integer.to_signed_bytes_be()),
     }
 }
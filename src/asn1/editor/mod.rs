@@ -5,22 +5,22 @@ mod string;
 mod time;
 
 use std::fmt;
+use std::rc::Rc;
 
 use ::time::OffsetDateTime;
+use asn1_parser::oid_registry::OidRegistry;
 use asn1_parser::{
-    Asn1, Asn1Decoder, Asn1Type, BitString, BmpString, Bool, Day, ExplicitTag, GeneralString, GeneralizedTime,
-    GtSecond, GtYear, Hour, IA5String, Integer, Minute, Month, Mutable, NumericString, ObjectIdentifier, OctetString,
-    PrintableString, Second, Sequence, Set, UtcTime, Utf8String, VisibleString, Year,
+    Asn1, Asn1Decoder, Asn1Type, Day, GeneralizedTime, GtSecond, GtYear, Hour, Minute, Month, UtcTime, builder,
 };
 use web_sys::HtmlInputElement;
-use yew::{Callback, Html, Properties, TargetCast, UseStateSetter, function_component, html, use_state};
+use yew::{Callback, Html, Properties, TargetCast, UseStateSetter, function_component, html, use_context, use_state};
 
 pub use self::integer::{BYTES_FORMATS, INTEGER_FORMATS, IntegerEditor};
 pub use self::null::NullEditor;
 pub use self::number::NumberEditor;
 pub use self::string::StringEditor;
 pub use self::time::{GeneralizedTimeEditor, UtcTimeEditor};
-use crate::asn1::scheme::validate_oid;
+use crate::asn1::scheme::resolve_oid;
 use crate::common::Switch;
 
 const OCTET_STRING: &str = "octet string";
@@ -42,6 +42,9 @@ const OBJECT_IDENTIFIER: &str = "object identifier";
 const BOOL: &str = "bool";
 const RAW: &str = "raw";
 
+/// `SEQUENCE { UTF8String { "TheBestTvarynka" } }`.
+const RAW_TEMPLATE: &[u8] = b"\x30\x11\x0c\x0fTheBestTvarynka";
+
 const TYPES: &[&str] = &[
     OCTET_STRING,
     PRINTABLE_STRING,
@@ -90,12 +93,7 @@ impl TryFrom<&str> for Asn1NodeValue {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         Ok(match value {
-            RAW => Self::Raw(
-                // Sequence { Utf8String: "TheBestTvarynka" }
-                vec![
-                    48, 17, 12, 15, 84, 104, 101, 66, 101, 115, 116, 84, 118, 97, 114, 121, 110, 107, 97,
-                ],
-            ),
+            RAW => Self::Raw(RAW_TEMPLATE.to_vec()),
             OCTET_STRING => Self::OctetString(b"tbt".to_vec()),
             BIT_STRING => Self::BitString(b"tbt".to_vec()),
             PRINTABLE_STRING => Self::PrintableString(String::from("tbt")),
@@ -112,17 +110,9 @@ impl TryFrom<&str> for Asn1NodeValue {
                 GtSecond::try_from(3.56).expect("valid second"),
                 None,
             )),
-            UTC_TIME => {
-                let now = OffsetDateTime::now_utc();
-                Self::UtcTime(UtcTime {
-                    year: Year::try_from((now.year() % 100) as u8).expect("valid year"),
-                    month: Month::try_from(now.month() as u8).expect("valid month"),
-                    day: Day::try_from(now.day()).expect("valid day"),
-                    hour: Hour::try_from(now.hour()).expect("valid hour"),
-                    minute: Minute::try_from(now.minute()).expect("valid minute"),
-                    second: Some(Second::try_from(now.second()).expect("valid second")),
-                })
-            }
+            UTC_TIME => Self::UtcTime(
+                UtcTime::try_from(OffsetDateTime::now_utc()).expect("current time should fit into UtcTime"),
+            ),
             UTF8_STRING => Self::Utf8String(String::from("tbt")),
             GENERAL_STRING => Self::GeneralString(String::from("tbt")),
             IA5_STRING => Self::Ia5String(String::from("tbt")),
@@ -170,42 +160,40 @@ impl fmt::Display for Asn1NodeValue {
 
 impl From<Asn1NodeValue> for Asn1Type {
     fn from(value: Asn1NodeValue) -> Self {
-        match value {
-            Asn1NodeValue::Raw(data) => Asn1::decode_buff(&data)
-                .map(Asn1::into_inner_asn1)
-                .unwrap_or_else(|err| {
-                    error!(?err, "Failed to parse provided asn1 buffer");
+        let node = match value {
+            Asn1NodeValue::Raw(data) => Asn1::decode_buff(&data).unwrap_or_else(|err| {
+                error!(?err, "Failed to parse provided asn1 buffer");
 
-                    Asn1Type::Utf8String(Mutable::new(Utf8String::new(String::from(
-                        "Failed to parse provided asn1 buffer",
-                    ))))
-                }),
-            Asn1NodeValue::OctetString(data) => Asn1Type::OctetString(Mutable::new(OctetString::new(data))),
-            Asn1NodeValue::BitString(data) => Asn1Type::BitString(Mutable::new(BitString::from(data))),
-            Asn1NodeValue::PrintableString(data) => Asn1Type::PrintableString(Mutable::new(PrintableString::new(data))),
-            Asn1NodeValue::Integer(data) => Asn1Type::Integer(Mutable::new(Integer::from(data))),
-            Asn1NodeValue::Sequence => Asn1Type::Sequence(Mutable::new(Sequence::new(Vec::new()))),
-            Asn1NodeValue::Set => Asn1Type::Set(Mutable::new(Set::new(Vec::new()))),
-            Asn1NodeValue::ExplicitTag(tag) => Asn1Type::ExplicitTag(Mutable::new(ExplicitTag::new(tag, Vec::new()))),
-            Asn1NodeValue::GeneralizedTime(data) => Asn1Type::GeneralizedTime(Mutable::new(data)),
-            Asn1NodeValue::UtcTime(data) => Asn1Type::UtcTime(Mutable::new(data)),
-            Asn1NodeValue::Utf8String(data) => Asn1Type::Utf8String(Mutable::new(Utf8String::new(data))),
-            Asn1NodeValue::GeneralString(data) => Asn1Type::GeneralString(Mutable::new(GeneralString::new(data))),
-            Asn1NodeValue::Ia5String(data) => Asn1Type::IA5String(Mutable::new(IA5String::new(data))),
-            Asn1NodeValue::VisibleString(data) => Asn1Type::VisibleString(Mutable::new(VisibleString::new(data))),
-            Asn1NodeValue::NumericString(data) => Asn1Type::NumericString(Mutable::new(NumericString::new(data))),
-            Asn1NodeValue::BmpString(data) => Asn1Type::BmpString(Mutable::new(BmpString::new(
-                data.encode_utf16().flat_map(|c| c.to_be_bytes()).collect(),
-            ))),
-            Asn1NodeValue::ObjectIdentifier(data) => {
-                Asn1Type::ObjectIdentifier(Mutable::new(ObjectIdentifier::new_unchecked(&data)))
-            }
-            Asn1NodeValue::Bool(data) => Asn1Type::Bool(Mutable::new(Bool::from(data))),
-        }
+                builder::utf8("Failed to parse provided asn1 buffer")
+            }),
+            Asn1NodeValue::OctetString(data) => builder::octets(data),
+            Asn1NodeValue::BitString(data) => builder::bits(data),
+            Asn1NodeValue::PrintableString(data) => builder::printable(data),
+            Asn1NodeValue::Integer(data) => builder::int(data),
+            Asn1NodeValue::Sequence => builder::seq([]),
+            Asn1NodeValue::Set => builder::set([]),
+            Asn1NodeValue::ExplicitTag(tag) => builder::explicit(tag, []),
+            Asn1NodeValue::GeneralizedTime(data) => builder::generalized_time(data),
+            Asn1NodeValue::UtcTime(data) => builder::utc_time(data),
+            Asn1NodeValue::Utf8String(data) => builder::utf8(data),
+            Asn1NodeValue::GeneralString(data) => builder::general(data),
+            Asn1NodeValue::Ia5String(data) => builder::ia5(data),
+            Asn1NodeValue::VisibleString(data) => builder::visible(data),
+            Asn1NodeValue::NumericString(data) => builder::numeric(data),
+            Asn1NodeValue::BmpString(data) => builder::bmp(&data),
+            Asn1NodeValue::ObjectIdentifier(data) => builder::oid(&data),
+            Asn1NodeValue::Bool(data) => builder::bool(data),
+        };
+
+        node.into_inner_asn1()
     }
 }
 
-fn editor(asn1_node: Asn1NodeValue, asn1_node_setter: UseStateSetter<Asn1NodeValue>) -> Html {
+fn editor(
+    asn1_node: Asn1NodeValue,
+    asn1_node_setter: UseStateSetter<Asn1NodeValue>,
+    oid_registry: Rc<OidRegistry>,
+) -> Html {
     match asn1_node {
         Asn1NodeValue::Raw(value) => html! {
             <IntegerEditor
@@ -234,8 +222,15 @@ fn editor(asn1_node: Asn1NodeValue, asn1_node_setter: UseStateSetter<Asn1NodeVal
         Asn1NodeValue::ObjectIdentifier(value) => html! {
             <StringEditor
                 {value}
-                setter={Callback::from(move |data| asn1_node_setter.set(Asn1NodeValue::ObjectIdentifier(data)))}
-                validator={Callback::from(move |s: String| validate_oid(&s))}
+                setter={Callback::from({
+                    let oid_registry = oid_registry.clone();
+                    move |data: String| {
+                        if let Some(oid) = resolve_oid(&oid_registry, &data) {
+                            asn1_node_setter.set(Asn1NodeValue::ObjectIdentifier(oid));
+                        }
+                    }
+                })}
+                validator={Callback::from(move |s: String| resolve_oid(&oid_registry, &s).is_some())}
             />
         },
         Asn1NodeValue::BmpString(value) => html! {
@@ -332,6 +327,7 @@ pub struct NodeValueOptions {
 #[function_component(NodeValueEditor)]
 pub fn node_value_editor(props: &NodeValueOptions) -> Html {
     let asn1_node = use_state(|| Asn1NodeValue::PrintableString(String::from("tbt")));
+    let oid_registry = use_context::<Rc<OidRegistry>>().unwrap_or_default();
 
     let asn1_node_setter = asn1_node.setter();
     let oninput = Callback::from(move |event: html::oninput::Event| {
@@ -368,7 +364,7 @@ pub fn node_value_editor(props: &NodeValueOptions) -> Html {
             <div>
                 <span class="asn-simple-value">{(*asn1_node).to_string()}</span>
             </div>
-            {editor((*asn1_node).clone(), asn1_node.setter())}
+            {editor((*asn1_node).clone(), asn1_node.setter(), oid_registry)}
             <div class="horizontal">
                 <button class="jwt-util-button" onclick={add_node_click}>{"Add node"}</button>
                 <button class="asn1-button-with-icon" onclick={cancel_click} title="Cancel">
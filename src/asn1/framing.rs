@@ -0,0 +1,216 @@
+//! Framing of the asn1 messages in the captured protocol streams.
+//!
+//! Protocols rarely send bare asn1 messages: Kerberos over TCP prefixes every message with its length, RDP
+//! wraps PDUs into TPKT and X.224 headers, etc. [split_frames] strips the framing headers so the messages
+//! can be decoded. Node offsets stay relative to the whole capture.
+
+use std::ops::Range;
+
+use asn1_parser::{Asn1, decode_buff_ranges, decode_buff_vec};
+use serde::{Deserialize, Serialize};
+
+const RAW: &str = "raw concatenation";
+const LENGTH_BE32: &str = "4-byte BE length";
+const LENGTH_BE16: &str = "2-byte BE length";
+const TPKT: &str = "TPKT";
+
+const TPKT_VERSION: u8 = 3;
+const TPKT_HEADER_LEN: usize = 4;
+/// X.224 Data TPDU header: length indicator, DT TPDU code, and the EOT flag.
+const X224_DATA_HEADER: [u8; 3] = [0x02, 0xf0, 0x80];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Framing {
+    /// Asn1 messages follow each other without any headers.
+    #[default]
+    Raw,
+    /// 4-byte big-endian length prefix (e.g. Kerberos over TCP).
+    LengthBe32,
+    /// 2-byte big-endian length prefix.
+    LengthBe16,
+    /// TPKT header (RFC 1006) followed by the optional X.224 Data TPDU header (e.g. RDP).
+    Tpkt,
+}
+
+impl AsRef<str> for Framing {
+    fn as_ref(&self) -> &str {
+        match self {
+            Framing::Raw => RAW,
+            Framing::LengthBe32 => LENGTH_BE32,
+            Framing::LengthBe16 => LENGTH_BE16,
+            Framing::Tpkt => TPKT,
+        }
+    }
+}
+
+impl TryFrom<&str> for Framing {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        FRAMINGS
+            .iter()
+            .find(|framing| framing.as_ref() == value)
+            .copied()
+            .ok_or_else(|| format!("invalid framing: {}", value))
+    }
+}
+
+pub const FRAMINGS: [Framing; 4] = [Framing::Raw, Framing::LengthBe32, Framing::LengthBe16, Framing::Tpkt];
+
+/// Framing header of one asn1 message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Header position in the capture.
+    pub offset: usize,
+    pub header: Vec<u8>,
+    /// Payload position in the capture.
+    pub payload: Range<usize>,
+    /// Amount of the top-level trees decoded from the payload.
+    pub trees: usize,
+}
+
+impl Frame {
+    pub fn caption(&self) -> String {
+        format!(
+            "frame at {}: header {}, payload {} bytes",
+            self.offset,
+            hex::encode(&self.header),
+            self.payload.len()
+        )
+    }
+}
+
+fn read_length(data: &[u8], offset: usize, len_size: usize) -> Result<usize, String> {
+    let bytes = data
+        .get(offset..offset + len_size)
+        .ok_or_else(|| format!("frame at {}: incomplete header", offset))?;
+
+    Ok(bytes.iter().fold(0, |len, byte| len << 8 | usize::from(*byte)))
+}
+
+/// Returns the header and payload length of the frame starting at the `offset`.
+fn frame_header(data: &[u8], offset: usize, framing: Framing) -> Result<(usize, usize), String> {
+    match framing {
+        Framing::Raw => Ok((0, data.len() - offset)),
+        Framing::LengthBe32 => Ok((4, read_length(data, offset, 4)?)),
+        Framing::LengthBe16 => Ok((2, read_length(data, offset, 2)?)),
+        Framing::Tpkt => {
+            if data[offset] != TPKT_VERSION {
+                return Err(format!(
+                    "frame at {}: invalid TPKT version: expected {}, got {}",
+                    offset, TPKT_VERSION, data[offset]
+                ));
+            }
+
+            // TPKT length includes the header.
+            let frame_len = read_length(data, offset + 2, 2)?;
+            if frame_len < TPKT_HEADER_LEN {
+                return Err(format!("frame at {}: invalid TPKT length: {}", offset, frame_len));
+            }
+
+            let header_len = if frame_len >= TPKT_HEADER_LEN + X224_DATA_HEADER.len()
+                && data[offset + TPKT_HEADER_LEN..].starts_with(&X224_DATA_HEADER)
+            {
+                TPKT_HEADER_LEN + X224_DATA_HEADER.len()
+            } else {
+                TPKT_HEADER_LEN
+            };
+
+            Ok((header_len, frame_len - header_len))
+        }
+    }
+}
+
+/// Splits the capture into frames. Frame headers are not decoded as asn1.
+pub fn split_frames(data: &[u8], framing: Framing) -> Result<Vec<Frame>, String> {
+    let mut frames = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        let (header_len, payload_len) = frame_header(data, offset, framing)?;
+
+        // The length comes from the untrusted input and can overflow `usize` on 32-bit targets (e.g. wasm32).
+        let overflow = || format!("frame at {}: payload length {} is too big", offset, payload_len);
+        let payload_start = offset.checked_add(header_len).ok_or_else(overflow)?;
+        let payload_end = payload_start.checked_add(payload_len).ok_or_else(overflow)?;
+
+        let payload = payload_start..payload_end;
+        if payload.end > data.len() {
+            return Err(format!(
+                "frame at {}: payload length is {} bytes but only {} bytes are left",
+                offset,
+                payload_len,
+                data.len() - payload.start.min(data.len())
+            ));
+        }
+
+        frames.push(Frame {
+            offset,
+            header: data[offset..payload.start].to_vec(),
+            payload: payload.clone(),
+            trees: 0,
+        });
+        offset = payload.end;
+    }
+
+    Ok(frames)
+}
+
+/// Decodes asn1 messages from the framed capture. Frames are empty for the [Framing::Raw] framing.
+pub fn decode_framed(data: &[u8], framing: Framing) -> Result<(Vec<Asn1>, Vec<Frame>), String> {
+    if framing == Framing::Raw {
+        return decode_buff_vec(data)
+            .map(|trees| (trees, Vec::new()))
+            .map_err(|err| err.to_string());
+    }
+
+    let mut frames = split_frames(data, framing)?;
+    let ranges = frames.iter().map(|frame| frame.payload.clone()).collect::<Vec<_>>();
+
+    let decoded = decode_buff_ranges(data, &ranges).map_err(|err| err.to_string())?;
+
+    let mut trees = Vec::new();
+    for (frame, frame_trees) in frames.iter_mut().zip(decoded) {
+        frame.trees = frame_trees.len();
+        trees.extend(frame_trees);
+    }
+
+    Ok((trees, frames))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Framing, decode_framed, split_frames};
+
+    #[test]
+    fn tpkt_with_x224() {
+        // TPKT { X.224 DT { NULL } }, TPKT { BOOLEAN TRUE }
+        let data = [
+            0x03, 0x00, 0x00, 0x09, 0x02, 0xf0, 0x80, 0x05, 0x00, 0x03, 0x00, 0x00, 0x07, 0x01, 0x01, 0xff,
+        ];
+
+        let (trees, frames) = decode_framed(&data, Framing::Tpkt).unwrap();
+
+        assert_eq!(2, trees.len());
+        assert_eq!(vec![0x03, 0x00, 0x00, 0x09, 0x02, 0xf0, 0x80], frames[0].header);
+        assert_eq!(7..9, frames[0].payload);
+        assert_eq!(13..16, frames[1].payload);
+        assert_eq!(13, trees[1].meta().tag_position());
+    }
+
+    #[test]
+    fn truncated_frame() {
+        let data = [0x00, 0x00, 0x00, 0x05, 0x05, 0x00];
+
+        assert!(split_frames(&data, Framing::LengthBe32).is_err());
+        assert!(split_frames(&data[..2], Framing::LengthBe32).is_err());
+        assert!(split_frames(&data, Framing::Tpkt).is_err());
+    }
+
+    #[test]
+    fn huge_frame_length() {
+        let data = [0xff, 0xff, 0xff, 0xfc, 0x05, 0x00];
+
+        assert!(split_frames(&data, Framing::LengthBe32).is_err());
+    }
+}
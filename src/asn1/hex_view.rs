@@ -3,6 +3,7 @@ use web_sys::MouseEvent;
 use yew::virtual_dom::VNode;
 use yew::{Callback, Classes, Html, Properties, function_component, html};
 
+use crate::asn1::framing::Frame;
 use crate::asn1::node_options::NodeOptions;
 use crate::asn1::{HighlightAction, compare_ids};
 use crate::common::{RcSlice, hex_format_byte};
@@ -13,18 +14,53 @@ pub struct HexViewerProps {
 
     pub cur_node: Option<u64>,
     pub set_cur_node: Callback<HighlightAction>,
+
+    /// Framing headers of the captured messages. They are rendered before the trees decoded from their payloads.
+    #[prop_or_default]
+    pub frames: Vec<Frame>,
+}
+
+fn build_frame_header(frame: &Frame) -> Html {
+    html! {
+        <div class="asn1-hex-node" title={frame.caption()}>
+            {for frame.header.iter().map(|byte| html! {
+                <span class="asn1-hex-byte asn1-hex-byte-frame">{hex_format_byte(*byte)}</span>
+            })}
+        </div>
+    }
 }
 
 #[function_component(HexViewer)]
 pub fn hex_viewer(props: &HexViewerProps) -> Html {
     let structures = props.structures.get();
 
+    let mut first_tree = 0;
+    let frame_starts = props
+        .frames
+        .iter()
+        .map(|frame| {
+            let start = first_tree;
+            first_tree += frame.trees;
+            (start, frame)
+        })
+        .collect::<Vec<_>>();
+    let frame_headers = |tree_index: usize| -> Html {
+        frame_starts
+            .iter()
+            .filter(|(start, _)| *start == tree_index)
+            .map(|(_, frame)| build_frame_header(frame))
+            .collect()
+    };
+
     html! {
         <div class="asn1-hex-viewer">
         {{
             structures.iter()
-                .map(|structure| {
+                .enumerate()
+                .map(|(i, structure)| {
                     html! {
+                        <>
+                        {frame_headers(i)}
                         <div class="asn1-hex-node">
                         {{
                             let mut bytes = Vec::with_capacity(structure.meta().raw_data.len());
@@ -39,10 +75,12 @@ pub fn hex_viewer(props: &HexViewerProps) -> Html {
                             bytes
                         }}
                         </div>
+                        </>
                     }
                 })
                 .collect::<Vec<_>>()
         }}
+        {frame_headers(structures.len())}
         </div>
     }
 }
@@ -41,6 +41,9 @@ macro_rules! define_string_node {
                             })}
                         />
                         <span class="asn-simple-value">{props.node.get().string().to_owned()}</span>
+                        <crate::asn1::content_sniffer::SniffedContentView
+                            data={crate::common::RcSlice::from(props.node.get().string().as_bytes())}
+                        />
                     </div>
                 }
             }
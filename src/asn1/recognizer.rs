@@ -0,0 +1,237 @@
+//! Recognition of the well-known asn1 structures.
+//!
+//! Every [StructureRecognizer] checks the shape of the tree and labels its nodes with the field names from the
+//! corresponding specification. The labels are displayed next to the nodes on the ASN.1 page.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use asn1_parser::{Asn1, Asn1Entity, Asn1Type};
+
+/// Field names of the recognized nodes by node id.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NodeLabels(pub Rc<HashMap<u64, String>>);
+
+pub trait StructureRecognizer {
+    /// Labels the tree nodes if the tree has the expected structure. Returns `true` if the tree is recognized.
+    fn recognize(&self, tree: &Asn1, labels: &mut HashMap<u64, String>) -> bool;
+}
+
+/// Recognizers tried on every node. Add a new [StructureRecognizer] here to support one more structure.
+const RECOGNIZERS: &[&dyn StructureRecognizer] = &[&TsRequestRecognizer];
+
+/// Runs all [RECOGNIZERS] on every node of the trees, including the trees encapsulated in the octet strings.
+pub fn recognize(trees: &[Asn1]) -> NodeLabels {
+    let mut labels = HashMap::new();
+    recognize_trees(trees, &mut labels);
+
+    NodeLabels(Rc::new(labels))
+}
+
+fn recognize_trees(trees: &[Asn1], labels: &mut HashMap<u64, String>) {
+    for tree in trees {
+        for recognizer in RECOGNIZERS {
+            recognizer.recognize(tree, labels);
+        }
+
+        match tree.inner_asn1() {
+            Asn1Type::Sequence(sequence) => recognize_trees(sequence.get().fields(), labels),
+            Asn1Type::Set(set) => recognize_trees(set.get().fields(), labels),
+            Asn1Type::ExplicitTag(explicit) => recognize_trees(explicit.get().inner(), labels),
+            Asn1Type::ApplicationTag(application) => recognize_trees(application.get().inner(), labels),
+            Asn1Type::OctetString(octet_string) => {
+                if let Some(inner) = octet_string.get().inner() {
+                    recognize_trees(&inner.get(), labels);
+                }
+            }
+            Asn1Type::BitString(bit_string) => {
+                if let Some(inner) = bit_string.get().inner() {
+                    recognize_trees(&inner.get(), labels);
+                }
+            }
+            Asn1Type::ImplicitTag(implicit) => {
+                if let Some(inner) = implicit.get().inner_asn1() {
+                    recognize_trees(std::slice::from_ref(inner), labels);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns `[tag number, inner node]` pairs if the tree is a SEQUENCE of single-node explicit tags
+/// with the increasing tag numbers.
+fn explicit_fields(tree: &Asn1) -> Option<Vec<(u8, u64, Asn1)>> {
+    let Asn1Type::Sequence(sequence) = tree.inner_asn1() else {
+        return None;
+    };
+
+    let mut fields = Vec::new();
+    for field in sequence.get().fields() {
+        let Asn1Type::ExplicitTag(explicit) = field.inner_asn1() else {
+            return None;
+        };
+        let explicit = explicit.get();
+        let [inner] = explicit.inner() else {
+            return None;
+        };
+
+        if let Some((previous_tag, _, _)) = fields.last()
+            && *previous_tag >= explicit.tag_number()
+        {
+            return None;
+        }
+
+        fields.push((explicit.tag_number(), field.id(), inner.clone()));
+    }
+
+    Some(fields)
+}
+
+fn integer_value(tree: &Asn1) -> Option<i64> {
+    match tree.inner_asn1() {
+        Asn1Type::Integer(integer) => i64::try_from(&*integer.get()).ok(),
+        _ => None,
+    }
+}
+
+fn is_octet_string(tree: &Asn1) -> bool {
+    matches!(tree.inner_asn1(), Asn1Type::OctetString(_))
+}
+
+/// CredSSP TSRequest (MS-CSSP 2.2.1).
+///
+/// ```not_rust
+/// TSRequest ::= SEQUENCE {
+///     version    [0] INTEGER,
+///     negoTokens [1] NegoData  OPTIONAL,
+///     authInfo   [2] OCTET STRING OPTIONAL,
+///     pubKeyAuth [3] OCTET STRING OPTIONAL,
+///     errorCode  [4] INTEGER OPTIONAL,
+///     clientNonce [5] OCTET STRING OPTIONAL
+/// }
+///
+/// NegoData ::= SEQUENCE OF SEQUENCE {
+///     negoToken [0] OCTET STRING
+/// }
+/// ```
+pub struct TsRequestRecognizer;
+
+impl TsRequestRecognizer {
+    /// Returns the labels of the NegoData items.
+    fn nego_data(nego_data: &Asn1) -> Option<Vec<(u64, String)>> {
+        let Asn1Type::Sequence(sequence) = nego_data.inner_asn1() else {
+            return None;
+        };
+
+        let mut labels = vec![(nego_data.id(), "NegoData".to_owned())];
+        for item in sequence.get().fields() {
+            let fields = explicit_fields(item)?;
+            let [(0, tag_id, nego_token)] = fields.as_slice() else {
+                return None;
+            };
+            if !is_octet_string(nego_token) {
+                return None;
+            }
+
+            labels.push((item.id(), "NegoDataItem".to_owned()));
+            labels.push((*tag_id, "negoToken".to_owned()));
+        }
+
+        Some(labels)
+    }
+}
+
+impl StructureRecognizer for TsRequestRecognizer {
+    fn recognize(&self, tree: &Asn1, labels: &mut HashMap<u64, String>) -> bool {
+        let Some(fields) = explicit_fields(tree) else {
+            return false;
+        };
+
+        let mut tree_labels = vec![(tree.id(), "TSRequest".to_owned())];
+        for (tag_number, tag_id, inner) in &fields {
+            let label = match tag_number {
+                0 => match integer_value(inner) {
+                    Some(version @ 1..=6) => format!("version: {}", version),
+                    _ => return false,
+                },
+                1 => {
+                    tree_labels.extend(match Self::nego_data(inner) {
+                        Some(nego_data) => nego_data,
+                        None => return false,
+                    });
+
+                    "negoTokens".to_owned()
+                }
+                2 if is_octet_string(inner) => "authInfo".to_owned(),
+                3 if is_octet_string(inner) => "pubKeyAuth".to_owned(),
+                4 => match integer_value(inner) {
+                    // errorCode is NTSTATUS, so we display it as an unsigned hex number.
+                    Some(error_code) => format!("errorCode: 0x{:08x}", error_code as u32),
+                    None => return false,
+                },
+                5 if is_octet_string(inner) => "clientNonce".to_owned(),
+                _ => return false,
+            };
+
+            tree_labels.push((*tag_id, label));
+        }
+
+        // The version field is mandatory.
+        if !matches!(fields.first(), Some((0, _, _))) {
+            return false;
+        }
+
+        labels.extend(tree_labels);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use asn1_parser::asn1;
+
+    use super::recognize;
+
+    #[test]
+    fn ts_request() {
+        let ts_request = asn1! {
+            seq {
+                explicit(0, int(6)),
+                explicit(1, seq { seq { explicit(0, octets(b"NTLMSSP\0".to_vec())) } }),
+                explicit(4, int(0xc000006d_u32)),
+            }
+        }
+        .unwrap();
+
+        let labels = recognize(std::slice::from_ref(&ts_request)).0;
+        let mut names = labels.values().cloned().collect::<Vec<_>>();
+        names.sort();
+
+        assert_eq!(
+            vec![
+                "NegoData",
+                "NegoDataItem",
+                "TSRequest",
+                "errorCode: 0xc000006d",
+                "negoToken",
+                "negoTokens",
+                "version: 6"
+            ],
+            names
+        );
+    }
+
+    #[test]
+    fn not_ts_request() {
+        let trees = [
+            asn1! { seq { explicit(1, int(6)) } }.unwrap(),
+            asn1! { seq { explicit(0, int(6)), explicit(0, int(6)) } }.unwrap(),
+            asn1! { seq { explicit(0, int(6)), explicit(2, int(6)) } }.unwrap(),
+            asn1! { set { explicit(0, int(6)) } }.unwrap(),
+        ];
+
+        assert!(recognize(&trees).0.is_empty());
+    }
+}
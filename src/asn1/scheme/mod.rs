@@ -6,11 +6,15 @@ mod strings;
 mod tag;
 mod time;
 
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use asn1_parser::diff::ChangeKind;
 use asn1_parser::{Asn1, Asn1Entity, Asn1Type};
-pub use oid::validate_oid;
+pub use oid::resolve_oid;
 use web_sys::MouseEvent;
 use yew::virtual_dom::VNode;
-use yew::{Callback, Children, Classes, Html, Properties, classes, function_component, html, use_state};
+use yew::{Callback, Children, Classes, Html, Properties, classes, function_component, html, use_context, use_state};
 
 use self::oid::ObjectIdentifierNode;
 use self::primitive::{BoolNode, EnumeratedNode, IntegerNode, NullNode};
@@ -23,6 +27,7 @@ use self::tag::{ApplicationTagNode, ExplicitTagNode, ImplicitTagNode};
 use self::time::{GeneralizedTimeNode, UtcTimeNode};
 use crate::asn1::HighlightAction;
 use crate::asn1::editor::{NodeValueEditor, RemoveNodeConfirmation};
+use crate::asn1::recognizer::NodeLabels;
 use crate::asn1::scheme::set::SetNode;
 
 #[derive(PartialEq, Properties, Clone)]
@@ -128,6 +133,14 @@ pub fn remove_node_button(props: &RemoveNodeButtonProps) -> Html {
     }
 }
 
+/// Ids of the nodes which length bytes have changed after the last re-encoding.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ChangedLengths(pub Rc<Vec<u64>>);
+
+/// Structural diff changes of the displayed trees by node id.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DiffMarks(pub Rc<HashMap<u64, ChangeKind>>);
+
 #[derive(PartialEq, Properties, Clone)]
 pub struct Asn1NodeProps {
     pub id: u64,
@@ -141,15 +154,34 @@ pub struct Asn1NodeProps {
 
 #[function_component(Asn1Node)]
 pub fn asn1_node(props: &Asn1NodeProps) -> Html {
-    fn get_node_class(id: u64, cur_id: &Option<u64>) -> Classes {
-        match cur_id {
+    fn get_node_class(id: u64, cur_id: &Option<u64>, length_changed: bool, change: Option<ChangeKind>) -> Classes {
+        let mut class = match cur_id {
             Some(cur_id) if *cur_id == id => {
                 classes!("hover_node", "asn1-node-container")
             }
             _ => classes!("asn1-node-container"),
+        };
+
+        if length_changed {
+            class.push("asn1-length-changed");
+        }
+
+        if let Some(change) = change {
+            class.push(format!("asn1-diff-{}", change.as_ref()));
         }
+
+        class
     }
 
+    let changed_lengths = use_context::<ChangedLengths>().unwrap_or_default();
+    let length_changed = changed_lengths.0.contains(&props.id);
+
+    let diff_marks = use_context::<DiffMarks>().unwrap_or_default();
+    let change = diff_marks.0.get(&props.id).copied();
+
+    let node_labels = use_context::<NodeLabels>().unwrap_or_default();
+    let label = node_labels.0.get(&props.id).cloned();
+
     let asn1_node_id = props.id;
     let set_cur_node_enter = props.set_cur_node.clone();
     let onmouseenter = Callback::from(move |_: MouseEvent| {
@@ -161,7 +193,16 @@ pub fn asn1_node(props: &Asn1NodeProps) -> Html {
     });
 
     html! {
-        <div class={get_node_class(props.id, &props.cur_id)} {onmouseenter} {onmouseleave} style="position: relative;">
+        <div
+            class={get_node_class(props.id, &props.cur_id, length_changed, change)}
+            title={length_changed.then_some("The length of this node has been re-encoded")}
+            {onmouseenter}
+            {onmouseleave}
+            style="position: relative;"
+        >
+            {label.map(|label| html! {
+                <span class="asn1-node-label">{label}</span>
+            })}
             {props.children.clone()}
             <AddNodeButton add_node={props.add_node.clone()} />
             <RemoveNodeButton remove_node={props.remove_node.clone()} />
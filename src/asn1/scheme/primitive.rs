@@ -103,7 +103,7 @@ pub fn integer(props: &IntegerNodeProps) -> Html {
                     <IntegerEditor value={props.node.get().raw_data().to_vec()} {setter} formats={INTEGER_FORMATS} />
                 })}
             />
-            <span class="asn-simple-value">{format!("{}", props.node.get().as_big_uint())}</span>
+            <span class="asn-simple-value">{format!("{}", props.node.get().as_big_int())}</span>
         </div>
     }
 }
@@ -140,7 +140,7 @@ pub fn enumerated(props: &EnumeratedNodeProps) -> Html {
                     <IntegerEditor value={props.node.get().raw_data().to_vec()} {setter} formats={INTEGER_FORMATS} />
                 })}
             />
-            <span class="asn-simple-value">{format!("{}", props.node.get().as_big_uint())}</span>
+            <span class="asn-simple-value">{format!("{}", props.node.get().as_big_int())}</span>
         </div>
     }
 }
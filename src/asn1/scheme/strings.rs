@@ -1,12 +1,14 @@
 use std::fmt::Write;
 
+use asn1_parser::preserving::encode_preserving;
 use asn1_parser::{
-    Asn1, Asn1Encoder, Asn1Type, BitString, BmpString, GeneralString, IA5String, Mutable, NumericString, OctetString,
+    Asn1, Asn1Type, BitString, BmpString, GeneralString, IA5String, Mutable, NumericString, OctetString,
     PrintableString, RawAsn1EntityData, Utf8String, VisibleString,
 };
 use yew::{Callback, Html, Properties, function_component, html};
 
 use crate::asn1::HighlightAction;
+use crate::asn1::content_sniffer::SniffedContentView;
 use crate::asn1::editor::{BYTES_FORMATS, IntegerEditor, StringEditor};
 use crate::asn1::node_options::NodeOptions;
 use crate::asn1::scheme::{AddNodeButton, build_asn1_schema};
@@ -43,12 +45,7 @@ pub fn octet_string(props: &OctetStringNodeProps) -> Html {
             let global_re_encode = props.re_encode.clone();
             let node = props.node.clone();
             let re_encode = Callback::from(move |_| {
-                let mut buf = vec![0; asn1_type.get().as_slice().needed_buf_size()];
-                asn1_type
-                    .get()
-                    .as_slice()
-                    .encode_buff(&mut buf)
-                    .expect("Node encoding should not fail");
+                let buf = encode_preserving(&asn1_type.get()).expect("Node encoding should not fail");
 
                 node.get_mut().set_octets(buf);
                 global_re_encode.emit(());
@@ -147,6 +144,7 @@ pub fn octet_string(props: &OctetStringNodeProps) -> Html {
                     />
                     <span class="asn1-node-info-label">{format!("({} bytes)", octets.len())}</span>
                     <span class="asn-simple-value">{encoded_octets}</span>
+                    <SniffedContentView data={RcSlice::from(octets)} />
                 </div>
             }
         }
@@ -185,12 +183,7 @@ pub fn bit_string(props: &BitStringNodeProps) -> Html {
             let global_re_encode = props.re_encode.clone();
             let node = props.node.clone();
             let re_encode = Callback::from(move |_| {
-                let mut buf = vec![0; asn1_type.get().as_slice().needed_buf_size()];
-                asn1_type
-                    .get()
-                    .as_slice()
-                    .encode_buff(&mut buf)
-                    .expect("Node encoding should not fail");
+                let buf = encode_preserving(&asn1_type.get()).expect("Node encoding should not fail");
 
                 node.get_mut().set_bits(buf);
                 global_re_encode.emit(());
@@ -288,6 +281,7 @@ pub fn bit_string(props: &BitStringNodeProps) -> Html {
                     />
                     <span class="asn1-node-info-label">{format!("({} bits)", bits_amount)}</span>
                     <span class="asn-simple-value">{display_bits}</span>
+                    <SniffedContentView data={RcSlice::from(raw_bits.get(1..).unwrap_or_default())} />
                 </div>
             }
         }
@@ -339,7 +333,8 @@ pub fn bmp_string(props: &BmpStringNodeProps) -> Html {
                     />
                 })}
             />
-            <span class="asn-simple-value">{s}</span>
+            <span class="asn-simple-value">{s.clone()}</span>
+            <SniffedContentView data={RcSlice::from(s.as_bytes())} />
         </div>
     }
 }
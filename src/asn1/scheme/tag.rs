@@ -1,9 +1,12 @@
+use std::slice;
 use std::str::from_utf8;
 
-use asn1_parser::{ApplicationTag, Asn1, Asn1Encoder, ExplicitTag, ImplicitTag, Mutable, RawAsn1EntityData};
+use asn1_parser::preserving::encode_preserving;
+use asn1_parser::{ApplicationTag, Asn1, ExplicitTag, ImplicitTag, Mutable, RawAsn1EntityData};
 use yew::{Callback, Html, Properties, function_component, html};
 
 use crate::asn1::HighlightAction;
+use crate::asn1::content_sniffer::SniffedContentView;
 use crate::asn1::editor::NumberEditor;
 use crate::asn1::node_options::NodeOptions;
 use crate::asn1::scheme::{AddNodeButton, build_asn1_schema};
@@ -221,13 +224,12 @@ pub fn implicit_tag(props: &ImplicitTagProps) -> Html {
 
     match node.inner_asn1() {
         Some(asn1) => {
-            let asn1_type = asn1.inner_asn1().clone();
+            let inner = asn1.clone();
             let global_re_encode = props.re_encode.clone();
             let node = props.node.clone();
             let tag_number = node.get().tag_number();
             let re_encode = Callback::from(move |_| {
-                let mut buf = vec![0; asn1_type.needed_buf_size()];
-                asn1_type.encode_buff(&mut buf).expect("Node encoding should not fail");
+                let buf = encode_preserving(slice::from_ref(&inner)).expect("Node encoding should not fail");
 
                 node.get_mut().set_octets(buf);
                 global_re_encode.emit(());
@@ -259,6 +261,7 @@ pub fn implicit_tag(props: &ImplicitTagProps) -> Html {
                 }} else { html!{
                     <span class="asn-simple-value">{hex::encode(octets)}</span>
                 }}}
+                <SniffedContentView data={RcSlice::from(octets)} />
             </div>
         },
     }
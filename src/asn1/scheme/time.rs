@@ -1,4 +1,5 @@
-use asn1_parser::{GeneralizedTime, Mutable, RawAsn1EntityData, UtcTime};
+use asn1_parser::{Asn1Result, GeneralizedTime, Mutable, RawAsn1EntityData, UtcTime};
+use time::{Duration, OffsetDateTime, UtcOffset};
 use yew::{Callback, Html, Properties, function_component, html};
 
 use crate::asn1::editor::{GeneralizedTimeEditor, UtcTimeEditor};
@@ -41,6 +42,7 @@ pub fn utc_time_string(props: &UtcTimeNodeProps) -> Html {
                 })}
             />
             <span class="asn-simple-value">{format_utc_time(&props.node.get())}</span>
+            {time_details(OffsetDateTime::try_from(&*props.node.get()))}
         </div>
     }
 }
@@ -81,20 +83,73 @@ pub fn general_time_string(props: &GeneralizedTimeNodeProps) -> Html {
                 })}
             />
             <span class="asn-simple-value">{format_generalized_time(&props.node.get())}</span>
+            {time_details(OffsetDateTime::try_from(&*props.node.get()))}
         </div>
     }
 }
 
-fn format_utc_time(utc_time: &UtcTime) -> String {
-    use time::OffsetDateTime;
+/// Renders the date time in the local time zone, the Unix timestamp, and the time relative to now.
+fn time_details(date_time: Asn1Result<OffsetDateTime>) -> Html {
+    let date_time = match date_time {
+        Ok(date_time) => date_time,
+        Err(err) => {
+            warn!(?err, "Can not convert asn1 time.");
+            return html! {};
+        }
+    };
 
-    let mut formatted = String::new();
+    let local_offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+    let local = date_time.to_offset(local_offset);
 
-    let current_year = (OffsetDateTime::now_utc().year() % 100) as u8;
-    if current_year < *utc_time.year.as_ref() {
-        formatted.push_str("19");
+    html! {
+        <>
+            <span class="total">{format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02} local",
+                local.year(),
+                u8::from(local.month()),
+                local.day(),
+                local.hour(),
+                local.minute(),
+                local.second(),
+            )}</span>
+            <span class="total">{format!("unix {}", date_time.unix_timestamp())}</span>
+            <span class="total">{format_relative_time(date_time - OffsetDateTime::now_utc())}</span>
+        </>
+    }
+}
+
+fn format_relative_time(duration: Duration) -> String {
+    const UNITS: [(&str, i64); 5] = [
+        ("year", 365 * 24 * 60 * 60),
+        ("day", 24 * 60 * 60),
+        ("hour", 60 * 60),
+        ("minute", 60),
+        ("second", 1),
+    ];
+
+    let seconds = duration.whole_seconds().unsigned_abs();
+    let (unit, amount) = UNITS
+        .iter()
+        .map(|(unit, unit_seconds)| (*unit, seconds / *unit_seconds as u64))
+        .find(|(_, amount)| *amount > 0)
+        .unwrap_or(("second", 0));
+    let plural = if amount == 1 { "" } else { "s" };
+
+    if duration.is_negative() {
+        format!("expired {amount} {unit}{plural} ago")
     } else {
+        format!("in {amount} {unit}{plural}")
+    }
+}
+
+fn format_utc_time(utc_time: &UtcTime) -> String {
+    let mut formatted = String::new();
+
+    // RFC 5280: two-digit years less than 50 belong to the 21st century.
+    if *utc_time.year.as_ref() < 50 {
         formatted.push_str("20");
+    } else {
+        formatted.push_str("19");
     }
     formatted.push_str(&format!("{:02}", utc_time.year.as_ref()));
 
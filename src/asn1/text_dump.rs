@@ -0,0 +1,65 @@
+use asn1_parser::dump::{DumpAsn1, OpensslDump};
+use asn1_parser::{Asn1, Mutable};
+use web_sys::HtmlInputElement;
+use yew::{Callback, Event, Html, Properties, TargetCast, function_component, html, use_state};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType, use_notification};
+
+const OPENSSL_ASN1PARSE: &str = "openssl asn1parse";
+const DUMPASN1: &str = "dumpasn1";
+
+const TEXT_DUMP_FORMATS: [&str; 2] = [OPENSSL_ASN1PARSE, DUMPASN1];
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct TextDumpProps {
+    pub structures: Mutable<Vec<Asn1>>,
+}
+
+#[function_component(TextDump)]
+pub fn text_dump(props: &TextDumpProps) -> Html {
+    let format = use_state(|| OPENSSL_ASN1PARSE);
+
+    let format_setter = format.setter();
+    let onchange = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let value = input.value();
+
+        if let Some(format) = TEXT_DUMP_FORMATS.iter().find(|format| **format == value) {
+            format_setter.set(format);
+        }
+    });
+
+    let clipboard = use_clipboard();
+    let notifications = use_notification::<Notification>();
+    let structures = props.structures.clone();
+    let selected_format = *format;
+    let onclick = Callback::from(move |_| {
+        let trees = structures.get();
+        let dump = if selected_format == DUMPASN1 {
+            DumpAsn1::new(&trees).to_string()
+        } else {
+            OpensslDump::new(&trees).to_string()
+        };
+
+        clipboard.write_text(dump);
+
+        notifications.spawn(Notification::from_description_and_type(
+            NotificationType::Info,
+            format!("{} text dump copied.", selected_format),
+        ));
+    });
+
+    html! {
+        <div class="horizontal">
+            <select class="base-input auto-width-input" {onchange}>
+                {TEXT_DUMP_FORMATS
+                    .iter()
+                    .map(|name| html! {
+                        <option selected={*format == *name} value={*name}>{name}</option>
+                    })
+                    .collect::<Vec<_>>()}
+            </select>
+            <button class="action-button" {onclick}>{"Copy as text dump"}</button>
+        </div>
+    }
+}
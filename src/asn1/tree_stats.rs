@@ -0,0 +1,106 @@
+use asn1_parser::stats::tree_stats;
+use asn1_parser::{Asn1, Mutable};
+use yew::{Callback, Html, Properties, function_component, html, use_state};
+
+use crate::asn1::HighlightAction;
+use crate::common::Checkbox;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct TreeStatsPanelProps {
+    pub structures: Mutable<Vec<Asn1>>,
+    pub set_cur_node: Callback<HighlightAction>,
+}
+
+/// Size breakdown of the trees. Clicking a row highlights the corresponding node.
+#[function_component(TreeStatsPanel)]
+pub fn tree_stats_panel(props: &TreeStatsPanelProps) -> Html {
+    let opened = use_state(|| false);
+    let selected = use_state(|| None::<u64>);
+
+    let opened_setter = opened.setter();
+    let checkbox = html! {
+        <Checkbox
+            id={"tree-stats".to_owned()}
+            name={"tree statistics".to_owned()}
+            checked={*opened}
+            set_checked={Callback::from(move |checked| opened_setter.set(checked))}
+        />
+    };
+
+    if !*opened {
+        return html! {
+            <div class="vertical">
+                {checkbox}
+            </div>
+        };
+    }
+
+    let stats = tree_stats(&props.structures.get());
+
+    let rows = stats.nodes.iter().map(|node| {
+        let id = node.id;
+        let set_cur_node = props.set_cur_node.clone();
+        let selected_node = selected.clone();
+        let onclick = Callback::from(move |_| {
+            if let Some(previous) = *selected_node {
+                set_cur_node.emit(HighlightAction::Hide(previous));
+            }
+
+            if *selected_node == Some(id) {
+                selected_node.set(None);
+            } else {
+                set_cur_node.emit(HighlightAction::Show(id));
+                selected_node.set(Some(id));
+            }
+        });
+
+        let percentage = node.percentage(stats.total_bytes);
+        let class = if *selected == Some(id) {
+            "asn1-stats-row asn1-stats-row-selected"
+        } else {
+            "asn1-stats-row"
+        };
+
+        html! {
+            <tr {class} {onclick}>
+                <td style={format!("padding-left: {}em;", node.depth)}>{node.type_name}</td>
+                <td>{node.bytes}</td>
+                <td>{node.descendants}</td>
+                <td>
+                    <div class="asn1-stats-bar">
+                        <div class="asn1-stats-bar-fill" style={format!("width: {:.2}%;", percentage)} />
+                        <span>{format!("{:.2}%", percentage)}</span>
+                    </div>
+                </td>
+            </tr>
+        }
+    });
+
+    html! {
+        <div class="vertical">
+            {checkbox}
+            <span class="total">
+                {format!(
+                    "{} nodes, max depth {}, {} bytes",
+                    stats.nodes.len(),
+                    stats.max_depth,
+                    stats.total_bytes
+                )}
+            </span>
+            <div class="horizontal asn1-stats-types">
+                {for stats.type_counts.iter().map(|(type_name, count)| html! {
+                    <span class="asn-simple-value">{format!("{}: {}", type_name, count)}</span>
+                })}
+            </div>
+            <table class="asn1-stats-table">
+                <tr>
+                    <th>{"node"}</th>
+                    <th>{"bytes"}</th>
+                    <th>{"descendants"}</th>
+                    <th>{"share"}</th>
+                </tr>
+                {for rows}
+            </table>
+        </div>
+    }
+}
@@ -2,7 +2,9 @@ use web_sys::HtmlInputElement;
 use yew::{Callback, Html, Properties, TargetCast, classes, function_component, html, use_effect_with, use_state};
 
 use super::BytesFormat;
-use crate::common::{BYTES_FORMATS, encode_bytes, get_format_button_class, get_set_format_callback, parse_bytes};
+use crate::common::{
+    BYTES_FORMATS, encode_bytes, get_format_button_class, get_set_format_callback, is_pem, parse_bytes,
+};
 
 #[derive(PartialEq, Properties, Clone)]
 pub struct ByteInputProps {
@@ -14,6 +16,9 @@ pub struct ByteInputProps {
     pub setter: Callback<Vec<u8>>,
     #[prop_or(2)]
     pub rows: u16,
+    /// Switches to the ascii format when the PEM armor is pasted.
+    #[prop_or_default]
+    pub detect_pem: bool,
 }
 
 #[function_component(ByteInput)]
@@ -24,6 +29,7 @@ pub fn byte_input(props: &ByteInputProps) -> Html {
         setter,
         placeholder,
         rows,
+        detect_pem,
     } = &props;
 
     let raw_value = use_state(|| encode_bytes(bytes, *format));
@@ -53,11 +59,22 @@ pub fn byte_input(props: &ByteInputProps) -> Html {
     let setter = setter.clone();
     let raw_value_setter = raw_value.setter();
     let format = *bytes_format;
+    let format_setter = bytes_format.setter();
+    let detect_pem = *detect_pem;
     let set_is_valid = is_valid.setter();
     let oninput = Callback::from(move |event: html::oninput::Event| {
         let input: HtmlInputElement = event.target_unchecked_into();
         let value = input.value();
 
+        if detect_pem && format != BytesFormat::Ascii && is_pem(&value) {
+            format_setter.set(BytesFormat::Ascii);
+            setter.emit(value.as_bytes().to_vec());
+            set_is_valid.set(true);
+            raw_value_setter.set(value);
+
+            return;
+        }
+
         match parse_bytes(&value, format) {
             Ok(bytes) => {
                 setter.emit(bytes);
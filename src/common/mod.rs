@@ -2,15 +2,19 @@ mod byte_input;
 mod bytes_viewer;
 mod checkbox;
 mod loader;
+pub mod ntlm;
+mod pem;
 mod rc_slice;
 mod simple_output;
 mod switch;
 mod table;
+pub mod zlib;
 
 use base64::Engine;
 pub use byte_input::{ByteInput, build_byte_input};
 pub use checkbox::Checkbox;
 pub use loader::Loader;
+pub use pem::{PemArmor, PemBlock, is_pem, parse_pem};
 pub use rc_slice::RcSlice;
 pub use simple_output::build_simple_output;
 pub use switch::Switch;
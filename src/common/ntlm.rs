@@ -0,0 +1,600 @@
+//! NTLM messages parsing (MS-NLMP 2.2.1).
+
+use md4::{Digest, Md4};
+use time::OffsetDateTime;
+
+pub const NTLM_SIGNATURE: &[u8; 8] = b"NTLMSSP\0";
+
+const NEGOTIATE_MESSAGE: u32 = 1;
+const CHALLENGE_MESSAGE: u32 = 2;
+const AUTHENTICATE_MESSAGE: u32 = 3;
+
+const NEGOTIATE_UNICODE: u32 = 0x0000_0001;
+const NEGOTIATE_VERSION: u32 = 0x0200_0000;
+
+const CHALLENGE_TARGET_INFO_OFFSET: usize = 40;
+const CHALLENGE_VERSION_OFFSET: usize = 48;
+
+/// AUTHENTICATE message header size without the MIC field.
+pub const AUTHENTICATE_HEADER_LEN: usize = 72;
+pub const MIC_LEN: usize = 16;
+/// NTLMv1 responses are always 24 bytes long.
+const NTLM_V1_RESPONSE_LEN: usize = 24;
+const NT_PROOF_STR_LEN: usize = 16;
+
+/// Difference between the FILETIME epoch (1601-01-01) and the unix epoch in seconds.
+const FILETIME_UNIX_EPOCH_DIFF: i64 = 11_644_473_600;
+const FILETIME_TICKS_PER_SECOND: i64 = 10_000_000;
+
+const NEGOTIATE_FLAGS: [(u32, &str); 22] = [
+    (0x0000_0001, "NEGOTIATE_UNICODE"),
+    (0x0000_0002, "NEGOTIATE_OEM"),
+    (0x0000_0004, "REQUEST_TARGET"),
+    (0x0000_0010, "NEGOTIATE_SIGN"),
+    (0x0000_0020, "NEGOTIATE_SEAL"),
+    (0x0000_0040, "NEGOTIATE_DATAGRAM"),
+    (0x0000_0080, "NEGOTIATE_LM_KEY"),
+    (0x0000_0200, "NEGOTIATE_NTLM"),
+    (0x0000_0800, "ANONYMOUS"),
+    (0x0000_1000, "NEGOTIATE_OEM_DOMAIN_SUPPLIED"),
+    (0x0000_2000, "NEGOTIATE_OEM_WORKSTATION_SUPPLIED"),
+    (0x0000_8000, "NEGOTIATE_ALWAYS_SIGN"),
+    (0x0001_0000, "TARGET_TYPE_DOMAIN"),
+    (0x0002_0000, "TARGET_TYPE_SERVER"),
+    (0x0008_0000, "NEGOTIATE_EXTENDED_SESSIONSECURITY"),
+    (0x0010_0000, "NEGOTIATE_IDENTIFY"),
+    (0x0040_0000, "REQUEST_NON_NT_SESSION_KEY"),
+    (0x0080_0000, "NEGOTIATE_TARGET_INFO"),
+    (0x0200_0000, "NEGOTIATE_VERSION"),
+    (0x2000_0000, "NEGOTIATE_128"),
+    (0x4000_0000, "NEGOTIATE_KEY_EXCH"),
+    (0x8000_0000, "NEGOTIATE_56"),
+];
+
+/// Returns names of the set negotiate flags.
+pub fn flag_names(flags: u32) -> Vec<&'static str> {
+    NEGOTIATE_FLAGS
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Checks if the data starts with the NTLM signature.
+pub fn is_ntlm(data: &[u8]) -> bool {
+    data.starts_with(NTLM_SIGNATURE)
+}
+
+/// Converts the FILETIME (100-nanosecond intervals since 1601-01-01) to the date time.
+pub fn filetime_to_date_time(filetime: u64) -> Option<OffsetDateTime> {
+    let filetime = i64::try_from(filetime).ok()?;
+
+    OffsetDateTime::from_unix_timestamp(filetime / FILETIME_TICKS_PER_SECOND - FILETIME_UNIX_EPOCH_DIFF).ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NtlmVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub build: u16,
+    pub revision: u8,
+}
+
+impl std::fmt::Display for NtlmVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{} (NTLM revision {})",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
+
+/// MsvAvTimestamp AV_PAIR id (MS-NLMP 2.2.2.1).
+pub const MSV_AV_TIMESTAMP: u16 = 7;
+
+/// AV_PAIR (MS-NLMP 2.2.2.1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvPair {
+    pub id: u16,
+    pub value: Vec<u8>,
+}
+
+impl AvPair {
+    pub fn name(&self) -> &'static str {
+        match self.id {
+            0 => "MsvAvEOL",
+            1 => "MsvAvNbComputerName",
+            2 => "MsvAvNbDomainName",
+            3 => "MsvAvDnsComputerName",
+            4 => "MsvAvDnsDomainName",
+            5 => "MsvAvDnsTreeName",
+            6 => "MsvAvFlags",
+            7 => "MsvAvTimestamp",
+            8 => "MsvAvSingleHost",
+            9 => "MsvAvTargetName",
+            10 => "MsvAvChannelBindings",
+            _ => "Unknown",
+        }
+    }
+
+    pub fn display_value(&self) -> String {
+        match self.id {
+            1..=5 | 9 => utf16_string(&self.value),
+            6 if self.value.len() == 4 => {
+                format!("0x{:08x}", u32::from_le_bytes(self.value[..4].try_into().unwrap()))
+            }
+            7 if self.value.len() == 8 => {
+                let filetime = u64::from_le_bytes(self.value[..8].try_into().unwrap());
+                match filetime_to_date_time(filetime) {
+                    Some(date_time) => format!("{} ({})", date_time, filetime),
+                    None => filetime.to_string(),
+                }
+            }
+            _ => hex::encode(&self.value),
+        }
+    }
+}
+
+/// NTLMv2_RESPONSE (MS-NLMP 2.2.2.8).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NtlmV2Response {
+    pub nt_proof_str: Vec<u8>,
+    pub timestamp: u64,
+    pub client_challenge: Vec<u8>,
+    pub av_pairs: Vec<AvPair>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NtChallengeResponse {
+    V1(Vec<u8>),
+    V2(NtlmV2Response),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiateMessage {
+    pub flags: u32,
+    pub domain: String,
+    pub workstation: String,
+    pub version: Option<NtlmVersion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeMessage {
+    pub flags: u32,
+    pub target_name: String,
+    pub server_challenge: Vec<u8>,
+    pub target_info: Vec<AvPair>,
+    pub version: Option<NtlmVersion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticateMessage {
+    pub flags: u32,
+    pub lm_challenge_response: Vec<u8>,
+    pub nt_challenge_response: NtChallengeResponse,
+    pub domain: String,
+    pub user: String,
+    pub workstation: String,
+    pub encrypted_random_session_key: Vec<u8>,
+    pub version: Option<NtlmVersion>,
+    pub mic: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NtlmMessage {
+    Negotiate(NegotiateMessage),
+    Challenge(ChallengeMessage),
+    Authenticate(AuthenticateMessage),
+}
+
+impl NtlmMessage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NtlmMessage::Negotiate(_) => "NEGOTIATE_MESSAGE",
+            NtlmMessage::Challenge(_) => "CHALLENGE_MESSAGE",
+            NtlmMessage::Authenticate(_) => "AUTHENTICATE_MESSAGE",
+        }
+    }
+
+    pub fn flags(&self) -> u32 {
+        match self {
+            NtlmMessage::Negotiate(message) => message.flags,
+            NtlmMessage::Challenge(message) => message.flags,
+            NtlmMessage::Authenticate(message) => message.flags,
+        }
+    }
+
+    /// Returns the message fields in a human-readable form.
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = vec![(
+            "NegotiateFlags".to_owned(),
+            format!("0x{:08x}: {}", self.flags(), flag_names(self.flags()).join(" | ")),
+        )];
+
+        let version = match self {
+            NtlmMessage::Negotiate(message) => {
+                fields.push(("DomainName".to_owned(), message.domain.clone()));
+                fields.push(("Workstation".to_owned(), message.workstation.clone()));
+
+                message.version
+            }
+            NtlmMessage::Challenge(message) => {
+                fields.push(("TargetName".to_owned(), message.target_name.clone()));
+                fields.push(("ServerChallenge".to_owned(), hex::encode(&message.server_challenge)));
+                push_av_pairs("TargetInfo", &message.target_info, &mut fields);
+
+                message.version
+            }
+            NtlmMessage::Authenticate(message) => {
+                fields.push(("DomainName".to_owned(), message.domain.clone()));
+                fields.push(("UserName".to_owned(), message.user.clone()));
+                fields.push(("Workstation".to_owned(), message.workstation.clone()));
+                fields.push((
+                    "LmChallengeResponse".to_owned(),
+                    hex::encode(&message.lm_challenge_response),
+                ));
+
+                match &message.nt_challenge_response {
+                    NtChallengeResponse::V1(response) => {
+                        fields.push(("NtChallengeResponse (NTLMv1)".to_owned(), hex::encode(response)));
+                    }
+                    NtChallengeResponse::V2(response) => {
+                        fields.push(("NTProofStr".to_owned(), hex::encode(&response.nt_proof_str)));
+                        fields.push((
+                            "TimeStamp".to_owned(),
+                            match filetime_to_date_time(response.timestamp) {
+                                Some(date_time) => format!("{} ({})", date_time, response.timestamp),
+                                None => response.timestamp.to_string(),
+                            },
+                        ));
+                        fields.push((
+                            "ChallengeFromClient".to_owned(),
+                            hex::encode(&response.client_challenge),
+                        ));
+                        push_av_pairs("AvPairs", &response.av_pairs, &mut fields);
+                    }
+                }
+
+                fields.push((
+                    "EncryptedRandomSessionKey".to_owned(),
+                    hex::encode(&message.encrypted_random_session_key),
+                ));
+                if let Some(mic) = &message.mic {
+                    fields.push(("MIC".to_owned(), hex::encode(mic)));
+                }
+
+                message.version
+            }
+        };
+
+        if let Some(version) = version {
+            fields.push(("Version".to_owned(), version.to_string()));
+        }
+
+        fields
+    }
+}
+
+fn push_av_pairs(name: &str, av_pairs: &[AvPair], fields: &mut Vec<(String, String)>) {
+    fields.extend(
+        av_pairs
+            .iter()
+            .filter(|av_pair| av_pair.id != 0)
+            .map(|av_pair| (format!("{}: {}", name, av_pair.name()), av_pair.display_value())),
+    );
+}
+
+fn utf16_string(data: &[u8]) -> String {
+    String::from_utf16_lossy(
+        &data
+            .chunks_exact(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn read_bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], String> {
+    data.get(offset..offset + len)
+        .ok_or_else(|| format!("NTLM message is too short: expected at least {} bytes", offset + len))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, String> {
+    Ok(u16::from_le_bytes(read_bytes(data, offset, 2)?.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, String> {
+    Ok(u32::from_le_bytes(read_bytes(data, offset, 4)?.try_into().unwrap()))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, String> {
+    Ok(u64::from_le_bytes(read_bytes(data, offset, 8)?.try_into().unwrap()))
+}
+
+/// Reads the payload field referenced by the (Len, MaxLen, BufferOffset) structure at the `offset`.
+fn read_field(data: &[u8], offset: usize) -> Result<&[u8], String> {
+    let len = usize::from(read_u16(data, offset)?);
+    let buffer_offset = read_u32(data, offset + 4)? as usize;
+
+    buffer_offset
+        .checked_add(len)
+        .and_then(|end| data.get(buffer_offset..end))
+        .ok_or_else(|| {
+            format!(
+                "NTLM field at {} points outside the message: offset {}, length {}",
+                offset, buffer_offset, len
+            )
+        })
+}
+
+/// Returns the payload offset of the field referenced at the `offset`, or `None` if the field is empty.
+fn field_offset(data: &[u8], offset: usize) -> Result<Option<usize>, String> {
+    if read_u16(data, offset)? == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(read_u32(data, offset + 4)? as usize))
+}
+
+fn read_string(data: &[u8], offset: usize, flags: u32) -> Result<String, String> {
+    let field = read_field(data, offset)?;
+
+    Ok(if flags & NEGOTIATE_UNICODE != 0 {
+        utf16_string(field)
+    } else {
+        String::from_utf8_lossy(field).into_owned()
+    })
+}
+
+fn read_version(data: &[u8], offset: usize, flags: u32) -> Result<Option<NtlmVersion>, String> {
+    if flags & NEGOTIATE_VERSION == 0 {
+        return Ok(None);
+    }
+
+    let version = read_bytes(data, offset, 8)?;
+
+    Ok(Some(NtlmVersion {
+        major: version[0],
+        minor: version[1],
+        build: u16::from_le_bytes([version[2], version[3]]),
+        revision: version[7],
+    }))
+}
+
+pub fn parse_av_pairs(mut data: &[u8]) -> Result<Vec<AvPair>, String> {
+    let mut av_pairs = Vec::new();
+
+    while !data.is_empty() {
+        let id = read_u16(data, 0)?;
+        let len = usize::from(read_u16(data, 2)?);
+        let value = read_bytes(data, 4, len)?.to_vec();
+
+        av_pairs.push(AvPair { id, value });
+        data = &data[4 + len..];
+
+        if id == 0 {
+            break;
+        }
+    }
+
+    Ok(av_pairs)
+}
+
+fn parse_nt_challenge_response(response: &[u8]) -> Result<NtChallengeResponse, String> {
+    if response.len() <= NTLM_V1_RESPONSE_LEN {
+        return Ok(NtChallengeResponse::V1(response.to_vec()));
+    }
+
+    // NTProofStr followed by NTLMv2_CLIENT_CHALLENGE: RespType, HiRespType, Reserved1, Reserved2, TimeStamp,
+    // ChallengeFromClient, Reserved3, AvPairs.
+    let client_challenge = &response[NT_PROOF_STR_LEN..];
+
+    Ok(NtChallengeResponse::V2(NtlmV2Response {
+        nt_proof_str: response[..NT_PROOF_STR_LEN].to_vec(),
+        timestamp: read_u64(client_challenge, 8)?,
+        client_challenge: read_bytes(client_challenge, 16, 8)?.to_vec(),
+        av_pairs: parse_av_pairs(client_challenge.get(28..).unwrap_or_default())?,
+    }))
+}
+
+/// Parses NEGOTIATE, CHALLENGE, or AUTHENTICATE message.
+pub fn parse_ntlm(data: &[u8]) -> Result<NtlmMessage, String> {
+    if !is_ntlm(data) {
+        return Err("Invalid NTLM signature".into());
+    }
+
+    match read_u32(data, 8)? {
+        NEGOTIATE_MESSAGE => {
+            let flags = read_u32(data, 12)?;
+
+            Ok(NtlmMessage::Negotiate(NegotiateMessage {
+                flags,
+                domain: read_string(data, 16, 0)?,
+                workstation: read_string(data, 24, 0)?,
+                version: read_version(data, 32, flags)?,
+            }))
+        }
+        CHALLENGE_MESSAGE => {
+            let flags = read_u32(data, 20)?;
+
+            // Older servers send the shorter header without the TargetInfo and Version fields. The payload
+            // (if any) starts right after the header.
+            let header_len = field_offset(data, 12)?.unwrap_or(data.len());
+            let target_info = if header_len >= CHALLENGE_TARGET_INFO_OFFSET + 8 {
+                parse_av_pairs(read_field(data, CHALLENGE_TARGET_INFO_OFFSET)?)?
+            } else {
+                Vec::new()
+            };
+            let version = if header_len >= CHALLENGE_VERSION_OFFSET + 8 {
+                read_version(data, CHALLENGE_VERSION_OFFSET, flags)?
+            } else {
+                None
+            };
+
+            Ok(NtlmMessage::Challenge(ChallengeMessage {
+                flags,
+                target_name: read_string(data, 12, flags)?,
+                server_challenge: read_bytes(data, 24, 8)?.to_vec(),
+                target_info,
+                version,
+            }))
+        }
+        AUTHENTICATE_MESSAGE => {
+            let flags = read_u32(data, 60)?;
+
+            // The MIC field is present only if the payload starts after it.
+            let payload_start = [12, 20, 28, 36, 44, 52]
+                .into_iter()
+                .map(|offset| field_offset(data, offset))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten()
+                .min()
+                .unwrap_or(data.len());
+            let mic = if payload_start >= AUTHENTICATE_HEADER_LEN + MIC_LEN {
+                Some(read_bytes(data, AUTHENTICATE_HEADER_LEN, MIC_LEN)?.to_vec())
+            } else {
+                None
+            };
+
+            Ok(NtlmMessage::Authenticate(AuthenticateMessage {
+                flags,
+                lm_challenge_response: read_field(data, 12)?.to_vec(),
+                nt_challenge_response: parse_nt_challenge_response(read_field(data, 20)?)?,
+                domain: read_string(data, 28, flags)?,
+                user: read_string(data, 36, flags)?,
+                workstation: read_string(data, 44, flags)?,
+                encrypted_random_session_key: read_field(data, 52)?.to_vec(),
+                version: read_version(data, 64, flags)?,
+                mic,
+            }))
+        }
+        message_type => Err(format!("Unknown NTLM message type: {}", message_type)),
+    }
+}
+
+/// NTOWFv1: MD4 hash of the UTF-16LE encoded password (MS-NLMP 3.3.1).
+pub fn nt_hash(password: &str) -> [u8; 16] {
+    Md4::digest(
+        password
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes())
+            .collect::<Vec<_>>(),
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AvPair, NtChallengeResponse, NtlmMessage, flag_names, nt_hash, parse_ntlm};
+
+    // MS-NLMP 4.2.2.1.2 NTOWFv1.
+    #[test]
+    fn ntowf_v1() {
+        assert_eq!("a4f49c406510bdcab6824ee7c30fd852", hex::encode(nt_hash("Password")));
+    }
+
+    // MS-NLMP 4.2.4.3 CHALLENGE_MESSAGE.
+    const CHALLENGE: &str = "4e544c4d53535000020000000c000c003800000033828ae20123456789abcdef00000000000000002400240044000000060070170000000f53006500720076006500720002000c0044006f006d00610069006e0001000c0053006500720076006500720000000000";
+
+    #[test]
+    fn challenge_message() {
+        let NtlmMessage::Challenge(challenge) = parse_ntlm(&hex::decode(CHALLENGE).unwrap()).unwrap() else {
+            panic!("expected CHALLENGE_MESSAGE");
+        };
+
+        assert_eq!("Server", challenge.target_name);
+        assert_eq!(hex::decode("0123456789abcdef").unwrap(), challenge.server_challenge);
+        assert_eq!(
+            vec![
+                AvPair {
+                    id: 2,
+                    value: "Domain".encode_utf16().flat_map(|c| c.to_le_bytes()).collect(),
+                },
+                AvPair {
+                    id: 1,
+                    value: "Server".encode_utf16().flat_map(|c| c.to_le_bytes()).collect(),
+                },
+                AvPair { id: 0, value: vec![] },
+            ],
+            challenge.target_info
+        );
+        assert!(flag_names(challenge.flags).contains(&"NEGOTIATE_KEY_EXCH"));
+        assert_eq!(6, challenge.version.unwrap().major);
+    }
+
+    #[test]
+    fn short_challenge_message() {
+        // The header ends right after the ServerChallenge: no TargetInfo and Version fields.
+        let mut message = b"NTLMSSP\0".to_vec();
+        message.extend_from_slice(&2_u32.to_le_bytes());
+        message.extend_from_slice(&[4, 0, 4, 0, 32, 0, 0, 0]);
+        message.extend_from_slice(&0x0200_0001_u32.to_le_bytes());
+        message.extend_from_slice(&[0x11; 8]);
+        message.extend_from_slice(&[b'a', 0, b'b', 0]);
+
+        let NtlmMessage::Challenge(challenge) = parse_ntlm(&message).unwrap() else {
+            panic!("expected CHALLENGE_MESSAGE");
+        };
+
+        assert_eq!("ab", challenge.target_name);
+        assert!(challenge.target_info.is_empty());
+        assert_eq!(None, challenge.version);
+    }
+
+    #[test]
+    fn field_outside_message() {
+        let mut message = hex::decode(CHALLENGE).unwrap();
+        // TargetInfoFields.BufferOffset
+        message[44..48].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(parse_ntlm(&message).unwrap_err().contains("points outside the message"));
+    }
+
+    #[test]
+    fn authenticate_message_with_mic() {
+        let mut nt_response = vec![0xaa; 16];
+        nt_response.extend_from_slice(&[1, 1, 0, 0, 0, 0, 0, 0]);
+        nt_response.extend_from_slice(&0x01d0_0000_0000_0000_u64.to_le_bytes());
+        nt_response.extend_from_slice(&[0xbb; 8]);
+        nt_response.extend_from_slice(&[0; 4]);
+        nt_response.extend_from_slice(&[0x06, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        let user = "user".encode_utf16().flat_map(|c| c.to_le_bytes()).collect::<Vec<_>>();
+
+        let payload_offset = 88_u32;
+        let mut message = b"NTLMSSP\0".to_vec();
+        message.extend_from_slice(&3_u32.to_le_bytes());
+        let fields = [
+            (0, payload_offset),                                            // LmChallengeResponse
+            (nt_response.len() as u16, payload_offset),                     // NtChallengeResponse
+            (0, payload_offset),                                            // DomainName
+            (user.len() as u16, payload_offset + nt_response.len() as u32), // UserName
+            (0, payload_offset),                                            // Workstation
+            (0, payload_offset),                                            // EncryptedRandomSessionKey
+        ];
+        for (len, offset) in fields {
+            message.extend_from_slice(&len.to_le_bytes());
+            message.extend_from_slice(&len.to_le_bytes());
+            message.extend_from_slice(&offset.to_le_bytes());
+        }
+        message.extend_from_slice(&0x0000_0001_u32.to_le_bytes());
+        message.extend_from_slice(&[0; 8]);
+        message.extend_from_slice(&[0xcc; 16]);
+        message.extend_from_slice(&nt_response);
+        message.extend_from_slice(&user);
+
+        let NtlmMessage::Authenticate(authenticate) = parse_ntlm(&message).unwrap() else {
+            panic!("expected AUTHENTICATE_MESSAGE");
+        };
+
+        assert_eq!("user", authenticate.user);
+        assert_eq!(Some(vec![0xcc; 16]), authenticate.mic);
+        let NtChallengeResponse::V2(response) = authenticate.nt_challenge_response else {
+            panic!("expected NTLMv2 response");
+        };
+        assert_eq!(vec![0xaa; 16], response.nt_proof_str);
+        assert_eq!(vec![0xbb; 8], response.client_challenge);
+        assert_eq!(2, response.av_pairs.len());
+    }
+}
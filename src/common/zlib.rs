@@ -0,0 +1,50 @@
+use std::io::Read;
+
+use flate2::read::ZlibDecoder;
+
+/// Larger decompressed zlib streams are rejected, so a small zlib bomb can not exhaust the memory.
+pub const MAX_ZLIB_DECOMPRESSED_LEN: u64 = 1024 * 1024;
+
+/// Decompresses the zlib stream. Streams longer than [MAX_ZLIB_DECOMPRESSED_LEN] after decompression are rejected.
+pub fn zlib_decompress(data: &[u8]) -> Result<Vec<u8>, String> {
+    // One extra byte tells the stream that exceeds the limit apart from the stream that fits exactly.
+    let mut decompressed = Vec::new();
+    let decompressed_len = ZlibDecoder::new(data)
+        .take(MAX_ZLIB_DECOMPRESSED_LEN + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(|err| format!("Can not decompress the input data: {:?}", err))?;
+
+    if decompressed_len as u64 > MAX_ZLIB_DECOMPRESSED_LEN {
+        return Err(format!(
+            "Decompressed data is longer than {} bytes",
+            MAX_ZLIB_DECOMPRESSED_LEN
+        ));
+    }
+
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::Compression;
+    use flate2::write::ZlibEncoder;
+
+    use super::{MAX_ZLIB_DECOMPRESSED_LEN, zlib_decompress};
+
+    fn compress(data: &[u8]) -> Vec<u8> {
+        let mut compressor = ZlibEncoder::new(Vec::new(), Compression::best());
+        compressor.write_all(data).unwrap();
+        compressor.finish().unwrap()
+    }
+
+    #[test]
+    fn decompress_limit() {
+        let data = vec![0; MAX_ZLIB_DECOMPRESSED_LEN as usize];
+        assert_eq!(data, zlib_decompress(&compress(&data)).unwrap());
+
+        let bomb = compress(&vec![0; MAX_ZLIB_DECOMPRESSED_LEN as usize + 1]);
+        assert!(zlib_decompress(&bomb).is_err());
+    }
+}
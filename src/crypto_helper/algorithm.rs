@@ -20,8 +20,20 @@ pub const BCRYPT: &str = "BCRYPT";
 pub const ZLIB: &str = "ZLIB";
 pub const ARGON2: &str = "ARGON2";
 pub const HMAC_SHA: &str = "HMAC-SHA";
-
-pub const SUPPORTED_ALGORITHMS: [&str; 14] = [
+pub const AES: &str = "AES";
+pub const CHACHA20: &str = "CHACHA20";
+pub const CHACHA20_POLY1305: &str = "CHACHA20-POLY1305";
+pub const XCHACHA20_POLY1305: &str = "XCHACHA20-POLY1305";
+pub const KRB_CIPHER: &str = "KRB-CIPHER";
+pub const KRB_CHECKSUM: &str = "KRB-CHECKSUM";
+pub const KRB_KEY_DERIVATION: &str = "KRB-KEY-DERIVATION";
+pub const KEYTAB: &str = "KEYTAB";
+pub const KRB_CREDENTIALS: &str = "KRB-CREDENTIALS";
+pub const KRB_PAC: &str = "KRB-PAC";
+pub const KRB_GSS_TOKEN: &str = "KRB-GSS-TOKEN";
+pub const NTLM: &str = "NTLM";
+
+pub const SUPPORTED_ALGORITHMS: [&str; 26] = [
     MD5,
     SHA1,
     SHA256,
@@ -36,18 +48,44 @@ pub const SUPPORTED_ALGORITHMS: [&str; 14] = [
     ZLIB,
     ARGON2,
     HMAC_SHA,
+    AES,
+    CHACHA20,
+    CHACHA20_POLY1305,
+    XCHACHA20_POLY1305,
+    KRB_CIPHER,
+    KRB_CHECKSUM,
+    KRB_KEY_DERIVATION,
+    KEYTAB,
+    KRB_CREDENTIALS,
+    KRB_PAC,
+    KRB_GSS_TOKEN,
+    NTLM,
 ];
 
 pub const HASHING_ALGOS: [&str; 7] = [MD5, SHA1, SHA256, SHA384, SHA512, BCRYPT, ARGON2];
 
-pub const ENCRYPTION_ALGOS: [&str; 3] = [AES128_CTS_HMAC_SHA1_96, AES256_CTS_HMAC_SHA1_96, RSA];
+pub const ENCRYPTION_ALGOS: [&str; 9] = [
+    AES128_CTS_HMAC_SHA1_96,
+    AES256_CTS_HMAC_SHA1_96,
+    RSA,
+    AES,
+    CHACHA20,
+    CHACHA20_POLY1305,
+    XCHACHA20_POLY1305,
+    KRB_CIPHER,
+    KRB_KEY_DERIVATION,
+];
 
-pub const HMAC_ALGOS: [&str; 3] = [HMAC_SHA1_96_AES128, HMAC_SHA1_96_AES256, HMAC_SHA];
+pub const HMAC_ALGOS: [&str; 4] = [HMAC_SHA1_96_AES128, HMAC_SHA1_96_AES256, HMAC_SHA, KRB_CHECKSUM];
 
 pub const HMAC_HASH_ALGOS: [&str; 3] = [SHA256, SHA384, SHA512];
 
 pub const COMPRESSION_ALGOS: [&str; 1] = [ZLIB];
 
+pub const KERBEROS_ALGOS: [&str; 4] = [KEYTAB, KRB_CREDENTIALS, KRB_PAC, KRB_GSS_TOKEN];
+
+pub const NTLM_ALGOS: [&str; 1] = [NTLM];
+
 const RSA_ACTIONS: [&str; 4] = ["Sign", "Verify", "Encrypt", "Decrypt"];
 
 pub const RSA_HASH_MD5: &str = "MD5";
@@ -110,6 +148,335 @@ pub struct KrbInput {
     pub data: KrbInputData,
 }
 
+/// Kerberos encryption types (RFC 3961 8, RFC 4757, RFC 8009).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum KrbEtype {
+    Des3CbcSha1Kd,
+    Aes128CtsHmacSha196,
+    #[default]
+    Aes256CtsHmacSha196,
+    Aes128CtsHmacSha256128,
+    Aes256CtsHmacSha384192,
+    Rc4Hmac,
+}
+
+pub const KRB_ETYPES: [KrbEtype; 6] = [
+    KrbEtype::Des3CbcSha1Kd,
+    KrbEtype::Aes128CtsHmacSha196,
+    KrbEtype::Aes256CtsHmacSha196,
+    KrbEtype::Aes128CtsHmacSha256128,
+    KrbEtype::Aes256CtsHmacSha384192,
+    KrbEtype::Rc4Hmac,
+];
+
+impl KrbEtype {
+    pub fn number(self) -> i32 {
+        match self {
+            KrbEtype::Des3CbcSha1Kd => 16,
+            KrbEtype::Aes128CtsHmacSha196 => 17,
+            KrbEtype::Aes256CtsHmacSha196 => 18,
+            KrbEtype::Aes128CtsHmacSha256128 => 19,
+            KrbEtype::Aes256CtsHmacSha384192 => 20,
+            KrbEtype::Rc4Hmac => 23,
+        }
+    }
+
+    /// Name of the checksum type used with this encryption type.
+    pub fn checksum_name(self) -> &'static str {
+        match self {
+            KrbEtype::Des3CbcSha1Kd => "hmac-sha1-des3-kd",
+            KrbEtype::Aes128CtsHmacSha196 => "hmac-sha1-96-aes128",
+            KrbEtype::Aes256CtsHmacSha196 => "hmac-sha1-96-aes256",
+            KrbEtype::Aes128CtsHmacSha256128 => "hmac-sha256-128-aes128",
+            KrbEtype::Aes256CtsHmacSha384192 => "hmac-sha384-192-aes256",
+            KrbEtype::Rc4Hmac => "hmac-md5",
+        }
+    }
+
+    /// Number of the checksum type used with this encryption type.
+    pub fn checksum_type(self) -> i32 {
+        match self {
+            KrbEtype::Des3CbcSha1Kd => 12,
+            KrbEtype::Aes128CtsHmacSha196 => 15,
+            KrbEtype::Aes256CtsHmacSha196 => 16,
+            KrbEtype::Aes128CtsHmacSha256128 => 19,
+            KrbEtype::Aes256CtsHmacSha384192 => 20,
+            KrbEtype::Rc4Hmac => -138,
+        }
+    }
+
+    /// Protocol key length in bytes.
+    pub fn key_len(self) -> usize {
+        match self {
+            KrbEtype::Des3CbcSha1Kd => 24,
+            KrbEtype::Aes128CtsHmacSha196 | KrbEtype::Aes128CtsHmacSha256128 | KrbEtype::Rc4Hmac => 16,
+            KrbEtype::Aes256CtsHmacSha196 | KrbEtype::Aes256CtsHmacSha384192 => 32,
+        }
+    }
+
+    /// Length of the integrity checksum in the cipher text.
+    pub fn hmac_len(self) -> usize {
+        match self {
+            KrbEtype::Des3CbcSha1Kd => 20,
+            KrbEtype::Aes128CtsHmacSha196 | KrbEtype::Aes256CtsHmacSha196 => 12,
+            KrbEtype::Aes128CtsHmacSha256128 | KrbEtype::Rc4Hmac => 16,
+            KrbEtype::Aes256CtsHmacSha384192 => 24,
+        }
+    }
+
+    /// Output length of the pseudo-random function (RFC 3961 3).
+    pub fn prf_len(self) -> usize {
+        match self {
+            KrbEtype::Des3CbcSha1Kd | KrbEtype::Aes128CtsHmacSha196 | KrbEtype::Aes256CtsHmacSha196 => 16,
+            KrbEtype::Rc4Hmac => 20,
+            KrbEtype::Aes128CtsHmacSha256128 => 32,
+            KrbEtype::Aes256CtsHmacSha384192 => 48,
+        }
+    }
+}
+
+impl AsRef<str> for KrbEtype {
+    fn as_ref(&self) -> &str {
+        match self {
+            KrbEtype::Des3CbcSha1Kd => "des3-cbc-sha1-kd",
+            KrbEtype::Aes128CtsHmacSha196 => "aes128-cts-hmac-sha1-96",
+            KrbEtype::Aes256CtsHmacSha196 => "aes256-cts-hmac-sha1-96",
+            KrbEtype::Aes128CtsHmacSha256128 => "aes128-cts-hmac-sha256-128",
+            KrbEtype::Aes256CtsHmacSha384192 => "aes256-cts-hmac-sha384-192",
+            KrbEtype::Rc4Hmac => "rc4-hmac",
+        }
+    }
+}
+
+impl TryFrom<i32> for KrbEtype {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        KRB_ETYPES
+            .iter()
+            .find(|etype| etype.number() == value)
+            .copied()
+            .ok_or_else(|| format!("krb: unsupported etype: {}", value))
+    }
+}
+
+/// Input for the Kerberos encryption and checksum with the selectable etype.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct KrbEtypeInput {
+    pub etype: KrbEtype,
+    pub input: KrbInput,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum KrbDerivationMode {
+    /// Password and salt to the protocol key.
+    #[default]
+    StringToKey,
+    /// Ke, Ki, and Kc for the key usage.
+    UsageKeys,
+    /// PRF+ from RFC 6113 5.1.
+    PrfPlus,
+    /// KRB-FX-CF2 from RFC 6113 5.1.
+    Cf2,
+}
+
+pub const KRB_DERIVATION_MODES: [KrbDerivationMode; 4] = [
+    KrbDerivationMode::StringToKey,
+    KrbDerivationMode::UsageKeys,
+    KrbDerivationMode::PrfPlus,
+    KrbDerivationMode::Cf2,
+];
+
+impl AsRef<str> for KrbDerivationMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            KrbDerivationMode::StringToKey => "string-to-key",
+            KrbDerivationMode::UsageKeys => "Ke/Ki/Kc",
+            KrbDerivationMode::PrfPlus => "PRF+",
+            KrbDerivationMode::Cf2 => "KRB-FX-CF2",
+        }
+    }
+}
+
+impl TryFrom<&str> for KrbDerivationMode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        KRB_DERIVATION_MODES
+            .iter()
+            .find(|mode| mode.as_ref() == value)
+            .copied()
+            .ok_or_else(|| format!("krb: unsupported key derivation mode: {}", value))
+    }
+}
+
+/// Input for the Kerberos key derivation explorer.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct KrbKeyDerivationInput {
+    pub etype: KrbEtype,
+    pub mode: KrbDerivationMode,
+    pub password: String,
+    pub realm: String,
+    /// Principal name. Components are separated by `/`.
+    pub principal: String,
+    /// Overrides the default salt (realm and principal name components) when not empty.
+    pub salt: String,
+    /// The etype default is used when not set.
+    pub iterations: Option<u32>,
+    /// Base key for Ke/Ki/Kc and PRF+, or the first key for KRB-FX-CF2.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key: Vec<u8>,
+    pub key_usage: i32,
+    /// The second KRB-FX-CF2 key.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key2: Vec<u8>,
+    /// PRF+ input or the first KRB-FX-CF2 pepper.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub pepper1: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub pepper2: Vec<u8>,
+    /// PRF+ output length in bytes. The etype PRF output length is used when not set.
+    pub prf_len: Option<usize>,
+}
+
+/// GSS-API Kerberos per-message token type (RFC 4121 4.2.6).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum GssTokenType {
+    #[default]
+    Wrap,
+    Mic,
+}
+
+impl From<GssTokenType> for bool {
+    fn from(token_type: GssTokenType) -> Self {
+        matches!(token_type, GssTokenType::Mic)
+    }
+}
+
+impl From<bool> for GssTokenType {
+    fn from(token_type: bool) -> Self {
+        match token_type {
+            true => GssTokenType::Mic,
+            false => GssTokenType::Wrap,
+        }
+    }
+}
+
+/// Input for the GSS-API Kerberos Wrap and MIC tokens (RFC 4121).
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct KrbGssTokenInput {
+    pub etype: KrbEtype,
+    /// Encrypt builds the token from the message. Decrypt parses the token.
+    pub mode: KrbMode,
+    pub token_type: GssTokenType,
+    /// Token flags. They are taken from the token header when the token is parsed.
+    pub acceptor: bool,
+    pub confidential: bool,
+    pub acceptor_subkey: bool,
+    pub seq_number: u64,
+    /// Filler length in the sealed Wrap token. The not sealed Wrap token has the checksum length in EC instead.
+    pub ec: u16,
+    /// Right rotation count of the Wrap token.
+    pub rrc: u16,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub message: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub token: Vec<u8>,
+}
+
+/// Input for the Kerberos credentials viewer and the PAC decoder.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct KrbDataKeyInput {
+    /// Credential cache file, KRB-CRED message, PAC, or the decrypted EncTicketPart.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub data: Vec<u8>,
+    /// KRB-CRED enc-part key or the service key for the PAC server checksum verification.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key: Vec<u8>,
+}
+
+/// NTLM challenge response version.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum NtlmResponseVersion {
+    V1,
+    #[default]
+    V2,
+}
+
+impl From<NtlmResponseVersion> for bool {
+    fn from(version: NtlmResponseVersion) -> Self {
+        matches!(version, NtlmResponseVersion::V2)
+    }
+}
+
+impl From<bool> for NtlmResponseVersion {
+    fn from(version: bool) -> Self {
+        match version {
+            true => NtlmResponseVersion::V2,
+            false => NtlmResponseVersion::V1,
+        }
+    }
+}
+
+/// Input for the NTLM calculator (MS-NLMP 3.3).
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct NtlmInput {
+    pub version: NtlmResponseVersion,
+    pub user: String,
+    pub domain: String,
+    pub password: String,
+    /// NTLMSSP_NEGOTIATE_EXTENDED_SESSIONSECURITY. NTLMv2 always uses it.
+    pub extended_session_security: bool,
+    /// NTLMSSP_NEGOTIATE_KEY_EXCH.
+    pub key_exchange: bool,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub server_challenge: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub client_challenge: Vec<u8>,
+    /// FILETIME of the NTLMv2 response.
+    pub timestamp: u64,
+    /// AV_PAIRs of the CHALLENGE_MESSAGE (NTLMv2 only).
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub target_info: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub encrypted_random_session_key: Vec<u8>,
+    /// The MIC is calculated when all three messages are provided.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub negotiate_message: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub challenge_message: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub authenticate_message: Vec<u8>,
+}
+
+impl NtlmInput {
+    /// MS-NLMP 4.2.4 NTLMv2 test vector.
+    pub fn ms_nlmp_preset() -> Self {
+        Self {
+            version: NtlmResponseVersion::V2,
+            user: "User".into(),
+            domain: "Domain".into(),
+            password: "Password".into(),
+            extended_session_security: true,
+            key_exchange: true,
+            server_challenge: vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef],
+            client_challenge: vec![0xaa; 8],
+            timestamp: 0,
+            target_info: vec![
+                0x02, 0x00, 0x0c, 0x00, 0x44, 0x00, 0x6f, 0x00, 0x6d, 0x00, 0x61, 0x00, 0x69, 0x00, 0x6e, 0x00, 0x01,
+                0x00, 0x0c, 0x00, 0x53, 0x00, 0x65, 0x00, 0x72, 0x00, 0x76, 0x00, 0x65, 0x00, 0x72, 0x00, 0x00, 0x00,
+                0x00, 0x00,
+            ],
+            encrypted_random_session_key: vec![
+                0xc5, 0xda, 0xd2, 0x54, 0x4f, 0xc9, 0x79, 0x90, 0x94, 0xce, 0x1c, 0xe9, 0x0b, 0xc9, 0xd0, 0x3e,
+            ],
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub struct RsaHashAlgorithm(pub HashAlgorithm);
 
@@ -609,6 +976,202 @@ impl Default for HmacShaInput {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum AesMode {
+    Ecb,
+    #[default]
+    Cbc,
+    Ctr,
+    Cfb,
+    Ofb,
+    Gcm,
+}
+
+pub const AES_MODES: [AesMode; 6] = [
+    AesMode::Ecb,
+    AesMode::Cbc,
+    AesMode::Ctr,
+    AesMode::Cfb,
+    AesMode::Ofb,
+    AesMode::Gcm,
+];
+
+impl AesMode {
+    /// Block modes encrypt whole blocks, so the data has to be padded.
+    pub fn needs_padding(self) -> bool {
+        matches!(self, AesMode::Ecb | AesMode::Cbc)
+    }
+}
+
+impl AsRef<str> for AesMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            AesMode::Ecb => "ECB",
+            AesMode::Cbc => "CBC",
+            AesMode::Ctr => "CTR",
+            AesMode::Cfb => "CFB",
+            AesMode::Ofb => "OFB",
+            AesMode::Gcm => "GCM",
+        }
+    }
+}
+
+impl TryFrom<&str> for AesMode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        AES_MODES
+            .iter()
+            .find(|mode| mode.as_ref() == value)
+            .copied()
+            .ok_or_else(|| format!("aes: unsupported mode: {}", value))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum AesPadding {
+    #[default]
+    Pkcs7,
+    None,
+    /// Pads with zero bytes. Ambiguous: unpadding also strips trailing zero bytes of the plaintext.
+    Zero,
+}
+
+pub const AES_PADDINGS: [AesPadding; 3] = [AesPadding::Pkcs7, AesPadding::None, AesPadding::Zero];
+
+impl AsRef<str> for AesPadding {
+    fn as_ref(&self) -> &str {
+        match self {
+            AesPadding::Pkcs7 => "PKCS#7",
+            AesPadding::None => "none",
+            AesPadding::Zero => "zero",
+        }
+    }
+}
+
+impl TryFrom<&str> for AesPadding {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        AES_PADDINGS
+            .iter()
+            .find(|padding| padding.as_ref() == value)
+            .copied()
+            .ok_or_else(|| format!("aes: unsupported padding: {}", value))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum CipherAction {
+    #[default]
+    Encrypt,
+    Decrypt,
+}
+
+impl From<CipherAction> for bool {
+    fn from(action: CipherAction) -> Self {
+        matches!(action, CipherAction::Decrypt)
+    }
+}
+
+impl From<bool> for CipherAction {
+    fn from(action: bool) -> Self {
+        if action {
+            CipherAction::Decrypt
+        } else {
+            CipherAction::Encrypt
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct AesInput {
+    pub mode: AesMode,
+    pub action: CipherAction,
+    pub padding: AesPadding,
+    /// 16, 24, or 32 bytes for AES-128, AES-192, and AES-256 respectively.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key: Vec<u8>,
+    /// IV for CBC, CFB, and OFB, the initial counter block for CTR, and the nonce for GCM.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub iv: Vec<u8>,
+    /// GCM additional authenticated data.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub aad: Vec<u8>,
+    /// GCM tag to verify during decryption.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub tag: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub payload: Vec<u8>,
+}
+
+/// Plaintext of the RFC 8439 test vectors.
+const SUNSCREEN: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct ChaCha20Input {
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub nonce: Vec<u8>,
+    /// Initial block counter.
+    pub counter: u32,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub payload: Vec<u8>,
+}
+
+impl ChaCha20Input {
+    /// RFC 8439 2.4.2 test vector.
+    pub fn rfc8439_preset() -> Self {
+        Self {
+            key: (0..32).collect(),
+            nonce: vec![0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0],
+            counter: 1,
+            payload: SUNSCREEN.to_vec(),
+        }
+    }
+}
+
+/// Input for the ChaCha20-Poly1305 and XChaCha20-Poly1305 AEADs.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub struct ChaChaPoly1305Input {
+    pub action: CipherAction,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub key: Vec<u8>,
+    /// 12 bytes for ChaCha20-Poly1305 and 24 bytes for XChaCha20-Poly1305.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub nonce: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub aad: Vec<u8>,
+    /// Tag to verify during decryption.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub tag: Vec<u8>,
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    pub payload: Vec<u8>,
+}
+
+impl ChaChaPoly1305Input {
+    /// RFC 8439 2.8.2 test vector.
+    pub fn rfc8439_preset() -> Self {
+        Self {
+            action: CipherAction::Encrypt,
+            key: (0x80..=0x9f).collect(),
+            nonce: vec![0x07, 0, 0, 0, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47],
+            aad: vec![0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7],
+            tag: Vec::new(),
+            payload: SUNSCREEN.to_vec(),
+        }
+    }
+
+    /// draft-irtf-cfrg-xchacha A.3.1 test vector.
+    pub fn xchacha_preset() -> Self {
+        Self {
+            nonce: (0x40..0x58).collect(),
+            ..Self::rfc8439_preset()
+        }
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum Algorithm {
@@ -631,6 +1194,20 @@ pub enum Algorithm {
     Zlib(ZlibInput),
     Argon2(Argon2Input),
     HmacSha(HmacShaInput),
+    Aes(AesInput),
+    ChaCha20(ChaCha20Input),
+    ChaCha20Poly1305(ChaChaPoly1305Input),
+    XChaCha20Poly1305(ChaChaPoly1305Input),
+    KrbCipher(KrbEtypeInput),
+    KrbChecksum(KrbEtypeInput),
+    KrbKeyDerivation(KrbKeyDerivationInput),
+    /// Keytab file data.
+    #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
+    Keytab(Vec<u8>),
+    KrbCredentials(KrbDataKeyInput),
+    KrbPac(KrbDataKeyInput),
+    KrbGssToken(KrbGssTokenInput),
+    Ntlm(NtlmInput),
 }
 
 impl TryFrom<&str> for Algorithm {
@@ -665,6 +1242,30 @@ impl TryFrom<&str> for Algorithm {
             return Ok(Algorithm::Argon2(Default::default()));
         } else if value == HMAC_SHA {
             return Ok(Algorithm::HmacSha(Default::default()));
+        } else if value == AES {
+            return Ok(Algorithm::Aes(Default::default()));
+        } else if value == CHACHA20 {
+            return Ok(Algorithm::ChaCha20(Default::default()));
+        } else if value == CHACHA20_POLY1305 {
+            return Ok(Algorithm::ChaCha20Poly1305(Default::default()));
+        } else if value == XCHACHA20_POLY1305 {
+            return Ok(Algorithm::XChaCha20Poly1305(Default::default()));
+        } else if value == KRB_CIPHER {
+            return Ok(Algorithm::KrbCipher(Default::default()));
+        } else if value == KRB_CHECKSUM {
+            return Ok(Algorithm::KrbChecksum(Default::default()));
+        } else if value == KRB_KEY_DERIVATION {
+            return Ok(Algorithm::KrbKeyDerivation(Default::default()));
+        } else if value == KEYTAB {
+            return Ok(Algorithm::Keytab(Default::default()));
+        } else if value == KRB_CREDENTIALS {
+            return Ok(Algorithm::KrbCredentials(Default::default()));
+        } else if value == KRB_PAC {
+            return Ok(Algorithm::KrbPac(Default::default()));
+        } else if value == KRB_GSS_TOKEN {
+            return Ok(Algorithm::KrbGssToken(Default::default()));
+        } else if value == NTLM {
+            return Ok(Algorithm::Ntlm(Default::default()));
         }
 
         Err(format!(
@@ -691,6 +1292,18 @@ impl From<&Algorithm> for &str {
             Algorithm::Zlib(_) => ZLIB,
             Algorithm::Argon2(_) => ARGON2,
             Algorithm::HmacSha(_) => HMAC_SHA,
+            Algorithm::Aes(_) => AES,
+            Algorithm::ChaCha20(_) => CHACHA20,
+            Algorithm::ChaCha20Poly1305(_) => CHACHA20_POLY1305,
+            Algorithm::XChaCha20Poly1305(_) => XCHACHA20_POLY1305,
+            Algorithm::KrbCipher(_) => KRB_CIPHER,
+            Algorithm::KrbChecksum(_) => KRB_CHECKSUM,
+            Algorithm::KrbKeyDerivation(_) => KRB_KEY_DERIVATION,
+            Algorithm::Keytab(_) => KEYTAB,
+            Algorithm::KrbCredentials(_) => KRB_CREDENTIALS,
+            Algorithm::KrbPac(_) => KRB_PAC,
+            Algorithm::KrbGssToken(_) => KRB_GSS_TOKEN,
+            Algorithm::Ntlm(_) => NTLM,
         }
     }
 }
@@ -0,0 +1,528 @@
+//! AES block cipher modes of operation (NIST SP 800-38A and SP 800-38D).
+
+use aes::cipher::array::{Array, ArraySize};
+use aes::cipher::consts::U16;
+use aes::cipher::typenum::U;
+use aes::cipher::{
+    Block, BlockCipherDecrypt, BlockCipherEncrypt, BlockModeDecrypt, BlockModeEncrypt, BlockSizeUser, KeyInit,
+    KeyIvInit, StreamCipher,
+};
+use aes::{Aes128, Aes192, Aes256};
+use aes_gcm::AesGcm;
+use aes_gcm::aead::AeadInOut;
+use cts::{Decrypt, Encrypt};
+
+use super::algorithm::{AesInput, AesMode, AesPadding, CipherAction};
+
+pub const AES_BLOCK_SIZE: usize = 16;
+pub const GCM_TAG_LEN: usize = 16;
+/// GCM tag lengths accepted for the verification (NIST SP 800-38D 5.2.1.2).
+fn is_valid_gcm_tag_len(len: usize) -> bool {
+    matches!(len, 4 | 8 | 12..=GCM_TAG_LEN)
+}
+
+/// AES-128, AES-192, or AES-256.
+trait Aes: BlockSizeUser<BlockSize = U16> + BlockCipherEncrypt + BlockCipherDecrypt + KeyInit {}
+
+impl<C: BlockSizeUser<BlockSize = U16> + BlockCipherEncrypt + BlockCipherDecrypt + KeyInit> Aes for C {}
+
+/// Calls the generic function with the AES variant matching the key length.
+macro_rules! with_aes {
+    ($key:expr, $f:ident($($arg:expr),*)) => {
+        match $key.len() {
+            16 => $f::<Aes128>($($arg),*),
+            24 => $f::<Aes192>($($arg),*),
+            32 => $f::<Aes256>($($arg),*),
+            len => Err(format!(
+                "aes: invalid key length: expected 16, 24, or 32 bytes but got {}",
+                len
+            )),
+        }
+    };
+}
+
+/// Calls `gcm` with the nonce size matching the IV length. `aes-gcm` takes the nonce size as a type parameter.
+macro_rules! gcm_with_nonce_size {
+    ($aes:ty, $iv:expr, $args:tt, [$($len:literal),*]) => {
+        match $iv.len() {
+            $($len => gcm::<$aes, U<$len>> $args,)*
+            0 => Err("aes-gcm: IV can not be empty".to_owned()),
+            len => Err(format!("aes-gcm: IV is too long: expected at most 64 bytes but got {}", len)),
+        }
+    };
+}
+
+fn invalid_iv(mode: AesMode, iv: &[u8]) -> String {
+    format!(
+        "aes-{}: invalid IV length: expected {} bytes but got {}",
+        mode.as_ref(),
+        AES_BLOCK_SIZE,
+        iv.len()
+    )
+}
+
+fn pad(mut data: Vec<u8>, padding: AesPadding) -> Result<Vec<u8>, String> {
+    match padding {
+        AesPadding::Pkcs7 => {
+            let pad_len = AES_BLOCK_SIZE - data.len() % AES_BLOCK_SIZE;
+            data.resize(data.len() + pad_len, pad_len as u8);
+        }
+        AesPadding::Zero => data.resize(data.len().div_ceil(AES_BLOCK_SIZE) * AES_BLOCK_SIZE, 0),
+        AesPadding::None => {
+            if !data.len().is_multiple_of(AES_BLOCK_SIZE) {
+                return Err(format!(
+                    "aes: the data length must be a multiple of {} bytes without padding but got {}",
+                    AES_BLOCK_SIZE,
+                    data.len()
+                ));
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+fn unpad(mut data: Vec<u8>, padding: AesPadding) -> Result<Vec<u8>, String> {
+    match padding {
+        AesPadding::Pkcs7 => {
+            let pad_len = usize::from(*data.last().ok_or("aes: can not remove PKCS#7 padding: empty data")?);
+
+            if pad_len == 0
+                || pad_len > AES_BLOCK_SIZE
+                || pad_len > data.len()
+                || data[data.len() - pad_len..]
+                    .iter()
+                    .any(|byte| usize::from(*byte) != pad_len)
+            {
+                return Err("aes: invalid PKCS#7 padding".to_owned());
+            }
+
+            data.truncate(data.len() - pad_len);
+        }
+        // Zero padding is ambiguous: trailing zero bytes of the plaintext itself are removed as well.
+        AesPadding::Zero => {
+            let len = data
+                .iter()
+                .rposition(|byte| *byte != 0)
+                .map(|i| i + 1)
+                .unwrap_or_default();
+            data.truncate(len);
+        }
+        AesPadding::None => {}
+    }
+
+    Ok(data)
+}
+
+fn blocks(data: &mut [u8]) -> &mut [Block<Aes128>] {
+    Array::slice_as_chunks_mut(data).0
+}
+
+/// The data must be padded to the block size.
+fn ecb<C: Aes>(key: &[u8], action: CipherAction, mut data: Vec<u8>) -> Result<Vec<u8>, String> {
+    let invalid_key = |_| format!("aes: invalid key length: {}", key.len());
+
+    match action {
+        CipherAction::Encrypt => ecb::Encryptor::<C>::new_from_slice(key)
+            .map_err(invalid_key)?
+            .encrypt_blocks(blocks(&mut data)),
+        CipherAction::Decrypt => ecb::Decryptor::<C>::new_from_slice(key)
+            .map_err(invalid_key)?
+            .decrypt_blocks(blocks(&mut data)),
+    }
+
+    Ok(data)
+}
+
+/// The data must be padded to the block size.
+fn cbc<C: Aes>(key: &[u8], action: CipherAction, iv: &[u8], mut data: Vec<u8>) -> Result<Vec<u8>, String> {
+    let invalid_iv = |_| invalid_iv(AesMode::Cbc, iv);
+
+    match action {
+        CipherAction::Encrypt => cbc::Encryptor::<C>::new_from_slices(key, iv)
+            .map_err(invalid_iv)?
+            .encrypt_blocks(blocks(&mut data)),
+        CipherAction::Decrypt => cbc::Decryptor::<C>::new_from_slices(key, iv)
+            .map_err(invalid_iv)?
+            .decrypt_blocks(blocks(&mut data)),
+    }
+
+    Ok(data)
+}
+
+/// CFB128.
+fn cfb<C: Aes>(key: &[u8], action: CipherAction, iv: &[u8], mut data: Vec<u8>) -> Result<Vec<u8>, String> {
+    let invalid_iv = |_| invalid_iv(AesMode::Cfb, iv);
+
+    match action {
+        CipherAction::Encrypt => cfb_mode::Encryptor::<C>::new_from_slices(key, iv)
+            .map_err(invalid_iv)?
+            .encrypt(&mut data),
+        CipherAction::Decrypt => cfb_mode::Decryptor::<C>::new_from_slices(key, iv)
+            .map_err(invalid_iv)?
+            .decrypt(&mut data),
+    }
+
+    Ok(data)
+}
+
+/// Xors the data with the key stream of the stream cipher mode.
+fn stream<M: KeyIvInit + StreamCipher>(
+    mode: AesMode,
+    key: &[u8],
+    iv: &[u8],
+    mut data: Vec<u8>,
+) -> Result<Vec<u8>, String> {
+    M::new_from_slices(key, iv)
+        .map_err(|_| invalid_iv(mode, iv))?
+        .apply_keystream(&mut data);
+
+    Ok(data)
+}
+
+/// Returns the cipher/plain text and the full-length tag of the cipher text.
+fn gcm<C: Aes, N: ArraySize>(
+    key: &[u8],
+    action: CipherAction,
+    iv: &[u8],
+    aad: &[u8],
+    data: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let cipher = AesGcm::<C, N>::new_from_slice(key).map_err(|_| format!("aes: invalid key length: {}", key.len()))?;
+    let nonce = Array::try_from(iv).map_err(|_| format!("aes-gcm: invalid IV length: {}", iv.len()))?;
+    let encrypt = |data: &mut [u8]| {
+        cipher
+            .encrypt_inout_detached(&nonce, aad, data.into())
+            .map_err(|_| "aes-gcm: the data is too long".to_owned())
+    };
+
+    // The encryption and decryption apply the same key stream.
+    let mut output = data.to_vec();
+    let tag = encrypt(&mut output)?;
+
+    match action {
+        CipherAction::Encrypt => Ok((output, tag.to_vec())),
+        CipherAction::Decrypt => {
+            // The tag above is calculated over the plain text. The plain text is encrypted back to get the tag
+            // of the input cipher text, so the truncated tags can be verified and the expected tag can be shown.
+            let tag = encrypt(&mut output.clone())?;
+
+            Ok((output, tag.to_vec()))
+        }
+    }
+}
+
+fn gcm_with_nonce_size<C: Aes>(
+    key: &[u8],
+    action: CipherAction,
+    iv: &[u8],
+    aad: &[u8],
+    data: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), String> {
+    gcm_with_nonce_size!(
+        C,
+        iv,
+        (key, action, iv, aad, data),
+        [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+            30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56,
+            57, 58, 59, 60, 61, 62, 63, 64
+        ]
+    )
+}
+
+/// CBC mode with ciphertext stealing (CBC-CS3) and zero IV as used by the Kerberos AES encryption types (RFC 3962 5).
+pub fn aes_cts(key: &[u8], action: CipherAction, data: &[u8]) -> Result<Vec<u8>, String> {
+    with_aes!(key, cbc_cs3(key, action, data))
+}
+
+fn cbc_cs3<C: Aes>(key: &[u8], action: CipherAction, data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() == AES_BLOCK_SIZE {
+        // RFC 3962 encrypts a single block without swapping, but `cts` steals from the empty last block.
+        return cbc::<C>(key, action, &[0; AES_BLOCK_SIZE], data.to_vec());
+    }
+
+    let mode = cts::CbcCs3::<C>::new_from_slices(key, &[0; AES_BLOCK_SIZE])
+        .map_err(|_| format!("aes: invalid key length: {}", key.len()))?;
+
+    let mut output = data.to_vec();
+    match action {
+        CipherAction::Encrypt => mode.encrypt(&mut output),
+        CipherAction::Decrypt => mode.decrypt(&mut output),
+    }
+    .map_err(|_| {
+        format!(
+            "aes-cts: input must be at least {} bytes long but got {}",
+            AES_BLOCK_SIZE,
+            data.len()
+        )
+    })?;
+
+    Ok(output)
+}
+
+/// Encrypts or decrypts the payload. The GCM tag is appended to the cipher text.
+pub fn process_aes(input: &AesInput) -> Result<Vec<u8>, String> {
+    with_aes!(input.key, process_aes_with(input))
+}
+
+fn process_aes_with<C: Aes>(input: &AesInput) -> Result<Vec<u8>, String> {
+    let AesInput {
+        mode,
+        action,
+        padding,
+        key,
+        iv,
+        aad,
+        tag,
+        payload,
+    } = input;
+
+    match mode {
+        AesMode::Ecb => match action {
+            CipherAction::Encrypt => ecb::<C>(key, *action, pad(payload.clone(), *padding)?),
+            CipherAction::Decrypt => unpad(
+                ecb::<C>(key, *action, pad(payload.clone(), AesPadding::None)?)?,
+                *padding,
+            ),
+        },
+        AesMode::Cbc => match action {
+            CipherAction::Encrypt => cbc::<C>(key, *action, iv, pad(payload.clone(), *padding)?),
+            CipherAction::Decrypt => unpad(
+                cbc::<C>(key, *action, iv, pad(payload.clone(), AesPadding::None)?)?,
+                *padding,
+            ),
+        },
+        AesMode::Ctr => stream::<ctr::Ctr128BE<C>>(*mode, key, iv, payload.clone()),
+        AesMode::Cfb => cfb::<C>(key, *action, iv, payload.clone()),
+        AesMode::Ofb => stream::<ofb::Ofb<C>>(*mode, key, iv, payload.clone()),
+        AesMode::Gcm => {
+            let (mut output, expected_tag) = gcm_with_nonce_size::<C>(key, *action, iv, aad, payload)?;
+
+            match action {
+                CipherAction::Encrypt => {
+                    output.extend_from_slice(&expected_tag);
+
+                    Ok(output)
+                }
+                CipherAction::Decrypt => {
+                    if !is_valid_gcm_tag_len(tag.len()) {
+                        return Err(format!(
+                            "aes-gcm: invalid tag length: expected 4, 8, or 12..={} bytes but got {}",
+                            GCM_TAG_LEN,
+                            tag.len()
+                        ));
+                    }
+
+                    if expected_tag[..tag.len()] != tag[..] {
+                        return Err(format!(
+                            "aes-gcm: tag mismatch: expected {} but got {}",
+                            hex::encode(&expected_tag[..tag.len()]),
+                            hex::encode(tag)
+                        ));
+                    }
+
+                    Ok(output)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aes_cts, process_aes};
+    use crate::crypto_helper::algorithm::{AesInput, AesMode, AesPadding, CipherAction};
+
+    const NIST_KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+    const NIST_IV: &str = "000102030405060708090a0b0c0d0e0f";
+    const NIST_PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51";
+
+    fn nist_input(mode: AesMode, iv: &str) -> AesInput {
+        AesInput {
+            mode,
+            padding: AesPadding::None,
+            key: hex::decode(NIST_KEY).unwrap(),
+            iv: hex::decode(iv).unwrap(),
+            payload: hex::decode(NIST_PLAINTEXT).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    /// NIST SP 800-38A F.1.1, F.2.1, F.3.13, F.4.1, and F.5.1.
+    #[test]
+    fn sp800_38a_vectors() {
+        let vectors = [
+            (
+                AesMode::Ecb,
+                NIST_IV,
+                "3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf",
+            ),
+            (
+                AesMode::Cbc,
+                NIST_IV,
+                "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2",
+            ),
+            (
+                AesMode::Cfb,
+                NIST_IV,
+                "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b",
+            ),
+            (
+                AesMode::Ofb,
+                NIST_IV,
+                "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed825",
+            ),
+            (
+                AesMode::Ctr,
+                "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+                "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff",
+            ),
+        ];
+
+        for (mode, iv, expected) in vectors {
+            let input = nist_input(mode, iv);
+            let cipher_text = process_aes(&input).unwrap();
+            assert_eq!(expected, hex::encode(&cipher_text), "{}", mode.as_ref());
+
+            let plain_text = process_aes(&AesInput {
+                action: CipherAction::Decrypt,
+                payload: cipher_text,
+                ..input
+            })
+            .unwrap();
+            assert_eq!(NIST_PLAINTEXT, hex::encode(plain_text), "{}", mode.as_ref());
+        }
+    }
+
+    /// GCM test cases 1 and 2 from the original GCM specification.
+    #[test]
+    fn gcm_vectors() {
+        let input = AesInput {
+            mode: AesMode::Gcm,
+            key: vec![0; 16],
+            iv: vec![0; 12],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "58e2fccefa7e3061367f1d57a4e7455a",
+            hex::encode(process_aes(&input).unwrap())
+        );
+        assert_eq!(
+            "0388dace60b6a392f328c2b971b2fe78ab6e47d42cec13bdf53a67b21257bddf",
+            hex::encode(
+                process_aes(&AesInput {
+                    payload: vec![0; 16],
+                    ..input
+                })
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn gcm_tag_verification() {
+        let input = AesInput {
+            mode: AesMode::Gcm,
+            key: vec![7; 32],
+            iv: vec![1; 20],
+            aad: b"header".to_vec(),
+            payload: b"crypto-helper".to_vec(),
+            ..Default::default()
+        };
+        let output = process_aes(&input).unwrap();
+        let (cipher_text, tag) = output.split_at(output.len() - 16);
+
+        let decrypt = |tag: &[u8]| {
+            process_aes(&AesInput {
+                action: CipherAction::Decrypt,
+                payload: cipher_text.to_vec(),
+                tag: tag.to_vec(),
+                ..input.clone()
+            })
+        };
+
+        assert_eq!(b"crypto-helper".to_vec(), decrypt(tag).unwrap());
+        assert_eq!(b"crypto-helper".to_vec(), decrypt(&tag[..12]).unwrap());
+        assert_eq!(b"crypto-helper".to_vec(), decrypt(&tag[..8]).unwrap());
+        assert_eq!(b"crypto-helper".to_vec(), decrypt(&tag[..4]).unwrap());
+        assert!(decrypt(&tag[..5]).unwrap_err().contains("invalid tag length"));
+        assert!(decrypt(&tag[..10]).unwrap_err().contains("invalid tag length"));
+        assert!(decrypt(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn paddings() {
+        let input = AesInput {
+            mode: AesMode::Cbc,
+            key: vec![1; 24],
+            iv: vec![2; 16],
+            payload: b"crypto".to_vec(),
+            ..Default::default()
+        };
+
+        for padding in [AesPadding::Pkcs7, AesPadding::Zero] {
+            let cipher_text = process_aes(&AesInput {
+                padding,
+                ..input.clone()
+            })
+            .unwrap();
+            assert_eq!(16, cipher_text.len());
+
+            let plain_text = process_aes(&AesInput {
+                padding,
+                action: CipherAction::Decrypt,
+                payload: cipher_text,
+                ..input.clone()
+            })
+            .unwrap();
+            assert_eq!(b"crypto".to_vec(), plain_text);
+        }
+
+        // Zero padding can not tell the plaintext trailing zeros from the padding.
+        let zero_input = AesInput {
+            padding: AesPadding::Zero,
+            payload: b"crypto\0\0".to_vec(),
+            ..input.clone()
+        };
+        let cipher_text = process_aes(&zero_input).unwrap();
+        let plain_text = process_aes(&AesInput {
+            action: CipherAction::Decrypt,
+            payload: cipher_text,
+            ..zero_input
+        })
+        .unwrap();
+        assert_eq!(b"crypto".to_vec(), plain_text);
+
+        assert!(
+            process_aes(&AesInput {
+                padding: AesPadding::None,
+                ..input
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn rfc3962_cts() {
+        let key = b"chicken teriyaki";
+        let plain = b"I would like the General Gau's Chicken, please, and wonton soup.";
+
+        for (len, expected) in [
+            (17, "c6353568f2bf8cb4d8a580362da7ff7f97"),
+            (32, "39312523a78662d5be7fcbcc98ebf5a897687268d6ecccc0c07b25e25ecfe584"),
+        ] {
+            let cipher = aes_cts(key, CipherAction::Encrypt, &plain[..len]).unwrap();
+
+            assert_eq!(expected, hex::encode(&cipher));
+            assert_eq!(plain[..len], aes_cts(key, CipherAction::Decrypt, &cipher).unwrap());
+        }
+
+        for len in [16, 31, 47] {
+            let cipher = aes_cts(key, CipherAction::Encrypt, &plain[..len]).unwrap();
+
+            assert_eq!(plain[..len], aes_cts(key, CipherAction::Decrypt, &cipher).unwrap());
+        }
+    }
+}
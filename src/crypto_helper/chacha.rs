@@ -0,0 +1,148 @@
+//! ChaCha20 stream cipher and ChaCha20-Poly1305 AEAD (RFC 8439), and XChaCha20-Poly1305 (draft-irtf-cfrg-xchacha).
+
+use chacha20::ChaCha20;
+use chacha20::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use chacha20poly1305::aead::{AeadInOut, KeyInit, Nonce};
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
+
+use super::algorithm::{ChaCha20Input, ChaChaPoly1305Input, CipherAction};
+
+pub const CHACHA20_KEY_LEN: usize = 32;
+pub const CHACHA20_NONCE_LEN: usize = 12;
+pub const XCHACHA20_NONCE_LEN: usize = 24;
+pub const POLY1305_TAG_LEN: usize = 16;
+
+const CHACHA20_BLOCK_LEN: u64 = 64;
+
+fn check_len(name: &str, value: &[u8], expected: usize) -> Result<(), String> {
+    if value.len() != expected {
+        return Err(format!(
+            "chacha20: invalid {} length: expected {} bytes but got {}",
+            name,
+            expected,
+            value.len()
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn process_chacha20(input: &ChaCha20Input) -> Result<Vec<u8>, String> {
+    check_len("key", &input.key, CHACHA20_KEY_LEN)?;
+    check_len("nonce", &input.nonce, CHACHA20_NONCE_LEN)?;
+
+    let mut cipher = ChaCha20::new_from_slices(&input.key, &input.nonce).map_err(|err| err.to_string())?;
+    cipher.seek(u64::from(input.counter) * CHACHA20_BLOCK_LEN);
+
+    let mut output = input.payload.clone();
+    cipher
+        .try_apply_keystream(&mut output)
+        .map_err(|_| "chacha20: the block counter overflows".to_owned())?;
+
+    Ok(output)
+}
+
+fn aead<A: AeadInOut + KeyInit>(input: &ChaChaPoly1305Input) -> Result<Vec<u8>, String> {
+    let cipher = A::new_from_slice(&input.key).map_err(|err| err.to_string())?;
+    let nonce = Nonce::<A>::try_from(input.nonce.as_slice()).map_err(|_| "chacha20: invalid nonce length")?;
+    let encrypt = |data: &mut [u8]| {
+        cipher
+            .encrypt_inout_detached(&nonce, &input.aad, data.into())
+            .map_err(|_| "chacha20: the data is too long".to_owned())
+    };
+
+    // The encryption and decryption apply the same key stream.
+    let mut output = input.payload.clone();
+    let tag = encrypt(&mut output)?;
+
+    match input.action {
+        CipherAction::Encrypt => {
+            output.extend_from_slice(&tag);
+
+            Ok(output)
+        }
+        CipherAction::Decrypt => {
+            check_len("tag", &input.tag, POLY1305_TAG_LEN)?;
+
+            // The plain text is encrypted back to show the expected tag on mismatch.
+            let tag = encrypt(&mut output.clone())?;
+            if tag[..] != input.tag[..] {
+                return Err(format!(
+                    "poly1305: tag mismatch: expected {} but got {}",
+                    hex::encode(tag),
+                    hex::encode(&input.tag)
+                ));
+            }
+
+            Ok(output)
+        }
+    }
+}
+
+/// Encrypts or decrypts the payload. The tag is appended to the cipher text.
+pub fn process_chacha20_poly1305(input: &ChaChaPoly1305Input) -> Result<Vec<u8>, String> {
+    check_len("key", &input.key, CHACHA20_KEY_LEN)?;
+    check_len("nonce", &input.nonce, CHACHA20_NONCE_LEN)?;
+
+    aead::<ChaCha20Poly1305>(input)
+}
+
+/// Encrypts or decrypts the payload. The tag is appended to the cipher text.
+pub fn process_xchacha20_poly1305(input: &ChaChaPoly1305Input) -> Result<Vec<u8>, String> {
+    check_len("key", &input.key, CHACHA20_KEY_LEN)?;
+    check_len("nonce", &input.nonce, XCHACHA20_NONCE_LEN)?;
+
+    aead::<XChaCha20Poly1305>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{process_chacha20, process_chacha20_poly1305, process_xchacha20_poly1305};
+    use crate::crypto_helper::algorithm::{ChaCha20Input, ChaChaPoly1305Input, CipherAction};
+
+    #[test]
+    fn rfc8439_chacha20() {
+        let cipher_text = process_chacha20(&ChaCha20Input::rfc8439_preset()).unwrap();
+
+        assert_eq!(
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0bf91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d807ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab77937365af90bbf74a35be6b40b8eedf2785e42874d",
+            hex::encode(cipher_text)
+        );
+    }
+
+    #[test]
+    fn rfc8439_aead() {
+        let input = ChaChaPoly1305Input::rfc8439_preset();
+        let output = process_chacha20_poly1305(&input).unwrap();
+        let (cipher_text, tag) = output.split_at(output.len() - 16);
+
+        assert_eq!(
+            "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d26586cec64b6116",
+            hex::encode(cipher_text)
+        );
+        assert_eq!("1ae10b594f09e26a7e902ecbd0600691", hex::encode(tag));
+
+        let decrypt = |tag: Vec<u8>| {
+            process_chacha20_poly1305(&ChaChaPoly1305Input {
+                action: CipherAction::Decrypt,
+                payload: cipher_text.to_vec(),
+                tag,
+                ..input.clone()
+            })
+        };
+        assert_eq!(input.payload, decrypt(tag.to_vec()).unwrap());
+        assert!(decrypt(vec![0; 16]).unwrap_err().contains("tag mismatch"));
+    }
+
+    #[test]
+    fn xchacha20_poly1305() {
+        let output = process_xchacha20_poly1305(&ChaChaPoly1305Input::xchacha_preset()).unwrap();
+        let (cipher_text, tag) = output.split_at(output.len() - 16);
+
+        assert_eq!(
+            "bd6d179d3e83d43b9576579493c0e939572a1700252bfaccbed2902c21396cbb731c7f1b0b4aa6440bf3a82f4eda7e39ae64c6708c54c216cb96b72e1213b4522f8c9ba40db5d945b11b69b982c1bb9e3f3fac2bc369488f76b2383565d3fff921f9664c97637da9768812f615c68b13b52e",
+            hex::encode(cipher_text)
+        );
+        assert_eq!("c0875924c1c7987947deafd8780acf49", hex::encode(tag));
+    }
+}
@@ -5,7 +5,7 @@ use argon2::{PasswordHasher, PasswordVerifier};
 use base64::Engine;
 use bcrypt::Version;
 use flate2::Compression;
-use flate2::write::{ZlibDecoder, ZlibEncoder};
+use flate2::write::ZlibEncoder;
 use picky::signature::SignatureAlgorithm;
 use picky_krb::crypto::{Checksum, Cipher};
 use rsa::Pkcs1v15Encrypt;
@@ -15,6 +15,7 @@ use super::algorithm::{
     Argon2Action, Argon2Input, BcryptAction, BcryptInput, HmacShaAction, HmacShaAlgorithm, HmacShaInput, KrbInput,
     KrbInputData, KrbMode, RsaAction, RsaInput, ZlibInput, ZlibMode,
 };
+use crate::common::zlib::zlib_decompress;
 
 pub fn process_rsa(input: &RsaInput) -> Result<Vec<u8>, String> {
     let payload = &input.payload;
@@ -83,15 +84,7 @@ pub fn process_zlib(input: &ZlibInput) -> Result<Vec<u8>, String> {
                 .finish()
                 .map_err(|err| format!("Can not finish compression: {:?}", err))
         }
-        ZlibMode::Decompress => {
-            let mut decompressor = ZlibDecoder::new(Vec::new());
-            decompressor
-                .write_all(&input.data)
-                .map_err(|err| format!("Can not decompress the input data: {:?}", err))?;
-            decompressor
-                .finish()
-                .map_err(|err| format!("Can not finish decompression: {:?}", err))
-        }
+        ZlibMode::Decompress => zlib_decompress(&input.data),
     }
 }
 
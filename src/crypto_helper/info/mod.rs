@@ -4,8 +4,10 @@ use web_sys::HtmlInputElement;
 use yew::html::onchange::Event;
 use yew::{Callback, Html, Properties, TargetCast, UseStateSetter, function_component, html, use_state};
 
-use super::algorithm::Algorithm;
-use crate::crypto_helper::algorithm::{COMPRESSION_ALGOS, ENCRYPTION_ALGOS, HASHING_ALGOS, HMAC_ALGOS};
+use super::algorithm::{Algorithm, ChaCha20Input, ChaChaPoly1305Input, NtlmInput};
+use crate::crypto_helper::algorithm::{
+    COMPRESSION_ALGOS, ENCRYPTION_ALGOS, HASHING_ALGOS, HMAC_ALGOS, KERBEROS_ALGOS, NTLM_ALGOS,
+};
 use crate::crypto_helper::info::algo_search::AlgoSearch;
 
 #[derive(PartialEq, Properties)]
@@ -14,7 +16,17 @@ pub struct InfoProps {
     pub set_algorithm: UseStateSetter<Algorithm>,
 }
 
-fn get_algorithm_info(algorithm: &Algorithm) -> Html {
+/// Button that loads the known test vector into the algorithm input.
+fn preset_button(set_algorithm: &UseStateSetter<Algorithm>, preset: Algorithm, title: &'static str) -> Html {
+    let set_algorithm = set_algorithm.clone();
+    let onclick = Callback::from(move |_| set_algorithm.set(preset.clone()));
+
+    html! {
+        <button class="action-button" {onclick}>{title}</button>
+    }
+}
+
+fn get_algorithm_info(algorithm: &Algorithm, set_algorithm: &UseStateSetter<Algorithm>) -> Html {
     match algorithm {
         Algorithm::Md5(_) => html! {
             <span>{"Input for MD5 hashing function."}
@@ -82,6 +94,95 @@ fn get_algorithm_info(algorithm: &Algorithm) -> Html {
             <a href="https://www.rfc-editor.org/rfc/rfc6234">{"RFC"}</a>
             </span>
         },
+        Algorithm::Aes(_) => html! {
+            <span>{"Encrypt/decrypt data with AES-128/192/256 in ECB, CBC, CTR, CFB, OFB, or GCM mode."}
+            <a href="https://csrc.nist.gov/pubs/sp/800/38/a/final">{"SP 800-38A"}</a>{", "}
+            <a href="https://csrc.nist.gov/pubs/sp/800/38/d/final">{"SP 800-38D"}</a>{"."}
+            </span>
+        },
+        Algorithm::ChaCha20(_) => html! {
+            <span>{"Encrypt/decrypt data with the ChaCha20 stream cipher."}
+            <a href="https://www.rfc-editor.org/rfc/rfc8439#section-2.4">{"RFC"}</a>{". "}
+            {preset_button(
+                set_algorithm,
+                Algorithm::ChaCha20(ChaCha20Input::rfc8439_preset()),
+                "Load RFC 8439 test vector",
+            )}
+            </span>
+        },
+        Algorithm::ChaCha20Poly1305(_) => html! {
+            <span>{"Encrypt/decrypt data with ChaCha20-Poly1305 AEAD. The tag is appended to the cipher text."}
+            <a href="https://www.rfc-editor.org/rfc/rfc8439#section-2.8">{"RFC"}</a>{". "}
+            {preset_button(
+                set_algorithm,
+                Algorithm::ChaCha20Poly1305(ChaChaPoly1305Input::rfc8439_preset()),
+                "Load RFC 8439 test vector",
+            )}
+            </span>
+        },
+        Algorithm::XChaCha20Poly1305(_) => html! {
+            <span>{"Encrypt/decrypt data with XChaCha20-Poly1305 AEAD (192-bit nonce). The tag is appended to the cipher text."}
+            <a href="https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha">{"Draft"}</a>{". "}
+            {preset_button(
+                set_algorithm,
+                Algorithm::XChaCha20Poly1305(ChaChaPoly1305Input::xchacha_preset()),
+                "Load draft test vector",
+            )}
+            </span>
+        },
+        Algorithm::KrbCipher(_) => html! {
+            <span>{"Encrypt/decrypt data using the selected Kerberos encryption type: des3-cbc-sha1-kd (16), aes128/256-cts-hmac-sha1-96 (17/18), aes128-cts-hmac-sha256-128 (19), aes256-cts-hmac-sha384-192 (20), or rc4-hmac (23)."}
+            <a href="https://www.rfc-editor.org/rfc/rfc3961.html">{"RFC 3961"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc8009.html">{"RFC 8009"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc4757.html">{"RFC 4757"}</a>{"."}
+            </span>
+        },
+        Algorithm::KrbKeyDerivation(_) => html! {
+            <span>{"Explore the Kerberos key derivation step by step: string-to-key from the password, realm, and principal name, the Ke/Ki/Kc keys for the key usage, PRF+, and KRB-FX-CF2."}
+            <a href="https://www.rfc-editor.org/rfc/rfc3961.html">{"RFC 3961"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc3962.html">{"RFC 3962"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc8009.html">{"RFC 8009"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc6113.html#section-5.1">{"RFC 6113"}</a>{"."}
+            </span>
+        },
+        Algorithm::Keytab(_) => html! {
+            <span>{"Decode the MIT keytab file (version 0x502): principals, KVNOs, timestamps, and etypes. Add or remove entries and export the new keytab. Paste the file as hex or base64 (e.g. the output of `base64 -w0 user.keytab`)."}
+            <a href="https://web.mit.edu/kerberos/krb5-latest/doc/formats/keytab_file_format.html">{"Format"}</a>{"."}
+            </span>
+        },
+        Algorithm::KrbCredentials(_) => html! {
+            <span>{"List the credentials of the credential cache (FILE ccache, versions 3 and 4) or the KRB-CRED message: client and server principals, ticket flags, times, the session key, and the ticket ASN.1 tree. The KRB-CRED enc-part is decrypted with the key usage 14."}
+            <a href="https://web.mit.edu/kerberos/krb5-latest/doc/formats/ccache_file_format.html">{"ccache format"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc4120.html#section-5.8">{"RFC 4120 5.8"}</a>{"."}
+            </span>
+        },
+        Algorithm::KrbPac(_) => html! {
+            <span>{"Decode the PAC from the AD-WIN2K-PAC authorization data or from the decrypted EncTicketPart: logon information (user, groups, and SIDs), client information, UPN and DNS information, and signatures. The server checksum is verified when the service key is provided."}
+            <a href="https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-pac/166d8064-c863-41e1-9c23-edaaa5f36962">{"MS-PAC"}</a>{"."}
+            </span>
+        },
+        Algorithm::KrbGssToken(_) => html! {
+            <span>{"Build or parse the GSS-API Kerberos Wrap and MIC tokens: TOK_ID, flags, EC, RRC, and SND_SEQ. The Wrap token is encrypted or checksummed and rotated by RRC, the MIC token is checksummed. The key usage (22-25) follows the token type and the sender."}
+            <a href="https://www.rfc-editor.org/rfc/rfc4121.html#section-4.2">{"RFC 4121"}</a>{"."}
+            </span>
+        },
+        Algorithm::Ntlm(_) => html! {
+            <span>{"Calculate the NTLM authentication step by step: NT hash, NTOWFv2, LMv1/NTLMv1 or LMv2/NTLMv2 responses, SessionBaseKey, KeyExchangeKey, ExportedSessionKey (RC4 key exchange), signing and sealing keys, and the MIC over the NEGOTIATE, CHALLENGE, and AUTHENTICATE messages."}
+            <a href="https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-nlmp/b38c36ed-2804-4868-a9ff-8dd3182128e4">{"MS-NLMP"}</a>{". "}
+            {preset_button(
+                set_algorithm,
+                Algorithm::Ntlm(NtlmInput::ms_nlmp_preset()),
+                "Load MS-NLMP test vector",
+            )}
+            </span>
+        },
+        Algorithm::KrbChecksum(_) => html! {
+            <span>{"Calculate the checksum of the selected Kerberos encryption type: hmac-sha1-des3-kd, hmac-sha1-96-aes128/256, hmac-sha256-128-aes128, hmac-sha384-192-aes256, or hmac-md5."}
+            <a href="https://www.rfc-editor.org/rfc/rfc3961.html">{"RFC 3961"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc8009.html">{"RFC 8009"}</a>{", "}
+            <a href="https://www.rfc-editor.org/rfc/rfc4757.html">{"RFC 4757"}</a>{"."}
+            </span>
+        },
     }
 }
 
@@ -121,6 +222,8 @@ pub fn info(props: &InfoProps) -> Html {
     let encryption_algos = generate_algo_list_for_yew!(algo_list: ENCRYPTION_ALGOS, props: props);
     let hmac_algos = generate_algo_list_for_yew!(algo_list: HMAC_ALGOS, props: props);
     let compression_algos = generate_algo_list_for_yew!(algo_list: COMPRESSION_ALGOS, props: props);
+    let kerberos_algos = generate_algo_list_for_yew!(algo_list: KERBEROS_ALGOS, props: props);
+    let ntlm_algos = generate_algo_list_for_yew!(algo_list: NTLM_ALGOS, props: props);
 
     html! {
         <div class="horizontal">
@@ -139,6 +242,12 @@ pub fn info(props: &InfoProps) -> Html {
                         <optgroup label="COMPRESSION"> {
                             compression_algos
                         }</optgroup>
+                        <optgroup label="KERBEROS"> {
+                            kerberos_algos
+                        }</optgroup>
+                        <optgroup label="NTLM"> {
+                            ntlm_algos
+                        }</optgroup>
                     </select>
                     <input type="checkbox" id={"algo-search"} class="search-input" onchange={on_algo_search_change} />
                     <label for={"algo-search"} class="search-button">
@@ -155,7 +264,7 @@ pub fn info(props: &InfoProps) -> Html {
                     }
                 }
             </div>
-            <div class="algo-info">{get_algorithm_info(&props.algorithm)}</div>
+            <div class="algo-info">{get_algorithm_info(&props.algorithm, &props.set_algorithm)}</div>
         </div>
     }
 }
@@ -0,0 +1,170 @@
+use web_sys::{Event, HtmlInputElement};
+use yew::{Callback, Html, Properties, TargetCast, function_component, html};
+
+use crate::common::{Switch, build_byte_input};
+use crate::crypto_helper::algorithm::{
+    AES_MODES, AES_PADDINGS, AesInput as AesInputData, AesMode, AesPadding, CipherAction,
+};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct AesInputProps {
+    pub input: AesInputData,
+    pub input_setter: Callback<AesInputData>,
+}
+
+fn iv_placeholder(mode: AesMode) -> &'static str {
+    match mode {
+        AesMode::Ecb => "",
+        AesMode::Ctr => "initial counter block (16 bytes)",
+        AesMode::Gcm => "nonce (12 bytes recommended)",
+        AesMode::Cbc | AesMode::Cfb | AesMode::Ofb => "IV (16 bytes)",
+    }
+}
+
+#[function_component(AesInput)]
+pub fn aes_input(props: &AesInputProps) -> Html {
+    let AesInputProps { input, input_setter } = props.clone();
+    let AesInputData {
+        mode,
+        action,
+        padding,
+        key,
+        iv,
+        aad,
+        tag,
+        payload,
+    } = input.clone();
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_mode_change = Callback::from(move |event: Event| {
+        let select: HtmlInputElement = event.target_unchecked_into();
+
+        if let Ok(mode) = AesMode::try_from(select.value().as_str()) {
+            setter.emit(AesInputData { mode, ..data.clone() });
+        }
+    });
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_padding_change = Callback::from(move |event: Event| {
+        let select: HtmlInputElement = event.target_unchecked_into();
+
+        if let Ok(padding) = AesPadding::try_from(select.value().as_str()) {
+            setter.emit(AesInputData {
+                padding,
+                ..data.clone()
+            });
+        }
+    });
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_action_switch = Callback::from(move |action: bool| {
+        setter.emit(AesInputData {
+            action: action.into(),
+            ..data.clone()
+        });
+    });
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_key_input = Callback::from(move |key| setter.emit(AesInputData { key, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_iv_input = Callback::from(move |iv| setter.emit(AesInputData { iv, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_aad_input = Callback::from(move |aad| setter.emit(AesInputData { aad, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_tag_input = Callback::from(move |tag| setter.emit(AesInputData { tag, ..data.clone() }));
+
+    let on_payload_input = Callback::from(move |payload| {
+        input_setter.emit(AesInputData {
+            payload,
+            ..input.clone()
+        })
+    });
+
+    let key_size = match key.len() {
+        16 | 24 | 32 => format!("AES-{}", key.len() * 8),
+        len => format!("invalid key length: {} bytes", len),
+    };
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <select class="base-input auto-width-input" onchange={on_mode_change}>
+                    {AES_MODES
+                        .iter()
+                        .map(|aes_mode| html! {
+                            <option selected={mode == *aes_mode} value={aes_mode.as_ref().to_owned()}>
+                                {aes_mode.as_ref()}
+                            </option>
+                        })
+                        .collect::<Vec<_>>()}
+                </select>
+                {if mode.needs_padding() {
+                    html! {
+                        <select class="base-input auto-width-input" onchange={on_padding_change}>
+                            {AES_PADDINGS
+                                .iter()
+                                .map(|aes_padding| html! {
+                                    <option selected={padding == *aes_padding} value={aes_padding.as_ref().to_owned()}>
+                                        {aes_padding.as_ref()}
+                                    </option>
+                                })
+                                .collect::<Vec<_>>()}
+                        </select>
+                    }
+                } else {
+                    html! {}
+                }}
+                <div class="horizontal">
+                    <span class="total">{"encrypt"}</span>
+                    <Switch id={"aes-action".to_string()} setter={on_action_switch} state={bool::from(action)}/>
+                    <span class="total">{"decrypt"}</span>
+                </div>
+                <span class="total">{key_size}</span>
+            </div>
+            {build_byte_input(key, on_key_input, None, Some("key (16, 24, or 32 bytes)".into()))}
+            {if mode == AesMode::Ecb {
+                html! {}
+            } else {
+                build_byte_input(iv, on_iv_input, None, Some(iv_placeholder(mode).into()))
+            }}
+            {if mode == AesMode::Gcm {
+                html! {
+                    <>
+                        {build_byte_input(aad, on_aad_input, None, Some("additional authenticated data".into()))}
+                        {if action == CipherAction::Decrypt {
+                            build_byte_input(tag, on_tag_input, None, Some("tag".into()))
+                        } else {
+                            html! {}
+                        }}
+                    </>
+                }
+            } else {
+                html! {}
+            }}
+            {build_byte_input(payload, on_payload_input, None, Some("payload".into()))}
+            {if mode.needs_padding() && padding == AesPadding::Zero && action == CipherAction::Decrypt {
+                html! {
+                    <span class="total">{"Note: zero padding is ambiguous, trailing zero bytes of the plaintext are removed too."}</span>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
+
+pub fn build_aes_input(input: AesInputData, input_setter: Callback<AesInputData>) -> Html {
+    html! {
+        <AesInput {input} {input_setter} />
+    }
+}
@@ -0,0 +1,162 @@
+use web_sys::HtmlInputElement;
+use yew::{Callback, Html, InputEvent, Properties, TargetCast, function_component, html};
+use yew_notifications::{Notification, NotificationType, use_notification};
+
+use crate::common::{Switch, build_byte_input};
+use crate::crypto_helper::algorithm::{ChaCha20Input as ChaCha20InputData, ChaChaPoly1305Input, CipherAction};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct ChaCha20InputProps {
+    pub input: ChaCha20InputData,
+    pub input_setter: Callback<ChaCha20InputData>,
+}
+
+#[function_component(ChaCha20Input)]
+pub fn chacha20_input(props: &ChaCha20InputProps) -> Html {
+    let ChaCha20InputProps { input, input_setter } = props.clone();
+    let ChaCha20InputData {
+        key,
+        nonce,
+        counter,
+        payload,
+    } = input.clone();
+
+    let notifications = use_notification::<Notification>();
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_key_input = Callback::from(move |key| setter.emit(ChaCha20InputData { key, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_nonce_input = Callback::from(move |nonce| setter.emit(ChaCha20InputData { nonce, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_counter_input = Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+
+        match input.value().parse::<u32>() {
+            Ok(counter) => setter.emit(ChaCha20InputData {
+                counter,
+                ..data.clone()
+            }),
+            Err(err) => notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Invalid block counter",
+                err.to_string(),
+                Notification::NOTIFICATION_LIFETIME,
+            )),
+        }
+    });
+
+    let on_payload_input = Callback::from(move |payload| {
+        input_setter.emit(ChaCha20InputData {
+            payload,
+            ..input.clone()
+        })
+    });
+
+    html! {
+        <div class="vertical">
+            {build_byte_input(key, on_key_input, None, Some("key (32 bytes)".into()))}
+            {build_byte_input(nonce, on_nonce_input, None, Some("nonce (12 bytes)".into()))}
+            <div class="horizontal">
+                <span class="total">{"block counter:"}</span>
+                <input type={"number"} class="base-input" value={counter.to_string()} oninput={on_counter_input} />
+            </div>
+            {build_byte_input(payload, on_payload_input, None, Some("payload".into()))}
+        </div>
+    }
+}
+
+pub fn build_chacha20_input(input: ChaCha20InputData, input_setter: Callback<ChaCha20InputData>) -> Html {
+    html! {
+        <ChaCha20Input {input} {input_setter} />
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct ChaChaPoly1305InputProps {
+    pub input: ChaChaPoly1305Input,
+    pub input_setter: Callback<ChaChaPoly1305Input>,
+    pub nonce_len: usize,
+}
+
+#[function_component(ChaChaPoly1305)]
+pub fn chacha_poly1305_input(props: &ChaChaPoly1305InputProps) -> Html {
+    let ChaChaPoly1305InputProps {
+        input,
+        input_setter,
+        nonce_len,
+    } = props.clone();
+    let ChaChaPoly1305Input {
+        action,
+        key,
+        nonce,
+        aad,
+        tag,
+        payload,
+    } = input.clone();
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_action_switch = Callback::from(move |action: bool| {
+        setter.emit(ChaChaPoly1305Input {
+            action: action.into(),
+            ..data.clone()
+        });
+    });
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_key_input = Callback::from(move |key| setter.emit(ChaChaPoly1305Input { key, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_nonce_input = Callback::from(move |nonce| setter.emit(ChaChaPoly1305Input { nonce, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_aad_input = Callback::from(move |aad| setter.emit(ChaChaPoly1305Input { aad, ..data.clone() }));
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_tag_input = Callback::from(move |tag| setter.emit(ChaChaPoly1305Input { tag, ..data.clone() }));
+
+    let on_payload_input = Callback::from(move |payload| {
+        input_setter.emit(ChaChaPoly1305Input {
+            payload,
+            ..input.clone()
+        })
+    });
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <span class="total">{"encrypt"}</span>
+                <Switch id={"chacha-action".to_string()} setter={on_action_switch} state={bool::from(action)}/>
+                <span class="total">{"decrypt"}</span>
+            </div>
+            {build_byte_input(key, on_key_input, None, Some("key (32 bytes)".into()))}
+            {build_byte_input(nonce, on_nonce_input, None, Some(format!("nonce ({} bytes)", nonce_len)))}
+            {build_byte_input(aad, on_aad_input, None, Some("additional authenticated data".into()))}
+            {if action == CipherAction::Decrypt {
+                build_byte_input(tag, on_tag_input, None, Some("tag (16 bytes)".into()))
+            } else {
+                html! {}
+            }}
+            {build_byte_input(payload, on_payload_input, None, Some("payload".into()))}
+        </div>
+    }
+}
+
+pub fn build_chacha_poly1305_input(
+    input: ChaChaPoly1305Input,
+    input_setter: Callback<ChaChaPoly1305Input>,
+    nonce_len: usize,
+) -> Html {
+    html! {
+        <ChaChaPoly1305 {input} {input_setter} {nonce_len} />
+    }
+}
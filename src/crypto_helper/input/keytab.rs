@@ -0,0 +1,210 @@
+use time::OffsetDateTime;
+use web_sys::{HtmlInputElement, InputEvent};
+use yew::{Callback, Html, Properties, TargetCast, UseStateSetter, function_component, html, use_state};
+use yew_notifications::{Notification, NotificationType, use_notification};
+
+use super::build_etype_select;
+use crate::common::{BytesFormat, build_byte_input};
+use crate::crypto_helper::Algorithm;
+use crate::crypto_helper::algorithm::{KrbEtype, KrbEtypeInput, KrbInput, KrbInputData};
+use crate::crypto_helper::krb::{KeytabEntry, NT_PRINCIPAL, encode_keytab, etype_name, format_timestamp, parse_keytab};
+
+/// Returns the algorithm that uses the entry key, if the etype is supported.
+fn key_algorithm(entry: &KeytabEntry) -> Option<Algorithm> {
+    let etype = KrbEtype::try_from(i32::from(entry.etype)).ok()?;
+    let input = KrbInput {
+        data: KrbInputData {
+            key: entry.key.clone(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    Some(match etype {
+        KrbEtype::Aes128CtsHmacSha196 => Algorithm::Aes128CtsHmacSha196(input),
+        KrbEtype::Aes256CtsHmacSha196 => Algorithm::Aes256CtsHmacSha196(input),
+        etype => Algorithm::KrbCipher(KrbEtypeInput { etype, input }),
+    })
+}
+
+fn new_entry() -> KeytabEntry {
+    KeytabEntry {
+        name_type: NT_PRINCIPAL,
+        kvno: 1,
+        etype: KrbEtype::default().number() as u16,
+        ..Default::default()
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KeytabInputProps {
+    pub data: Vec<u8>,
+    pub setter: UseStateSetter<Algorithm>,
+}
+
+#[function_component(KeytabInput)]
+pub fn keytab_input(props: &KeytabInputProps) -> Html {
+    let KeytabInputProps { data, setter } = props.clone();
+
+    let notifications = use_notification::<Notification>();
+    let entry = use_state(new_entry);
+    let principal = use_state(String::new);
+    // Parsed when the entry is added, so intermediate values do not produce errors.
+    let kvno = use_state(|| new_entry().kvno.to_string());
+
+    let data_setter = setter.clone();
+    let set_data = Callback::from(move |data| data_setter.set(Algorithm::Keytab(data)));
+
+    let entries = match parse_keytab(&data) {
+        Ok(entries) => entries,
+        Err(err) if !data.is_empty() => {
+            return html! {
+                <div class="vertical">
+                    {build_byte_input(data, set_data, Some(BytesFormat::Base64), Some("keytab file".into()))}
+                    <span class="total">{err}</span>
+                </div>
+            };
+        }
+        Err(_) => Vec::new(),
+    };
+
+    let rows = entries
+        .iter()
+        .enumerate()
+        .map(|(index, keytab_entry)| {
+            let use_key = key_algorithm(keytab_entry).map(|algorithm| {
+                let setter = setter.clone();
+                Callback::from(move |_| setter.set(algorithm.clone()))
+            });
+
+            let mut remaining = entries.clone();
+            remaining.remove(index);
+            let set_data = set_data.clone();
+            let remove = Callback::from(move |_| set_data.emit(encode_keytab(&remaining)));
+
+            html! {
+                <>
+                    <span class="table-cell">{keytab_entry.principal()}</span>
+                    <span class="table-cell">{keytab_entry.kvno}</span>
+                    <span class="table-cell">{format_timestamp(keytab_entry.timestamp.into())}</span>
+                    <span class="table-cell">{etype_name(keytab_entry.etype.into())}</span>
+                    <span class="table-cell full-cipher">{hex::encode(&keytab_entry.key)}</span>
+                    <div class="horizontal">
+                        {if let Some(onclick) = use_key {
+                            html! { <button class="action-button" {onclick}>{"use this key"}</button> }
+                        } else {
+                            html! {}
+                        }}
+                        <button class="action-button" onclick={remove}>{"remove"}</button>
+                    </div>
+                </>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let principal_setter = principal.setter();
+    let on_principal = Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        principal_setter.set(input.value());
+    });
+
+    let kvno_setter = kvno.setter();
+    let on_kvno = Callback::from(move |event: InputEvent| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        kvno_setter.set(input.value());
+    });
+
+    let etype_entry = entry.clone();
+    let set_etype = Callback::from(move |etype: KrbEtype| {
+        etype_entry.set(KeytabEntry {
+            etype: etype.number() as u16,
+            ..(*etype_entry).clone()
+        })
+    });
+
+    let key_entry = entry.clone();
+    let set_key = Callback::from(move |key| {
+        key_entry.set(KeytabEntry {
+            key,
+            ..(*key_entry).clone()
+        })
+    });
+
+    let add_entry = entry.clone();
+    let add_principal = principal.clone();
+    let add_kvno = kvno.clone();
+    let add_notifications = notifications.clone();
+    let add_entries = entries.clone();
+    let add_set_data = set_data.clone();
+    let add = Callback::from(move |_| {
+        let mut new_entry = (*add_entry).clone();
+        let kvno = add_kvno
+            .parse()
+            .map_err(|err: std::num::ParseIntError| format!("keytab: invalid kvno: {}", err));
+        if let Err(err) = kvno.and_then(|kvno| {
+            new_entry.kvno = kvno;
+            new_entry.set_principal(&add_principal)
+        }) {
+            add_notifications.spawn(Notification::new(
+                NotificationType::Error,
+                "Keytab entry",
+                err,
+                Notification::NOTIFICATION_LIFETIME,
+            ));
+            return;
+        }
+        new_entry.timestamp = OffsetDateTime::now_utc().unix_timestamp() as u32;
+
+        let mut entries = add_entries.clone();
+        entries.push(new_entry);
+        add_set_data.emit(encode_keytab(&entries));
+    });
+
+    html! {
+        <div class="vertical">
+            {build_byte_input(data, set_data, Some(BytesFormat::Base64), Some("keytab file".into()))}
+            <span class="total">{format!("entries: {}", entries.len())}</span>
+            {if entries.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <div class="keytab-entries">
+                        <span class="table-cell">{"principal"}</span>
+                        <span class="table-cell">{"kvno"}</span>
+                        <span class="table-cell">{"timestamp"}</span>
+                        <span class="table-cell">{"etype"}</span>
+                        <span class="table-cell">{"key"}</span>
+                        <span />
+                        {for rows}
+                    </div>
+                }
+            }}
+            <span class="total">{"New entry"}</span>
+            <div class="horizontal">
+                <input
+                    class="base-input"
+                    placeholder={"principal name (e.g. HTTP/example.com@EXAMPLE.COM)"}
+                    value={(*principal).clone()}
+                    oninput={on_principal}
+                />
+                <span class="total">{"kvno:"}</span>
+                <input
+                    type={"number"}
+                    class="base-input auto-width-input"
+                    value={(*kvno).clone()}
+                    oninput={on_kvno}
+                />
+                <span class="total">{"etype:"}</span>
+                {build_etype_select(KrbEtype::try_from(i32::from(entry.etype)).unwrap_or_default(), set_etype)}
+            </div>
+            {build_byte_input(entry.key.clone(), set_key, None, Some("key".into()))}
+            <button class="action-button" onclick={add}>{"add entry"}</button>
+        </div>
+    }
+}
+
+pub fn build_keytab_input(data: Vec<u8>, setter: UseStateSetter<Algorithm>) -> Html {
+    html! {
+        <KeytabInput {data} {setter} />
+    }
+}
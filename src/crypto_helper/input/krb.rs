@@ -1,4 +1,3 @@
-use picky_krb::crypto::CipherSuite;
 use web_sys::{HtmlInputElement, InputEvent, MouseEvent};
 use yew::{
     Callback, Html, Properties, TargetCast, UseStateSetter, classes, function_component, html, use_effect_with,
@@ -6,8 +5,10 @@ use yew::{
 };
 use yew_notifications::{Notification, NotificationType, use_notification};
 
+use super::build_etype_select;
 use crate::common::{Switch, build_byte_input};
-use crate::crypto_helper::algorithm::{KrbInput as KerberosInput, KrbMode};
+use crate::crypto_helper::algorithm::{KrbEtype, KrbInput as KerberosInput, KrbMode};
+use crate::crypto_helper::krb::string_to_key;
 
 fn get_usage_number_name(usage_number: i32) -> &'static str {
     match usage_number {
@@ -44,11 +45,8 @@ fn gen_on_input_handle(setter: UseStateSetter<String>) -> Callback<InputEvent> {
     })
 }
 
-fn generate_key(cipher: &CipherSuite, password: &str, salt: &str) -> Result<Vec<u8>, String> {
-    cipher
-        .cipher()
-        .generate_key_from_password(password.as_bytes(), salt.as_bytes())
-        .map_err(|e| e.to_string())
+fn generate_key(etype: KrbEtype, password: &str, salt: &str) -> Result<Vec<u8>, String> {
+    string_to_key(etype, password.as_bytes(), salt.as_bytes())
 }
 
 #[derive(PartialEq, Properties, Clone)]
@@ -56,7 +54,9 @@ pub struct KrbInputProps {
     pub krb_input: KerberosInput,
     pub krb_input_setter: Callback<KerberosInput>,
     // needs it for the key generation algorithm
-    pub krb_algo: CipherSuite,
+    pub krb_algo: KrbEtype,
+    // set when the user can select the etype
+    pub etype_setter: Option<Callback<KrbEtype>>,
 
     // options
     pub with_mode: bool,
@@ -82,7 +82,7 @@ pub fn krb_input(props: &KrbInputProps) -> Html {
     let password = use_state(String::new);
     let salt = use_state(String::new);
 
-    let cipher = props.krb_algo.clone();
+    let etype = props.krb_algo;
     let password_value = (*password).clone();
     let salt_value = (*salt).clone();
 
@@ -92,7 +92,7 @@ pub fn krb_input(props: &KrbInputProps) -> Html {
     let krb_data = krb_input.data.clone();
     let generate_key_from_password = Callback::from(move |event: MouseEvent| {
         event.prevent_default();
-        match generate_key(&cipher, &password_value, &salt_value) {
+        match generate_key(etype, &password_value, &salt_value) {
             Ok(key) => {
                 let mut data = krb_data.clone();
                 data.key = key;
@@ -155,6 +155,13 @@ pub fn krb_input(props: &KrbInputProps) -> Html {
 
     html! {
         <div class="enc-params">
+            {if let Some(etype_setter) = props.etype_setter.clone() { html! {
+                <div class="vertical">
+                    <span class="total">{"Encryption type"}</span>
+                    {build_etype_select(etype, etype_setter)}
+                    <span class="total">{format!("checksum: {}", etype.checksum_name())}</span>
+                </div>
+            }} else { html! {} }}
             {build_byte_input(krb_input.data.key.clone(), set_key, None, Some("key".into()))}
             <div class="vertical">
                 <span class="total">{"Key usage number"}</span>
@@ -219,10 +226,17 @@ pub fn krb_input(props: &KrbInputProps) -> Html {
 pub fn build_krb_input(
     krb_input: KerberosInput,
     krb_input_setter: Callback<KerberosInput>,
-    krb_algo: CipherSuite,
+    krb_algo: KrbEtype,
+    etype_setter: Option<Callback<KrbEtype>>,
     with_mode: bool,
 ) -> Html {
     html! {
-        <KrbInput krb_input={krb_input} krb_input_setter={krb_input_setter} krb_algo={krb_algo} with_mode={with_mode} />
+        <KrbInput
+            krb_input={krb_input}
+            krb_input_setter={krb_input_setter}
+            krb_algo={krb_algo}
+            etype_setter={etype_setter}
+            with_mode={with_mode}
+        />
     }
 }
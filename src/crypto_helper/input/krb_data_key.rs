@@ -0,0 +1,73 @@
+use yew::{Callback, Html, Properties, function_component, html};
+
+use crate::common::{BytesFormat, build_byte_input};
+use crate::crypto_helper::algorithm::KrbDataKeyInput as KrbDataKeyInputData;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbDataKeyInputProps {
+    pub input: KrbDataKeyInputData,
+    pub input_setter: Callback<KrbDataKeyInputData>,
+    pub data_format: Option<BytesFormat>,
+    pub data_placeholder: &'static str,
+    pub key_placeholder: &'static str,
+}
+
+#[function_component(KrbDataKeyInput)]
+pub fn krb_data_key_input(props: &KrbDataKeyInputProps) -> Html {
+    let KrbDataKeyInputProps {
+        input,
+        input_setter,
+        data_format,
+        data_placeholder,
+        key_placeholder,
+    } = props.clone();
+    let KrbDataKeyInputData { data, key } = input;
+
+    let set_input = input_setter.clone();
+    let data_key = key.clone();
+    let set_data = Callback::from(move |data: Vec<u8>| {
+        set_input.emit(KrbDataKeyInputData {
+            data,
+            key: data_key.clone(),
+        });
+    });
+
+    let key_data = data.clone();
+    let set_key = Callback::from(move |key: Vec<u8>| {
+        input_setter.emit(KrbDataKeyInputData {
+            data: key_data.clone(),
+            key,
+        });
+    });
+
+    html! {
+        <div class="vertical">
+            {build_byte_input(data, set_data, data_format, Some(data_placeholder.into()))}
+            {build_byte_input(key, set_key, None, Some(key_placeholder.into()))}
+        </div>
+    }
+}
+
+pub fn build_krb_credentials_input(input: KrbDataKeyInputData, input_setter: Callback<KrbDataKeyInputData>) -> Html {
+    html! {
+        <KrbDataKeyInput
+            {input}
+            {input_setter}
+            data_format={Some(BytesFormat::Base64)}
+            data_placeholder="ccache file or KRB-CRED message"
+            key_placeholder="KRB-CRED enc-part key (not needed for the null etype)"
+        />
+    }
+}
+
+pub fn build_krb_pac_input(input: KrbDataKeyInputData, input_setter: Callback<KrbDataKeyInputData>) -> Html {
+    html! {
+        <KrbDataKeyInput
+            {input}
+            {input_setter}
+            data_format={None}
+            data_placeholder="PAC or the decrypted EncTicketPart"
+            key_placeholder="service key for the server checksum verification (optional)"
+        />
+    }
+}
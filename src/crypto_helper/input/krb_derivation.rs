@@ -0,0 +1,167 @@
+use web_sys::{Event, HtmlInputElement};
+use yew::{Callback, Html, Properties, TargetCast, function_component, html};
+use yew_notifications::use_notification;
+
+use super::{FieldCallbacks, build_etype_select, parse};
+use crate::common::build_byte_input;
+use crate::crypto_helper::algorithm::{
+    KRB_DERIVATION_MODES, KrbDerivationMode, KrbKeyDerivationInput as KrbKeyDerivationInputData,
+};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbKeyDerivationInputProps {
+    pub input: KrbKeyDerivationInputData,
+    pub input_setter: Callback<KrbKeyDerivationInputData>,
+}
+
+#[function_component(KrbKeyDerivationInput)]
+pub fn krb_key_derivation_input(props: &KrbKeyDerivationInputProps) -> Html {
+    let KrbKeyDerivationInputProps { input, input_setter } = props.clone();
+
+    let fields = FieldCallbacks::new(input.clone(), input_setter.clone(), use_notification());
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let set_etype = Callback::from(move |etype| {
+        setter.emit(KrbKeyDerivationInputData { etype, ..data.clone() });
+    });
+
+    let setter = input_setter.clone();
+    let data = input.clone();
+    let on_mode_change = Callback::from(move |event: Event| {
+        let select: HtmlInputElement = event.target_unchecked_into();
+
+        if let Ok(mode) = KrbDerivationMode::try_from(select.value().as_str()) {
+            setter.emit(KrbKeyDerivationInputData { mode, ..data.clone() });
+        }
+    });
+
+    let KrbKeyDerivationInputData {
+        etype,
+        mode,
+        password,
+        realm,
+        principal,
+        salt,
+        iterations,
+        key,
+        key_usage,
+        key2,
+        pepper1,
+        pepper2,
+        prf_len,
+    } = input.clone();
+
+    let params = match mode {
+        KrbDerivationMode::StringToKey => html! {
+            <div class="vertical">
+                <input
+                    class="base-input"
+                    placeholder={"password"}
+                    value={password}
+                    oninput={fields.on_text(|data, password| data.password = password)}
+                />
+                <div class="horizontal">
+                    <input
+                        class="base-input"
+                        placeholder={"realm"}
+                        value={realm}
+                        oninput={fields.on_text(|data, realm| data.realm = realm)}
+                    />
+                    <input
+                        class="base-input"
+                        placeholder={"principal name (e.g. HTTP/example.com)"}
+                        value={principal}
+                        oninput={fields.on_text(|data, principal| data.principal = principal)}
+                    />
+                </div>
+                <input
+                    class="base-input"
+                    placeholder={"salt (overrides realm and principal name)"}
+                    value={salt}
+                    oninput={fields.on_text(|data, salt| data.salt = salt)}
+                />
+                <input
+                    type={"number"}
+                    class="base-input"
+                    placeholder={"PBKDF2 iterations (etype default)"}
+                    value={iterations.map(|iterations| iterations.to_string()).unwrap_or_default()}
+                    oninput={fields.on_number("PBKDF2 iterations parsing", |data, value| {
+                        data.iterations = if value.is_empty() { None } else { Some(parse(value)?) };
+                        Ok(())
+                    })}
+                />
+            </div>
+        },
+        KrbDerivationMode::UsageKeys => html! {
+            <div class="vertical">
+                {build_byte_input(key, fields.on_bytes(|data, key| data.key = key), None, Some("base key".into()))}
+                <span class="total">{"Key usage number"}</span>
+                <input
+                    type={"number"}
+                    class="base-input"
+                    placeholder={"usage number"}
+                    value={key_usage.to_string()}
+                    oninput={fields.on_number("KRB key usage parsing", |data, value| {
+                        data.key_usage = parse(value)?;
+                        Ok(())
+                    })}
+                />
+            </div>
+        },
+        KrbDerivationMode::PrfPlus => html! {
+            <div class="vertical">
+                {build_byte_input(key, fields.on_bytes(|data, key| data.key = key), None, Some("key".into()))}
+                {build_byte_input(pepper1, fields.on_bytes(|data, pepper1| data.pepper1 = pepper1), None, Some("input".into()))}
+                <span class="total">{"Output length"}</span>
+                <input
+                    type={"number"}
+                    class="base-input"
+                    placeholder={"output length in bytes (etype PRF length)"}
+                    value={prf_len.map(|prf_len| prf_len.to_string()).unwrap_or_default()}
+                    oninput={fields.on_number("PRF+ output length parsing", |data, value| {
+                        data.prf_len = if value.is_empty() { None } else { Some(parse(value)?) };
+                        Ok(())
+                    })}
+                />
+            </div>
+        },
+        KrbDerivationMode::Cf2 => html! {
+            <div class="vertical">
+                {build_byte_input(key, fields.on_bytes(|data, key| data.key = key), None, Some("key1".into()))}
+                {build_byte_input(key2, fields.on_bytes(|data, key2| data.key2 = key2), None, Some("key2".into()))}
+                {build_byte_input(pepper1, fields.on_bytes(|data, pepper1| data.pepper1 = pepper1), None, Some("pepper1".into()))}
+                {build_byte_input(pepper2, fields.on_bytes(|data, pepper2| data.pepper2 = pepper2), None, Some("pepper2".into()))}
+            </div>
+        },
+    };
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <select class="base-input auto-width-input" onchange={on_mode_change}>
+                    {KRB_DERIVATION_MODES
+                        .iter()
+                        .map(|derivation_mode| html! {
+                            <option selected={mode == *derivation_mode} value={derivation_mode.as_ref().to_owned()}>
+                                {derivation_mode.as_ref()}
+                            </option>
+                        })
+                        .collect::<Vec<_>>()}
+                </select>
+                <span class="total">{"etype:"}</span>
+                {build_etype_select(etype, set_etype)}
+            </div>
+            {params}
+        </div>
+    }
+}
+
+pub fn build_krb_key_derivation_input(
+    input: KrbKeyDerivationInputData,
+    input_setter: Callback<KrbKeyDerivationInputData>,
+) -> Html {
+    html! {
+        <KrbKeyDerivationInput {input} {input_setter} />
+    }
+}
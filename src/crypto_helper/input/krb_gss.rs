@@ -0,0 +1,153 @@
+use yew::{Callback, Html, Properties, function_component, html};
+use yew_notifications::use_notification;
+
+use super::{FieldCallbacks, parse};
+use crate::common::{Switch, build_byte_input};
+use crate::crypto_helper::algorithm::{GssTokenType, KrbEtype, KrbGssTokenInput as KrbGssTokenInputData, KrbMode};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbGssTokenInputProps {
+    pub input: KrbGssTokenInputData,
+    pub input_setter: Callback<KrbGssTokenInputData>,
+}
+
+#[function_component(KrbGssTokenInput)]
+pub fn krb_gss_token_input(props: &KrbGssTokenInputProps) -> Html {
+    let KrbGssTokenInputProps { input, input_setter } = props.clone();
+
+    let fields = FieldCallbacks::new(input.clone(), input_setter.clone(), use_notification());
+
+    let KrbGssTokenInputData {
+        etype,
+        mode,
+        token_type,
+        acceptor,
+        confidential,
+        acceptor_subkey,
+        seq_number,
+        ec,
+        rrc,
+        key,
+        message,
+        token,
+    } = input.clone();
+    let is_wrap = token_type == GssTokenType::Wrap;
+
+    let params = match mode {
+        KrbMode::Encrypt => html! {
+            <div class="vertical">
+                <div class="horizontal">
+                    <span class="total">{"initiator"}</span>
+                    <Switch id={"gss-acceptor"} setter={fields.on_switch(|data, acceptor| data.acceptor = acceptor)} state={acceptor} />
+                    <span class="total">{"acceptor"}</span>
+                    <span class="total">{"|"}</span>
+                    <span class="total">{"acceptor subkey"}</span>
+                    <Switch
+                        id={"gss-acceptor-subkey"}
+                        setter={fields.on_switch(|data, acceptor_subkey| data.acceptor_subkey = acceptor_subkey)}
+                        state={acceptor_subkey}
+                    />
+                    {if is_wrap { html! {
+                        <>
+                            <span class="total">{"|"}</span>
+                            <span class="total">{"sealed"}</span>
+                            <Switch
+                                id={"gss-confidential"}
+                                setter={fields.on_switch(|data, confidential| data.confidential = confidential)}
+                                state={confidential}
+                            />
+                        </>
+                    }} else { html! {} }}
+                </div>
+                <div class="horizontal">
+                    <span class="total">{"SND_SEQ:"}</span>
+                    <input
+                        type={"number"}
+                        class="base-input auto-width-input"
+                        value={seq_number.to_string()}
+                        oninput={fields.on_number("SND_SEQ parsing", |data, value| {
+                            data.seq_number = parse(value)?;
+                            Ok(())
+                        })}
+                    />
+                    {if is_wrap { html! {
+                        <>
+                            <span class="total">{"RRC:"}</span>
+                            <input
+                                type={"number"}
+                                class="base-input auto-width-input"
+                                value={rrc.to_string()}
+                                oninput={fields.on_number("RRC parsing", |data, value| {
+                                    data.rrc = parse(value)?;
+                                    Ok(())
+                                })}
+                            />
+                        </>
+                    }} else { html! {} }}
+                    {if is_wrap && confidential { html! {
+                        <>
+                            <span class="total">{"EC:"}</span>
+                            <input
+                                type={"number"}
+                                class="base-input auto-width-input"
+                                value={ec.to_string()}
+                                oninput={fields.on_number("EC parsing", |data, value| {
+                                    data.ec = parse(value)?;
+                                    Ok(())
+                                })}
+                            />
+                        </>
+                    }} else { html! {} }}
+                </div>
+                {build_byte_input(message, fields.on_bytes(|data, message| data.message = message), None, Some("message".into()))}
+            </div>
+        },
+        KrbMode::Decrypt => html! {
+            <div class="vertical">
+                {build_byte_input(token, fields.on_bytes(|data, token| data.token = token), None, Some("token".into()))}
+                {if is_wrap { html! {} } else { html! {
+                    {build_byte_input(message, fields.on_bytes(|data, message| data.message = message), None, Some("message".into()))}
+                }}}
+            </div>
+        },
+    };
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <span class="total">{"seal"}</span>
+                <Switch id={"gss-mode"} setter={fields.on_switch(|data, mode| data.mode = mode.into())} state={bool::from(mode)} />
+                <span class="total">{"unseal"}</span>
+                <span class="total">{"|"}</span>
+                <span class="total">{"Wrap"}</span>
+                <Switch
+                    id={"gss-token-type"}
+                    setter={fields.on_switch(|data, token_type| data.token_type = token_type.into())}
+                    state={bool::from(token_type)}
+                />
+                <span class="total">{"MIC"}</span>
+                <span class="total">{"|"}</span>
+                <span class="total">{"etype:"}</span>
+                <input
+                    type={"number"}
+                    class="base-input auto-width-input"
+                    placeholder={"etype"}
+                    value={etype.number().to_string()}
+                    oninput={fields.on_number("KRB etype parsing", |data, value| {
+                        data.etype = KrbEtype::try_from(parse::<i32>(value)?)?;
+                        Ok(())
+                    })}
+                />
+                <span class="total">{format!("{} ({})", etype.as_ref(), etype.checksum_name())}</span>
+            </div>
+            {build_byte_input(key, fields.on_bytes(|data, key| data.key = key), None, Some("session key".into()))}
+            {params}
+        </div>
+    }
+}
+
+pub fn build_krb_gss_token_input(input: KrbGssTokenInputData, input_setter: Callback<KrbGssTokenInputData>) -> Html {
+    html! {
+        <KrbGssTokenInput {input} {input_setter} />
+    }
+}
@@ -1,23 +1,171 @@
+mod aes;
 mod argon2;
 mod bcrypt;
+mod chacha;
 mod hmac_sha;
+mod keytab;
 mod krb;
+mod krb_data_key;
+mod krb_derivation;
+mod krb_gss;
+mod ntlm;
 mod rsa;
 mod zlib;
 
-use picky_krb::crypto::CipherSuite;
-use yew::{Callback, Html, Properties, UseStateSetter, function_component, html};
+use std::str::FromStr;
 
+use web_sys::{Event, HtmlInputElement, InputEvent};
+use yew::{Callback, Html, Properties, TargetCast, UseStateSetter, function_component, html};
+use yew_notifications::{Notification, NotificationType, NotificationsManager};
+
+use self::aes::build_aes_input;
 use self::argon2::build_argon2_input;
 use self::bcrypt::build_bcrypt_input;
+use self::chacha::{build_chacha_poly1305_input, build_chacha20_input};
 use self::hmac_sha::build_hmac_sha_input;
+use self::keytab::build_keytab_input;
 use self::krb::build_krb_input;
+use self::krb_data_key::{build_krb_credentials_input, build_krb_pac_input};
+use self::krb_derivation::build_krb_key_derivation_input;
+use self::krb_gss::build_krb_gss_token_input;
+use self::ntlm::build_ntlm_input;
 use self::rsa::build_rsa_input;
 use self::zlib::build_zlib_input;
 use super::Algorithm;
-use super::algorithm::{KrbInput, KrbMode};
+use super::algorithm::{KRB_ETYPES, KrbEtype, KrbEtypeInput, KrbInput, KrbMode};
+use super::chacha::{CHACHA20_NONCE_LEN, XCHACHA20_NONCE_LEN};
 use crate::common::build_byte_input;
 
+/// Parses the number input value.
+fn parse<T: FromStr>(value: &str) -> Result<T, String>
+where
+    T::Err: ToString,
+{
+    value.parse::<T>().map_err(|err| err.to_string())
+}
+
+/// Builds the callbacks that update one field of the input data and emit the updated data.
+struct FieldCallbacks<T> {
+    data: T,
+    setter: Callback<T>,
+    notifications: NotificationsManager<Notification>,
+}
+
+impl<T: Clone + 'static> FieldCallbacks<T> {
+    fn new(data: T, setter: Callback<T>, notifications: NotificationsManager<Notification>) -> Self {
+        Self {
+            data,
+            setter,
+            notifications,
+        }
+    }
+
+    /// Parses the number input value and updates the input data with it. The parsing error is shown as the
+    /// notification with the given title.
+    fn on_number(&self, title: &'static str, update: fn(&mut T, &str) -> Result<(), String>) -> Callback<InputEvent> {
+        let setter = self.setter.clone();
+        let data = self.data.clone();
+        let notifications = self.notifications.clone();
+
+        Callback::from(move |event: InputEvent| {
+            let value: HtmlInputElement = event.target_unchecked_into();
+
+            let mut data = data.clone();
+            match update(&mut data, &value.value()) {
+                Ok(()) => setter.emit(data),
+                Err(err) => notifications.spawn(Notification::new(
+                    NotificationType::Error,
+                    title,
+                    err,
+                    Notification::NOTIFICATION_LIFETIME,
+                )),
+            }
+        })
+    }
+
+    fn on_text(&self, update: fn(&mut T, String)) -> Callback<InputEvent> {
+        let setter = self.setter.clone();
+        let data = self.data.clone();
+
+        Callback::from(move |event: InputEvent| {
+            let value: HtmlInputElement = event.target_unchecked_into();
+
+            let mut data = data.clone();
+            update(&mut data, value.value());
+            setter.emit(data);
+        })
+    }
+
+    fn on_switch(&self, update: fn(&mut T, bool)) -> Callback<bool> {
+        self.on_value(update)
+    }
+
+    fn on_bytes(&self, update: fn(&mut T, Vec<u8>)) -> Callback<Vec<u8>> {
+        self.on_value(update)
+    }
+
+    fn on_value<V: 'static>(&self, update: fn(&mut T, V)) -> Callback<V> {
+        let setter = self.setter.clone();
+        let data = self.data.clone();
+
+        Callback::from(move |value| {
+            let mut data = data.clone();
+            update(&mut data, value);
+            setter.emit(data);
+        })
+    }
+}
+
+/// Selector over the supported Kerberos encryption types.
+fn build_etype_select(etype: KrbEtype, setter: Callback<KrbEtype>) -> Html {
+    let onchange = Callback::from(move |event: Event| {
+        let select: HtmlInputElement = event.target_unchecked_into();
+
+        if let Ok(etype) = parse::<i32>(&select.value()).and_then(KrbEtype::try_from) {
+            setter.emit(etype);
+        }
+    });
+
+    html! {
+        <select class="base-input auto-width-input" {onchange}>
+            {KRB_ETYPES
+                .iter()
+                .map(|krb_etype| html! {
+                    <option selected={etype == *krb_etype} value={krb_etype.number().to_string()}>
+                        {format!("{} ({})", krb_etype.as_ref(), krb_etype.number())}
+                    </option>
+                })
+                .collect::<Vec<_>>()}
+        </select>
+    }
+}
+
+fn build_krb_etype_input(
+    input: &KrbEtypeInput,
+    setter: UseStateSetter<Algorithm>,
+    algorithm: fn(KrbEtypeInput) -> Algorithm,
+    with_mode: bool,
+) -> Html {
+    let etype = input.etype;
+    let krb_input = input.input.clone();
+
+    let input_setter = setter.clone();
+    let etype_setter = Callback::from(move |etype| {
+        setter.set(algorithm(KrbEtypeInput {
+            etype,
+            input: krb_input.clone(),
+        }))
+    });
+
+    build_krb_input(
+        input.input.clone(),
+        Callback::from(move |input| input_setter.set(algorithm(KrbEtypeInput { etype, input }))),
+        etype,
+        Some(etype_setter),
+        with_mode,
+    )
+}
+
 fn get_input_components(algorithm: &Algorithm, setter: &UseStateSetter<Algorithm>) -> Html {
     let setter = setter.clone();
     match algorithm {
@@ -54,13 +202,15 @@ fn get_input_components(algorithm: &Algorithm, setter: &UseStateSetter<Algorithm
         Algorithm::Aes128CtsHmacSha196(kerberos_input) => build_krb_input(
             kerberos_input.clone(),
             Callback::from(move |kerberos_input| setter.set(Algorithm::Aes128CtsHmacSha196(kerberos_input))),
-            CipherSuite::Aes128CtsHmacSha196,
+            KrbEtype::Aes128CtsHmacSha196,
+            None,
             true,
         ),
         Algorithm::Aes256CtsHmacSha196(kerberos_input) => build_krb_input(
             kerberos_input.clone(),
             Callback::from(move |kerberos_input| setter.set(Algorithm::Aes256CtsHmacSha196(kerberos_input))),
-            CipherSuite::Aes256CtsHmacSha196,
+            KrbEtype::Aes256CtsHmacSha196,
+            None,
             true,
         ),
         Algorithm::HmacSha196Aes128(kerberos_input) => build_krb_input(
@@ -71,7 +221,8 @@ fn get_input_components(algorithm: &Algorithm, setter: &UseStateSetter<Algorithm
             Callback::from(move |kerberos_input: KrbInput| {
                 setter.set(Algorithm::HmacSha196Aes128(kerberos_input.data))
             }),
-            CipherSuite::Aes128CtsHmacSha196,
+            KrbEtype::Aes128CtsHmacSha196,
+            None,
             false,
         ),
         Algorithm::HmacSha196Aes256(kerberos_input) => build_krb_input(
@@ -82,7 +233,8 @@ fn get_input_components(algorithm: &Algorithm, setter: &UseStateSetter<Algorithm
             Callback::from(move |kerberos_input: KrbInput| {
                 setter.set(Algorithm::HmacSha196Aes256(kerberos_input.data))
             }),
-            CipherSuite::Aes256CtsHmacSha196,
+            KrbEtype::Aes256CtsHmacSha196,
+            None,
             false,
         ),
         Algorithm::Rsa(input) => build_rsa_input(
@@ -105,6 +257,47 @@ fn get_input_components(algorithm: &Algorithm, setter: &UseStateSetter<Algorithm
             input.clone(),
             Callback::from(move |input| setter.set(Algorithm::HmacSha(input))),
         ),
+        Algorithm::Aes(input) => build_aes_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::Aes(input))),
+        ),
+        Algorithm::ChaCha20(input) => build_chacha20_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::ChaCha20(input))),
+        ),
+        Algorithm::ChaCha20Poly1305(input) => build_chacha_poly1305_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::ChaCha20Poly1305(input))),
+            CHACHA20_NONCE_LEN,
+        ),
+        Algorithm::XChaCha20Poly1305(input) => build_chacha_poly1305_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::XChaCha20Poly1305(input))),
+            XCHACHA20_NONCE_LEN,
+        ),
+        Algorithm::KrbCipher(input) => build_krb_etype_input(input, setter, Algorithm::KrbCipher, true),
+        Algorithm::KrbChecksum(input) => build_krb_etype_input(input, setter, Algorithm::KrbChecksum, false),
+        Algorithm::KrbKeyDerivation(input) => build_krb_key_derivation_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::KrbKeyDerivation(input))),
+        ),
+        Algorithm::Keytab(input) => build_keytab_input(input.clone(), setter),
+        Algorithm::KrbCredentials(input) => build_krb_credentials_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::KrbCredentials(input))),
+        ),
+        Algorithm::KrbPac(input) => build_krb_pac_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::KrbPac(input))),
+        ),
+        Algorithm::KrbGssToken(input) => build_krb_gss_token_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::KrbGssToken(input))),
+        ),
+        Algorithm::Ntlm(input) => build_ntlm_input(
+            input.clone(),
+            Callback::from(move |input| setter.set(Algorithm::Ntlm(input))),
+        ),
     }
 }
 
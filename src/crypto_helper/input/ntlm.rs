@@ -0,0 +1,154 @@
+use yew::{Callback, Html, Properties, function_component, html};
+use yew_notifications::use_notification;
+
+use super::{FieldCallbacks, parse};
+use crate::common::{Switch, build_byte_input};
+use crate::crypto_helper::algorithm::{NtlmInput as NtlmInputData, NtlmResponseVersion};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct NtlmInputProps {
+    pub input: NtlmInputData,
+    pub input_setter: Callback<NtlmInputData>,
+}
+
+#[function_component(NtlmInput)]
+pub fn ntlm_input(props: &NtlmInputProps) -> Html {
+    let NtlmInputProps { input, input_setter } = props.clone();
+
+    let fields = FieldCallbacks::new(input.clone(), input_setter.clone(), use_notification());
+
+    let NtlmInputData {
+        version,
+        user,
+        domain,
+        password,
+        extended_session_security,
+        key_exchange,
+        server_challenge,
+        client_challenge,
+        timestamp,
+        target_info,
+        encrypted_random_session_key,
+        negotiate_message,
+        challenge_message,
+        authenticate_message,
+    } = input.clone();
+    let is_v2 = version == NtlmResponseVersion::V2;
+
+    html! {
+        <div class="vertical">
+            <div class="horizontal">
+                <span class="total">{"NTLMv1"}</span>
+                <Switch id={"ntlm-version"} setter={fields.on_switch(|data, version| data.version = version.into())} state={bool::from(version)} />
+                <span class="total">{"NTLMv2"}</span>
+                {if is_v2 { html! {} } else { html! {
+                    <>
+                        <span class="total">{"|"}</span>
+                        <span class="total">{"extended session security"}</span>
+                        <Switch
+                            id={"ntlm-ess"}
+                            setter={fields.on_switch(|data, ess| data.extended_session_security = ess)}
+                            state={extended_session_security}
+                        />
+                    </>
+                }}}
+                <span class="total">{"|"}</span>
+                <span class="total">{"key exchange"}</span>
+                <Switch
+                    id={"ntlm-key-exchange"}
+                    setter={fields.on_switch(|data, key_exchange| data.key_exchange = key_exchange)}
+                    state={key_exchange}
+                />
+            </div>
+            <div class="horizontal">
+                <input
+                    class="base-input"
+                    placeholder={"user"}
+                    value={user}
+                    oninput={fields.on_text(|data, user| data.user = user)}
+                />
+                <input
+                    class="base-input"
+                    placeholder={"domain"}
+                    value={domain}
+                    oninput={fields.on_text(|data, domain| data.domain = domain)}
+                />
+            </div>
+            <input
+                class="base-input"
+                placeholder={"password"}
+                value={password}
+                oninput={fields.on_text(|data, password| data.password = password)}
+            />
+            {build_byte_input(
+                server_challenge,
+                fields.on_bytes(|data, server_challenge| data.server_challenge = server_challenge),
+                None,
+                Some("server challenge".into()),
+            )}
+            {if is_v2 || extended_session_security { html! {
+                {build_byte_input(
+                    client_challenge,
+                    fields.on_bytes(|data, client_challenge| data.client_challenge = client_challenge),
+                    None,
+                    Some("client challenge".into()),
+                )}
+            }} else { html! {} }}
+            {if is_v2 { html! {
+                <>
+                    <div class="horizontal">
+                        <span class="total">{"timestamp (FILETIME):"}</span>
+                        <input
+                            type={"number"}
+                            class="base-input"
+                            value={timestamp.to_string()}
+                            oninput={fields.on_number("NTLM timestamp parsing", |data, value| {
+                                data.timestamp = parse(value)?;
+                                Ok(())
+                            })}
+                        />
+                    </div>
+                    {build_byte_input(
+                        target_info,
+                        fields.on_bytes(|data, target_info| data.target_info = target_info),
+                        None,
+                        Some("target info".into()),
+                    )}
+                </>
+            }} else { html! {} }}
+            {if key_exchange { html! {
+                {build_byte_input(
+                    encrypted_random_session_key,
+                    fields.on_bytes(|data, key| data.encrypted_random_session_key = key),
+                    None,
+                    Some("encrypted random session key".into()),
+                )}
+            }} else { html! {} }}
+            <span class="total">{"Messages for the MIC (optional)"}</span>
+            {build_byte_input(
+                negotiate_message,
+                fields.on_bytes(|data, message| data.negotiate_message = message),
+                None,
+                Some("NEGOTIATE_MESSAGE".into()),
+            )}
+            {build_byte_input(
+                challenge_message,
+                fields.on_bytes(|data, message| data.challenge_message = message),
+                None,
+                Some("CHALLENGE_MESSAGE".into()),
+            )}
+            {build_byte_input(
+                authenticate_message,
+                fields.on_bytes(|data, message| data.authenticate_message = message),
+                None,
+                Some("AUTHENTICATE_MESSAGE".into()),
+            )}
+        </div>
+    }
+}
+
+pub fn build_ntlm_input(input: NtlmInputData, input_setter: Callback<NtlmInputData>) -> Html {
+    html! {
+        <NtlmInput {input} {input_setter} />
+    }
+}
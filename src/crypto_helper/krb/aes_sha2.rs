@@ -0,0 +1,237 @@
+//! aes128-cts-hmac-sha256-128 and aes256-cts-hmac-sha384-192 encryption types (RFC 8009).
+
+use sha2::{Sha256, Sha384};
+
+use super::{DecryptTrace, pbkdf2};
+use crate::crypto_helper::algorithm::{CipherAction, KrbEtype};
+use crate::crypto_helper::block_modes::{AES_BLOCK_SIZE, aes_cts};
+
+/// Default PBKDF2 iteration count (RFC 8009 4).
+pub const ITERATION_COUNT: u32 = 32_768;
+
+pub const KE_CONSTANT: u8 = 0xaa;
+pub const KI_CONSTANT: u8 = 0x55;
+pub const KC_CONSTANT: u8 = 0x99;
+
+fn hmac(etype: KrbEtype, key: &[u8], data: &[u8]) -> Vec<u8> {
+    match etype {
+        KrbEtype::Aes256CtsHmacSha384192 => sign_hmac!(hash_alg: Sha384, key: key, msg: data,),
+        _ => sign_hmac!(hash_alg: Sha256, key: key, msg: data,),
+    }
+}
+
+/// KDF-HMAC-SHA2 from RFC 8009 3.
+pub fn kdf(etype: KrbEtype, key: &[u8], label: &[u8], len: usize) -> Vec<u8> {
+    kdf_with_context(etype, key, label, &[], len)
+}
+
+pub fn kdf_with_context(etype: KrbEtype, key: &[u8], label: &[u8], context: &[u8], len: usize) -> Vec<u8> {
+    let mut data = 1_u32.to_be_bytes().to_vec();
+    data.extend_from_slice(label);
+    data.push(0);
+    data.extend_from_slice(context);
+    data.extend_from_slice(&((len * 8) as u32).to_be_bytes());
+
+    let mut output = hmac(etype, key, &data);
+    output.truncate(len);
+
+    output
+}
+
+pub fn derive_key(etype: KrbEtype, key: &[u8], key_usage: i32, constant: u8, len: usize) -> Vec<u8> {
+    let mut label = key_usage.to_be_bytes().to_vec();
+    label.push(constant);
+
+    kdf(etype, key, &label, len)
+}
+
+fn check_key(etype: KrbEtype, key: &[u8]) -> Result<(), String> {
+    if key.len() != etype.key_len() {
+        return Err(format!(
+            "{}: invalid key length: expected {} bytes but got {}",
+            etype.as_ref(),
+            etype.key_len(),
+            key.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn integrity_hmac(etype: KrbEtype, ki: &[u8], cipher_text: &[u8]) -> Vec<u8> {
+    // The HMAC is calculated over the (zero) IV concatenated with the cipher text.
+    let mut data = vec![0; AES_BLOCK_SIZE];
+    data.extend_from_slice(cipher_text);
+
+    let mut hmac = hmac(etype, ki, &data);
+    hmac.truncate(etype.hmac_len());
+
+    hmac
+}
+
+pub fn encrypt(
+    etype: KrbEtype,
+    key: &[u8],
+    key_usage: i32,
+    confounder: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>, String> {
+    check_key(etype, key)?;
+
+    let ke = derive_key(etype, key, key_usage, KE_CONSTANT, etype.key_len());
+    let ki = derive_key(etype, key, key_usage, KI_CONSTANT, etype.hmac_len());
+
+    let mut plain_text = confounder.to_vec();
+    plain_text.extend_from_slice(payload);
+
+    let mut cipher_text = aes_cts(&ke, CipherAction::Encrypt, &plain_text)?;
+    let hmac = integrity_hmac(etype, &ki, &cipher_text);
+    cipher_text.extend_from_slice(&hmac);
+
+    Ok(cipher_text)
+}
+
+pub fn decrypt_trace(etype: KrbEtype, key: &[u8], key_usage: i32, cipher_data: &[u8]) -> Result<DecryptTrace, String> {
+    check_key(etype, key)?;
+
+    let hmac_len = etype.hmac_len();
+    if cipher_data.len() < AES_BLOCK_SIZE + hmac_len {
+        return Err(format!(
+            "{}: invalid cipher length: expected at least {} bytes but got {}",
+            etype.as_ref(),
+            AES_BLOCK_SIZE + hmac_len,
+            cipher_data.len()
+        ));
+    }
+
+    let ke = derive_key(etype, key, key_usage, KE_CONSTANT, etype.key_len());
+    let ki = derive_key(etype, key, key_usage, KI_CONSTANT, hmac_len);
+
+    let (cipher_text, hmac) = cipher_data.split_at(cipher_data.len() - hmac_len);
+    let mut confounder = aes_cts(&ke, CipherAction::Decrypt, cipher_text)?;
+    let plaintext = confounder.split_off(AES_BLOCK_SIZE);
+
+    Ok(DecryptTrace {
+        confounder,
+        plaintext,
+        expected_hmac: integrity_hmac(etype, &ki, cipher_text),
+        hmac: hmac.to_vec(),
+    })
+}
+
+pub fn decrypt(etype: KrbEtype, key: &[u8], key_usage: i32, cipher_data: &[u8]) -> Result<Vec<u8>, String> {
+    let trace = decrypt_trace(etype, key, key_usage, cipher_data)?;
+
+    if !trace.is_integrity_valid() {
+        return Err(format!(
+            "{}: integrity check failed: expected hmac {} but got {}",
+            etype.as_ref(),
+            hex::encode(trace.expected_hmac),
+            hex::encode(trace.hmac)
+        ));
+    }
+
+    Ok(trace.plaintext)
+}
+
+pub fn checksum(etype: KrbEtype, key: &[u8], key_usage: i32, payload: &[u8]) -> Result<Vec<u8>, String> {
+    check_key(etype, key)?;
+
+    let kc = derive_key(etype, key, key_usage, KC_CONSTANT, etype.hmac_len());
+
+    let mut checksum = hmac(etype, &kc, payload);
+    checksum.truncate(etype.hmac_len());
+
+    Ok(checksum)
+}
+
+/// saltp = enctype-name | 0x00 | salt
+pub fn salt_p(etype: KrbEtype, salt: &[u8]) -> Vec<u8> {
+    let mut salt_p = etype.as_ref().as_bytes().to_vec();
+    salt_p.push(0);
+    salt_p.extend_from_slice(salt);
+
+    salt_p
+}
+
+pub fn string_to_key(etype: KrbEtype, password: &[u8], salt: &[u8]) -> Vec<u8> {
+    let tkey = pbkdf2(etype, password, &salt_p(etype, salt), ITERATION_COUNT);
+
+    kdf(etype, &tkey, b"kerberos", etype.key_len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checksum, decrypt, encrypt, string_to_key};
+    use crate::crypto_helper::algorithm::KrbEtype;
+
+    const AES128_KEY: &str = "3705d96080c17728a0e800eab6e0d23c";
+
+    #[test]
+    fn rfc8009_string_to_key() {
+        let mut salt = hex::decode("10df9dd783e5bc8acea1730e74355f61").unwrap();
+        salt.extend_from_slice(b"ATHENA.MIT.EDUraeburn");
+
+        assert_eq!(
+            "089bca48b105ea6ea77ca5d2f39dc5e7",
+            hex::encode(string_to_key(KrbEtype::Aes128CtsHmacSha256128, b"password", &salt))
+        );
+        assert_eq!(
+            "45bd806dbf6a833a9cffc1c94589a222367a79bc21c413718906e9f578a78467",
+            hex::encode(string_to_key(KrbEtype::Aes256CtsHmacSha384192, b"password", &salt))
+        );
+    }
+
+    #[test]
+    fn rfc8009_checksum() {
+        let payload = hex::decode("000102030405060708090a0b0c0d0e0f1011121314").unwrap();
+
+        assert_eq!(
+            "d78367186643d67b411cba9139fc1dee",
+            hex::encode(
+                checksum(
+                    KrbEtype::Aes128CtsHmacSha256128,
+                    &hex::decode(AES128_KEY).unwrap(),
+                    2,
+                    &payload
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn rfc8009_encryption() {
+        let confounder = hex::decode("7e5895eaf2672435bad817f545a37148").unwrap();
+        let cipher = encrypt(
+            KrbEtype::Aes128CtsHmacSha256128,
+            &hex::decode(AES128_KEY).unwrap(),
+            2,
+            &confounder,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            "ef85fb890bb8472f4dab20394dca781dad877eda39d50c870c0d5a0a8e48c718",
+            hex::encode(cipher)
+        );
+    }
+
+    #[test]
+    fn encryption_round_trip() {
+        let key = hex::decode(AES128_KEY).unwrap();
+        let confounder = [7; 16];
+
+        for payload in [&b""[..], b"short", b"a payload longer than the single aes block"] {
+            let cipher = encrypt(KrbEtype::Aes128CtsHmacSha256128, &key, 3, &confounder, payload).unwrap();
+
+            assert_eq!(payload.len() + 16 + 16, cipher.len());
+            assert_eq!(
+                payload,
+                decrypt(KrbEtype::Aes128CtsHmacSha256128, &key, 3, &cipher).unwrap()
+            );
+            assert!(decrypt(KrbEtype::Aes128CtsHmacSha256128, &key, 4, &cipher).is_err());
+        }
+    }
+}
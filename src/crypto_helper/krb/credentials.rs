@@ -0,0 +1,560 @@
+//! Kerberos credentials from the credential cache (FILE format v3/v4) and KRB-CRED (RFC 4120 5.8) messages.
+
+use picky_asn1::wrapper::{
+    Asn1SequenceOf, ExplicitContextTag0, ExplicitContextTag1, ExplicitContextTag2, ExplicitContextTag3,
+    ExplicitContextTag4, ExplicitContextTag5, ExplicitContextTag6, ExplicitContextTag7, ExplicitContextTag8,
+    ExplicitContextTag9, ExplicitContextTag10, IntegerAsn1, Optional,
+};
+use picky_asn1_der::application_tag::ApplicationTag;
+use picky_krb::data_types::{
+    EncryptedData, EncryptionKey, HostAddress, HostAddresses, KerberosFlags, KerberosTime, Microseconds, PrincipalName,
+    Realm, Ticket,
+};
+use serde::{Deserialize, Serialize};
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+use super::reader::{Encoding, Reader};
+use super::{decrypt, etype_name};
+use crate::crypto_helper::algorithm::{KrbDataKeyInput, KrbEtype};
+
+/// KRB-CRED enc-part key usage (RFC 4120 7.5.1).
+pub const KRB_CRED_KEY_USAGE: i32 = 14;
+
+const CCACHE_MAGIC: u8 = 0x05;
+const KRB_CRED_TYPE: u8 = 22;
+const ENC_KRB_CRED_PART_TYPE: u8 = 29;
+
+/// Ticket flag names by the bit number (RFC 4120 5.3).
+const TICKET_FLAGS: [(usize, &str); 15] = [
+    (1, "forwardable"),
+    (2, "forwarded"),
+    (3, "proxiable"),
+    (4, "proxy"),
+    (5, "may-postdate"),
+    (6, "postdated"),
+    (7, "invalid"),
+    (8, "renewable"),
+    (9, "initial"),
+    (10, "pre-authent"),
+    (11, "hw-authent"),
+    (12, "transited-policy-checked"),
+    (13, "ok-as-delegate"),
+    (15, "enc-pa-rep"),
+    (16, "anonymous"),
+];
+
+/// ```not_rust
+/// KRB-CRED        ::= [APPLICATION 22]   SEQUENCE {
+///         pvno            [0] INTEGER (5),
+///         msg-type        [1] INTEGER (22),
+///         tickets         [2] SEQUENCE OF Ticket,
+///         enc-part        [3] EncryptedData -- EncKrbCredPart
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct KrbCredInner {
+    pub pvno: ExplicitContextTag0<IntegerAsn1>,
+    pub msg_type: ExplicitContextTag1<IntegerAsn1>,
+    pub tickets: ExplicitContextTag2<Asn1SequenceOf<Ticket>>,
+    pub enc_part: ExplicitContextTag3<EncryptedData>,
+}
+
+pub type KrbCred = ApplicationTag<KrbCredInner, KRB_CRED_TYPE>;
+
+/// ```not_rust
+/// KrbCredInfo     ::= SEQUENCE {
+///         key             [0] EncryptionKey,
+///         prealm          [1] Realm OPTIONAL,
+///         pname           [2] PrincipalName OPTIONAL,
+///         flags           [3] TicketFlags OPTIONAL,
+///         authtime        [4] KerberosTime OPTIONAL,
+///         starttime       [5] KerberosTime OPTIONAL,
+///         endtime         [6] KerberosTime OPTIONAL,
+///         renew-till      [7] KerberosTime OPTIONAL,
+///         srealm          [8] Realm OPTIONAL,
+///         sname           [9] PrincipalName OPTIONAL,
+///         caddr           [10] HostAddresses OPTIONAL
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct KrbCredInfo {
+    pub key: ExplicitContextTag0<EncryptionKey>,
+    #[serde(default)]
+    pub prealm: Optional<Option<ExplicitContextTag1<Realm>>>,
+    #[serde(default)]
+    pub pname: Optional<Option<ExplicitContextTag2<PrincipalName>>>,
+    #[serde(default)]
+    pub flags: Optional<Option<ExplicitContextTag3<KerberosFlags>>>,
+    #[serde(default)]
+    pub auth_time: Optional<Option<ExplicitContextTag4<KerberosTime>>>,
+    #[serde(default)]
+    pub start_time: Optional<Option<ExplicitContextTag5<KerberosTime>>>,
+    #[serde(default)]
+    pub end_time: Optional<Option<ExplicitContextTag6<KerberosTime>>>,
+    #[serde(default)]
+    pub renew_till: Optional<Option<ExplicitContextTag7<KerberosTime>>>,
+    #[serde(default)]
+    pub srealm: Optional<Option<ExplicitContextTag8<Realm>>>,
+    #[serde(default)]
+    pub sname: Optional<Option<ExplicitContextTag9<PrincipalName>>>,
+    #[serde(default)]
+    pub caddr: Optional<Option<ExplicitContextTag10<HostAddresses>>>,
+}
+
+/// ```not_rust
+/// EncKrbCredPart  ::= [APPLICATION 29]   SEQUENCE {
+///         ticket-info     [0] SEQUENCE OF KrbCredInfo,
+///         nonce           [1] UInt32 OPTIONAL,
+///         timestamp       [2] KerberosTime OPTIONAL,
+///         usec            [3] Microseconds OPTIONAL,
+///         s-address       [4] HostAddress OPTIONAL,
+///         r-address       [5] HostAddress OPTIONAL
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct EncKrbCredPartInner {
+    pub ticket_info: ExplicitContextTag0<Asn1SequenceOf<KrbCredInfo>>,
+    #[serde(default)]
+    pub nonce: Optional<Option<ExplicitContextTag1<IntegerAsn1>>>,
+    #[serde(default)]
+    pub timestamp: Optional<Option<ExplicitContextTag2<KerberosTime>>>,
+    #[serde(default)]
+    pub usec: Optional<Option<ExplicitContextTag3<Microseconds>>>,
+    #[serde(default)]
+    pub s_address: Optional<Option<ExplicitContextTag4<HostAddress>>>,
+    #[serde(default)]
+    pub r_address: Optional<Option<ExplicitContextTag5<HostAddress>>>,
+}
+
+pub type EncKrbCredPart = ApplicationTag<EncKrbCredPartInner, ENC_KRB_CRED_PART_TYPE>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Credential {
+    pub client: String,
+    pub server: String,
+    pub flags: u32,
+    /// Unix timestamps.
+    pub auth_time: Option<i64>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub renew_till: Option<i64>,
+    pub key_etype: i32,
+    pub key: Vec<u8>,
+    /// DER-encoded Ticket.
+    pub ticket: Vec<u8>,
+}
+
+/// Returns the names of the set ticket flags.
+pub fn ticket_flag_names(flags: u32) -> Vec<&'static str> {
+    TICKET_FLAGS
+        .iter()
+        .filter(|(bit, _)| flags & (0x8000_0000 >> bit) != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+trait CcacheReader<'data> {
+    fn read_data(&mut self) -> Result<&'data [u8], String>;
+    fn read_principal(&mut self) -> Result<String, String>;
+    fn read_time(&mut self) -> Result<Option<i64>, String>;
+}
+
+impl<'data> CcacheReader<'data> for Reader<'data> {
+    fn read_data(&mut self) -> Result<&'data [u8], String> {
+        let len = self.read_u32()?;
+
+        self.read(len as usize)
+    }
+
+    fn read_principal(&mut self) -> Result<String, String> {
+        let _name_type = self.read_u32()?;
+        let components_count = self.read_u32()?;
+        let realm = String::from_utf8_lossy(self.read_data()?).into_owned();
+        let components = (0..components_count)
+            .map(|_| Ok(String::from_utf8_lossy(self.read_data()?).into_owned()))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(format!("{}@{}", components.join("/"), realm))
+    }
+
+    fn read_time(&mut self) -> Result<Option<i64>, String> {
+        Ok(match self.read_u32()? {
+            0 => None,
+            time => Some(time.into()),
+        })
+    }
+}
+
+fn parse_ccache_credential(reader: &mut Reader, version: u8) -> Result<Credential, String> {
+    let client = reader.read_principal()?;
+    let server = reader.read_principal()?;
+
+    let key_etype = reader.read_u16()?.into();
+    if version == 3 {
+        // The etype is written twice in the version 3.
+        reader.read_u16()?;
+    }
+    let key = reader.read_data()?.to_vec();
+
+    let auth_time = reader.read_time()?;
+    let start_time = reader.read_time()?;
+    let end_time = reader.read_time()?;
+    let renew_till = reader.read_time()?;
+    let _is_skey = reader.read_array::<1>()?;
+    let flags = reader.read_u32()?;
+
+    // Addresses and authorization data.
+    for _ in 0..2 {
+        for _ in 0..reader.read_u32()? {
+            reader.read_u16()?;
+            reader.read_data()?;
+        }
+    }
+
+    let ticket = reader.read_data()?.to_vec();
+    let _second_ticket = reader.read_data()?;
+
+    Ok(Credential {
+        client,
+        server,
+        flags,
+        auth_time,
+        start_time,
+        end_time,
+        renew_till,
+        key_etype,
+        key,
+        ticket,
+    })
+}
+
+/// Parses the FILE credential cache (versions 3 and 4).
+pub fn parse_ccache(data: &[u8]) -> Result<Vec<Credential>, String> {
+    let mut reader = Reader::new("ccache", Encoding::BigEndian, data);
+
+    let [magic, version] = reader.read_array()?;
+    if magic != CCACHE_MAGIC || !(3..=4).contains(&version) {
+        return Err(format!(
+            "ccache: unsupported version: expected 0503 or 0504 but got {:02x}{:02x}",
+            magic, version
+        ));
+    }
+
+    if version == 4 {
+        let header_len = reader.read_u16()?;
+        reader.read(header_len.into())?;
+    }
+
+    let _default_principal = reader.read_principal()?;
+
+    let mut credentials = Vec::new();
+    while !reader.is_empty() {
+        credentials.push(parse_ccache_credential(&mut reader, version)?);
+    }
+
+    Ok(credentials)
+}
+
+fn integer(value: &IntegerAsn1) -> i64 {
+    let bytes = &value.0;
+    let init = if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+        -1
+    } else {
+        0
+    };
+
+    bytes.iter().fold(init, |value, byte| (value << 8) | i64::from(*byte))
+}
+
+fn principal(name: Option<&PrincipalName>, realm: Option<&Realm>) -> String {
+    let name = name
+        .map(|name| {
+            name.name_string
+                .0
+                .iter()
+                .map(|component| component.to_string())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default();
+
+    match realm {
+        Some(realm) => format!("{}@{}", name, realm.0),
+        None => name,
+    }
+}
+
+fn unix_time(time: &KerberosTime) -> Option<i64> {
+    let date = Date::from_calendar_date(time.year().into(), Month::try_from(time.month()).ok()?, time.day()).ok()?;
+    let time = Time::from_hms(time.hour(), time.minute(), time.second()).ok()?;
+
+    Some(PrimitiveDateTime::new(date, time).assume_utc().unix_timestamp())
+}
+
+fn credential(info: &KrbCredInfo, ticket: Vec<u8>) -> Credential {
+    let flags = info
+        .flags
+        .0
+        .as_ref()
+        .map(|flags| {
+            let mut bytes = flags.0.payload_view().to_vec();
+            bytes.resize(4, 0);
+
+            u32::from_be_bytes(bytes[0..4].try_into().expect("length is 4"))
+        })
+        .unwrap_or_default();
+
+    Credential {
+        client: principal(info.pname.0.as_deref(), info.prealm.0.as_deref()),
+        server: principal(info.sname.0.as_deref(), info.srealm.0.as_deref()),
+        flags,
+        auth_time: info.auth_time.0.as_ref().and_then(|time| unix_time(time)),
+        start_time: info.start_time.0.as_ref().and_then(|time| unix_time(time)),
+        end_time: info.end_time.0.as_ref().and_then(|time| unix_time(time)),
+        renew_till: info.renew_till.0.as_ref().and_then(|time| unix_time(time)),
+        key_etype: integer(&info.key.key_type) as i32,
+        key: info.key.key_value.0.0.clone(),
+        ticket,
+    }
+}
+
+/// Returns the EncKrbCredPart. The null etype (0) means that the enc-part is not encrypted.
+pub fn decrypt_krb_cred_part(krb_cred: &KrbCred, key: &[u8]) -> Result<Vec<u8>, String> {
+    let enc_part = &krb_cred.0.enc_part;
+    let cipher = &enc_part.cipher.0.0;
+
+    match integer(&enc_part.etype) as i32 {
+        0 => Ok(cipher.clone()),
+        etype => {
+            if key.is_empty() {
+                return Err(format!(
+                    "KRB-CRED: the key is needed to decrypt the enc-part ({})",
+                    etype_name(etype)
+                ));
+            }
+
+            decrypt(KrbEtype::try_from(etype)?, key, KRB_CRED_KEY_USAGE, cipher)
+        }
+    }
+}
+
+pub fn parse_krb_cred(data: &[u8], key: &[u8]) -> Result<Vec<Credential>, String> {
+    let krb_cred: KrbCred = picky_asn1_der::from_bytes(data).map_err(|err| format!("KRB-CRED: {}", err))?;
+    let enc_part: EncKrbCredPart = picky_asn1_der::from_bytes(&decrypt_krb_cred_part(&krb_cred, key)?)
+        .map_err(|err| format!("EncKrbCredPart: {}", err))?;
+
+    let tickets = &krb_cred.0.tickets.0.0;
+    enc_part
+        .0
+        .ticket_info
+        .0
+        .0
+        .iter()
+        .enumerate()
+        .map(|(index, info)| {
+            let ticket = match tickets.get(index) {
+                Some(ticket) => picky_asn1_der::to_vec(ticket).map_err(|err| format!("Ticket: {}", err))?,
+                None => Vec::new(),
+            };
+
+            Ok(credential(info, ticket))
+        })
+        .collect()
+}
+
+/// Parses the ccache file or the KRB-CRED message depending on the first byte.
+pub fn parse_credentials(input: &KrbDataKeyInput) -> Result<Vec<Credential>, String> {
+    match input.data.first() {
+        Some(&CCACHE_MAGIC) => parse_ccache(&input.data),
+        Some(_) => parse_krb_cred(&input.data, &input.key),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Returns the decrypted EncKrbCredPart for KRB-CRED and the ccache file itself.
+pub fn process_krb_credentials(input: &KrbDataKeyInput) -> Result<Vec<u8>, String> {
+    if input.data.first() == Some(&CCACHE_MAGIC) {
+        parse_ccache(&input.data)?;
+
+        Ok(input.data.clone())
+    } else {
+        let krb_cred: KrbCred = picky_asn1_der::from_bytes(&input.data).map_err(|err| format!("KRB-CRED: {}", err))?;
+
+        decrypt_krb_cred_part(&krb_cred, &input.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use picky_asn1::bit_string::BitString;
+    use picky_asn1::date::GeneralizedTime;
+    use picky_asn1::restricted_string::IA5String;
+    use picky_asn1::wrapper::{
+        Asn1SequenceOf, BitStringAsn1, ExplicitContextTag0, ExplicitContextTag1, ExplicitContextTag2,
+        ExplicitContextTag3, ExplicitContextTag6, ExplicitContextTag8, ExplicitContextTag9, GeneralStringAsn1,
+        GeneralizedTimeAsn1, IntegerAsn1, OctetStringAsn1, Optional,
+    };
+    use picky_asn1_der::application_tag::ApplicationTag;
+    use picky_krb::data_types::{EncryptedData, EncryptionKey, PrincipalName, TicketInner};
+
+    use super::{
+        EncKrbCredPartInner, KRB_CRED_KEY_USAGE, KrbCredInfo, KrbCredInner, parse_ccache, parse_krb_cred,
+        ticket_flag_names,
+    };
+    use crate::crypto_helper::algorithm::KrbEtype;
+    use crate::crypto_helper::krb::encrypt;
+
+    fn kerberos_string(value: &str) -> GeneralStringAsn1 {
+        IA5String::from_string(value.to_owned()).unwrap().into()
+    }
+
+    fn principal_name(components: &[&str]) -> PrincipalName {
+        PrincipalName {
+            name_type: ExplicitContextTag0::from(IntegerAsn1::from(vec![1])),
+            name_string: ExplicitContextTag1::from(Asn1SequenceOf::from(
+                components
+                    .iter()
+                    .map(|component| kerberos_string(component))
+                    .collect::<Vec<_>>(),
+            )),
+        }
+    }
+
+    fn krb_cred(etype: u8, cipher: Vec<u8>) -> Vec<u8> {
+        let ticket = ApplicationTag::from(TicketInner {
+            tkt_vno: ExplicitContextTag0::from(IntegerAsn1::from(vec![5])),
+            realm: ExplicitContextTag1::from(kerberos_string("EXAMPLE.COM")),
+            sname: ExplicitContextTag2::from(principal_name(&["krbtgt", "EXAMPLE.COM"])),
+            enc_part: ExplicitContextTag3::from(EncryptedData {
+                etype: ExplicitContextTag0::from(IntegerAsn1::from(vec![18])),
+                kvno: Optional::from(None),
+                cipher: ExplicitContextTag2::from(OctetStringAsn1::from(vec![1, 2, 3])),
+            }),
+        });
+
+        picky_asn1_der::to_vec(&ApplicationTag::<_, 22>::from(KrbCredInner {
+            pvno: ExplicitContextTag0::from(IntegerAsn1::from(vec![5])),
+            msg_type: ExplicitContextTag1::from(IntegerAsn1::from(vec![22])),
+            tickets: ExplicitContextTag2::from(Asn1SequenceOf::from(vec![ticket])),
+            enc_part: ExplicitContextTag3::from(EncryptedData {
+                etype: ExplicitContextTag0::from(IntegerAsn1::from(vec![etype])),
+                kvno: Optional::from(None),
+                cipher: ExplicitContextTag2::from(OctetStringAsn1::from(cipher)),
+            }),
+        }))
+        .unwrap()
+    }
+
+    fn enc_krb_cred_part() -> Vec<u8> {
+        let info = KrbCredInfo {
+            key: ExplicitContextTag0::from(EncryptionKey {
+                key_type: ExplicitContextTag0::from(IntegerAsn1::from(vec![18])),
+                key_value: ExplicitContextTag1::from(OctetStringAsn1::from(vec![0x42; 32])),
+            }),
+            prealm: Optional::from(Some(ExplicitContextTag1::from(kerberos_string("EXAMPLE.COM")))),
+            pname: Optional::from(Some(ExplicitContextTag2::from(principal_name(&["user"])))),
+            flags: Optional::from(Some(ExplicitContextTag3::from(BitStringAsn1::from(
+                BitString::with_bytes(vec![0x40, 0xe1, 0x00, 0x00]),
+            )))),
+            auth_time: Optional::from(None),
+            start_time: Optional::from(None),
+            end_time: Optional::from(Some(ExplicitContextTag6::from(GeneralizedTimeAsn1::from(
+                GeneralizedTime::new(2024, 1, 1, 10, 0, 0).unwrap(),
+            )))),
+            renew_till: Optional::from(None),
+            srealm: Optional::from(Some(ExplicitContextTag8::from(kerberos_string("EXAMPLE.COM")))),
+            sname: Optional::from(Some(ExplicitContextTag9::from(principal_name(&[
+                "krbtgt",
+                "EXAMPLE.COM",
+            ])))),
+            caddr: Optional::from(None),
+        };
+
+        picky_asn1_der::to_vec(&ApplicationTag::<_, 29>::from(EncKrbCredPartInner {
+            ticket_info: ExplicitContextTag0::from(Asn1SequenceOf::from(vec![info])),
+            nonce: Optional::from(None),
+            timestamp: Optional::from(None),
+            usec: Optional::from(None),
+            s_address: Optional::from(None),
+            r_address: Optional::from(None),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn krb_cred_null_etype() {
+        let credentials = parse_krb_cred(&krb_cred(0, enc_krb_cred_part()), &[]).unwrap();
+
+        assert_eq!(1, credentials.len());
+        let credential = &credentials[0];
+        assert_eq!("user@EXAMPLE.COM", credential.client);
+        assert_eq!("krbtgt/EXAMPLE.COM@EXAMPLE.COM", credential.server);
+        assert_eq!(Some(1_704_103_200), credential.end_time);
+        assert_eq!(18, credential.key_etype);
+        assert_eq!(0x61, credential.ticket[0]);
+        assert_eq!(
+            vec!["forwardable", "renewable", "initial", "pre-authent", "enc-pa-rep"],
+            ticket_flag_names(credential.flags)
+        );
+    }
+
+    #[test]
+    fn krb_cred_encrypted() {
+        let key = [7; 32];
+        let cipher = encrypt(
+            KrbEtype::Aes256CtsHmacSha196,
+            &key,
+            KRB_CRED_KEY_USAGE,
+            &enc_krb_cred_part(),
+        )
+        .unwrap();
+        let data = krb_cred(18, cipher);
+
+        assert_eq!("user@EXAMPLE.COM", parse_krb_cred(&data, &key).unwrap()[0].client);
+        assert!(parse_krb_cred(&data, &[]).unwrap_err().contains("the key is needed"));
+        assert!(parse_krb_cred(&data, &[8; 32]).is_err());
+    }
+
+    #[test]
+    fn ccache() {
+        let principal = |components: &[&str]| {
+            let mut data = vec![0, 0, 0, 1];
+            data.extend_from_slice(&(components.len() as u32).to_be_bytes());
+            for value in ["EXAMPLE.COM"].iter().chain(components) {
+                data.extend_from_slice(&(value.len() as u32).to_be_bytes());
+                data.extend_from_slice(value.as_bytes());
+            }
+            data
+        };
+
+        let mut data = vec![0x05, 0x04, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x08, 0, 0, 0, 0, 0, 0, 0, 0];
+        data.extend(principal(&["user"]));
+        data.extend(principal(&["user"]));
+        data.extend(principal(&["krbtgt", "EXAMPLE.COM"]));
+        // key
+        data.extend([0x00, 0x11, 0, 0, 0, 16]);
+        data.extend([0x42; 16]);
+        // auth, start, end, and renew times
+        data.extend(1_704_067_200_u32.to_be_bytes());
+        data.extend([0; 4]);
+        data.extend(1_704_103_200_u32.to_be_bytes());
+        data.extend([0; 4]);
+        // is_skey and flags
+        data.push(0);
+        data.extend(0x40e1_0000_u32.to_be_bytes());
+        // addresses, authorization data, ticket, and second ticket
+        data.extend([0; 8]);
+        data.extend([0, 0, 0, 2, 0x61, 0x00]);
+        data.extend([0; 4]);
+
+        let credentials = parse_ccache(&data).unwrap();
+
+        assert_eq!(1, credentials.len());
+        assert_eq!("krbtgt/EXAMPLE.COM@EXAMPLE.COM", credentials[0].server);
+        assert_eq!(Some(1_704_067_200), credentials[0].auth_time);
+        assert_eq!(None, credentials[0].start_time);
+        assert_eq!(17, credentials[0].key_etype);
+        assert_eq!(vec![0x61, 0x00], credentials[0].ticket);
+        assert!(parse_ccache(&data[..data.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,606 @@
+//! Step by step Kerberos key derivation: string-to-key, Ke/Ki/Kc, PRF+, and KRB-FX-CF2.
+
+use cbc::cipher::array::Array;
+use cbc::cipher::{BlockModeEncrypt, KeyIvInit};
+use des::TdesEde3;
+use picky_krb::crypto::KERBEROS;
+use picky_krb::crypto::aes::{AesSize, derive_key as aes_derive_key};
+use picky_krb::crypto::des::{DES3_BLOCK_SIZE, DES3_SEED_LEN, derive_key as des3_derive_key};
+use sha1::{Digest, Sha1};
+
+use super::{aes_sha2, hmac_sha1, pbkdf2, rc4_hmac};
+use crate::common::ntlm::nt_hash;
+use crate::crypto_helper::algorithm::{CipherAction, KrbDerivationMode, KrbEtype, KrbKeyDerivationInput};
+use crate::crypto_helper::block_modes::{AES_BLOCK_SIZE, aes_cts};
+use crate::crypto_helper::primitives::{DerivationStep, hmac_md5};
+
+/// Default PBKDF2 iteration count for the AES-SHA1 etypes (RFC 3962 4).
+const AES_SHA1_ITERATION_COUNT: u32 = 4096;
+
+const PRF_CONSTANT: &[u8] = b"prf";
+
+/// DES weak and semi-weak keys (NIST SP 800-67 3.3.2).
+const DES_WEAK_KEYS: [[u8; 8]; 16] = [
+    [0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01],
+    [0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe],
+    [0xe0, 0xe0, 0xe0, 0xe0, 0xf1, 0xf1, 0xf1, 0xf1],
+    [0x1f, 0x1f, 0x1f, 0x1f, 0x0e, 0x0e, 0x0e, 0x0e],
+    [0x01, 0x1f, 0x01, 0x1f, 0x01, 0x0e, 0x01, 0x0e],
+    [0x1f, 0x01, 0x1f, 0x01, 0x0e, 0x01, 0x0e, 0x01],
+    [0x01, 0xe0, 0x01, 0xe0, 0x01, 0xf1, 0x01, 0xf1],
+    [0xe0, 0x01, 0xe0, 0x01, 0xf1, 0x01, 0xf1, 0x01],
+    [0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe],
+    [0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01, 0xfe, 0x01],
+    [0x1f, 0xe0, 0x1f, 0xe0, 0x0e, 0xf1, 0x0e, 0xf1],
+    [0xe0, 0x1f, 0xe0, 0x1f, 0xf1, 0x0e, 0xf1, 0x0e],
+    [0x1f, 0xfe, 0x1f, 0xfe, 0x0e, 0xfe, 0x0e, 0xfe],
+    [0xfe, 0x1f, 0xfe, 0x1f, 0xfe, 0x0e, 0xfe, 0x0e],
+    [0xe0, 0xfe, 0xe0, 0xfe, 0xf1, 0xfe, 0xf1, 0xfe],
+    [0xfe, 0xe0, 0xfe, 0xe0, 0xfe, 0xf1, 0xfe, 0xf1],
+];
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// n-fold (RFC 3961 5.1). The output length is in bytes.
+fn n_fold(data: &[u8], len: usize) -> Vec<u8> {
+    let bits = data.len() * 8;
+    let bit = |position: usize| (data[position / 8] >> (7 - position % 8)) & 1;
+
+    // The input is repeated until the length is a multiple of the output length. Every next copy is rotated
+    // 13 bits to the right.
+    let total_len = len * data.len() / gcd(len, data.len());
+    let repeated = (0..total_len).map(|byte| {
+        let rotation = 13 * (byte / data.len()) % bits;
+
+        (0..8).fold(0_u8, |acc, i| {
+            let position = (byte % data.len()) * 8 + i;
+            (acc << 1) | bit((position + bits - rotation) % bits)
+        })
+    });
+
+    // The output length blocks are added with the ones' complement addition. Every column sums up
+    // `total_len / len` bytes, so it needs more than 16 bits for the long inputs.
+    let mut sum = vec![0_u32; len];
+    for (i, byte) in repeated.enumerate() {
+        sum[i % len] += u32::from(byte);
+    }
+    let mut carry = 1;
+    while carry != 0 {
+        carry = 0;
+        for value in sum.iter_mut().rev() {
+            *value += carry;
+            carry = *value >> 8;
+            *value &= 0xff;
+        }
+        sum[len - 1] += carry;
+    }
+
+    sum.into_iter().map(|value| value as u8).collect()
+}
+
+/// Sets the DES odd parity bit (the least significant one).
+fn odd_parity(byte: u8) -> u8 {
+    let byte = byte & 0xfe;
+
+    byte | u8::from(byte.count_ones().is_multiple_of(2))
+}
+
+/// DES3 random-to-key (RFC 3961 6.3.1): every 7 bytes are spread over 8 bytes with the parity bits.
+fn des3_random_to_key(seed: &[u8]) -> Vec<u8> {
+    seed.chunks(7)
+        .flat_map(|chunk| {
+            let mut key = chunk.iter().map(|byte| odd_parity(*byte)).collect::<Vec<_>>();
+            // The eighth byte holds the least significant bits of the other seven.
+            let last = chunk
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, byte)| acc | ((byte & 1) << (i + 1)));
+            key.push(odd_parity(last));
+
+            if DES_WEAK_KEYS.iter().any(|weak_key| weak_key[..] == key[..]) {
+                key[7] ^= 0xf0;
+            }
+
+            key
+        })
+        .collect()
+}
+
+fn aes_size(etype: KrbEtype) -> AesSize {
+    match etype {
+        KrbEtype::Aes128CtsHmacSha196 => AesSize::Aes128,
+        _ => AesSize::Aes256,
+    }
+}
+
+fn check_key(etype: KrbEtype, name: &str, key: &[u8]) -> Result<(), String> {
+    if key.len() != etype.key_len() {
+        return Err(format!(
+            "{}: invalid {} length: expected {} bytes but got {}",
+            etype.as_ref(),
+            name,
+            etype.key_len(),
+            key.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// The default salt is the realm concatenated with the principal name components (RFC 4120 4).
+fn salt(input: &KrbKeyDerivationInput) -> Vec<u8> {
+    if !input.salt.is_empty() {
+        return input.salt.as_bytes().to_vec();
+    }
+
+    let mut salt = input.realm.clone();
+    input
+        .principal
+        .split('/')
+        .for_each(|component| salt.push_str(component));
+
+    salt.into_bytes()
+}
+
+fn string_to_key_steps(input: &KrbKeyDerivationInput) -> Result<Vec<DerivationStep>, String> {
+    let etype = input.etype;
+    let password = input.password.as_bytes();
+    let salt = salt(input);
+    let salt_description = if input.salt.is_empty() {
+        "realm | principal name components"
+    } else {
+        "user provided salt"
+    };
+
+    Ok(match etype {
+        KrbEtype::Rc4Hmac => vec![
+            DerivationStep::new(
+                "password",
+                "UTF-16LE encoded password. The salt is not used",
+                input.password.encode_utf16().flat_map(|c| c.to_le_bytes()).collect(),
+            ),
+            DerivationStep::new("key", "MD4(password)", nt_hash(&input.password).to_vec()),
+        ],
+        KrbEtype::Des3CbcSha1Kd => {
+            let folded = n_fold(&[password, &salt].concat(), DES3_SEED_LEN);
+            let tkey = des3_random_to_key(&folded);
+            let key = des3_derive_key(&tkey, KERBEROS).map_err(|err| err.to_string())?;
+
+            vec![
+                DerivationStep::new("salt", salt_description, salt.clone()),
+                DerivationStep::new("n-fold", "168-fold(password | salt)", folded),
+                DerivationStep::new("tkey", "random-to-key(n-fold). The DES parity bits are added", tkey),
+                DerivationStep::new("key", "DK(tkey, \"kerberos\")", key),
+            ]
+        }
+        KrbEtype::Aes128CtsHmacSha196 | KrbEtype::Aes256CtsHmacSha196 => {
+            let iterations = input.iterations.unwrap_or(AES_SHA1_ITERATION_COUNT);
+
+            let tkey = pbkdf2(etype, password, &salt, iterations);
+            let key = aes_derive_key(&tkey, KERBEROS, &aes_size(etype)).map_err(|err| err.to_string())?;
+
+            vec![
+                DerivationStep::new("salt", salt_description, salt.clone()),
+                DerivationStep::new(
+                    "tkey",
+                    format!(
+                        "random-to-key(PBKDF2-HMAC-SHA1(password, salt, {})). random-to-key is the identity function",
+                        iterations
+                    ),
+                    tkey,
+                ),
+                DerivationStep::new("key", "DK(tkey, \"kerberos\")", key),
+            ]
+        }
+        KrbEtype::Aes128CtsHmacSha256128 | KrbEtype::Aes256CtsHmacSha384192 => {
+            let iterations = input.iterations.unwrap_or(aes_sha2::ITERATION_COUNT);
+            let salt_p = aes_sha2::salt_p(etype, &salt);
+
+            let tkey = pbkdf2(etype, password, &salt_p, iterations);
+            let key = aes_sha2::kdf(etype, &tkey, KERBEROS, etype.key_len());
+
+            vec![
+                DerivationStep::new("salt", salt_description, salt.clone()),
+                DerivationStep::new("saltp", "enctype-name | 0x00 | salt", salt_p),
+                DerivationStep::new(
+                    "tkey",
+                    format!(
+                        "random-to-key(PBKDF2-HMAC-SHA2(password, saltp, {})). random-to-key is the identity function",
+                        iterations
+                    ),
+                    tkey,
+                ),
+                DerivationStep::new("key", "KDF-HMAC-SHA2(tkey, \"kerberos\")", key),
+            ]
+        }
+    })
+}
+
+fn usage_constant(key_usage: i32, constant: u8) -> Vec<u8> {
+    let mut well_known = key_usage.to_be_bytes().to_vec();
+    well_known.push(constant);
+
+    well_known
+}
+
+fn usage_keys_steps(etype: KrbEtype, key: &[u8], key_usage: i32) -> Result<Vec<DerivationStep>, String> {
+    check_key(etype, "key", key)?;
+
+    if etype == KrbEtype::Rc4Hmac {
+        let ms_usage = rc4_hmac::translate_usage(key_usage);
+
+        return Ok(vec![
+            DerivationStep::new(
+                "K1",
+                format!("HMAC-MD5(key, {}). Key usage translated to the message type", ms_usage),
+                hmac_md5(key, &ms_usage.to_le_bytes()).to_vec(),
+            ),
+            DerivationStep::new(
+                "Ksign",
+                "HMAC-MD5(key, \"signaturekey\\0\")",
+                hmac_md5(key, rc4_hmac::SIGNATURE_KEY_CONSTANT).to_vec(),
+            ),
+        ]);
+    }
+
+    [
+        ("Ke", aes_sha2::KE_CONSTANT),
+        ("Ki", aes_sha2::KI_CONSTANT),
+        ("Kc", aes_sha2::KC_CONSTANT),
+    ]
+    .into_iter()
+    .map(|(name, constant)| {
+        let well_known = usage_constant(key_usage, constant);
+
+        let (function, value) = match etype {
+            KrbEtype::Des3CbcSha1Kd => ("DK", des3_derive_key(key, &well_known).map_err(|err| err.to_string())?),
+            KrbEtype::Aes128CtsHmacSha196 | KrbEtype::Aes256CtsHmacSha196 => (
+                "DK",
+                aes_derive_key(key, &well_known, &aes_size(etype)).map_err(|err| err.to_string())?,
+            ),
+            _ => {
+                let len = if constant == aes_sha2::KE_CONSTANT {
+                    etype.key_len()
+                } else {
+                    etype.hmac_len()
+                };
+
+                (
+                    "KDF-HMAC-SHA2",
+                    aes_sha2::derive_key(etype, key, key_usage, constant, len),
+                )
+            }
+        };
+
+        Ok(DerivationStep::new(
+            name,
+            format!("{}(key, {})", function, hex::encode(&well_known)),
+            value,
+        ))
+    })
+    .collect()
+}
+
+/// The pseudo-random function of the etype (RFC 3961 3).
+pub fn prf(etype: KrbEtype, key: &[u8], data: &[u8]) -> Result<Vec<u8>, String> {
+    check_key(etype, "key", key)?;
+
+    match etype {
+        KrbEtype::Des3CbcSha1Kd => {
+            // RFC 3961 6.3: the SHA-1 hash truncated to the block size multiple is encrypted with DK(key, "prf")
+            // in the CBC mode with the zero IV.
+            let prf_key = des3_derive_key(key, PRF_CONSTANT).map_err(|err| err.to_string())?;
+            let hash = Sha1::digest(data);
+            let mut output = hash[..hash.len() / DES3_BLOCK_SIZE * DES3_BLOCK_SIZE].to_vec();
+
+            cbc::Encryptor::<TdesEde3>::new_from_slices(&prf_key, &[0; DES3_BLOCK_SIZE])
+                .map_err(|err| err.to_string())?
+                .encrypt_blocks(Array::slice_as_chunks_mut(&mut output).0);
+
+            Ok(output)
+        }
+        KrbEtype::Rc4Hmac => Ok(hmac_sha1(key, data)),
+        KrbEtype::Aes128CtsHmacSha196 | KrbEtype::Aes256CtsHmacSha196 => {
+            // RFC 3962 6: the SHA-1 hash truncated to the block size is encrypted with DK(key, "prf").
+            let prf_key = aes_derive_key(key, PRF_CONSTANT, &aes_size(etype)).map_err(|err| err.to_string())?;
+            let hash = Sha1::digest(data);
+
+            aes_cts(&prf_key, CipherAction::Encrypt, &hash[..AES_BLOCK_SIZE])
+        }
+        KrbEtype::Aes128CtsHmacSha256128 | KrbEtype::Aes256CtsHmacSha384192 => Ok(aes_sha2::kdf_with_context(
+            etype,
+            key,
+            PRF_CONSTANT,
+            data,
+            etype.prf_len(),
+        )),
+    }
+}
+
+/// PRF+(key, data) = PRF(key, 1 | data) | PRF(key, 2 | data) | ...
+fn prf_plus_steps(etype: KrbEtype, key: &[u8], data: &[u8], len: usize) -> Result<Vec<DerivationStep>, String> {
+    let mut steps = Vec::new();
+    let mut output = Vec::with_capacity(len);
+
+    for counter in 1..=u8::MAX {
+        if output.len() >= len {
+            break;
+        }
+
+        let mut prf_input = vec![counter];
+        prf_input.extend_from_slice(data);
+
+        let block = prf(etype, key, &prf_input)?;
+        output.extend_from_slice(&block);
+
+        steps.push(DerivationStep::new(
+            format!("PRF #{}", counter),
+            format!("PRF(key, {})", hex::encode(&prf_input)),
+            block,
+        ));
+    }
+
+    if output.len() < len {
+        return Err(format!("PRF+: output length is too big: {}", len));
+    }
+    output.truncate(len);
+
+    steps.push(DerivationStep::new("PRF+", format!("first {} bytes", len), output));
+
+    Ok(steps)
+}
+
+fn cf2_steps(input: &KrbKeyDerivationInput) -> Result<Vec<DerivationStep>, String> {
+    let etype = input.etype;
+    check_key(etype, "second key", &input.key2)?;
+
+    // The key-generation seed length equals the key length for the etypes except DES3.
+    let len = if etype == KrbEtype::Des3CbcSha1Kd {
+        DES3_SEED_LEN
+    } else {
+        etype.key_len()
+    };
+
+    let prf1 = prf_plus_steps(etype, &input.key, &input.pepper1, len)?;
+    let prf2 = prf_plus_steps(etype, &input.key2, &input.pepper2, len)?;
+
+    let output1 = prf1.last().map(|step| step.value.clone()).unwrap_or_default();
+    let output2 = prf2.last().map(|step| step.value.clone()).unwrap_or_default();
+    let seed = output1.iter().zip(&output2).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+
+    let key = if etype == KrbEtype::Des3CbcSha1Kd {
+        DerivationStep::new(
+            "key",
+            "random-to-key(octet-string-1 ^ octet-string-2). The DES parity bits are added",
+            des3_random_to_key(&seed),
+        )
+    } else {
+        DerivationStep::new(
+            "key",
+            "random-to-key(octet-string-1 ^ octet-string-2). random-to-key is the identity function",
+            seed,
+        )
+    };
+
+    Ok(vec![
+        DerivationStep::new("octet-string-1", "PRF+(key1, pepper1)", output1.clone()),
+        DerivationStep::new("octet-string-2", "PRF+(key2, pepper2)", output2.clone()),
+        key,
+    ])
+}
+
+pub fn derivation_steps(input: &KrbKeyDerivationInput) -> Result<Vec<DerivationStep>, String> {
+    match input.mode {
+        KrbDerivationMode::StringToKey => string_to_key_steps(input),
+        KrbDerivationMode::UsageKeys => usage_keys_steps(input.etype, &input.key, input.key_usage),
+        KrbDerivationMode::PrfPlus => prf_plus_steps(
+            input.etype,
+            &input.key,
+            &input.pepper1,
+            input.prf_len.unwrap_or(input.etype.prf_len()),
+        ),
+        KrbDerivationMode::Cf2 => cf2_steps(input),
+    }
+}
+
+/// Returns the value of the last derivation step.
+pub fn process_krb_key_derivation(input: &KrbKeyDerivationInput) -> Result<Vec<u8>, String> {
+    Ok(derivation_steps(input)?
+        .pop()
+        .map(|step| step.value)
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{derivation_steps, n_fold, prf, process_krb_key_derivation};
+    use crate::crypto_helper::algorithm::{KrbDerivationMode, KrbEtype, KrbKeyDerivationInput};
+    use crate::crypto_helper::krb::string_to_key;
+
+    #[test]
+    fn rfc3962_string_to_key() {
+        let input = KrbKeyDerivationInput {
+            etype: KrbEtype::Aes128CtsHmacSha196,
+            password: "password".into(),
+            realm: "ATHENA.MIT.EDU".into(),
+            principal: "raeburn".into(),
+            iterations: Some(1),
+            ..Default::default()
+        };
+        let steps = derivation_steps(&input).unwrap();
+
+        assert_eq!(b"ATHENA.MIT.EDUraeburn".to_vec(), steps[0].value);
+        assert_eq!("cdedb5281bb2f801565a1122b2563515", hex::encode(&steps[1].value));
+        assert_eq!("42263c6e89f4fc28b8df68ee09799f15", hex::encode(&steps[2].value));
+    }
+
+    #[test]
+    fn default_iterations_match_string_to_key() {
+        for etype in [
+            KrbEtype::Aes256CtsHmacSha196,
+            KrbEtype::Rc4Hmac,
+            KrbEtype::Des3CbcSha1Kd,
+        ] {
+            let input = KrbKeyDerivationInput {
+                etype,
+                password: "5hYYSAfFJp".into(),
+                realm: "EXAMPLE.COM".into(),
+                principal: "test1".into(),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                string_to_key(etype, b"5hYYSAfFJp", b"EXAMPLE.COMtest1").unwrap(),
+                process_krb_key_derivation(&input).unwrap()
+            );
+        }
+    }
+
+    // RFC 3961 A.1.
+    #[test]
+    fn rfc3961_n_fold() {
+        for (data, len, expected) in [
+            (&b"012345"[..], 8, "be072631276b1955"),
+            (b"password", 7, "78a07b6caf85fa"),
+            (b"Rough Consensus, and Running Code", 8, "bb6ed30870b7f0e0"),
+            (b"password", 21, "59e4a8ca7c0385c3c37b3f6d2000247cb6e6bd5b3e"),
+            (
+                b"MASSACHVSETTS INSTITVTE OF TECHNOLOGY",
+                24,
+                "db3b0d8f0b061e603282b308a50841229ad798fab9540c1b",
+            ),
+            (b"kerberos", 8, "6b65726265726f73"),
+        ] {
+            assert_eq!(expected, hex::encode(n_fold(data, len)));
+        }
+    }
+
+    #[test]
+    fn n_fold_long_input() {
+        // The ones' complement sum of all-ones blocks is all ones.
+        assert_eq!(vec![0xff; 21], n_fold(&[0xff; 263], 21));
+
+        // Generated with MIT krb5 krb5_c_string_to_key.
+        let password = "p".repeat(250);
+        assert_eq!(
+            "4ac4b56bc22c4aba04898f5204541f0b6efdcb160b7a5e07",
+            hex::encode(string_to_key(KrbEtype::Des3CbcSha1Kd, password.as_bytes(), b"ATHENA.MIT.EDUraeburn").unwrap())
+        );
+        let input = KrbKeyDerivationInput {
+            etype: KrbEtype::Des3CbcSha1Kd,
+            password,
+            realm: "ATHENA.MIT.EDU".into(),
+            principal: "raeburn".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            "4ac4b56bc22c4aba04898f5204541f0b6efdcb160b7a5e07",
+            hex::encode(process_krb_key_derivation(&input).unwrap())
+        );
+    }
+
+    // Generated with MIT krb5 krb5_c_prf.
+    #[test]
+    fn des3_prf() {
+        let key = hex::decode("850bb51358548cd05e86768c313e3bfef7511937dcf72c3e").unwrap();
+
+        assert_eq!(
+            "32eb2c6930987d312ac7e6fa6791387e",
+            hex::encode(prf(KrbEtype::Des3CbcSha1Kd, &key, b"test").unwrap())
+        );
+
+        let input = KrbKeyDerivationInput {
+            etype: KrbEtype::Des3CbcSha1Kd,
+            mode: KrbDerivationMode::PrfPlus,
+            key,
+            pepper1: b"test".to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(
+            "8fa4314a7c539c573077cc97f64a9909",
+            hex::encode(process_krb_key_derivation(&input).unwrap())
+        );
+    }
+
+    #[test]
+    fn des3_string_to_key_steps() {
+        let input = KrbKeyDerivationInput {
+            etype: KrbEtype::Des3CbcSha1Kd,
+            password: "password".into(),
+            realm: "ATHENA.MIT.EDU".into(),
+            principal: "raeburn".into(),
+            ..Default::default()
+        };
+        let steps = derivation_steps(&input).unwrap();
+
+        // RFC 3961 A.4.
+        assert_eq!(
+            "850bb51358548cd05e86768c313e3bfef7511937dcf72c3e",
+            hex::encode(&steps[3].value)
+        );
+    }
+
+    #[test]
+    fn default_prf_len() {
+        for etype in [KrbEtype::Aes128CtsHmacSha196, KrbEtype::Aes256CtsHmacSha384192] {
+            let input = KrbKeyDerivationInput {
+                etype,
+                mode: KrbDerivationMode::PrfPlus,
+                key: vec![1; etype.key_len()],
+                pepper1: b"test".to_vec(),
+                ..Default::default()
+            };
+
+            assert_eq!(etype.prf_len(), process_krb_key_derivation(&input).unwrap().len());
+        }
+    }
+
+    // RFC 6113 Appendix A.
+    #[test]
+    fn rfc6113_cf2() {
+        for (etype, expected) in [
+            (KrbEtype::Aes128CtsHmacSha196, "97df97e4b798b29eb31ed7280287a92a"),
+            (
+                KrbEtype::Aes256CtsHmacSha196,
+                "4d6ca4e629785c1f01baf55e2e548566b9617ae3a96868c337cb93b5e72b1c7b",
+            ),
+            (KrbEtype::Rc4Hmac, "24d7f6b6bae4e5c00d2082c5ebab3672"),
+            // Generated with MIT krb5 krb5_c_fx_cf2_simple.
+            (
+                KrbEtype::Des3CbcSha1Kd,
+                "e58f9eb643862c13ad38e529313462a7f73e62834fe54a01",
+            ),
+        ] {
+            let input = KrbKeyDerivationInput {
+                etype,
+                mode: KrbDerivationMode::Cf2,
+                key: string_to_key(etype, b"key1", b"key1").unwrap(),
+                key2: string_to_key(etype, b"key2", b"key2").unwrap(),
+                pepper1: b"a".to_vec(),
+                pepper2: b"b".to_vec(),
+                ..Default::default()
+            };
+
+            assert_eq!(expected, hex::encode(process_krb_key_derivation(&input).unwrap()));
+        }
+    }
+
+    #[test]
+    fn usage_keys() {
+        let input = KrbKeyDerivationInput {
+            etype: KrbEtype::Aes128CtsHmacSha256128,
+            mode: KrbDerivationMode::UsageKeys,
+            key: hex::decode("3705d96080c17728a0e800eab6e0d23c").unwrap(),
+            key_usage: 2,
+            ..Default::default()
+        };
+        let steps = derivation_steps(&input).unwrap();
+
+        // RFC 8009 Appendix A.
+        assert_eq!("9b197dd1e8c5609d6e67c3e37c62c72e", hex::encode(&steps[0].value));
+        assert_eq!("9fda0e56ab2d85e1569a688696c26a6c", hex::encode(&steps[1].value));
+        assert_eq!("b31a018a48f54776f403e9a396325dc3", hex::encode(&steps[2].value));
+    }
+}
@@ -0,0 +1,463 @@
+//! GSS-API Kerberos per-message tokens (RFC 4121 4.2).
+//!
+//! Every token starts with the 16-byte header. The Wrap token body is rotated right by RRC bytes.
+
+use super::{DES3_BLOCK_SIZE, checksum, decrypt, encrypt};
+use crate::crypto_helper::algorithm::{GssTokenType, KrbEtype, KrbGssTokenInput, KrbMode};
+
+pub const GSS_HEADER_LEN: usize = 16;
+
+const MIC_TOKEN_ID: [u8; 2] = [0x04, 0x04];
+const WRAP_TOKEN_ID: [u8; 2] = [0x05, 0x04];
+const FILLER: u8 = 0xff;
+
+pub const FLAG_SENT_BY_ACCEPTOR: u8 = 0x01;
+pub const FLAG_SEALED: u8 = 0x02;
+pub const FLAG_ACCEPTOR_SUBKEY: u8 = 0x04;
+
+/// Key usages (RFC 4121 2).
+const KG_USAGE_ACCEPTOR_SEAL: i32 = 22;
+const KG_USAGE_ACCEPTOR_SIGN: i32 = 23;
+const KG_USAGE_INITIATOR_SEAL: i32 = 24;
+const KG_USAGE_INITIATOR_SIGN: i32 = 25;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GssTokenHeader {
+    pub token_type: GssTokenType,
+    pub flags: u8,
+    /// Extra count. Only the Wrap token has it.
+    pub ec: u16,
+    /// Right rotation count. Only the Wrap token has it.
+    pub rrc: u16,
+    pub seq_number: u64,
+}
+
+impl GssTokenHeader {
+    pub fn is_sealed(&self) -> bool {
+        self.flags & FLAG_SEALED != 0
+    }
+
+    /// Returns the names of the set flags.
+    pub fn flag_names(&self) -> Vec<&'static str> {
+        [
+            (FLAG_SENT_BY_ACCEPTOR, "SentByAcceptor"),
+            (FLAG_SEALED, "Sealed"),
+            (FLAG_ACCEPTOR_SUBKEY, "AcceptorSubkey"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.flags & flag != 0)
+        .map(|(_, name)| name)
+        .collect()
+    }
+
+    /// Wrap tokens use the seal usages with or without confidentiality (RFC 4121 2).
+    fn key_usage(&self) -> i32 {
+        let acceptor = self.flags & FLAG_SENT_BY_ACCEPTOR != 0;
+
+        match (self.token_type == GssTokenType::Wrap, acceptor) {
+            (true, true) => KG_USAGE_ACCEPTOR_SEAL,
+            (true, false) => KG_USAGE_INITIATOR_SEAL,
+            (false, true) => KG_USAGE_ACCEPTOR_SIGN,
+            (false, false) => KG_USAGE_INITIATOR_SIGN,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; GSS_HEADER_LEN] {
+        let mut header = [FILLER; GSS_HEADER_LEN];
+
+        match self.token_type {
+            GssTokenType::Mic => header[0..2].copy_from_slice(&MIC_TOKEN_ID),
+            GssTokenType::Wrap => {
+                header[0..2].copy_from_slice(&WRAP_TOKEN_ID);
+                header[4..6].copy_from_slice(&self.ec.to_be_bytes());
+                header[6..8].copy_from_slice(&self.rrc.to_be_bytes());
+            }
+        }
+        header[2] = self.flags;
+        header[8..16].copy_from_slice(&self.seq_number.to_be_bytes());
+
+        header
+    }
+
+    pub fn parse(token: &[u8]) -> Result<Self, String> {
+        if token.len() < GSS_HEADER_LEN {
+            return Err(format!(
+                "GSS token: expected at least {} bytes but got {}",
+                GSS_HEADER_LEN,
+                token.len()
+            ));
+        }
+
+        let (token_type, filler) = match [token[0], token[1]] {
+            MIC_TOKEN_ID => (GssTokenType::Mic, &token[3..8]),
+            WRAP_TOKEN_ID => (GssTokenType::Wrap, &token[3..4]),
+            id => return Err(format!("GSS token: unsupported TOK_ID: {}", hex::encode(id))),
+        };
+        if filler.iter().any(|byte| *byte != FILLER) {
+            return Err(format!("GSS token: invalid filler: {}", hex::encode(filler)));
+        }
+
+        let (ec, rrc) = match token_type {
+            GssTokenType::Wrap => (
+                u16::from_be_bytes([token[4], token[5]]),
+                u16::from_be_bytes([token[6], token[7]]),
+            ),
+            GssTokenType::Mic => (0, 0),
+        };
+
+        Ok(Self {
+            token_type,
+            flags: token[2],
+            ec,
+            rrc,
+            seq_number: u64::from_be_bytes(token[8..16].try_into().expect("length is checked above")),
+        })
+    }
+}
+
+/// Unwrap or MIC verification steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GssTokenTrace {
+    pub header: GssTokenHeader,
+    /// Token body after the left rotation by RRC.
+    pub body: Vec<u8>,
+    /// Unwrapped message.
+    pub message: Vec<u8>,
+    /// Computed checksum or the expected header copy of the sealed Wrap token.
+    pub expected: Vec<u8>,
+    /// Received checksum or the decrypted header copy of the sealed Wrap token.
+    pub received: Vec<u8>,
+}
+
+impl GssTokenTrace {
+    pub fn is_valid(&self) -> bool {
+        self.expected == self.received
+    }
+}
+
+fn check_etype(etype: KrbEtype) -> Result<(), String> {
+    if etype == KrbEtype::Rc4Hmac {
+        return Err("GSS token: rc4-hmac uses the RFC 4757 token format".into());
+    }
+
+    Ok(())
+}
+
+/// DES3 pads the plaintext with zeros that can not be removed after the decryption, so EC is raised to make
+/// the sealed data block aligned (RFC 4121 4.2.3). The CTS etypes need no padding.
+fn sealed_ec(etype: KrbEtype, message_len: usize, ec: u16) -> u16 {
+    if etype != KrbEtype::Des3CbcSha1Kd {
+        return ec;
+    }
+
+    let unaligned = (message_len + usize::from(ec) + GSS_HEADER_LEN) % DES3_BLOCK_SIZE;
+    if unaligned == 0 {
+        ec
+    } else {
+        ec.saturating_add((DES3_BLOCK_SIZE - unaligned) as u16)
+    }
+}
+
+fn rotation(rrc: u16, len: usize) -> usize {
+    if len == 0 { 0 } else { usize::from(rrc) % len }
+}
+
+/// Header with EC and RRC set to zero. It is used for the checksum of the not sealed Wrap token.
+fn zeroed_header(header: &GssTokenHeader) -> [u8; GSS_HEADER_LEN] {
+    GssTokenHeader {
+        ec: 0,
+        rrc: 0,
+        ..header.clone()
+    }
+    .encode()
+}
+
+fn header_flags(input: &KrbGssTokenInput) -> u8 {
+    let mut flags = 0;
+    if input.acceptor {
+        flags |= FLAG_SENT_BY_ACCEPTOR;
+    }
+    if input.confidential && input.token_type == GssTokenType::Wrap {
+        flags |= FLAG_SEALED;
+    }
+    if input.acceptor_subkey {
+        flags |= FLAG_ACCEPTOR_SUBKEY;
+    }
+
+    flags
+}
+
+pub fn build_gss_token(input: &KrbGssTokenInput) -> Result<Vec<u8>, String> {
+    check_etype(input.etype)?;
+
+    let mut header = GssTokenHeader {
+        token_type: input.token_type,
+        flags: header_flags(input),
+        ec: 0,
+        rrc: 0,
+        seq_number: input.seq_number,
+    };
+    let key_usage = header.key_usage();
+
+    let mut body = match input.token_type {
+        GssTokenType::Mic => {
+            let mut data = input.message.clone();
+            data.extend_from_slice(&header.encode());
+
+            let mut token = header.encode().to_vec();
+            token.extend_from_slice(&checksum(input.etype, &input.key, key_usage, &data)?);
+
+            return Ok(token);
+        }
+        GssTokenType::Wrap if header.is_sealed() => {
+            header.ec = sealed_ec(input.etype, input.message.len(), input.ec);
+
+            // The encrypted header copy has RRC set to zero.
+            let mut data = input.message.clone();
+            data.resize(data.len() + usize::from(header.ec), 0);
+            data.extend_from_slice(&header.encode());
+
+            encrypt(input.etype, &input.key, key_usage, &data)?
+        }
+        GssTokenType::Wrap => {
+            // EC of the not sealed Wrap token is the checksum length, so the input EC is not used.
+            let mut data = input.message.clone();
+            data.extend_from_slice(&zeroed_header(&header));
+            let checksum = checksum(input.etype, &input.key, key_usage, &data)?;
+
+            header.ec = checksum.len() as u16;
+
+            let mut body = input.message.clone();
+            body.extend_from_slice(&checksum);
+            body
+        }
+    };
+
+    header.rrc = input.rrc;
+    let rotation = rotation(input.rrc, body.len());
+    body.rotate_right(rotation);
+
+    let mut token = header.encode().to_vec();
+    token.extend_from_slice(&body);
+
+    Ok(token)
+}
+
+/// Unwraps the Wrap token or verifies the MIC token of the input message. The key usage follows the token type and flags.
+pub fn gss_token_trace(etype: KrbEtype, key: &[u8], token: &[u8], message: &[u8]) -> Result<GssTokenTrace, String> {
+    check_etype(etype)?;
+
+    let header = GssTokenHeader::parse(token)?;
+    let key_usage = header.key_usage();
+    let mut body = token[GSS_HEADER_LEN..].to_vec();
+
+    if header.token_type == GssTokenType::Mic {
+        let mut data = message.to_vec();
+        data.extend_from_slice(&token[0..GSS_HEADER_LEN]);
+
+        return Ok(GssTokenTrace {
+            expected: checksum(etype, key, key_usage, &data)?,
+            header,
+            received: body.clone(),
+            message: message.to_vec(),
+            body,
+        });
+    }
+
+    let rotation = rotation(header.rrc, body.len());
+    body.rotate_left(rotation);
+
+    let ec = usize::from(header.ec);
+    if header.is_sealed() {
+        let plaintext = decrypt(etype, key, key_usage, &body)?;
+        if plaintext.len() < ec + GSS_HEADER_LEN {
+            return Err(format!(
+                "GSS token: decrypted data is too short: expected at least {} bytes but got {}",
+                ec + GSS_HEADER_LEN,
+                plaintext.len()
+            ));
+        }
+        let message_len = plaintext.len() - ec - GSS_HEADER_LEN;
+
+        Ok(GssTokenTrace {
+            expected: GssTokenHeader {
+                rrc: 0,
+                ..header.clone()
+            }
+            .encode()
+            .to_vec(),
+            received: plaintext[message_len + ec..].to_vec(),
+            message: plaintext[..message_len].to_vec(),
+            header,
+            body,
+        })
+    } else {
+        if body.len() < ec {
+            return Err(format!(
+                "GSS token: expected the {}-byte checksum but got {} bytes",
+                ec,
+                body.len()
+            ));
+        }
+        let (message, received) = body.split_at(body.len() - ec);
+
+        let mut data = message.to_vec();
+        data.extend_from_slice(&zeroed_header(&header));
+
+        Ok(GssTokenTrace {
+            expected: checksum(etype, key, key_usage, &data)?,
+            received: received.to_vec(),
+            message: message.to_vec(),
+            header,
+            body,
+        })
+    }
+}
+
+/// Builds the token or returns the unwrapped message. The MIC verification returns the checksum.
+pub fn process_krb_gss_token(input: &KrbGssTokenInput) -> Result<Vec<u8>, String> {
+    match input.mode {
+        KrbMode::Encrypt => build_gss_token(input),
+        KrbMode::Decrypt => {
+            let trace = gss_token_trace(input.etype, &input.key, &input.token, &input.message)?;
+
+            if !trace.is_valid() {
+                return Err("GSS token: integrity check failed".into());
+            }
+
+            Ok(match trace.header.token_type {
+                GssTokenType::Wrap => trace.message,
+                GssTokenType::Mic => trace.expected,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FLAG_SEALED, FLAG_SENT_BY_ACCEPTOR, GssTokenHeader, build_gss_token, gss_token_trace};
+    use crate::crypto_helper::algorithm::{GssTokenType, KrbEtype, KrbGssTokenInput};
+
+    fn input(token_type: GssTokenType, confidential: bool) -> KrbGssTokenInput {
+        KrbGssTokenInput {
+            etype: KrbEtype::Aes256CtsHmacSha196,
+            token_type,
+            acceptor: true,
+            confidential,
+            seq_number: 0x0102_0304,
+            ec: 4,
+            rrc: 28,
+            key: vec![0x42; 32],
+            message: b"gss message".to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn header() {
+        let header = GssTokenHeader {
+            token_type: GssTokenType::Wrap,
+            flags: FLAG_SENT_BY_ACCEPTOR | FLAG_SEALED,
+            ec: 0x10,
+            rrc: 0x1c,
+            seq_number: 5,
+        };
+        let encoded = header.encode();
+
+        assert_eq!("050403ff0010001c0000000000000005", hex::encode(encoded));
+        assert_eq!(header, GssTokenHeader::parse(&encoded).unwrap());
+        assert_eq!(vec!["SentByAcceptor", "Sealed"], header.flag_names());
+        assert!(GssTokenHeader::parse(&[0x05, 0x04, 0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn wrap_round_trip() {
+        for confidential in [true, false] {
+            let input = input(GssTokenType::Wrap, confidential);
+            let token = build_gss_token(&input).unwrap();
+
+            let trace = gss_token_trace(input.etype, &input.key, &token, &[]).unwrap();
+            assert!(trace.is_valid());
+            assert_eq!(input.message, trace.message);
+            assert_eq!(28, trace.header.rrc);
+            assert_eq!(confidential, trace.header.is_sealed());
+
+            let mut tampered = token.clone();
+            *tampered.last_mut().unwrap() ^= 1;
+            assert!(
+                gss_token_trace(input.etype, &input.key, &tampered, &[])
+                    .map(|trace| !trace.is_valid())
+                    .unwrap_or(true)
+            );
+        }
+    }
+
+    #[test]
+    fn des3_sealed_wrap_round_trip() {
+        let input = KrbGssTokenInput {
+            etype: KrbEtype::Des3CbcSha1Kd,
+            key: vec![0x42; 24],
+            message: b"odd gss message".to_vec(),
+            ..input(GssTokenType::Wrap, true)
+        };
+        let token = build_gss_token(&input).unwrap();
+
+        let trace = gss_token_trace(input.etype, &input.key, &token, &[]).unwrap();
+        assert!(trace.is_valid());
+        assert_eq!(input.message, trace.message);
+        // 15-byte message, 16-byte header, and 4 + 5 EC bytes are aligned to the DES3 block size.
+        assert_eq!(9, trace.header.ec);
+    }
+
+    /// MIT krb5 1.20.1 initiator tokens of the "message" message. The key is the initiator subkey.
+    #[test]
+    fn mit_tokens() {
+        let key = hex::decode("8b6402ea0e1a379ec754ad4875bde81b3dbd52129fa057daa4061463d3878cd4").unwrap();
+        let input = |token_type, seq_number| KrbGssTokenInput {
+            etype: KrbEtype::Aes256CtsHmacSha196,
+            token_type,
+            seq_number,
+            key: key.clone(),
+            message: b"message".to_vec(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "040400ffffffffff000000002147b331cce84424051c7d99d0059bc8",
+            hex::encode(build_gss_token(&input(GssTokenType::Mic, 0x2147_b331)).unwrap())
+        );
+        assert_eq!(
+            "050400ff000c0000000000002147b3306d657373616765e3888c1b067e5d2886c3b14b",
+            hex::encode(build_gss_token(&input(GssTokenType::Wrap, 0x2147_b330)).unwrap())
+        );
+
+        let sealed = hex::decode(concat!(
+            "050402ff00000000000000002147b32f0364721c74bb8d9bb38be3cb2add1bce13c27734c815b15c5c9997a99cec6bef",
+            "75e78b616b4fd6fbd66b17b952f6ce2607d726",
+        ))
+        .unwrap();
+        let trace = gss_token_trace(KrbEtype::Aes256CtsHmacSha196, &key, &sealed, &[]).unwrap();
+        assert!(trace.is_valid());
+        assert_eq!(b"message".to_vec(), trace.message);
+        assert_eq!(0x2147_b32f, trace.header.seq_number);
+    }
+
+    #[test]
+    fn mic() {
+        let input = input(GssTokenType::Mic, false);
+        let token = build_gss_token(&input).unwrap();
+
+        assert_eq!("040401ffffffffff0000000001020304", hex::encode(&token[..16]));
+        assert_eq!(16 + 12, token.len());
+        assert!(
+            gss_token_trace(input.etype, &input.key, &token, &input.message)
+                .unwrap()
+                .is_valid()
+        );
+        assert!(
+            !gss_token_trace(input.etype, &input.key, &token, b"other")
+                .unwrap()
+                .is_valid()
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! MIT keytab file format (version 0x502).
+//!
+//! keytab = version entry*. Every entry is prefixed with its signed 32-bit size. A negative size marks a hole
+//! (deleted entry) of that many bytes. All numbers are big-endian.
+
+use time::OffsetDateTime;
+
+use super::reader::{Encoding, Reader};
+
+pub const KEYTAB_VERSION: [u8; 2] = [0x05, 0x02];
+
+/// KRB5_NT_PRINCIPAL (RFC 4120 6.2).
+pub const NT_PRINCIPAL: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KeytabEntry {
+    pub realm: String,
+    pub components: Vec<String>,
+    pub name_type: u32,
+    /// Unix time when the entry was written.
+    pub timestamp: u32,
+    pub kvno: u32,
+    pub etype: u16,
+    pub key: Vec<u8>,
+}
+
+impl KeytabEntry {
+    /// Returns the principal name in the `name/instance@REALM` form.
+    pub fn principal(&self) -> String {
+        format!("{}@{}", self.components.join("/"), self.realm)
+    }
+
+    /// Parses the principal name in the `name/instance@REALM` form.
+    pub fn set_principal(&mut self, principal: &str) -> Result<(), String> {
+        let (name, realm) = principal
+            .rsplit_once('@')
+            .ok_or_else(|| format!("keytab: principal name must contain the realm: {}", principal))?;
+
+        if name.is_empty() || realm.is_empty() {
+            return Err(format!("keytab: invalid principal name: {}", principal));
+        }
+
+        self.realm = realm.to_owned();
+        self.components = name.split('/').map(ToOwned::to_owned).collect();
+
+        Ok(())
+    }
+}
+
+/// Formats the Kerberos timestamp as the UTC date and time.
+pub fn format_timestamp(timestamp: i64) -> String {
+    match OffsetDateTime::from_unix_timestamp(timestamp) {
+        Ok(date_time) => format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+            date_time.year(),
+            u8::from(date_time.month()),
+            date_time.day(),
+            date_time.hour(),
+            date_time.minute(),
+            date_time.second(),
+        ),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+/// Keytab strings are prefixed with the 16-bit length.
+trait KeytabReader {
+    fn read_string(&mut self) -> Result<String, String>;
+}
+
+impl KeytabReader for Reader<'_> {
+    fn read_string(&mut self) -> Result<String, String> {
+        let len = self.read_u16()?;
+
+        Ok(String::from_utf8_lossy(self.read(len.into())?).into_owned())
+    }
+}
+
+fn parse_entry(data: &[u8]) -> Result<KeytabEntry, String> {
+    let mut reader = Reader::new("keytab", Encoding::BigEndian, data);
+
+    let components_count = reader.read_u16()?;
+    let realm = reader.read_string()?;
+    let components = (0..components_count)
+        .map(|_| reader.read_string())
+        .collect::<Result<_, _>>()?;
+    let name_type = reader.read_u32()?;
+    let timestamp = reader.read_u32()?;
+    let kvno8 = reader.read_u8()?;
+    let etype = reader.read_u16()?;
+    let key_len = reader.read_u16()?;
+    let key = reader.read(key_len.into())?.to_vec();
+
+    // The 32-bit kvno is optional and replaces the 8-bit one when present and not zero.
+    let kvno = match reader.read_u32() {
+        Ok(kvno) if kvno != 0 => kvno,
+        _ => kvno8.into(),
+    };
+
+    Ok(KeytabEntry {
+        realm,
+        components,
+        name_type,
+        timestamp,
+        kvno,
+        etype,
+        key,
+    })
+}
+
+pub fn parse_keytab(data: &[u8]) -> Result<Vec<KeytabEntry>, String> {
+    let mut reader = Reader::new("keytab", Encoding::BigEndian, data);
+
+    let version = reader.read_array::<2>()?;
+    if version != KEYTAB_VERSION {
+        return Err(format!(
+            "keytab: unsupported version: expected {} but got {}",
+            hex::encode(KEYTAB_VERSION),
+            hex::encode(version)
+        ));
+    }
+
+    let mut entries = Vec::new();
+    while !reader.is_empty() {
+        let size = i32::from_be_bytes(reader.read_array()?);
+
+        if size < 0 {
+            // Skip the hole.
+            reader.read(size.unsigned_abs() as usize)?;
+        } else if size == 0 {
+            break;
+        } else {
+            entries.push(parse_entry(reader.read(size as usize)?)?);
+        }
+    }
+
+    Ok(entries)
+}
+
+fn write_string(data: &mut Vec<u8>, value: &str) {
+    data.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    data.extend_from_slice(value.as_bytes());
+}
+
+fn encode_entry(entry: &KeytabEntry) -> Vec<u8> {
+    let mut data = (entry.components.len() as u16).to_be_bytes().to_vec();
+    write_string(&mut data, &entry.realm);
+    for component in &entry.components {
+        write_string(&mut data, component);
+    }
+    data.extend_from_slice(&entry.name_type.to_be_bytes());
+    data.extend_from_slice(&entry.timestamp.to_be_bytes());
+    data.push(entry.kvno as u8);
+    data.extend_from_slice(&entry.etype.to_be_bytes());
+    data.extend_from_slice(&(entry.key.len() as u16).to_be_bytes());
+    data.extend_from_slice(&entry.key);
+    data.extend_from_slice(&entry.kvno.to_be_bytes());
+
+    data
+}
+
+pub fn encode_keytab(entries: &[KeytabEntry]) -> Vec<u8> {
+    let mut data = KEYTAB_VERSION.to_vec();
+
+    for entry in entries {
+        let entry = encode_entry(entry);
+        data.extend_from_slice(&(entry.len() as i32).to_be_bytes());
+        data.extend_from_slice(&entry);
+    }
+
+    data
+}
+
+/// Re-encodes the keytab. Holes are dropped.
+pub fn process_keytab(data: &[u8]) -> Result<Vec<u8>, String> {
+    Ok(encode_keytab(&parse_keytab(data)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeytabEntry, NT_PRINCIPAL, encode_keytab, format_timestamp, parse_keytab};
+
+    const KEYTAB: &str = concat!(
+        "0502",
+        // entry size
+        "00000036",
+        // components count, realm, and the components
+        "0001",
+        "000b",
+        "4558414d504c452e434f4d",
+        "0004",
+        "75736572",
+        // name type, timestamp, 8-bit kvno
+        "00000001",
+        "65920080",
+        "02",
+        // etype and key
+        "0011",
+        "0010",
+        "000102030405060708090a0b0c0d0e0f",
+        // 32-bit kvno
+        "00000002",
+        // hole
+        "fffffffc",
+        "00000000",
+    );
+
+    fn entry() -> KeytabEntry {
+        KeytabEntry {
+            realm: "EXAMPLE.COM".into(),
+            components: vec!["user".into()],
+            name_type: NT_PRINCIPAL,
+            timestamp: 1_704_067_200,
+            kvno: 2,
+            etype: 17,
+            key: (0..16).collect(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let entries = parse_keytab(&hex::decode(KEYTAB).unwrap()).unwrap();
+
+        assert_eq!(vec![entry()], entries);
+        assert_eq!("user@EXAMPLE.COM", entries[0].principal());
+        assert_eq!("2024-01-01 00:00:00 UTC", format_timestamp(entries[0].timestamp.into()));
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let mut service = entry();
+        service.set_principal("HTTP/www.example.com@EXAMPLE.COM").unwrap();
+        service.kvno = 300;
+        service.etype = 18;
+        service.key = vec![0x42; 32];
+
+        let entries = vec![entry(), service];
+        let keytab = encode_keytab(&entries);
+
+        assert_eq!(&hex::decode(KEYTAB).unwrap()[..0x3c], &keytab[..0x3c]);
+        assert_eq!(entries, parse_keytab(&keytab).unwrap());
+    }
+
+    #[test]
+    fn invalid_keytab() {
+        assert!(parse_keytab(&[0x05, 0x01]).unwrap_err().contains("unsupported version"));
+        assert!(
+            parse_keytab(&hex::decode(&KEYTAB[..40]).unwrap())
+                .unwrap_err()
+                .contains("unexpected end of data")
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! Kerberos encryption types and checksums (RFC 3961).
+//!
+//! AES-SHA1 and DES3 are provided by `picky-krb`, the rest is implemented here.
+
+mod aes_sha2;
+mod credentials;
+mod derivation;
+mod gss;
+mod keytab;
+mod pac;
+mod rc4_hmac;
+mod reader;
+
+pub use credentials::{Credential, parse_credentials, process_krb_credentials, ticket_flag_names};
+pub use derivation::{derivation_steps, process_krb_key_derivation};
+pub use gss::{GssTokenHeader, GssTokenTrace, gss_token_trace, process_krb_gss_token};
+use hmac::{Hmac, KeyInit, Mac};
+pub use keytab::{KeytabEntry, NT_PRINCIPAL, encode_keytab, format_timestamp, parse_keytab, process_keytab};
+pub use pac::{
+    GroupMembership, LogonInfo, Pac, PacChecksumTrace, PacInfo, PacSignature, format_filetime, parse_pac,
+    process_krb_pac,
+};
+use pbkdf2::pbkdf2_hmac;
+use picky_krb::crypto::{ChecksumSuite, CipherSuite, DecryptWithoutChecksum};
+use rsa::rand_core::{OsRng, RngCore};
+use sha1::Sha1;
+use sha2::{Sha256, Sha384};
+
+use super::algorithm::{KrbEtype, KrbEtypeInput, KrbMode};
+use super::block_modes::AES_BLOCK_SIZE;
+
+const DES3_BLOCK_SIZE: usize = 8;
+
+/// Returns the etype name or the number if the etype is unknown.
+pub fn etype_name(etype: i32) -> String {
+    match KrbEtype::try_from(etype) {
+        Ok(krb_etype) => format!("{} ({})", krb_etype.as_ref(), etype),
+        Err(_) => etype.to_string(),
+    }
+}
+
+fn picky_suites(etype: KrbEtype) -> Option<(CipherSuite, ChecksumSuite)> {
+    match etype {
+        KrbEtype::Des3CbcSha1Kd => Some((CipherSuite::Des3CbcSha1Kd, ChecksumSuite::HmacSha1Des3Kd)),
+        KrbEtype::Aes128CtsHmacSha196 => Some((CipherSuite::Aes128CtsHmacSha196, ChecksumSuite::HmacSha196Aes128)),
+        KrbEtype::Aes256CtsHmacSha196 => Some((CipherSuite::Aes256CtsHmacSha196, ChecksumSuite::HmacSha196Aes256)),
+        KrbEtype::Aes128CtsHmacSha256128 | KrbEtype::Aes256CtsHmacSha384192 | KrbEtype::Rc4Hmac => None,
+    }
+}
+
+fn hmac_sha1(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = <Hmac<Sha1> as KeyInit>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data);
+
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// PBKDF2 with the HMAC of the etype hash function (RFC 3962 4, RFC 8009 4).
+fn pbkdf2(etype: KrbEtype, password: &[u8], salt: &[u8], iterations: u32) -> Vec<u8> {
+    let mut tkey = vec![0; etype.key_len()];
+    match etype {
+        KrbEtype::Aes128CtsHmacSha256128 => pbkdf2_hmac::<Sha256>(password, salt, iterations, &mut tkey),
+        KrbEtype::Aes256CtsHmacSha384192 => pbkdf2_hmac::<Sha384>(password, salt, iterations, &mut tkey),
+        _ => pbkdf2_hmac::<Sha1>(password, salt, iterations, &mut tkey),
+    }
+
+    tkey
+}
+
+fn confounder_len(etype: KrbEtype) -> usize {
+    match etype {
+        KrbEtype::Des3CbcSha1Kd => DES3_BLOCK_SIZE,
+        KrbEtype::Rc4Hmac => rc4_hmac::CONFOUNDER_LEN,
+        _ => AES_BLOCK_SIZE,
+    }
+}
+
+fn confounder(len: usize) -> Vec<u8> {
+    let mut confounder = vec![0; len];
+    OsRng.fill_bytes(&mut confounder);
+
+    confounder
+}
+
+pub fn encrypt(etype: KrbEtype, key: &[u8], key_usage: i32, payload: &[u8]) -> Result<Vec<u8>, String> {
+    if let Some((cipher, _)) = picky_suites(etype) {
+        return cipher
+            .cipher()
+            .encrypt(key, key_usage, payload)
+            .map_err(|err| err.to_string());
+    }
+
+    match etype {
+        KrbEtype::Rc4Hmac => rc4_hmac::encrypt(key, key_usage, &confounder(confounder_len(etype)), payload),
+        _ => aes_sha2::encrypt(etype, key, key_usage, &confounder(confounder_len(etype)), payload),
+    }
+}
+
+pub fn decrypt(etype: KrbEtype, key: &[u8], key_usage: i32, cipher_data: &[u8]) -> Result<Vec<u8>, String> {
+    if let Some((cipher, _)) = picky_suites(etype) {
+        return cipher
+            .cipher()
+            .decrypt(key, key_usage, cipher_data)
+            .map_err(|err| err.to_string());
+    }
+
+    match etype {
+        KrbEtype::Rc4Hmac => rc4_hmac::decrypt(key, key_usage, cipher_data),
+        _ => aes_sha2::decrypt(etype, key, key_usage, cipher_data),
+    }
+}
+
+/// Intermediate values of the decryption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecryptTrace {
+    pub confounder: Vec<u8>,
+    pub plaintext: Vec<u8>,
+    /// HMAC calculated during the decryption.
+    pub expected_hmac: Vec<u8>,
+    /// HMAC received in the cipher data.
+    pub hmac: Vec<u8>,
+}
+
+impl DecryptTrace {
+    pub fn is_integrity_valid(&self) -> bool {
+        self.expected_hmac == self.hmac
+    }
+}
+
+/// Decrypts the cipher data like [decrypt] but does not fail when the integrity check fails.
+pub fn decrypt_trace(etype: KrbEtype, key: &[u8], key_usage: i32, cipher_data: &[u8]) -> Result<DecryptTrace, String> {
+    if let Some((cipher, _)) = picky_suites(etype) {
+        let min_len = confounder_len(etype) + etype.hmac_len();
+        if cipher_data.len() < min_len {
+            return Err(format!(
+                "{}: invalid cipher length: expected at least {} bytes but got {}",
+                etype.as_ref(),
+                min_len,
+                cipher_data.len()
+            ));
+        }
+
+        let DecryptWithoutChecksum {
+            plaintext,
+            confounder,
+            checksum,
+            ki,
+        } = cipher
+            .cipher()
+            .decrypt_no_checksum(key, key_usage, cipher_data)
+            .map_err(|err| err.to_string())?;
+
+        let mut expected_hmac = hmac_sha1(&ki, &[confounder.as_slice(), plaintext.as_slice()].concat());
+        expected_hmac.truncate(etype.hmac_len());
+
+        return Ok(DecryptTrace {
+            confounder,
+            plaintext,
+            expected_hmac,
+            hmac: checksum,
+        });
+    }
+
+    match etype {
+        KrbEtype::Rc4Hmac => rc4_hmac::decrypt_trace(key, key_usage, cipher_data),
+        _ => aes_sha2::decrypt_trace(etype, key, key_usage, cipher_data),
+    }
+}
+
+/// Calculates the checksum of the etype's associated checksum type.
+pub fn checksum(etype: KrbEtype, key: &[u8], key_usage: i32, payload: &[u8]) -> Result<Vec<u8>, String> {
+    if let Some((_, checksum)) = picky_suites(etype) {
+        return checksum
+            .hasher()
+            .checksum(key, key_usage, payload)
+            .map_err(|err| err.to_string());
+    }
+
+    match etype {
+        KrbEtype::Rc4Hmac => rc4_hmac::checksum(key, key_usage, payload),
+        _ => aes_sha2::checksum(etype, key, key_usage, payload),
+    }
+}
+
+pub fn string_to_key(etype: KrbEtype, password: &[u8], salt: &[u8]) -> Result<Vec<u8>, String> {
+    if let Some((cipher, _)) = picky_suites(etype) {
+        return cipher
+            .cipher()
+            .generate_key_from_password(password, salt)
+            .map_err(|err| err.to_string());
+    }
+
+    Ok(match etype {
+        KrbEtype::Rc4Hmac => rc4_hmac::string_to_key(password),
+        _ => aes_sha2::string_to_key(etype, password, salt),
+    })
+}
+
+pub fn process_krb_etype_cipher(input: &KrbEtypeInput) -> Result<Vec<u8>, String> {
+    let KrbEtypeInput { etype, input } = input;
+
+    match input.mode {
+        KrbMode::Encrypt => encrypt(*etype, &input.data.key, input.data.key_usage, &input.data.payload),
+        KrbMode::Decrypt => decrypt(*etype, &input.data.key, input.data.key_usage, &input.data.payload),
+    }
+}
+
+pub fn process_krb_etype_checksum(input: &KrbEtypeInput) -> Result<Vec<u8>, String> {
+    let KrbEtypeInput { etype, input } = input;
+
+    checksum(*etype, &input.data.key, input.data.key_usage, &input.data.payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt_trace, encrypt, pbkdf2};
+    use crate::crypto_helper::algorithm::{KRB_ETYPES, KrbEtype};
+
+    #[test]
+    fn pbkdf2_hmac_sha256() {
+        assert_eq!(
+            "120fb6cffcf8b32c43e7225256c4f837",
+            hex::encode(pbkdf2(KrbEtype::Aes128CtsHmacSha256128, b"password", b"salt", 1))
+        );
+    }
+
+    #[test]
+    fn decrypt_trace_integrity() {
+        // The second payload is not a multiple of the DES3 block size, so DES3 zero-pads it and the padding
+        // can not be told from the plaintext after the decryption (RFC 3961 5.3).
+        for payload in [b"kerberos payload".as_slice(), b"kerberos payload!"] {
+            for etype in KRB_ETYPES {
+                let key = vec![0x42; etype.key_len()];
+                let cipher = encrypt(etype, &key, 2, payload).unwrap();
+
+                let trace = decrypt_trace(etype, &key, 2, &cipher).unwrap();
+                assert!(trace.is_integrity_valid(), "{}", etype.as_ref());
+                let mut expected = payload.to_vec();
+                if etype == KrbEtype::Des3CbcSha1Kd {
+                    expected.resize(payload.len().div_ceil(8) * 8, 0);
+                }
+                assert_eq!(expected, trace.plaintext, "{}", etype.as_ref());
+                assert_eq!(etype.hmac_len(), trace.hmac.len());
+
+                let trace = decrypt_trace(etype, &key, 3, &cipher).unwrap();
+                assert!(!trace.is_integrity_valid(), "{}", etype.as_ref());
+            }
+        }
+    }
+}
@@ -0,0 +1,728 @@
+//! Privilege Attribute Certificate (MS-PAC).
+//!
+//! PACTYPE = cBuffers version PAC_INFO_BUFFER*. Every PAC_INFO_BUFFER points to the buffer data by its offset from
+//! the PAC start. All numbers are little-endian. The logon information is NDR-encoded (MS-RPCE 2.2.6).
+
+use picky_krb::data_types::{AuthorizationData, EncTicketPart};
+
+use super::reader::{Encoding, Reader};
+use super::{checksum, format_timestamp};
+use crate::crypto_helper::algorithm::{KRB_ETYPES, KrbDataKeyInput, KrbEtype};
+
+/// KERB_NON_KERB_CKSUM_SALT (MS-PAC 2.8).
+pub const PAC_CHECKSUM_KEY_USAGE: i32 = 17;
+
+const AD_IF_RELEVANT: u8 = 1;
+const AD_WIN2K_PAC: u8 = 128;
+
+const LOGON_INFO: u32 = 1;
+const SERVER_CHECKSUM: u32 = 6;
+const KDC_CHECKSUM: u32 = 7;
+const CLIENT_INFO: u32 = 10;
+const UPN_DNS_INFO: u32 = 12;
+const TICKET_CHECKSUM: u32 = 16;
+const FULL_PAC_CHECKSUM: u32 = 19;
+
+/// The UPN_DNS_INFO contains the SAM name and the SID.
+const UPN_DNS_INFO_EXTENDED: u32 = 0x2;
+
+/// FILETIME value of the time that never comes.
+const FILETIME_NEVER: u64 = 0x7fff_ffff_ffff_ffff;
+/// Seconds between 1601-01-01 and 1970-01-01.
+const FILETIME_UNIX_EPOCH: i64 = 11_644_473_600;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacBuffer {
+    pub buffer_type: u32,
+    pub offset: u64,
+    pub data: Vec<u8>,
+}
+
+impl PacBuffer {
+    /// Buffer type name (MS-PAC 2.4).
+    pub fn type_name(&self) -> &'static str {
+        match self.buffer_type {
+            LOGON_INFO => "Logon information",
+            2 => "Credentials information",
+            SERVER_CHECKSUM => "Server checksum",
+            KDC_CHECKSUM => "KDC checksum",
+            CLIENT_INFO => "Client name and ticket information",
+            11 => "Constrained delegation information",
+            UPN_DNS_INFO => "UPN and DNS information",
+            13 => "Client claims information",
+            14 => "Device information",
+            15 => "Device claims information",
+            TICKET_CHECKSUM => "Ticket checksum",
+            17 => "PAC attributes",
+            18 => "PAC requestor",
+            FULL_PAC_CHECKSUM => "Full PAC checksum",
+            _ => "Unknown",
+        }
+    }
+
+    pub fn info(&self) -> Result<PacInfo, String> {
+        Ok(match self.buffer_type {
+            LOGON_INFO => PacInfo::LogonInfo(Box::new(parse_logon_info(&self.data)?)),
+            CLIENT_INFO => PacInfo::ClientInfo(parse_client_info(&self.data)?),
+            UPN_DNS_INFO => PacInfo::UpnDnsInfo(parse_upn_dns_info(&self.data)?),
+            SERVER_CHECKSUM | KDC_CHECKSUM | TICKET_CHECKSUM | FULL_PAC_CHECKSUM => {
+                PacInfo::Signature(parse_signature(&self.data)?)
+            }
+            _ => PacInfo::Raw,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacInfo {
+    LogonInfo(Box<LogonInfo>),
+    ClientInfo(ClientInfo),
+    UpnDnsInfo(UpnDnsInfo),
+    Signature(PacSignature),
+    /// The buffer is not decoded.
+    Raw,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMembership {
+    pub relative_id: u32,
+    pub attributes: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidAndAttributes {
+    pub sid: String,
+    pub attributes: u32,
+}
+
+/// KERB_VALIDATION_INFO (MS-PAC 2.5). Times are FILETIME values.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LogonInfo {
+    pub logon_time: u64,
+    pub logoff_time: u64,
+    pub kick_off_time: u64,
+    pub password_last_set: u64,
+    pub password_can_change: u64,
+    pub password_must_change: u64,
+    pub effective_name: String,
+    pub full_name: String,
+    pub logon_script: String,
+    pub profile_path: String,
+    pub home_directory: String,
+    pub home_directory_drive: String,
+    pub logon_count: u16,
+    pub bad_password_count: u16,
+    pub user_id: u32,
+    pub primary_group_id: u32,
+    pub group_ids: Vec<GroupMembership>,
+    pub user_flags: u32,
+    pub logon_server: String,
+    pub logon_domain_name: String,
+    pub logon_domain_id: Option<String>,
+    pub user_account_control: u32,
+    pub extra_sids: Vec<SidAndAttributes>,
+    pub resource_group_domain_sid: Option<String>,
+    pub resource_group_ids: Vec<GroupMembership>,
+}
+
+impl LogonInfo {
+    /// Returns the SID of the account in the logon domain.
+    pub fn domain_sid(&self, relative_id: u32) -> String {
+        match &self.logon_domain_id {
+            Some(domain) => format!("{}-{}", domain, relative_id),
+            None => relative_id.to_string(),
+        }
+    }
+}
+
+/// PAC_CLIENT_INFO (MS-PAC 2.7).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// FILETIME of the ticket auth time.
+    pub client_id: u64,
+    pub name: String,
+}
+
+/// UPN_DNS_INFO (MS-PAC 2.10).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpnDnsInfo {
+    pub upn: String,
+    pub dns_domain_name: String,
+    pub flags: u32,
+    pub sam_name: Option<String>,
+    pub sid: Option<String>,
+}
+
+/// PAC_SIGNATURE_DATA (MS-PAC 2.8).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacSignature {
+    pub signature_type: i32,
+    pub signature: Vec<u8>,
+}
+
+impl PacSignature {
+    /// Returns the etype whose checksum type is used for the signature.
+    pub fn etype(&self) -> Option<KrbEtype> {
+        KRB_ETYPES
+            .into_iter()
+            .find(|etype| etype.checksum_type() == self.signature_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PacChecksumTrace {
+    pub computed: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl PacChecksumTrace {
+    pub fn is_valid(&self) -> bool {
+        self.computed == self.signature
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pac {
+    pub version: u32,
+    pub buffers: Vec<PacBuffer>,
+    /// Whole PAC data. It is needed for the checksum verification.
+    pub data: Vec<u8>,
+}
+
+impl Pac {
+    pub fn server_signature(&self) -> Option<Result<PacSignature, String>> {
+        self.buffers
+            .iter()
+            .find(|buffer| buffer.buffer_type == SERVER_CHECKSUM)
+            .map(|buffer| parse_signature(&buffer.data))
+    }
+
+    /// Calculates the server checksum over the PAC with the server and KDC signatures set to zero.
+    pub fn verify_server_checksum(&self, key: &[u8]) -> Result<PacChecksumTrace, String> {
+        let signature = self
+            .server_signature()
+            .ok_or_else(|| "PAC: the server checksum buffer is missing".to_owned())??;
+        let etype = signature
+            .etype()
+            .ok_or_else(|| format!("PAC: unsupported signature type: {}", signature.signature_type))?;
+
+        let mut data = self.data.clone();
+        for buffer in &self.buffers {
+            if buffer.buffer_type == SERVER_CHECKSUM || buffer.buffer_type == KDC_CHECKSUM {
+                let len = parse_signature(&buffer.data)?.signature.len();
+                let start = buffer.offset as usize + 4;
+                data[start..start + len].fill(0);
+            }
+        }
+
+        Ok(PacChecksumTrace {
+            computed: checksum(etype, key, PAC_CHECKSUM_KEY_USAGE, &data)?,
+            signature: signature.signature,
+        })
+    }
+}
+
+/// Formats the FILETIME value as the UTC date and time.
+pub fn format_filetime(filetime: u64) -> String {
+    match filetime {
+        0 => "-".into(),
+        FILETIME_NEVER => "never".into(),
+        filetime => format_timestamp((filetime / 10_000_000) as i64 - FILETIME_UNIX_EPOCH),
+    }
+}
+
+fn ndr_reader(data: &[u8]) -> Reader<'_> {
+    Reader::new("PAC", Encoding::Ndr, data)
+}
+
+/// NDR structures of the PAC buffers.
+trait PacReader {
+    fn read_filetime(&mut self) -> Result<u64, String>;
+    fn read_utf16(&mut self, len: usize) -> Result<String, String>;
+    fn read_unicode_string(&mut self) -> Result<u32, String>;
+    fn read_unicode_string_data(&mut self, pointer: u32) -> Result<String, String>;
+    fn read_sid(&mut self) -> Result<String, String>;
+    fn read_sid_data(&mut self, pointer: u32) -> Result<Option<String>, String>;
+    fn read_groups_data(&mut self, pointer: u32) -> Result<Vec<GroupMembership>, String>;
+    fn read_extra_sids_data(&mut self, pointer: u32) -> Result<Vec<SidAndAttributes>, String>;
+}
+
+impl PacReader for Reader<'_> {
+    /// FILETIME is the structure of two 32-bit numbers, so it is aligned by 4.
+    fn read_filetime(&mut self) -> Result<u64, String> {
+        let low = self.read_u32()?;
+        let high = self.read_u32()?;
+
+        Ok((u64::from(high) << 32) | u64::from(low))
+    }
+
+    fn read_utf16(&mut self, len: usize) -> Result<String, String> {
+        let data = self.read(len)?;
+
+        Ok(String::from_utf16_lossy(
+            &data
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    /// RPC_UNICODE_STRING: Length, MaximumLength, and the Buffer pointer. Returns the pointer.
+    fn read_unicode_string(&mut self) -> Result<u32, String> {
+        let _len = self.read_u16()?;
+        let _max_len = self.read_u16()?;
+
+        self.read_u32()
+    }
+
+    /// Deferred RPC_UNICODE_STRING buffer: conformant varying array of WCHARs.
+    fn read_unicode_string_data(&mut self, pointer: u32) -> Result<String, String> {
+        if pointer == 0 {
+            return Ok(String::new());
+        }
+
+        let _max_count = self.read_u32()?;
+        let _offset = self.read_u32()?;
+        let count = self.read_u32()?;
+
+        self.read_utf16(count as usize * 2)
+    }
+
+    /// RPC_SID without the conformant array size.
+    fn read_sid(&mut self) -> Result<String, String> {
+        let revision = self.read_u8()?;
+        let sub_authority_count = self.read_u8()?;
+        let authority = self.read_array::<6>()?;
+        let authority = authority
+            .iter()
+            .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte));
+
+        let mut sid = format!("S-{}-{}", revision, authority);
+        for _ in 0..sub_authority_count {
+            sid.push_str(&format!("-{}", self.read_u32()?));
+        }
+
+        Ok(sid)
+    }
+
+    /// Deferred PISID.
+    fn read_sid_data(&mut self, pointer: u32) -> Result<Option<String>, String> {
+        if pointer == 0 {
+            return Ok(None);
+        }
+
+        let _max_count = self.read_u32()?;
+
+        self.read_sid().map(Some)
+    }
+
+    /// Deferred array of GROUP_MEMBERSHIP.
+    fn read_groups_data(&mut self, pointer: u32) -> Result<Vec<GroupMembership>, String> {
+        if pointer == 0 {
+            return Ok(Vec::new());
+        }
+
+        let count = self.read_u32()?;
+        (0..count)
+            .map(|_| {
+                Ok(GroupMembership {
+                    relative_id: self.read_u32()?,
+                    attributes: self.read_u32()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Deferred array of KERB_SID_AND_ATTRIBUTES followed by the SIDs.
+    fn read_extra_sids_data(&mut self, pointer: u32) -> Result<Vec<SidAndAttributes>, String> {
+        if pointer == 0 {
+            return Ok(Vec::new());
+        }
+
+        let count = self.read_u32()?;
+        let sids = (0..count)
+            .map(|_| Ok((self.read_u32()?, self.read_u32()?)))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        sids.into_iter()
+            .map(|(sid, attributes)| {
+                Ok(SidAndAttributes {
+                    sid: self.read_sid_data(sid)?.unwrap_or_default(),
+                    attributes,
+                })
+            })
+            .collect()
+    }
+}
+
+fn parse_logon_info(data: &[u8]) -> Result<LogonInfo, String> {
+    let mut reader = ndr_reader(data);
+
+    // Type serialization version 1 (MS-RPCE 2.2.6): common header, private header, and the referent pointer.
+    let [version, endianness] = reader.read_array()?;
+    if version != 1 || endianness != 0x10 {
+        return Err(format!(
+            "PAC: unsupported NDR serialization: version {} endianness {:#04x}",
+            version, endianness
+        ));
+    }
+    reader.read(14)?;
+    reader.read_u32()?;
+
+    let mut info = LogonInfo {
+        logon_time: reader.read_filetime()?,
+        logoff_time: reader.read_filetime()?,
+        kick_off_time: reader.read_filetime()?,
+        password_last_set: reader.read_filetime()?,
+        password_can_change: reader.read_filetime()?,
+        password_must_change: reader.read_filetime()?,
+        ..Default::default()
+    };
+
+    let effective_name = reader.read_unicode_string()?;
+    let full_name = reader.read_unicode_string()?;
+    let logon_script = reader.read_unicode_string()?;
+    let profile_path = reader.read_unicode_string()?;
+    let home_directory = reader.read_unicode_string()?;
+    let home_directory_drive = reader.read_unicode_string()?;
+    info.logon_count = reader.read_u16()?;
+    info.bad_password_count = reader.read_u16()?;
+    info.user_id = reader.read_u32()?;
+    info.primary_group_id = reader.read_u32()?;
+    let _group_count = reader.read_u32()?;
+    let group_ids = reader.read_u32()?;
+    info.user_flags = reader.read_u32()?;
+    let _user_session_key = reader.read(16)?;
+    let logon_server = reader.read_unicode_string()?;
+    let logon_domain_name = reader.read_unicode_string()?;
+    let logon_domain_id = reader.read_u32()?;
+    let _reserved1 = reader.read(8)?;
+    info.user_account_control = reader.read_u32()?;
+    let _sub_auth_status = reader.read_u32()?;
+    let _last_successful_logon = reader.read_filetime()?;
+    let _last_failed_logon = reader.read_filetime()?;
+    let _failed_logon_count = reader.read_u32()?;
+    let _reserved3 = reader.read_u32()?;
+    let _sid_count = reader.read_u32()?;
+    let extra_sids = reader.read_u32()?;
+    let resource_group_domain_sid = reader.read_u32()?;
+    let _resource_group_count = reader.read_u32()?;
+    let resource_group_ids = reader.read_u32()?;
+
+    // Deferred pointers follow in the order of the structure fields.
+    info.effective_name = reader.read_unicode_string_data(effective_name)?;
+    info.full_name = reader.read_unicode_string_data(full_name)?;
+    info.logon_script = reader.read_unicode_string_data(logon_script)?;
+    info.profile_path = reader.read_unicode_string_data(profile_path)?;
+    info.home_directory = reader.read_unicode_string_data(home_directory)?;
+    info.home_directory_drive = reader.read_unicode_string_data(home_directory_drive)?;
+    info.group_ids = reader.read_groups_data(group_ids)?;
+    info.logon_server = reader.read_unicode_string_data(logon_server)?;
+    info.logon_domain_name = reader.read_unicode_string_data(logon_domain_name)?;
+    info.logon_domain_id = reader.read_sid_data(logon_domain_id)?;
+    info.extra_sids = reader.read_extra_sids_data(extra_sids)?;
+    info.resource_group_domain_sid = reader.read_sid_data(resource_group_domain_sid)?;
+    info.resource_group_ids = reader.read_groups_data(resource_group_ids)?;
+
+    Ok(info)
+}
+
+fn parse_client_info(data: &[u8]) -> Result<ClientInfo, String> {
+    let mut reader = ndr_reader(data);
+
+    let client_id = reader.read_filetime()?;
+    let name_len = reader.read_u16()?;
+
+    Ok(ClientInfo {
+        client_id,
+        name: reader.read_utf16(name_len.into())?,
+    })
+}
+
+/// Reads the UTF-16 string by its length and offset from the buffer start.
+fn read_utf16_at(data: &[u8], len: u16, offset: u16) -> Result<String, String> {
+    let mut reader = ndr_reader(data);
+    reader.read(offset.into())?;
+
+    reader.read_utf16(len.into())
+}
+
+fn parse_upn_dns_info(data: &[u8]) -> Result<UpnDnsInfo, String> {
+    let mut reader = ndr_reader(data);
+
+    let upn_len = reader.read_u16()?;
+    let upn_offset = reader.read_u16()?;
+    let dns_domain_name_len = reader.read_u16()?;
+    let dns_domain_name_offset = reader.read_u16()?;
+    let flags = reader.read_u32()?;
+
+    let (sam_name, sid) = if flags & UPN_DNS_INFO_EXTENDED != 0 {
+        let sam_name_len = reader.read_u16()?;
+        let sam_name_offset = reader.read_u16()?;
+        let sid_len = reader.read_u16()?;
+        let sid_offset = reader.read_u16()?;
+
+        let mut sid_reader = ndr_reader(data);
+        sid_reader.read(sid_offset.into())?;
+        let sid_data = sid_reader.read(sid_len.into())?;
+
+        (
+            Some(read_utf16_at(data, sam_name_len, sam_name_offset)?),
+            Some(ndr_reader(sid_data).read_sid()?),
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok(UpnDnsInfo {
+        upn: read_utf16_at(data, upn_len, upn_offset)?,
+        dns_domain_name: read_utf16_at(data, dns_domain_name_len, dns_domain_name_offset)?,
+        flags,
+        sam_name,
+        sid,
+    })
+}
+
+fn parse_signature(data: &[u8]) -> Result<PacSignature, String> {
+    let mut reader = ndr_reader(data);
+
+    let signature_type = reader.read_u32()? as i32;
+    let mut signature = PacSignature {
+        signature_type,
+        signature: Vec::new(),
+    };
+    // The RODCIdentifier may follow the signature, so its length is taken from the checksum type when it is known.
+    let len = match signature.etype() {
+        Some(etype) => etype.hmac_len(),
+        None => data.len() - 4,
+    };
+    signature.signature = reader.read(len)?.to_vec();
+
+    Ok(signature)
+}
+
+pub fn parse_pac(data: &[u8]) -> Result<Pac, String> {
+    let mut reader = ndr_reader(data);
+
+    let buffers_count = reader.read_u32()?;
+    let version = reader.read_u32()?;
+    if version != 0 {
+        return Err(format!("PAC: unsupported version: expected 0 but got {}", version));
+    }
+
+    let buffers = (0..buffers_count)
+        .map(|_| {
+            let buffer_type = reader.read_u32()?;
+            let size = reader.read_u32()?;
+            let offset = u64::from(reader.read_u32()?) | (u64::from(reader.read_u32()?) << 32);
+
+            let data = usize::try_from(offset)
+                .ok()
+                .and_then(|start| data.get(start..start.checked_add(size as usize)?))
+                .ok_or_else(|| {
+                    format!(
+                        "PAC: buffer {} is out of range: offset {} size {}",
+                        buffer_type, offset, size
+                    )
+                })?;
+
+            Ok(PacBuffer {
+                buffer_type,
+                offset,
+                data: data.to_vec(),
+            })
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(Pac {
+        version,
+        buffers,
+        data: data.to_vec(),
+    })
+}
+
+fn find_pac_in_authorization_data(authorization_data: &AuthorizationData) -> Option<Vec<u8>> {
+    authorization_data.0.iter().find_map(|element| {
+        let data = &element.ad_data.0.0;
+
+        match element.ad_type.0.0.as_slice() {
+            [AD_WIN2K_PAC] | [0, AD_WIN2K_PAC] => Some(data.clone()),
+            [AD_IF_RELEVANT] => find_pac_in_authorization_data(&picky_asn1_der::from_bytes(data).ok()?),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the PAC from the decrypted EncTicketPart or the input data if it is not the EncTicketPart.
+pub fn extract_pac(data: &[u8]) -> Result<Vec<u8>, String> {
+    let Ok(enc_ticket_part) = picky_asn1_der::from_bytes::<EncTicketPart>(data) else {
+        return Ok(data.to_vec());
+    };
+
+    enc_ticket_part
+        .0
+        .authorization_data
+        .0
+        .as_ref()
+        .and_then(|authorization_data| find_pac_in_authorization_data(authorization_data))
+        .ok_or_else(|| "PAC: the EncTicketPart does not contain AD-WIN2K-PAC".to_owned())
+}
+
+/// Returns the PAC. The PAC is parsed to report the invalid data.
+pub fn process_krb_pac(input: &KrbDataKeyInput) -> Result<Vec<u8>, String> {
+    let pac = extract_pac(&input.data)?;
+    parse_pac(&pac)?;
+
+    Ok(pac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PacInfo, format_filetime, parse_pac, parse_upn_dns_info};
+
+    /// MS-PAC sample PAC with the logon information, client information, server checksum, and KDC checksum.
+    const PAC: &str = concat!(
+        "040000000000000001000000b004000048000000000000000a00000012000000f8040000000000000600000014000000",
+        "10050000000000000700000014000000280500000000000001100800cccccccca00400000000000000000200d186660f",
+        "656ac601ffffffffffffff7fffffffffffffff7f17d439fe784ac6011794a328424bc601175424977a81c60108000800",
+        "040002002400240008000200120012000c00020000000000100002000000000014000200000000001800020054100000",
+        "97792c00010200001a0000001c000200200000000000000000000000000000000000000016001800200002000a000c00",
+        "240002002800020000000000000000001000000000000000000000000000000000000000000000000000000000000000",
+        "0d0000002c0002000000000000000000000000000400000000000000040000006c007a00680075001200000000000000",
+        "120000004c0069007100690061006e00670028004c006100720072007900290020005a00680075000900000000000000",
+        "090000006e0074006400730032002e006200610074000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000001a00000061c433000700000009c32d00070000005eb43200070000000102000007000000",
+        "97b92c00070000002bf1320007000000ce30330007000000a72e2e00070000002af132000700000098b92c0007000000",
+        "62c4330007000000940133000700000076c4330007000000aefe2d000700000032d22c00070000001608320007000000",
+        "425b2e00070000005fb4320007000000ca9c35000700000085442d0007000000c2f0320007000000e9ea310007000000",
+        "ed8e2e0007000000b6eb310007000000ab2e2e0007000000720e2e00070000000c000000000000000b0000004e005400",
+        "4400450056002d00440043002d003000350000000600000000000000050000004e005400440045005600000004000000",
+        "0104000000000005150000005951b81766725d2564633b0b0d0000003000020007000000340002000700002038000200",
+        "070000203c000200070000204000020007000020440002000700002048000200070000204c0002000700002050000200",
+        "07000020540002000700002058000200070000205c000200070000206000020007000020050000000105000000000005",
+        "15000000b9301b2eb7414c6c8c3b351501020000050000000105000000000005150000005951b81766725d2564633b0b",
+        "74542f00050000000105000000000005150000005951b81766725d2564633b0be8383200050000000105000000000005",
+        "150000005951b81766725d2564633b0bcd383200050000000105000000000005150000005951b81766725d2564633b0b",
+        "5db43200050000000105000000000005150000005951b81766725d2564633b0b41163500050000000105000000000005",
+        "150000005951b81766725d2564633b0be8ea3100050000000105000000000005150000005951b81766725d2564633b0b",
+        "c1193200050000000105000000000005150000005951b81766725d2564633b0b29f13200050000000105000000000005",
+        "150000005951b81766725d2564633b0b0f5f2e00050000000105000000000005150000005951b81766725d2564633b0b",
+        "2f5b2e00050000000105000000000005150000005951b81766725d2564633b0bef8f3100050000000105000000000005",
+        "150000005951b81766725d2564633b0b075f2e00000000000049d90e656ac60108006c007a0068007500000000000000",
+        "76ffffff41edce9a34815d3aef7bc98874805d250000000076fffffff7a534dab2c02986efe0fbe5110a4f3200000000",
+    );
+
+    fn utf16(value: &str) -> Vec<u8> {
+        value.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    #[test]
+    fn parse() {
+        let pac = parse_pac(&hex::decode(PAC).unwrap()).unwrap();
+
+        assert_eq!(
+            vec![1, 10, 6, 7],
+            pac.buffers.iter().map(|buffer| buffer.buffer_type).collect::<Vec<_>>()
+        );
+
+        let PacInfo::LogonInfo(logon_info) = pac.buffers[0].info().unwrap() else {
+            panic!("logon information is expected");
+        };
+        assert_eq!("2006-04-28 01:42:50 UTC", format_filetime(logon_info.logon_time));
+        assert_eq!("never", format_filetime(logon_info.logoff_time));
+        assert_eq!("lzhu", logon_info.effective_name);
+        assert_eq!("Liqiang(Larry) Zhu", logon_info.full_name);
+        assert_eq!("ntds2.bat", logon_info.logon_script);
+        assert_eq!("", logon_info.profile_path);
+        assert_eq!(2_914_711, logon_info.user_id);
+        assert_eq!(513, logon_info.primary_group_id);
+        assert_eq!(26, logon_info.group_ids.len());
+        assert_eq!("NTDEV-DC-05", logon_info.logon_server);
+        assert_eq!("NTDEV", logon_info.logon_domain_name);
+        assert_eq!(
+            "S-1-5-21-397955417-626881126-188441444-2914711",
+            logon_info.domain_sid(logon_info.user_id)
+        );
+        assert_eq!(13, logon_info.extra_sids.len());
+        assert_eq!(
+            "S-1-5-21-773533881-1816936887-355810188-513",
+            logon_info.extra_sids[0].sid
+        );
+
+        let PacInfo::ClientInfo(client_info) = pac.buffers[1].info().unwrap() else {
+            panic!("client information is expected");
+        };
+        assert_eq!("lzhu", client_info.name);
+
+        let PacInfo::Signature(signature) = pac.buffers[2].info().unwrap() else {
+            panic!("signature is expected");
+        };
+        assert_eq!(-138, signature.signature_type);
+        assert_eq!(16, signature.signature.len());
+    }
+
+    /// PACs with the client information signed by MIT krb5 1.20.1 `krb5_pac_sign` for user@EXAMPLE.COM. The server
+    /// and KDC keys are the same.
+    #[test]
+    fn server_checksum() {
+        for (pac, key) in [
+            (
+                concat!(
+                    "03000000000000000a000000120000003800000000000000060000001000000050000000000000000700000010000000",
+                    "600000000000000000006dc64717da010800750073006500720000000000000010000000621641fe35a83e164c15373e",
+                    "10000000bcd27779c575f82b6b6bedd3",
+                ),
+                (0..32).collect::<Vec<u8>>(),
+            ),
+            (
+                concat!(
+                    "03000000000000000a000000120000003800000000000000060000001400000050000000000000000700000014000000",
+                    "680000000000000000006dc64717da010800750073006500720000000000000076ffffffe4628f32118ee4cb9a074a05",
+                    "e3fd28d50000000076ffffff2de3f438a29c44347446b7ffa425774300000000",
+                ),
+                (0..16).collect::<Vec<u8>>(),
+            ),
+        ] {
+            let pac = parse_pac(&hex::decode(pac).unwrap()).unwrap();
+
+            let trace = pac.verify_server_checksum(&key).unwrap();
+            assert!(trace.is_valid(), "{}", hex::encode(&trace.computed));
+
+            let mut wrong_key = key.clone();
+            wrong_key[0] ^= 1;
+            assert!(!pac.verify_server_checksum(&wrong_key).unwrap().is_valid());
+        }
+    }
+
+    #[test]
+    fn upn_dns_info() {
+        let upn = utf16("user@example.com");
+        let dns_domain_name = utf16("EXAMPLE.COM");
+        let sam_name = utf16("user");
+        let sid = hex::decode("010500000000000515000000010000000200000003000000e9030000").unwrap();
+
+        let mut data = Vec::new();
+        for (len, offset) in [(upn.len(), 24), (dns_domain_name.len(), 56)] {
+            data.extend((len as u16).to_le_bytes());
+            data.extend((offset as u16).to_le_bytes());
+        }
+        data.extend(2_u32.to_le_bytes());
+        for (len, offset) in [(sam_name.len(), 80), (sid.len(), 88)] {
+            data.extend((len as u16).to_le_bytes());
+            data.extend((offset as u16).to_le_bytes());
+        }
+        data.resize(24, 0);
+        data.extend(upn);
+        data.extend(dns_domain_name);
+        data.resize(80, 0);
+        data.extend(sam_name);
+        data.extend(sid);
+
+        let info = parse_upn_dns_info(&data).unwrap();
+
+        assert_eq!("user@example.com", info.upn);
+        assert_eq!("EXAMPLE.COM", info.dns_domain_name);
+        assert_eq!(Some("user".to_owned()), info.sam_name);
+        assert_eq!(Some("S-1-5-21-1-2-3-1001".to_owned()), info.sid);
+    }
+}
@@ -0,0 +1,137 @@
+//! rc4-hmac encryption type and hmac-md5 checksum (RFC 4757).
+
+use md5::{Digest, Md5};
+
+use super::DecryptTrace;
+use crate::common::ntlm::nt_hash;
+use crate::crypto_helper::primitives::{hmac_md5, rc4};
+
+pub const CONFOUNDER_LEN: usize = 8;
+pub const KEY_LEN: usize = 16;
+const CHECKSUM_LEN: usize = 16;
+pub const SIGNATURE_KEY_CONSTANT: &[u8] = b"signaturekey\0";
+
+/// Maps the Kerberos key usage to the Microsoft message type (RFC 4757 3).
+pub fn translate_usage(key_usage: i32) -> i32 {
+    match key_usage {
+        3 | 9 => 8,
+        23 => 13,
+        usage => usage,
+    }
+}
+
+fn check_key(key: &[u8]) -> Result<(), String> {
+    if key.len() != KEY_LEN {
+        return Err(format!(
+            "rc4-hmac: invalid key length: expected {} bytes but got {}",
+            KEY_LEN,
+            key.len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn usage_key(key: &[u8], key_usage: i32) -> [u8; 16] {
+    hmac_md5(key, &translate_usage(key_usage).to_le_bytes())
+}
+
+pub fn encrypt(key: &[u8], key_usage: i32, confounder: &[u8], payload: &[u8]) -> Result<Vec<u8>, String> {
+    check_key(key)?;
+
+    let k1 = usage_key(key, key_usage);
+
+    let mut plain_text = confounder.to_vec();
+    plain_text.extend_from_slice(payload);
+
+    let checksum = hmac_md5(&k1, &plain_text);
+    let k3 = hmac_md5(&k1, &checksum);
+
+    // The checksum goes before the cipher text.
+    let mut cipher_data = checksum.to_vec();
+    cipher_data.extend_from_slice(&rc4(&k3, &plain_text));
+
+    Ok(cipher_data)
+}
+
+pub fn decrypt_trace(key: &[u8], key_usage: i32, cipher_data: &[u8]) -> Result<DecryptTrace, String> {
+    check_key(key)?;
+
+    if cipher_data.len() < CHECKSUM_LEN + CONFOUNDER_LEN {
+        return Err(format!(
+            "rc4-hmac: invalid cipher length: expected at least {} bytes but got {}",
+            CHECKSUM_LEN + CONFOUNDER_LEN,
+            cipher_data.len()
+        ));
+    }
+
+    let k1 = usage_key(key, key_usage);
+
+    let (checksum, cipher_text) = cipher_data.split_at(CHECKSUM_LEN);
+    let k3 = hmac_md5(&k1, checksum);
+    let mut plain_text = rc4(&k3, cipher_text);
+
+    let expected_hmac = hmac_md5(&k1, &plain_text).to_vec();
+    let plaintext = plain_text.split_off(CONFOUNDER_LEN);
+
+    Ok(DecryptTrace {
+        confounder: plain_text,
+        plaintext,
+        expected_hmac,
+        hmac: checksum.to_vec(),
+    })
+}
+
+pub fn decrypt(key: &[u8], key_usage: i32, cipher_data: &[u8]) -> Result<Vec<u8>, String> {
+    let trace = decrypt_trace(key, key_usage, cipher_data)?;
+
+    if !trace.is_integrity_valid() {
+        return Err(format!(
+            "rc4-hmac: integrity check failed: expected hmac {} but got {}",
+            hex::encode(trace.expected_hmac),
+            hex::encode(trace.hmac)
+        ));
+    }
+
+    Ok(trace.plaintext)
+}
+
+/// hmac-md5 checksum (RFC 4757 4).
+pub fn checksum(key: &[u8], key_usage: i32, payload: &[u8]) -> Result<Vec<u8>, String> {
+    check_key(key)?;
+
+    let signature_key = hmac_md5(key, SIGNATURE_KEY_CONSTANT);
+
+    let mut data = translate_usage(key_usage).to_le_bytes().to_vec();
+    data.extend_from_slice(payload);
+
+    Ok(hmac_md5(&signature_key, &Md5::digest(data)).to_vec())
+}
+
+/// The rc4-hmac key is the NT hash of the password. The salt is not used.
+pub fn string_to_key(password: &[u8]) -> Vec<u8> {
+    nt_hash(&String::from_utf8_lossy(password)).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt, string_to_key};
+
+    #[test]
+    fn encryption_round_trip() {
+        let key = string_to_key(b"Password");
+        assert_eq!("a4f49c406510bdcab6824ee7c30fd852", hex::encode(&key));
+
+        let cipher = encrypt(&key, 3, &[1; 8], b"payload").unwrap();
+
+        assert_eq!(16 + 8 + 7, cipher.len());
+        assert_eq!(b"payload".to_vec(), decrypt(&key, 3, &cipher).unwrap());
+        // Key usages 3 and 9 share the message type.
+        assert_eq!(b"payload".to_vec(), decrypt(&key, 9, &cipher).unwrap());
+        assert!(
+            decrypt(&key, 2, &cipher)
+                .unwrap_err()
+                .contains("integrity check failed")
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Byte reader shared by the keytab, credential cache, and PAC parsers.
+
+/// Keytab and credential cache integers are big-endian. NDR (PAC) integers are little-endian and aligned by
+/// their size from the buffer start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    BigEndian,
+    Ndr,
+}
+
+pub struct Reader<'data> {
+    /// Format name used in the error messages.
+    format: &'static str,
+    encoding: Encoding,
+    data: &'data [u8],
+    position: usize,
+}
+
+impl<'data> Reader<'data> {
+    pub fn new(format: &'static str, encoding: Encoding, data: &'data [u8]) -> Self {
+        Self {
+            format,
+            encoding,
+            data,
+            position: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position == self.data.len()
+    }
+
+    pub fn read(&mut self, len: usize) -> Result<&'data [u8], String> {
+        let rest = &self.data[self.position..];
+        if rest.len() < len {
+            return Err(format!(
+                "{}: unexpected end of data: expected {} bytes but got {}",
+                self.format,
+                len,
+                rest.len()
+            ));
+        }
+        self.position += len;
+
+        Ok(&rest[..len])
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.read(N)?.try_into().expect("length is checked by the read method"))
+    }
+
+    pub fn align(&mut self, alignment: usize) -> Result<(), String> {
+        let padding = (alignment - self.position % alignment) % alignment;
+        self.read(padding)?;
+
+        Ok(())
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, String> {
+        Ok(match self.encoding {
+            Encoding::BigEndian => u16::from_be_bytes(self.read_array()?),
+            Encoding::Ndr => {
+                self.align(2)?;
+                u16::from_le_bytes(self.read_array()?)
+            }
+        })
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, String> {
+        Ok(match self.encoding {
+            Encoding::BigEndian => u32::from_be_bytes(self.read_array()?),
+            Encoding::Ndr => {
+                self.align(4)?;
+                u32::from_le_bytes(self.read_array()?)
+            }
+        })
+    }
+}
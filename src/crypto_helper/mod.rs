@@ -2,14 +2,20 @@
 pub mod macros;
 
 mod algorithm;
+mod block_modes;
+mod chacha;
 mod computations;
 mod info;
 mod input;
+mod krb;
+mod ntlm;
 mod output;
+mod primitives;
 
 pub use algorithm::Algorithm;
 use info::Info;
 use input::Input;
+use md5::Md5;
 use output::Output;
 use picky_krb::crypto::{ChecksumSuite, CipherSuite};
 use sha1::{Digest, Sha1};
@@ -18,9 +24,16 @@ use yew::{Callback, Html, function_component, html, use_effect_with, use_state};
 use yew_hooks::{use_clipboard, use_local_storage, use_location};
 use yew_notifications::{Notification, NotificationType, use_notification};
 
+use self::block_modes::process_aes;
+use self::chacha::{process_chacha20, process_chacha20_poly1305, process_xchacha20_poly1305};
 use self::computations::{
     process_argon2, process_hmac_sha, process_krb_cipher, process_krb_hmac, process_rsa, process_zlib,
 };
+use self::krb::{
+    process_keytab, process_krb_credentials, process_krb_etype_checksum, process_krb_etype_cipher,
+    process_krb_gss_token, process_krb_key_derivation, process_krb_pac,
+};
+use self::ntlm::process_ntlm;
 use crate::crypto_helper::computations::process_bcrypt;
 use crate::url_query_params::{Asn1, generate_crypto_helper_link};
 
@@ -28,7 +41,7 @@ const CRYPTO_HELPER_LOCAL_STORAGE_KEY: &str = "CRYPTO_HELPER_DATA";
 
 fn convert(algorithm: &Algorithm) -> Result<Vec<u8>, String> {
     match algorithm {
-        Algorithm::Md5(input) => Ok(md5::compute(input).to_vec()),
+        Algorithm::Md5(input) => Ok(Md5::digest(input).to_vec()),
         Algorithm::Sha1(input) => {
             let mut sha1 = Sha1::new();
             sha1.update(input);
@@ -64,6 +77,18 @@ fn convert(algorithm: &Algorithm) -> Result<Vec<u8>, String> {
         Algorithm::Zlib(input) => process_zlib(input),
         Algorithm::Argon2(input) => process_argon2(input),
         Algorithm::HmacSha(input) => process_hmac_sha(input),
+        Algorithm::Aes(input) => process_aes(input),
+        Algorithm::ChaCha20(input) => process_chacha20(input),
+        Algorithm::ChaCha20Poly1305(input) => process_chacha20_poly1305(input),
+        Algorithm::XChaCha20Poly1305(input) => process_xchacha20_poly1305(input),
+        Algorithm::KrbCipher(input) => process_krb_etype_cipher(input),
+        Algorithm::KrbChecksum(input) => process_krb_etype_checksum(input),
+        Algorithm::KrbKeyDerivation(input) => process_krb_key_derivation(input),
+        Algorithm::Keytab(input) => process_keytab(input),
+        Algorithm::KrbCredentials(input) => process_krb_credentials(input),
+        Algorithm::KrbPac(input) => process_krb_pac(input),
+        Algorithm::KrbGssToken(input) => process_krb_gss_token(input),
+        Algorithm::Ntlm(input) => process_ntlm(input),
     }
 }
 
@@ -167,6 +192,7 @@ pub fn crypto_helper() -> Html {
         } else {
             let query = Asn1 {
                 asn1: output_data.clone(),
+                framing: Default::default(),
             };
             let query_string = serde_qs::to_string(&query).expect("Failed to serialize query");
             let url = format!("/asn1?{}", query_string);
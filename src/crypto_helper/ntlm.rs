@@ -0,0 +1,664 @@
+//! Step by step NTLM computations: NTOWF, NTLMv1/v2 responses, session keys, sign/seal keys, and MIC (MS-NLMP 3).
+
+use des::Des;
+use des::cipher::{Array, BlockCipherEncrypt, KeyInit};
+use md4::{Digest, Md4};
+use md5::Md5;
+
+use super::algorithm::{NtlmInput, NtlmResponseVersion};
+use super::primitives::{DerivationStep, hmac_md5, rc4};
+use crate::common::ntlm::{
+    AUTHENTICATE_HEADER_LEN, MIC_LEN, MSV_AV_TIMESTAMP, filetime_to_date_time, nt_hash, parse_av_pairs,
+};
+
+const CHALLENGE_LEN: usize = 8;
+const SESSION_KEY_LEN: usize = 16;
+const LM_PASSWORD_LEN: usize = 14;
+const LM_MAGIC: &[u8; 8] = b"KGS!@#$%";
+
+const CLIENT_SIGNING_MAGIC: &[u8] = b"session key to client-to-server signing key magic constant\0";
+const SERVER_SIGNING_MAGIC: &[u8] = b"session key to server-to-client signing key magic constant\0";
+const CLIENT_SEALING_MAGIC: &[u8] = b"session key to client-to-server sealing key magic constant\0";
+const SERVER_SEALING_MAGIC: &[u8] = b"session key to server-to-client sealing key magic constant\0";
+
+/// NTLM authentication values (MS-NLMP 3). The steps show how every value is calculated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NtlmTrace {
+    pub nt_challenge_response: Vec<u8>,
+    pub lm_challenge_response: Vec<u8>,
+    pub session_base_key: Vec<u8>,
+    pub key_exchange_key: Vec<u8>,
+    pub exported_session_key: Vec<u8>,
+    /// There are no signing keys without the extended session security.
+    pub client_signing_key: Option<Vec<u8>>,
+    pub server_signing_key: Option<Vec<u8>>,
+    /// Client and server sealing keys are the same without the extended session security.
+    pub client_sealing_key: Vec<u8>,
+    pub server_sealing_key: Vec<u8>,
+    /// MIC calculated when all three messages are provided.
+    pub mic: Option<Vec<u8>>,
+    /// MIC received in the AUTHENTICATE_MESSAGE.
+    pub received_mic: Option<Vec<u8>>,
+    /// Every intermediate value from the password to the MIC.
+    pub steps: Vec<DerivationStep>,
+}
+
+impl NtlmTrace {
+    pub fn is_mic_valid(&self) -> Option<bool> {
+        Some(self.mic.as_ref()? == self.received_mic.as_ref()?)
+    }
+}
+
+/// Challenge responses and keys calculated by the NTLMv1 or NTLMv2 steps.
+struct Responses {
+    nt_challenge_response: Vec<u8>,
+    lm_challenge_response: Vec<u8>,
+    session_base_key: Vec<u8>,
+    key_exchange_key: Vec<u8>,
+}
+
+fn utf16(value: &str) -> Vec<u8> {
+    value.encode_utf16().flat_map(|c| c.to_le_bytes()).collect()
+}
+
+fn check_len(name: &str, value: &[u8], expected: usize) -> Result<(), String> {
+    if value.len() != expected {
+        return Err(format!(
+            "NTLM: invalid {} length: expected {} bytes but got {}",
+            name,
+            expected,
+            value.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Spreads 56 key bits over 8 bytes. The DES parity bits are ignored.
+fn des_key(key: &[u8]) -> [u8; 8] {
+    let bits = key.iter().fold(0_u64, |bits, byte| (bits << 8) | u64::from(*byte));
+
+    core::array::from_fn(|i| (((bits >> (49 - 7 * i)) & 0x7f) as u8) << 1)
+}
+
+fn des(key: &[u8], data: &[u8; 8]) -> [u8; 8] {
+    let cipher = Des::new_from_slice(&des_key(key)).expect("DES key is always 8 bytes long");
+
+    let mut block = Array::from(*data);
+    cipher.encrypt_block(&mut block);
+
+    block.into()
+}
+
+/// DESL: the 16-byte key is split into three 7-byte DES keys (MS-NLMP 6).
+fn desl(key: &[u8], data: &[u8; 8]) -> Vec<u8> {
+    let mut key = key.to_vec();
+    key.resize(21, 0);
+
+    key.chunks_exact(7).flat_map(|key| des(key, data)).collect()
+}
+
+/// LMOWFv1: the upper-cased OEM password padded to 14 bytes, split into two DES keys.
+///
+/// The OEM code page depends on the client, so only ASCII passwords are accepted. ASCII is the same in every code page.
+/// Longer passwords have no LM hash, so they are rejected instead of being truncated.
+fn lm_hash(password: &str) -> Result<Vec<u8>, String> {
+    if !password.is_ascii() {
+        return Err("NTLM: LMOWFv1 supports only ASCII passwords because the OEM code page is unknown".into());
+    }
+    if password.len() > LM_PASSWORD_LEN {
+        return Err(format!(
+            "NTLM: LMOWFv1 is not available for passwords longer than {} characters",
+            LM_PASSWORD_LEN
+        ));
+    }
+
+    let mut password = password.to_ascii_uppercase().into_bytes();
+    password.resize(LM_PASSWORD_LEN, 0);
+
+    Ok(password.chunks_exact(7).flat_map(|key| des(key, LM_MAGIC)).collect())
+}
+
+/// Pushes the NTLMv1 steps and returns the responses with the KeyExchangeKey (MS-NLMP 3.3.1, 3.4.5.1).
+fn v1_steps(input: &NtlmInput, steps: &mut Vec<DerivationStep>) -> Result<Responses, String> {
+    let server_challenge: [u8; CHALLENGE_LEN] = input
+        .server_challenge
+        .as_slice()
+        .try_into()
+        .map_err(|_| format!("NTLM: server challenge must be {} bytes long", CHALLENGE_LEN))?;
+
+    let nt_owf = nt_hash(&input.password).to_vec();
+    steps.push(DerivationStep::new("NTOWFv1", "MD4(UNICODE(password))", nt_owf.clone()));
+
+    let (nt_challenge_response, lm_challenge_response) = if input.extended_session_security {
+        check_len("client challenge", &input.client_challenge, CHALLENGE_LEN)?;
+
+        let challenge_hash = Md5::digest([input.server_challenge.as_slice(), &input.client_challenge].concat());
+        steps.push(DerivationStep::new(
+            "challenge hash",
+            "MD5(server challenge | client challenge)",
+            challenge_hash.to_vec(),
+        ));
+
+        let challenge: [u8; CHALLENGE_LEN] = challenge_hash[..CHALLENGE_LEN].try_into().unwrap();
+        let nt_challenge_response = desl(&nt_owf, &challenge);
+        steps.push(DerivationStep::new(
+            "NtChallengeResponse",
+            "DESL(NTOWFv1, challenge hash[0..8])",
+            nt_challenge_response.clone(),
+        ));
+
+        let mut lm_challenge_response = input.client_challenge.clone();
+        lm_challenge_response.resize(24, 0);
+        steps.push(DerivationStep::new(
+            "LmChallengeResponse",
+            "client challenge | Z(16)",
+            lm_challenge_response.clone(),
+        ));
+
+        (nt_challenge_response, lm_challenge_response)
+    } else {
+        let lm_owf = lm_hash(&input.password)?;
+        steps.push(DerivationStep::new(
+            "LMOWFv1",
+            "DES(UPPER(password)[0..7], \"KGS!@#$%\") | DES(UPPER(password)[7..14], \"KGS!@#$%\")",
+            lm_owf.clone(),
+        ));
+
+        let nt_challenge_response = desl(&nt_owf, &server_challenge);
+        steps.push(DerivationStep::new(
+            "NtChallengeResponse",
+            "DESL(NTOWFv1, server challenge)",
+            nt_challenge_response.clone(),
+        ));
+
+        let lm_challenge_response = desl(&lm_owf, &server_challenge);
+        steps.push(DerivationStep::new(
+            "LmChallengeResponse",
+            "DESL(LMOWFv1, server challenge)",
+            lm_challenge_response.clone(),
+        ));
+
+        (nt_challenge_response, lm_challenge_response)
+    };
+
+    let session_base_key = Md4::digest(&nt_owf).to_vec();
+    steps.push(DerivationStep::new(
+        "SessionBaseKey",
+        "MD4(NTOWFv1)",
+        session_base_key.clone(),
+    ));
+
+    let key_exchange_key = if input.extended_session_security {
+        let key_exchange_key = hmac_md5(
+            &session_base_key,
+            &[
+                input.server_challenge.as_slice(),
+                &lm_challenge_response[..CHALLENGE_LEN],
+            ]
+            .concat(),
+        )
+        .to_vec();
+        steps.push(DerivationStep::new(
+            "KeyExchangeKey",
+            "HMAC_MD5(SessionBaseKey, server challenge | LmChallengeResponse[0..8])",
+            key_exchange_key.clone(),
+        ));
+
+        key_exchange_key
+    } else {
+        steps.push(DerivationStep::new(
+            "KeyExchangeKey",
+            "SessionBaseKey",
+            session_base_key.clone(),
+        ));
+
+        session_base_key.clone()
+    };
+
+    Ok(Responses {
+        nt_challenge_response,
+        lm_challenge_response,
+        session_base_key,
+        key_exchange_key,
+    })
+}
+
+/// Pushes the NTLMv2 steps and returns the responses with the KeyExchangeKey (MS-NLMP 3.3.2).
+fn v2_steps(input: &NtlmInput, steps: &mut Vec<DerivationStep>) -> Result<Responses, String> {
+    check_len("server challenge", &input.server_challenge, CHALLENGE_LEN)?;
+    check_len("client challenge", &input.client_challenge, CHALLENGE_LEN)?;
+
+    let nt_hash = nt_hash(&input.password);
+    steps.push(DerivationStep::new(
+        "NT hash",
+        "MD4(UNICODE(password))",
+        nt_hash.to_vec(),
+    ));
+
+    let user_domain = utf16(&(input.user.to_uppercase() + &input.domain));
+    steps.push(DerivationStep::new(
+        "user | domain",
+        "UNICODE(UPPER(user) | domain)",
+        user_domain.clone(),
+    ));
+
+    let nt_owf = hmac_md5(&nt_hash, &user_domain).to_vec();
+    steps.push(DerivationStep::new(
+        "NTOWFv2",
+        "HMAC_MD5(NT hash, user | domain). LMOWFv2 is the same",
+        nt_owf.clone(),
+    ));
+
+    let timestamp = match filetime_to_date_time(input.timestamp) {
+        Some(date_time) => format!("{} ({})", date_time, input.timestamp),
+        None => input.timestamp.to_string(),
+    };
+    let mut temp = vec![1, 1, 0, 0, 0, 0, 0, 0];
+    temp.extend_from_slice(&input.timestamp.to_le_bytes());
+    temp.extend_from_slice(&input.client_challenge);
+    temp.extend_from_slice(&[0; 4]);
+    temp.extend_from_slice(&input.target_info);
+    temp.extend_from_slice(&[0; 4]);
+    steps.push(DerivationStep::new(
+        "temp",
+        format!(
+            "0x01 | 0x01 | Z(6) | timestamp | client challenge | Z(4) | target info | Z(4). Timestamp: {}",
+            timestamp
+        ),
+        temp.clone(),
+    ));
+
+    let nt_proof_str = hmac_md5(&nt_owf, &[input.server_challenge.as_slice(), &temp].concat()).to_vec();
+    steps.push(DerivationStep::new(
+        "NTProofStr",
+        "HMAC_MD5(NTOWFv2, server challenge | temp)",
+        nt_proof_str.clone(),
+    ));
+
+    let nt_challenge_response = [nt_proof_str.as_slice(), &temp].concat();
+    steps.push(DerivationStep::new(
+        "NtChallengeResponse",
+        "NTProofStr | temp",
+        nt_challenge_response.clone(),
+    ));
+
+    // The client does not send the LMv2 response when the server provides the timestamp (MS-NLMP 3.1.5.1.2).
+    let av_pairs = parse_av_pairs(&input.target_info).map_err(|err| format!("NTLM: invalid target info: {}", err))?;
+    let lm_challenge_response = if av_pairs.iter().any(|av_pair| av_pair.id == MSV_AV_TIMESTAMP) {
+        let lm_challenge_response = vec![0; 24];
+        steps.push(DerivationStep::new(
+            "LmChallengeResponse",
+            "Z(24). The target info contains MsvAvTimestamp",
+            lm_challenge_response.clone(),
+        ));
+
+        lm_challenge_response
+    } else {
+        let lm_proof = hmac_md5(
+            &nt_owf,
+            &[input.server_challenge.as_slice(), &input.client_challenge].concat(),
+        );
+        let lm_challenge_response = [lm_proof.as_slice(), &input.client_challenge].concat();
+        steps.push(DerivationStep::new(
+            "LmChallengeResponse",
+            "HMAC_MD5(LMOWFv2, server challenge | client challenge) | client challenge",
+            lm_challenge_response.clone(),
+        ));
+
+        lm_challenge_response
+    };
+
+    let session_base_key = hmac_md5(&nt_owf, &nt_proof_str).to_vec();
+    steps.push(DerivationStep::new(
+        "SessionBaseKey",
+        "HMAC_MD5(NTOWFv2, NTProofStr)",
+        session_base_key.clone(),
+    ));
+    steps.push(DerivationStep::new(
+        "KeyExchangeKey",
+        "SessionBaseKey",
+        session_base_key.clone(),
+    ));
+
+    Ok(Responses {
+        nt_challenge_response,
+        lm_challenge_response,
+        key_exchange_key: session_base_key.clone(),
+        session_base_key,
+    })
+}
+
+/// Returns the MIC stored in the AUTHENTICATE_MESSAGE.
+pub fn authenticate_mic(authenticate_message: &[u8]) -> Option<&[u8]> {
+    authenticate_message.get(AUTHENTICATE_HEADER_LEN..AUTHENTICATE_HEADER_LEN + MIC_LEN)
+}
+
+/// Pushes the MIC step and returns the MIC when all three messages are provided.
+fn mic_steps(
+    input: &NtlmInput,
+    exported_session_key: &[u8],
+    steps: &mut Vec<DerivationStep>,
+) -> Result<Option<Vec<u8>>, String> {
+    if input.negotiate_message.is_empty() || input.challenge_message.is_empty() || input.authenticate_message.is_empty()
+    {
+        return Ok(None);
+    }
+
+    if authenticate_mic(&input.authenticate_message).is_none() {
+        return Err(format!(
+            "NTLM: AUTHENTICATE_MESSAGE is too short to contain the MIC: expected at least {} bytes",
+            AUTHENTICATE_HEADER_LEN + MIC_LEN
+        ));
+    }
+
+    let mut authenticate_message = input.authenticate_message.clone();
+    authenticate_message[AUTHENTICATE_HEADER_LEN..AUTHENTICATE_HEADER_LEN + MIC_LEN].fill(0);
+
+    let mic = hmac_md5(
+        exported_session_key,
+        &[
+            input.negotiate_message.as_slice(),
+            &input.challenge_message,
+            &authenticate_message,
+        ]
+        .concat(),
+    )
+    .to_vec();
+    steps.push(DerivationStep::new(
+        "MIC",
+        "HMAC_MD5(ExportedSessionKey, NEGOTIATE_MESSAGE | CHALLENGE_MESSAGE | AUTHENTICATE_MESSAGE with the zeroed MIC)",
+        mic.clone(),
+    ));
+
+    Ok(Some(mic))
+}
+
+/// Calculates every value of the NTLM authentication from the password to the MIC.
+pub fn ntlm_trace(input: &NtlmInput) -> Result<NtlmTrace, String> {
+    let mut steps = Vec::new();
+
+    let Responses {
+        nt_challenge_response,
+        lm_challenge_response,
+        session_base_key,
+        key_exchange_key,
+    } = match input.version {
+        NtlmResponseVersion::V1 => v1_steps(input, &mut steps)?,
+        NtlmResponseVersion::V2 => v2_steps(input, &mut steps)?,
+    };
+
+    let exported_session_key = if input.key_exchange {
+        check_len(
+            "encrypted random session key",
+            &input.encrypted_random_session_key,
+            SESSION_KEY_LEN,
+        )?;
+
+        let exported_session_key = rc4(&key_exchange_key, &input.encrypted_random_session_key);
+        steps.push(DerivationStep::new(
+            "ExportedSessionKey",
+            "RC4(KeyExchangeKey, EncryptedRandomSessionKey)",
+            exported_session_key.clone(),
+        ));
+
+        exported_session_key
+    } else {
+        steps.push(DerivationStep::new(
+            "ExportedSessionKey",
+            "KeyExchangeKey",
+            key_exchange_key.clone(),
+        ));
+
+        key_exchange_key.clone()
+    };
+
+    let session_key = |steps: &mut Vec<DerivationStep>, name: &str, magic: &[u8]| {
+        let key = Md5::digest([exported_session_key.as_slice(), magic].concat()).to_vec();
+        steps.push(DerivationStep::new(
+            name,
+            format!(
+                "MD5(ExportedSessionKey | \"{}\"). NTLMSSP_NEGOTIATE_128 is assumed",
+                String::from_utf8_lossy(&magic[..magic.len() - 1])
+            ),
+            key.clone(),
+        ));
+
+        key
+    };
+
+    let (client_signing_key, server_signing_key, client_sealing_key, server_sealing_key) =
+        if input.version == NtlmResponseVersion::V2 || input.extended_session_security {
+            (
+                Some(session_key(&mut steps, "client signing key", CLIENT_SIGNING_MAGIC)),
+                Some(session_key(&mut steps, "server signing key", SERVER_SIGNING_MAGIC)),
+                session_key(&mut steps, "client sealing key", CLIENT_SEALING_MAGIC),
+                session_key(&mut steps, "server sealing key", SERVER_SEALING_MAGIC),
+            )
+        } else {
+            steps.push(DerivationStep::new(
+                "sealing key",
+                "ExportedSessionKey. There are no signing keys without the extended session security",
+                exported_session_key.clone(),
+            ));
+
+            (None, None, exported_session_key.clone(), exported_session_key.clone())
+        };
+
+    let mic = mic_steps(input, &exported_session_key, &mut steps)?;
+    let received_mic = mic
+        .as_ref()
+        .and_then(|_| authenticate_mic(&input.authenticate_message))
+        .map(|mic| mic.to_vec());
+
+    Ok(NtlmTrace {
+        nt_challenge_response,
+        lm_challenge_response,
+        session_base_key,
+        key_exchange_key,
+        exported_session_key,
+        client_signing_key,
+        server_signing_key,
+        client_sealing_key,
+        server_sealing_key,
+        mic,
+        received_mic,
+        steps,
+    })
+}
+
+/// Returns the ExportedSessionKey. The output shows the whole [NtlmTrace].
+pub fn process_ntlm(input: &NtlmInput) -> Result<Vec<u8>, String> {
+    Ok(ntlm_trace(input)?.exported_session_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{authenticate_mic, ntlm_trace};
+    use crate::crypto_helper::algorithm::{NtlmInput, NtlmResponseVersion};
+    use crate::crypto_helper::primitives::DerivationStep;
+
+    fn step(steps: &[DerivationStep], name: &str) -> String {
+        hex::encode(&steps.iter().find(|step| step.name == name).unwrap().value)
+    }
+
+    // MS-NLMP 4.2.2.
+    #[test]
+    fn ntlm_v1() {
+        let input = NtlmInput {
+            version: NtlmResponseVersion::V1,
+            extended_session_security: false,
+            key_exchange: false,
+            ..NtlmInput::ms_nlmp_preset()
+        };
+        let steps = ntlm_trace(&input).unwrap().steps;
+
+        assert_eq!("a4f49c406510bdcab6824ee7c30fd852", step(&steps, "NTOWFv1"));
+        assert_eq!("e52cac67419a9a224a3b108f3fa6cb6d", step(&steps, "LMOWFv1"));
+        assert_eq!(
+            "67c43011f30298a2ad35ece64f16331c44bdbed927841f94",
+            step(&steps, "NtChallengeResponse")
+        );
+        assert_eq!(
+            "98def7b87f88aa5dafe2df779688a172def11c7d5ccdef13",
+            step(&steps, "LmChallengeResponse")
+        );
+        assert_eq!("d87262b0cde4b1cb7499becccdf10784", step(&steps, "SessionBaseKey"));
+    }
+
+    // MS-NLMP 4.2.3.
+    #[test]
+    fn ntlm_v1_extended_session_security() {
+        let input = NtlmInput {
+            version: NtlmResponseVersion::V1,
+            key_exchange: false,
+            ..NtlmInput::ms_nlmp_preset()
+        };
+        let steps = ntlm_trace(&input).unwrap().steps;
+
+        assert_eq!(
+            "7537f803ae367128ca458204bde7caf81e97ed2683267232",
+            step(&steps, "NtChallengeResponse")
+        );
+        assert_eq!(
+            "aaaaaaaaaaaaaaaa00000000000000000000000000000000",
+            step(&steps, "LmChallengeResponse")
+        );
+        assert_eq!("eb93429a8bd952f8b89c55b87f475edc", step(&steps, "KeyExchangeKey"));
+    }
+
+    // MS-NLMP 4.2.4.
+    #[test]
+    fn ntlm_v2() {
+        let steps = ntlm_trace(&NtlmInput::ms_nlmp_preset()).unwrap().steps;
+
+        assert_eq!("0c868a403bfd7a93a3001ef22ef02e3f", step(&steps, "NTOWFv2"));
+        assert_eq!(
+            "86c35097ac9cec102554764a57cccc19aaaaaaaaaaaaaaaa",
+            step(&steps, "LmChallengeResponse")
+        );
+        assert_eq!("68cd0ab851e51c96aabc927bebef6a1c", step(&steps, "NTProofStr"));
+        assert_eq!("8de40ccadbc14a82f15cb0ad0de95ca3", step(&steps, "SessionBaseKey"));
+        assert_eq!("55555555555555555555555555555555", step(&steps, "ExportedSessionKey"));
+        assert_eq!("4788dc861b4782f35d43fd98fe1a2d39", step(&steps, "client signing key"));
+        assert_eq!("59f600973cc4960a25480a7c196e4c58", step(&steps, "client sealing key"));
+    }
+
+    // MS-NLMP 4.2.4 has no MIC, so the AUTHENTICATE_MESSAGE is built from the 4.2.4 values and the 4.2.4.3
+    // CHALLENGE_MESSAGE. The expected MIC is calculated with the Python hmac module.
+    #[test]
+    fn mic() {
+        let input = NtlmInput {
+            negotiate_message: hex::decode(
+                "4e544c4d535350000100000033828ae200000000000000000000000000000000060070170000000f",
+            )
+            .unwrap(),
+            challenge_message: hex::decode(concat!(
+                "4e544c4d53535000020000000c000c003800000033828ae20123456789abcdef00000000000000002400240044000000",
+                "060070170000000f53006500720076006500720002000c0044006f006d00610069006e0001000c0053006500720076",
+                "006500720000000000",
+            ))
+            .unwrap(),
+            authenticate_message: hex::decode(concat!(
+                "4e544c4d5353500003000000180018005800000054005400700000000c000c00c400000008000800d000000010001000",
+                "d800000010001000e800000033828ae2060070170000000fddf2ea27964563bb4bdf9b66aa32b4e086c35097ac9cec10",
+                "2554764a57cccc19aaaaaaaaaaaaaaaa68cd0ab851e51c96aabc927bebef6a1c01010000000000000000000000000000",
+                "aaaaaaaaaaaaaaaa0000000002000c0044006f006d00610069006e0001000c0053006500720076006500720000000000",
+                "0000000044006f006d00610069006e00550073006500720043004f004d0050005500540045005200c5dad2544fc97990",
+                "94ce1ce90bc9d03e",
+            ))
+            .unwrap(),
+            ..NtlmInput::ms_nlmp_preset()
+        };
+        let trace = ntlm_trace(&input).unwrap();
+
+        assert_eq!(
+            "ddf2ea27964563bb4bdf9b66aa32b4e0",
+            hex::encode(trace.mic.as_ref().unwrap())
+        );
+        assert_eq!(trace.mic.as_deref(), authenticate_mic(&input.authenticate_message));
+        assert_eq!(Some(true), trace.is_mic_valid());
+    }
+
+    #[test]
+    fn lm_hash_ascii_only() {
+        let input = NtlmInput {
+            version: NtlmResponseVersion::V1,
+            extended_session_security: false,
+            key_exchange: false,
+            password: "Paßword".into(),
+            ..NtlmInput::ms_nlmp_preset()
+        };
+
+        assert!(ntlm_trace(&input).is_err());
+    }
+
+    #[test]
+    fn lm_hash_long_password() {
+        let input = NtlmInput {
+            version: NtlmResponseVersion::V1,
+            extended_session_security: false,
+            key_exchange: false,
+            password: "FifteenCharsPwd".into(),
+            ..NtlmInput::ms_nlmp_preset()
+        };
+
+        assert!(ntlm_trace(&input).unwrap_err().contains("longer than 14"));
+    }
+
+    #[test]
+    fn lm_v2_with_server_timestamp() {
+        let mut target_info = vec![0x07, 0x00, 0x08, 0x00, 0x00, 0x80, 0x3e, 0xd5, 0xde, 0xb1, 0x9d, 0x01];
+        target_info.extend_from_slice(&NtlmInput::ms_nlmp_preset().target_info);
+        let input = NtlmInput {
+            target_info,
+            ..NtlmInput::ms_nlmp_preset()
+        };
+        let trace = ntlm_trace(&input).unwrap();
+
+        assert_eq!(vec![0; 24], trace.lm_challenge_response);
+        assert_eq!(
+            hex::encode(&trace.lm_challenge_response),
+            step(&trace.steps, "LmChallengeResponse")
+        );
+    }
+
+    #[test]
+    fn trace_fields() {
+        let trace = ntlm_trace(&NtlmInput::ms_nlmp_preset()).unwrap();
+
+        assert_eq!(
+            "86c35097ac9cec102554764a57cccc19aaaaaaaaaaaaaaaa",
+            hex::encode(&trace.lm_challenge_response)
+        );
+        assert_eq!(
+            "68cd0ab851e51c96aabc927bebef6a1c",
+            hex::encode(&trace.nt_challenge_response[..16])
+        );
+        assert_eq!("8de40ccadbc14a82f15cb0ad0de95ca3", hex::encode(&trace.key_exchange_key));
+        assert_eq!(
+            "55555555555555555555555555555555",
+            hex::encode(&trace.exported_session_key)
+        );
+        assert_eq!(
+            "4788dc861b4782f35d43fd98fe1a2d39",
+            hex::encode(trace.client_signing_key.as_ref().unwrap())
+        );
+        assert_eq!(
+            "59f600973cc4960a25480a7c196e4c58",
+            hex::encode(&trace.client_sealing_key)
+        );
+        assert_eq!(None, trace.mic);
+        assert_eq!(None, trace.is_mic_valid());
+
+        let trace = ntlm_trace(&NtlmInput {
+            version: NtlmResponseVersion::V1,
+            extended_session_security: false,
+            key_exchange: false,
+            ..NtlmInput::ms_nlmp_preset()
+        })
+        .unwrap();
+        assert_eq!(None, trace.client_signing_key);
+        assert_eq!(trace.exported_session_key, trace.server_sealing_key);
+    }
+}
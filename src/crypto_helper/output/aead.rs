@@ -0,0 +1,83 @@
+use yew::{Callback, Html, Properties, function_component, html};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType};
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct AeadOutputProps {
+    /// Length of the tag appended to the cipher text. Zero if the output does not contain the tag.
+    tag_len: usize,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+}
+
+#[function_component(AeadOutput)]
+pub fn aead_output(props: &AeadOutputProps) -> Html {
+    let AeadOutputProps {
+        tag_len,
+        output,
+        add_notification,
+    } = &props;
+
+    let len = output.len();
+    let tag_len = (*tag_len).min(len);
+    let cipher_len = len - tag_len;
+
+    let cipher = hex::encode(&output[0..cipher_len]);
+    let tag = hex::encode(&output[cipher_len..]);
+
+    let clipboard = use_clipboard();
+    let copy = move |data: String, message: &'static str| {
+        let clipboard = clipboard.clone();
+        let add_notification = add_notification.clone();
+
+        Callback::from(move |_| {
+            clipboard.write_text(data.clone());
+
+            add_notification.emit(Notification::from_description_and_type(NotificationType::Info, message));
+        })
+    };
+
+    let onclick = copy(hex::encode(output), "output copied");
+    let on_cipher_click = copy(cipher.clone(), "cipher text copied");
+    let on_tag_click = copy(tag.clone(), "tag copied");
+
+    html! {
+        <div class="output">
+            {if tag_len > 0 {
+                html! {
+                    <>
+                        <span class="full-cipher" {onclick}>
+                            <span class="cipher">{cipher.clone()}</span>
+                            <span class="hmac">{tag.clone()}</span>
+                        </span>
+                        <span class="total">
+                            {format!("total: {}. cipher: {}. tag: {}.", len, cipher_len, tag_len)}
+                        </span>
+                        <span class="total">{"detached:"}</span>
+                        <span class="full-cipher" onclick={on_cipher_click}>
+                            <span class="cipher">{cipher}</span>
+                        </span>
+                        <span class="full-cipher" onclick={on_tag_click}>
+                            <span class="hmac">{tag}</span>
+                        </span>
+                    </>
+                }
+            } else {
+                html! {
+                    <>
+                        <span class="full-cipher" {onclick}>
+                            <span class="cipher">{cipher}</span>
+                        </span>
+                        <span class="total">{format!("total: {}.", len)}</span>
+                    </>
+                }
+            }}
+        </div>
+    }
+}
+
+pub fn build_aead_output(tag_len: usize, output: Vec<u8>, add_notification: Callback<Notification>) -> Html {
+    html! {
+        <AeadOutput {tag_len} {output} {add_notification} />
+    }
+}
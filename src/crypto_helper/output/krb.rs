@@ -1,14 +1,97 @@
-use yew::{Callback, Html, Properties, function_component, html};
+use asn1_parser::dump::DumpAsn1;
+use asn1_parser::{Asn1, Asn1Decoder, Asn1Type};
+use yew::{Callback, Html, Properties, function_component, html, use_memo};
 use yew_hooks::use_clipboard;
 use yew_notifications::{Notification, NotificationType};
 
-use crate::crypto_helper::algorithm::KrbMode;
+use crate::asn1::Framing;
+use crate::crypto_helper::algorithm::{KrbEtype, KrbInput, KrbMode};
+use crate::crypto_helper::krb::{DecryptTrace, decrypt_trace};
+use crate::url_query_params::generate_asn1_link;
 
-const HMAC_LEN: usize = 12;
+/// Names of the encrypted parts by their application tag number (RFC 4120 5).
+fn enc_part_name(tag_number: u8) -> Option<&'static str> {
+    match tag_number {
+        2 => Some("Authenticator"),
+        3 => Some("EncTicketPart"),
+        25 => Some("EncASRepPart"),
+        26 => Some("EncTGSRepPart"),
+        27 => Some("EncAPRepPart"),
+        28 => Some("EncKrbPrivPart"),
+        29 => Some("EncKrbCredPart"),
+        _ => None,
+    }
+}
+
+fn build_plaintext_asn1(plaintext: &[u8]) -> Html {
+    // The DES3 plaintext is padded with zeros, so only the first tree is decoded.
+    let Ok(tree) = Asn1::decode_buff(plaintext) else {
+        return html! {};
+    };
+
+    let name = match tree.inner_asn1() {
+        Asn1Type::ApplicationTag(application) => enc_part_name(application.get().tag_number()),
+        _ => None,
+    };
+
+    build_asn1_tree(&format!("plaintext: {}", name.unwrap_or("ASN.1")), &tree)
+}
+
+/// Renders the ASN.1 tree dump with the link to the ASN.1 page.
+pub fn build_asn1_tree(title: &str, tree: &Asn1) -> Html {
+    let data = tree.meta().raw_bytes().to_vec();
+
+    html! {
+        <div class="vertical">
+            <span class="total">{title}</span>
+            <pre class="asn1-sniffed-text">{DumpAsn1::new(std::slice::from_ref(tree)).to_string()}</pre>
+            <a href={generate_asn1_link(data, Framing::Raw)} class="a-link" target="_blank">{"Open on the ASN.1 page"}</a>
+        </div>
+    }
+}
+
+fn build_decrypt_trace(trace: &DecryptTrace, copy: &Callback<String>) -> Html {
+    let row = |name: &str, value: &[u8]| {
+        let value = hex::encode(value);
+        let copy = copy.clone();
+        let onclick = Callback::from({
+            let value = value.clone();
+            move |_| copy.emit(value.clone())
+        });
+
+        html! {
+            <>
+                <span class="table-cell">{name}</span>
+                <span class="table-cell full-cipher" {onclick}>{value}</span>
+            </>
+        }
+    };
+
+    let (integrity, integrity_class) = if trace.is_integrity_valid() {
+        ("ok", "table-cell integrity-ok")
+    } else {
+        ("failed", "table-cell integrity-failed")
+    };
+
+    html! {
+        <>
+            <div class="table-container">
+                {row("confounder", &trace.confounder)}
+                {row("plaintext", &trace.plaintext)}
+                {row("computed hmac", &trace.expected_hmac)}
+                {row("received hmac", &trace.hmac)}
+                <span class="table-cell">{"integrity"}</span>
+                <span class={integrity_class}>{integrity}</span>
+            </div>
+            {build_plaintext_asn1(&trace.plaintext)}
+        </>
+    }
+}
 
 #[derive(PartialEq, Properties, Clone)]
 pub struct KrbOutputProps {
-    mode: KrbMode,
+    etype: KrbEtype,
+    input: KrbInput,
     output: Vec<u8>,
     add_notification: Callback<Notification>,
 }
@@ -16,30 +99,55 @@ pub struct KrbOutputProps {
 #[function_component(KrbOutput)]
 pub fn krb_output(props: &KrbOutputProps) -> Html {
     let KrbOutputProps {
-        mode,
+        etype,
+        input,
         output,
         add_notification,
     } = &props;
+    let mode = input.mode;
+
+    // The trace follows the input, so the data that fails the integrity check can be inspected too.
+    let trace = use_memo((*etype, input.clone()), |(etype, input)| {
+        if input.mode == KrbMode::Decrypt && !input.data.payload.is_empty() {
+            Some(decrypt_trace(
+                *etype,
+                &input.data.key,
+                input.data.key_usage,
+                &input.data.payload,
+            ))
+        } else {
+            None
+        }
+    });
 
     let len = output.len();
 
-    let (cipher_len, hmac_len, cipher, hmac) = if len < HMAC_LEN {
+    let hmac_len = etype.hmac_len();
+    let (cipher_len, hmac_len, cipher, hmac) = if len < hmac_len || mode == KrbMode::Decrypt {
         (len, 0, hex::encode(output), "".into())
+    } else if *etype == KrbEtype::Rc4Hmac {
+        // rc4-hmac puts the checksum before the cipher text.
+        (
+            len - hmac_len,
+            hmac_len,
+            hex::encode(&output[hmac_len..]),
+            hex::encode(&output[0..hmac_len]),
+        )
     } else {
-        let cipher_len = len - HMAC_LEN;
+        let cipher_len = len - hmac_len;
         (
             cipher_len,
-            HMAC_LEN,
+            hmac_len,
             hex::encode(&output[0..cipher_len]),
             hex::encode(&output[cipher_len..]),
         )
     };
+    let hmac_first = *etype == KrbEtype::Rc4Hmac;
 
-    let hex_output = hex::encode(output);
     let clipboard = use_clipboard();
     let add_notification = add_notification.clone();
-    let onclick = Callback::from(move |_| {
-        clipboard.write_text(hex_output.clone());
+    let copy = Callback::from(move |value: String| {
+        clipboard.write_text(value);
 
         add_notification.emit(Notification::from_description_and_type(
             NotificationType::Info,
@@ -47,11 +155,24 @@ pub fn krb_output(props: &KrbOutputProps) -> Html {
         ));
     });
 
+    let hex_output = hex::encode(output);
+    let copy_output = copy.clone();
+    let onclick = Callback::from(move |_| copy_output.emit(hex_output.clone()));
+
     html! {
         <div class="output">
             <span class="full-cipher" {onclick}>
-                <span class="cipher">{cipher}</span>
-                <span class="hmac">{hmac}</span>
+                {if hmac_first { html! {
+                    <>
+                        <span class="hmac">{hmac}</span>
+                        <span class="cipher">{cipher}</span>
+                    </>
+                }} else { html! {
+                    <>
+                        <span class="cipher">{cipher}</span>
+                        <span class="hmac">{hmac}</span>
+                    </>
+                }}}
             </span>
             {
                 match mode {
@@ -65,12 +186,24 @@ pub fn krb_output(props: &KrbOutputProps) -> Html {
                     }
                 }
             }
+            {match trace.as_ref() {
+                Some(Ok(trace)) => build_decrypt_trace(trace, &copy),
+                Some(Err(err)) => html! {
+                    <span class="total">{err}</span>
+                },
+                None => html! {},
+            }}
         </div>
     }
 }
 
-pub fn build_krb_output(mode: KrbMode, output: Vec<u8>, add_notification: Callback<Notification>) -> Html {
+pub fn build_krb_output(
+    etype: KrbEtype,
+    input: KrbInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+) -> Html {
     html! {
-        <KrbOutput {mode} {output} {add_notification} />
+        <KrbOutput {etype} {input} {output} {add_notification} />
     }
 }
@@ -0,0 +1,110 @@
+use asn1_parser::{Asn1, Asn1Decoder};
+use yew::{Callback, Html, Properties, function_component, html, use_memo};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType};
+
+use super::krb::build_asn1_tree;
+use crate::crypto_helper::algorithm::KrbDataKeyInput;
+use crate::crypto_helper::krb::{Credential, etype_name, format_timestamp, parse_credentials, ticket_flag_names};
+
+fn format_time(time: Option<i64>) -> String {
+    time.map(format_timestamp).unwrap_or_else(|| "-".into())
+}
+
+fn build_credential(index: usize, credential: &Credential, copy: &Callback<String>) -> Html {
+    let key = hex::encode(&credential.key);
+    let copy = copy.clone();
+    let onclick = Callback::from({
+        let key = key.clone();
+        move |_| copy.emit(key.clone())
+    });
+
+    let flags = ticket_flag_names(credential.flags).join(", ");
+
+    html! {
+        <div class="vertical">
+            <span class="total"><b>{format!("credential #{}", index + 1)}</b></span>
+            <div class="table-container">
+                <span class="table-cell">{"client"}</span>
+                <span class="table-cell">{&credential.client}</span>
+                <span class="table-cell">{"server"}</span>
+                <span class="table-cell">{&credential.server}</span>
+                <span class="table-cell">{"flags"}</span>
+                <span class="table-cell">{format!("{:08x} {}", credential.flags, flags)}</span>
+                <span class="table-cell">{"auth time"}</span>
+                <span class="table-cell">{format_time(credential.auth_time)}</span>
+                <span class="table-cell">{"start time"}</span>
+                <span class="table-cell">{format_time(credential.start_time)}</span>
+                <span class="table-cell">{"end time"}</span>
+                <span class="table-cell">{format_time(credential.end_time)}</span>
+                <span class="table-cell">{"renew till"}</span>
+                <span class="table-cell">{format_time(credential.renew_till)}</span>
+                <span class="table-cell">{"session key etype"}</span>
+                <span class="table-cell">{etype_name(credential.key_etype)}</span>
+                <span class="table-cell">{"session key"}</span>
+                <span class="table-cell full-cipher" {onclick}>{key}</span>
+            </div>
+            {match Asn1::decode_buff(&credential.ticket) {
+                Ok(ticket) => build_asn1_tree("ticket", &ticket),
+                Err(_) => html! {},
+            }}
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbCredentialsOutputProps {
+    input: KrbDataKeyInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+}
+
+#[function_component(KrbCredentialsOutput)]
+pub fn krb_credentials_output(props: &KrbCredentialsOutputProps) -> Html {
+    let KrbCredentialsOutputProps {
+        input,
+        output,
+        add_notification,
+    } = props.clone();
+
+    // Credentials are parsed again only when the new output is computed and not on every input change.
+    let credentials = use_memo(output, move |output| {
+        if output.is_empty() {
+            Ok(Vec::new())
+        } else {
+            parse_credentials(&input)
+        }
+    });
+
+    let clipboard = use_clipboard();
+    let copy = Callback::from(move |value: String| {
+        clipboard.write_text(value);
+
+        add_notification.emit(Notification::from_description_and_type(
+            NotificationType::Info,
+            "session key copied",
+        ));
+    });
+
+    match credentials.as_ref() {
+        Ok(credentials) => html! {
+            <div class="output">
+                <span class="total">{format!("credentials: {}", credentials.len())}</span>
+                {for credentials.iter().enumerate().map(|(index, credential)| build_credential(index, credential, &copy))}
+            </div>
+        },
+        Err(err) => html! {
+            <span class="total">{err}</span>
+        },
+    }
+}
+
+pub fn build_krb_credentials_output(
+    input: KrbDataKeyInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+) -> Html {
+    html! {
+        <KrbCredentialsOutput {input} {output} {add_notification} />
+    }
+}
@@ -0,0 +1,77 @@
+use yew::{Callback, Html, Properties, function_component, html, use_memo};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType};
+
+use crate::crypto_helper::algorithm::KrbKeyDerivationInput;
+use crate::crypto_helper::krb::derivation_steps;
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbKeyDerivationOutputProps {
+    input: KrbKeyDerivationInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+}
+
+#[function_component(KrbKeyDerivationOutput)]
+pub fn krb_key_derivation_output(props: &KrbKeyDerivationOutputProps) -> Html {
+    let KrbKeyDerivationOutputProps {
+        input,
+        output,
+        add_notification,
+    } = props.clone();
+
+    // Steps are recalculated only when the new output is computed and not on every input change.
+    let steps = use_memo(output.clone(), move |output| {
+        if output.is_empty() {
+            Ok(Vec::new())
+        } else {
+            derivation_steps(&input)
+        }
+    });
+
+    let clipboard = use_clipboard();
+
+    match steps.as_ref() {
+        Ok(steps) => html! {
+            <div class="table-container">
+                {for steps.iter().map(|step| {
+                    let value = hex::encode(&step.value);
+                    let clipboard = clipboard.clone();
+                    let add_notification = add_notification.clone();
+                    let onclick = Callback::from({
+                        let value = value.clone();
+                        move |_| {
+                            clipboard.write_text(value.clone());
+                            add_notification.emit(Notification::from_description_and_type(
+                                NotificationType::Info,
+                                "value copied",
+                            ));
+                        }
+                    });
+
+                    html! {
+                        <>
+                            <span class="table-cell">
+                                <b>{&step.name}</b>{": "}{&step.description}
+                            </span>
+                            <span class="table-cell full-cipher" {onclick}>{value}</span>
+                        </>
+                    }
+                })}
+            </div>
+        },
+        Err(err) => html! {
+            <span class="total">{err}</span>
+        },
+    }
+}
+
+pub fn build_krb_key_derivation_output(
+    input: KrbKeyDerivationInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+) -> Html {
+    html! {
+        <KrbKeyDerivationOutput {input} {output} {add_notification} />
+    }
+}
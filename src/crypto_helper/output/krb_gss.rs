@@ -0,0 +1,152 @@
+use yew::{Callback, Html, Properties, function_component, html, use_memo};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType};
+
+use crate::crypto_helper::algorithm::{GssTokenType, KrbGssTokenInput, KrbMode};
+use crate::crypto_helper::krb::{GssTokenHeader, GssTokenTrace, gss_token_trace};
+
+fn build_value(name: &str, value: &[u8], copy: &Callback<String>) -> Html {
+    let value = hex::encode(value);
+    let copy = copy.clone();
+    let onclick = Callback::from({
+        let value = value.clone();
+        move |_| copy.emit(value.clone())
+    });
+
+    html! {
+        <>
+            <span class="table-cell">{name}</span>
+            <span class="table-cell full-cipher" {onclick}>{value}</span>
+        </>
+    }
+}
+
+fn build_header(header: &GssTokenHeader) -> Html {
+    let token_id = match header.token_type {
+        GssTokenType::Wrap => "0504 (Wrap)",
+        GssTokenType::Mic => "0404 (MIC)",
+    };
+
+    html! {
+        <>
+            <span class="table-cell">{"TOK_ID"}</span>
+            <span class="table-cell">{token_id}</span>
+            <span class="table-cell">{"flags"}</span>
+            <span class="table-cell">{format!("{:02x} {}", header.flags, header.flag_names().join(", "))}</span>
+            {if header.token_type == GssTokenType::Wrap { html! {
+                <>
+                    <span class="table-cell">{"EC"}</span>
+                    <span class="table-cell">{header.ec}</span>
+                    <span class="table-cell">{"RRC"}</span>
+                    <span class="table-cell">{header.rrc}</span>
+                </>
+            }} else { html! {} }}
+            <span class="table-cell">{"SND_SEQ"}</span>
+            <span class="table-cell">{header.seq_number}</span>
+        </>
+    }
+}
+
+fn build_trace(trace: &GssTokenTrace, copy: &Callback<String>) -> Html {
+    let (integrity, integrity_class) = if trace.is_valid() {
+        ("ok", "table-cell integrity-ok")
+    } else {
+        ("failed", "table-cell integrity-failed")
+    };
+    let (expected, received) = if trace.header.token_type == GssTokenType::Wrap && trace.header.is_sealed() {
+        ("expected header copy", "decrypted header copy")
+    } else {
+        ("computed checksum", "received checksum")
+    };
+
+    html! {
+        <div class="table-container">
+            {build_header(&trace.header)}
+            {if trace.header.token_type == GssTokenType::Wrap { html! {
+                <>
+                    {build_value("rotated back body", &trace.body, copy)}
+                    {build_value("message", &trace.message, copy)}
+                </>
+            }} else { html! {} }}
+            {build_value(expected, &trace.expected, copy)}
+            {build_value(received, &trace.received, copy)}
+            <span class="table-cell">{"integrity"}</span>
+            <span class={integrity_class}>{integrity}</span>
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbGssTokenOutputProps {
+    input: KrbGssTokenInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+}
+
+#[function_component(KrbGssTokenOutput)]
+pub fn krb_gss_token_output(props: &KrbGssTokenOutputProps) -> Html {
+    let KrbGssTokenOutputProps {
+        input,
+        output,
+        add_notification,
+    } = props.clone();
+
+    // The trace follows the input, so the token that fails the integrity check can be inspected too.
+    let trace = use_memo(input.clone(), |input| {
+        if input.mode == KrbMode::Decrypt && !input.token.is_empty() {
+            Some(gss_token_trace(input.etype, &input.key, &input.token, &input.message))
+        } else {
+            None
+        }
+    });
+
+    let clipboard = use_clipboard();
+    let copy = Callback::from(move |value: String| {
+        clipboard.write_text(value);
+
+        add_notification.emit(Notification::from_description_and_type(
+            NotificationType::Info,
+            "output copied",
+        ));
+    });
+
+    let details = match input.mode {
+        KrbMode::Encrypt => match GssTokenHeader::parse(&output) {
+            Ok(header) => html! {
+                <div class="table-container">
+                    {build_header(&header)}
+                </div>
+            },
+            Err(_) => html! {},
+        },
+        KrbMode::Decrypt => match trace.as_ref() {
+            Some(Ok(trace)) => build_trace(trace, &copy),
+            Some(Err(err)) => html! {
+                <span class="total">{err}</span>
+            },
+            None => html! {},
+        },
+    };
+
+    let hex_output = hex::encode(&output);
+    let copy_output = copy.clone();
+    let onclick = Callback::from(move |_| copy_output.emit(hex_output.clone()));
+
+    html! {
+        <div class="output">
+            <span class="full-cipher" {onclick}>{hex::encode(&output)}</span>
+            <span class="total">{format!("total: {}.", output.len())}</span>
+            {details}
+        </div>
+    }
+}
+
+pub fn build_krb_gss_token_output(
+    input: KrbGssTokenInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+) -> Html {
+    html! {
+        <KrbGssTokenOutput {input} {output} {add_notification} />
+    }
+}
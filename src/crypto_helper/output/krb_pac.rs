@@ -0,0 +1,228 @@
+use yew::{Callback, Html, Properties, function_component, html, use_memo};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType};
+
+use crate::crypto_helper::algorithm::KrbDataKeyInput;
+use crate::crypto_helper::krb::{
+    GroupMembership, LogonInfo, Pac, PacChecksumTrace, PacInfo, PacSignature, format_filetime, parse_pac,
+};
+
+type Row = (&'static str, String);
+
+fn format_groups(groups: &[GroupMembership], domain: Option<&String>) -> String {
+    groups
+        .iter()
+        .map(|group| match domain {
+            Some(domain) => format!("{}-{} ({:#x})", domain, group.relative_id, group.attributes),
+            None => format!("{} ({:#x})", group.relative_id, group.attributes),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn logon_info_rows(info: &LogonInfo) -> Vec<Row> {
+    vec![
+        ("logon time", format_filetime(info.logon_time)),
+        ("logoff time", format_filetime(info.logoff_time)),
+        ("kick off time", format_filetime(info.kick_off_time)),
+        ("password last set", format_filetime(info.password_last_set)),
+        ("password can change", format_filetime(info.password_can_change)),
+        ("password must change", format_filetime(info.password_must_change)),
+        ("effective name", info.effective_name.clone()),
+        ("full name", info.full_name.clone()),
+        ("logon script", info.logon_script.clone()),
+        ("profile path", info.profile_path.clone()),
+        ("home directory", info.home_directory.clone()),
+        ("home directory drive", info.home_directory_drive.clone()),
+        ("logon count", info.logon_count.to_string()),
+        ("bad password count", info.bad_password_count.to_string()),
+        ("user sid", info.domain_sid(info.user_id)),
+        ("primary group sid", info.domain_sid(info.primary_group_id)),
+        ("groups", format_groups(&info.group_ids, info.logon_domain_id.as_ref())),
+        ("user flags", format!("{:#x}", info.user_flags)),
+        ("logon server", info.logon_server.clone()),
+        ("logon domain name", info.logon_domain_name.clone()),
+        ("logon domain sid", info.logon_domain_id.clone().unwrap_or_default()),
+        ("user account control", format!("{:#x}", info.user_account_control)),
+        (
+            "extra sids",
+            info.extra_sids
+                .iter()
+                .map(|sid| format!("{} ({:#x})", sid.sid, sid.attributes))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        (
+            "resource groups",
+            format_groups(&info.resource_group_ids, info.resource_group_domain_sid.as_ref()),
+        ),
+    ]
+}
+
+fn signature_rows(signature: &PacSignature) -> Vec<Row> {
+    vec![
+        (
+            "signature type",
+            match signature.etype() {
+                Some(etype) => format!("{} ({})", etype.checksum_name(), signature.signature_type),
+                None => signature.signature_type.to_string(),
+            },
+        ),
+        ("signature", hex::encode(&signature.signature)),
+    ]
+}
+
+fn info_rows(info: &PacInfo) -> Vec<Row> {
+    match info {
+        PacInfo::LogonInfo(info) => logon_info_rows(info),
+        PacInfo::ClientInfo(info) => vec![
+            ("client id", format_filetime(info.client_id)),
+            ("name", info.name.clone()),
+        ],
+        PacInfo::UpnDnsInfo(info) => {
+            let mut rows = vec![
+                ("upn", info.upn.clone()),
+                ("dns domain name", info.dns_domain_name.clone()),
+                ("flags", format!("{:#x}", info.flags)),
+            ];
+            if let Some(sam_name) = &info.sam_name {
+                rows.push(("sam name", sam_name.clone()));
+            }
+            if let Some(sid) = &info.sid {
+                rows.push(("sid", sid.clone()));
+            }
+            rows
+        }
+        PacInfo::Signature(signature) => signature_rows(signature),
+        PacInfo::Raw => Vec::new(),
+    }
+}
+
+fn build_rows(rows: Vec<Row>) -> Html {
+    html! {
+        <div class="table-container">
+            {for rows.into_iter().map(|(name, value)| html! {
+                <>
+                    <span class="table-cell">{name}</span>
+                    <span class="table-cell pac-value">{value}</span>
+                </>
+            })}
+        </div>
+    }
+}
+
+fn build_checksum_trace(trace: &PacChecksumTrace) -> Html {
+    let (result, result_class) = if trace.is_valid() {
+        ("ok", "table-cell integrity-ok")
+    } else {
+        ("failed", "table-cell integrity-failed")
+    };
+
+    html! {
+        <div class="table-container">
+            <span class="table-cell">{"computed server checksum"}</span>
+            <span class="table-cell">{hex::encode(&trace.computed)}</span>
+            <span class="table-cell">{"server signature"}</span>
+            <span class="table-cell">{hex::encode(&trace.signature)}</span>
+            <span class="table-cell">{"server checksum"}</span>
+            <span class={result_class}>{result}</span>
+        </div>
+    }
+}
+
+fn build_pac(pac: &Pac, key: &[u8], copy: &Callback<String>) -> Html {
+    html! {
+        <>
+            <span class="total">{format!("version: {}. buffers: {}.", pac.version, pac.buffers.len())}</span>
+            {for pac.buffers.iter().map(|buffer| {
+                let data = hex::encode(&buffer.data);
+                let copy = copy.clone();
+                let onclick = Callback::from({
+                    let data = data.clone();
+                    move |_| copy.emit(data.clone())
+                });
+
+                html! {
+                    <div class="vertical">
+                        <span class="total">
+                            <b>{buffer.type_name()}</b>
+                            {format!(" (type {}, offset {}, size {})", buffer.buffer_type, buffer.offset, buffer.data.len())}
+                        </span>
+                        {match buffer.info() {
+                            Ok(PacInfo::Raw) => html! {
+                                <span class="full-cipher" {onclick}>{data}</span>
+                            },
+                            Ok(info) => build_rows(info_rows(&info)),
+                            Err(err) => html! {
+                                <span class="total">{err}</span>
+                            },
+                        }}
+                    </div>
+                }
+            })}
+            {if key.is_empty() {
+                html! {}
+            } else {
+                match pac.verify_server_checksum(key) {
+                    Ok(trace) => build_checksum_trace(&trace),
+                    Err(err) => html! {
+                        <span class="total">{err}</span>
+                    },
+                }
+            }}
+        </>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct KrbPacOutputProps {
+    input: KrbDataKeyInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+}
+
+#[function_component(KrbPacOutput)]
+pub fn krb_pac_output(props: &KrbPacOutputProps) -> Html {
+    let KrbPacOutputProps {
+        input,
+        output,
+        add_notification,
+    } = props.clone();
+
+    // The output is the PAC extracted from the input, so it is parsed again only when the new output is computed.
+    let pac = use_memo(output, |output| {
+        if output.is_empty() {
+            None
+        } else {
+            Some(parse_pac(output))
+        }
+    });
+
+    let clipboard = use_clipboard();
+    let copy = Callback::from(move |value: String| {
+        clipboard.write_text(value);
+
+        add_notification.emit(Notification::from_description_and_type(
+            NotificationType::Info,
+            "buffer copied",
+        ));
+    });
+
+    html! {
+        <div class="output">
+            {match pac.as_ref() {
+                Some(Ok(pac)) => build_pac(pac, &input.key, &copy),
+                Some(Err(err)) => html! {
+                    <span class="total">{err}</span>
+                },
+                None => html! {},
+            }}
+        </div>
+    }
+}
+
+pub fn build_krb_pac_output(input: KrbDataKeyInput, output: Vec<u8>, add_notification: Callback<Notification>) -> Html {
+    html! {
+        <KrbPacOutput {input} {output} {add_notification} />
+    }
+}
@@ -1,12 +1,35 @@
+mod aead;
 mod krb;
+mod krb_credentials;
+mod krb_derivation;
+mod krb_gss;
+mod krb_pac;
+mod ntlm;
 
 use yew::{Callback, Html, Properties, function_component, html};
 use yew_notifications::{Notification, use_notification};
 
+use self::aead::build_aead_output;
 use self::krb::build_krb_output;
+use self::krb_credentials::build_krb_credentials_output;
+use self::krb_derivation::build_krb_key_derivation_output;
+use self::krb_gss::build_krb_gss_token_output;
+use self::krb_pac::build_krb_pac_output;
+use self::ntlm::build_ntlm_output;
 use super::Algorithm;
+use super::algorithm::{AesMode, CipherAction, KrbEtype};
+use super::block_modes::GCM_TAG_LEN;
+use super::chacha::POLY1305_TAG_LEN;
 use crate::common::{BytesFormat, build_simple_output};
 
+/// Only the encryption produces the tag. It is appended to the cipher text.
+fn aead_tag_len(action: CipherAction, tag_len: usize) -> usize {
+    match action {
+        CipherAction::Encrypt => tag_len,
+        CipherAction::Decrypt => 0,
+    }
+}
+
 fn get_output_components(algorithm: &Algorithm, output: &[u8], add_notification: Callback<Notification>) -> Html {
     match algorithm {
         Algorithm::Md5(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
@@ -14,8 +37,18 @@ fn get_output_components(algorithm: &Algorithm, output: &[u8], add_notification:
         Algorithm::Sha256(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::Sha384(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::Sha512(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
-        Algorithm::Aes128CtsHmacSha196(input) => build_krb_output(input.mode, output.to_vec(), add_notification),
-        Algorithm::Aes256CtsHmacSha196(input) => build_krb_output(input.mode, output.to_vec(), add_notification),
+        Algorithm::Aes128CtsHmacSha196(input) => build_krb_output(
+            KrbEtype::Aes128CtsHmacSha196,
+            input.clone(),
+            output.to_vec(),
+            add_notification,
+        ),
+        Algorithm::Aes256CtsHmacSha196(input) => build_krb_output(
+            KrbEtype::Aes256CtsHmacSha196,
+            input.clone(),
+            output.to_vec(),
+            add_notification,
+        ),
         Algorithm::HmacSha196Aes128(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::HmacSha196Aes256(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::Rsa(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
@@ -30,6 +63,40 @@ fn get_output_components(algorithm: &Algorithm, output: &[u8], add_notification:
         Algorithm::Zlib(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::Argon2(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
         Algorithm::HmacSha(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
+        Algorithm::Aes(input) => build_aead_output(
+            if input.mode == AesMode::Gcm {
+                aead_tag_len(input.action, GCM_TAG_LEN)
+            } else {
+                0
+            },
+            output.to_vec(),
+            add_notification,
+        ),
+        Algorithm::ChaCha20(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
+        Algorithm::ChaCha20Poly1305(input) => build_aead_output(
+            aead_tag_len(input.action, POLY1305_TAG_LEN),
+            output.to_vec(),
+            add_notification,
+        ),
+        Algorithm::XChaCha20Poly1305(input) => build_aead_output(
+            aead_tag_len(input.action, POLY1305_TAG_LEN),
+            output.to_vec(),
+            add_notification,
+        ),
+        Algorithm::KrbCipher(input) => {
+            build_krb_output(input.etype, input.input.clone(), output.to_vec(), add_notification)
+        }
+        Algorithm::KrbChecksum(_) => build_simple_output(output.into(), BytesFormat::Hex, add_notification),
+        Algorithm::KrbKeyDerivation(input) => {
+            build_krb_key_derivation_output(input.clone(), output.to_vec(), add_notification)
+        }
+        Algorithm::Keytab(_) => build_simple_output(output.into(), BytesFormat::Base64, add_notification),
+        Algorithm::KrbCredentials(input) => {
+            build_krb_credentials_output(input.clone(), output.to_vec(), add_notification)
+        }
+        Algorithm::KrbPac(input) => build_krb_pac_output(input.clone(), output.to_vec(), add_notification),
+        Algorithm::KrbGssToken(input) => build_krb_gss_token_output(input.clone(), output.to_vec(), add_notification),
+        Algorithm::Ntlm(input) => build_ntlm_output(input.clone(), output.to_vec(), add_notification),
     }
 }
 
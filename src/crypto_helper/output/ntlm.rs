@@ -0,0 +1,126 @@
+use yew::{Callback, Html, Properties, function_component, html, use_memo};
+use yew_hooks::use_clipboard;
+use yew_notifications::{Notification, NotificationType};
+
+use crate::crypto_helper::algorithm::NtlmInput;
+use crate::crypto_helper::ntlm::{NtlmTrace, ntlm_trace};
+use crate::crypto_helper::primitives::DerivationStep;
+
+fn build_value(name: &str, description: &str, value: &[u8], copy: &Callback<String>) -> Html {
+    let value = hex::encode(value);
+    let copy = copy.clone();
+    let onclick = Callback::from({
+        let value = value.clone();
+        move |_| copy.emit(value.clone())
+    });
+
+    html! {
+        <>
+            <span class="table-cell">
+                <b>{name}</b>
+                {if description.is_empty() { html! {} } else { html! { <>{": "}{description}</> } }}
+            </span>
+            <span class="table-cell full-cipher" {onclick}>{value}</span>
+        </>
+    }
+}
+
+fn build_trace(trace: &NtlmTrace, copy: &Callback<String>) -> Html {
+    let optional = |name: &str, value: &Option<Vec<u8>>| match value {
+        Some(value) => build_value(name, "", value, copy),
+        None => html! {},
+    };
+
+    html! {
+        <div class="table-container">
+            {build_value("NtChallengeResponse", "", &trace.nt_challenge_response, copy)}
+            {build_value("LmChallengeResponse", "", &trace.lm_challenge_response, copy)}
+            {build_value("SessionBaseKey", "", &trace.session_base_key, copy)}
+            {build_value("KeyExchangeKey", "", &trace.key_exchange_key, copy)}
+            {build_value("ExportedSessionKey", "", &trace.exported_session_key, copy)}
+            {optional("client signing key", &trace.client_signing_key)}
+            {optional("server signing key", &trace.server_signing_key)}
+            {build_value("client sealing key", "", &trace.client_sealing_key, copy)}
+            {build_value("server sealing key", "", &trace.server_sealing_key, copy)}
+            {optional("MIC", &trace.mic)}
+            {optional("received MIC", &trace.received_mic)}
+            {match trace.is_mic_valid() {
+                Some(true) => html! {
+                    <>
+                        <span class="table-cell">{"AUTHENTICATE_MESSAGE MIC"}</span>
+                        <span class="table-cell integrity-ok">{"ok"}</span>
+                    </>
+                },
+                Some(false) => html! {
+                    <>
+                        <span class="table-cell">{"AUTHENTICATE_MESSAGE MIC"}</span>
+                        <span class="table-cell integrity-failed">{"failed"}</span>
+                    </>
+                },
+                None => html! {},
+            }}
+        </div>
+    }
+}
+
+fn build_steps(steps: &[DerivationStep], copy: &Callback<String>) -> Html {
+    html! {
+        <div class="table-container">
+            {for steps.iter().map(|step| build_value(&step.name, &step.description, &step.value, copy))}
+        </div>
+    }
+}
+
+#[derive(PartialEq, Properties, Clone)]
+pub struct NtlmOutputProps {
+    input: NtlmInput,
+    output: Vec<u8>,
+    add_notification: Callback<Notification>,
+}
+
+#[function_component(NtlmOutput)]
+pub fn ntlm_output(props: &NtlmOutputProps) -> Html {
+    let NtlmOutputProps {
+        input,
+        output,
+        add_notification,
+    } = props.clone();
+
+    // The trace is recalculated only when the new output is computed and not on every input change.
+    let trace = use_memo(output.clone(), move |output| {
+        if output.is_empty() {
+            None
+        } else {
+            Some(ntlm_trace(&input))
+        }
+    });
+
+    let clipboard = use_clipboard();
+    let copy = Callback::from(move |value: String| {
+        clipboard.write_text(value);
+
+        add_notification.emit(Notification::from_description_and_type(
+            NotificationType::Info,
+            "value copied",
+        ));
+    });
+
+    match trace.as_ref() {
+        Some(Ok(trace)) => html! {
+            <>
+                {build_trace(trace, &copy)}
+                {build_steps(&trace.steps, &copy)}
+            </>
+        },
+        Some(Err(err)) => html! {
+            <span class="total">{err}</span>
+        },
+        None => html! {},
+    }
+}
+
+pub fn build_ntlm_output(input: NtlmInput, output: Vec<u8>, add_notification: Callback<Notification>) -> Html {
+    html! {
+        <NtlmOutput {input} {output} {add_notification} />
+    }
+}
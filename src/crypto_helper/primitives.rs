@@ -0,0 +1,58 @@
+//! Primitives shared by the Kerberos and NTLM computations.
+
+use hmac::{Hmac, KeyInit, Mac};
+use md5::Md5;
+use rc4::{Rc4, StreamCipher};
+
+/// One intermediate value of the step by step computation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DerivationStep {
+    pub name: String,
+    pub description: String,
+    pub value: Vec<u8>,
+}
+
+impl DerivationStep {
+    pub fn new(name: impl Into<String>, description: impl Into<String>, value: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            value,
+        }
+    }
+}
+
+pub fn hmac_md5(key: &[u8], data: &[u8]) -> [u8; 16] {
+    let mut mac = Hmac::<Md5>::new_from_slice(key).expect("HMAC should accept keys of any length");
+    mac.update(data);
+
+    mac.finalize().into_bytes().into()
+}
+
+/// The key must be 1 to 256 bytes long.
+pub fn rc4(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut output = data.to_vec();
+    Rc4::new_from_slice(key)
+        .expect("RC4 key should be 1 to 256 bytes long")
+        .apply_keystream(&mut output);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hmac_md5, rc4};
+
+    #[test]
+    fn rfc2202_hmac_md5() {
+        assert_eq!(
+            "750c783e6ab0b503eaa86e310a5db738",
+            hex::encode(hmac_md5(b"Jefe", b"what do ya want for nothing?"))
+        );
+    }
+
+    #[test]
+    fn rc4_key_stream() {
+        assert_eq!("bbf316e8d940af0ad3", hex::encode(rc4(b"Key", b"Plaintext")));
+    }
+}
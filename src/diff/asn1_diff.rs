@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use asn1_parser::Asn1;
+use asn1_parser::diff::{ChangeKind, NodeChange, diff_trees};
+use web_sys::KeyboardEvent;
+use yew::{Callback, ContextProvider, Html, function_component, html, use_effect_with, use_state};
+use yew_hooks::use_local_storage;
+
+use crate::asn1::{DiffMarks, build_asn1_schema, decode_document};
+use crate::common::ByteInput;
+
+const LOCAL_STORAGE_ORIGINAL_ASN1: &str = "ORIGINAL_ASN1_DATA";
+const LOCAL_STORAGE_CHANGED_ASN1: &str = "CHANGED_ASN1_DATA";
+
+const CHANGE_KINDS: [ChangeKind; 4] = [
+    ChangeKind::Added,
+    ChangeKind::Removed,
+    ChangeKind::Changed,
+    ChangeKind::Moved,
+];
+
+struct Asn1DiffData {
+    original: Vec<Asn1>,
+    changed: Vec<Asn1>,
+    changes: Vec<NodeChange>,
+}
+
+fn diff_documents(original: &[u8], changed: &[u8]) -> Result<Asn1DiffData, String> {
+    let original = decode_document(original).map_err(|err| format!("original: {}", err))?;
+    let changed = decode_document(changed).map_err(|err| format!("changed: {}", err))?;
+    let changes = diff_trees(&original, &changed);
+
+    Ok(Asn1DiffData {
+        original,
+        changed,
+        changes,
+    })
+}
+
+/// Collects the change marks of one side of the diff.
+fn diff_marks(changes: &[NodeChange], node_id: impl Fn(&NodeChange) -> Option<u64>) -> DiffMarks {
+    DiffMarks(Rc::new(
+        changes
+            .iter()
+            .filter_map(|change| node_id(change).map(|id| (id, change.kind)))
+            .collect::<HashMap<_, _>>(),
+    ))
+}
+
+/// Renders the read-only trees.
+fn render_trees(trees: &[Asn1], cur_node: Option<u64>) -> Html {
+    trees
+        .iter()
+        .map(|tree| {
+            build_asn1_schema(
+                tree,
+                &cur_node,
+                &Callback::noop(),
+                Callback::noop(),
+                Callback::noop(),
+                Callback::noop(),
+            )
+        })
+        .collect()
+}
+
+fn render_summary(changes: &[NodeChange]) -> String {
+    if changes.is_empty() {
+        return "The documents are structurally equal.".to_owned();
+    }
+
+    CHANGE_KINDS
+        .iter()
+        .map(|kind| {
+            format!(
+                "{} {}",
+                changes.iter().filter(|change| change.kind == *kind).count(),
+                kind.as_ref()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Node by node comparison of two asn1 documents.
+#[function_component(Asn1Diff)]
+pub fn asn1_diff() -> Html {
+    let original = use_state(Vec::new);
+    let changed = use_state(Vec::new);
+    let diff = use_state(|| None::<Result<Rc<Asn1DiffData>, String>>);
+    let selected = use_state(|| None::<usize>);
+
+    let original_local_storage = use_local_storage::<String>(LOCAL_STORAGE_ORIGINAL_ASN1.to_owned());
+    let original_setter = original.setter();
+    let changed_local_storage = use_local_storage::<String>(LOCAL_STORAGE_CHANGED_ASN1.to_owned());
+    let changed_setter = changed.setter();
+    use_effect_with([], move |_: &[(); 0]| {
+        if let Some(original) = (*original_local_storage).as_ref()
+            && let Ok(bytes) = hex::decode(original)
+        {
+            original_setter.set(bytes);
+        }
+        if let Some(changed) = (*changed_local_storage).as_ref()
+            && let Ok(bytes) = hex::decode(changed)
+        {
+            changed_setter.set(bytes);
+        }
+    });
+
+    let local_storage = use_local_storage::<String>(LOCAL_STORAGE_ORIGINAL_ASN1.to_owned());
+    use_effect_with(original.clone(), move |original| {
+        local_storage.set(hex::encode(original.as_slice()));
+    });
+
+    let local_storage = use_local_storage::<String>(LOCAL_STORAGE_CHANGED_ASN1.to_owned());
+    use_effect_with(changed.clone(), move |changed| {
+        local_storage.set(hex::encode(changed.as_slice()));
+    });
+
+    let diff_setter = diff.setter();
+    let selected_setter = selected.setter();
+    let original_data = (*original).clone();
+    let changed_data = (*changed).clone();
+    let run_diff = Callback::from(move |_: ()| {
+        selected_setter.set(None);
+        diff_setter.set(Some(diff_documents(&original_data, &changed_data).map(Rc::new)));
+    });
+
+    let onkeydown = {
+        let run_diff = run_diff.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.ctrl_key() && event.code() == "Enter" {
+                run_diff.emit(());
+            }
+        })
+    };
+    let onclick = Callback::from(move |_| run_diff.emit(()));
+
+    let original_setter = original.setter();
+    let changed_setter = changed.setter();
+
+    html! {
+        <div class="vertical" {onkeydown}>
+            <div class="horizontal asn1-diff-inputs">
+                <ByteInput
+                    bytes={(*original).clone()}
+                    setter={Callback::from(move |data| original_setter.set(data))}
+                    placeholder={"original asn1 data or PEM".to_owned()}
+                    rows={8}
+                    detect_pem={true}
+                />
+                <ByteInput
+                    bytes={(*changed).clone()}
+                    setter={Callback::from(move |data| changed_setter.set(data))}
+                    placeholder={"changed asn1 data or PEM".to_owned()}
+                    rows={8}
+                    detect_pem={true}
+                />
+            </div>
+            <div class="horizontal">
+                <button class="action-button" {onclick}>{"Diff"}</button>
+                <span class="total">{"(ctrl+enter)"}</span>
+            </div>
+            {match (*diff).clone() {
+                None => html! {},
+                Some(Err(err)) => html! { <span class="asn1-diff-error">{err}</span> },
+                Some(Ok(diff)) => {
+                    let selected_change = (*selected).and_then(|index| diff.changes.get(index));
+                    let original_node = selected_change.and_then(|change| change.original);
+                    let changed_node = selected_change.and_then(|change| change.changed);
+
+                    let rows = diff.changes.iter().enumerate().map(|(index, change)| {
+                        let selected_setter = selected.setter();
+                        let is_selected = *selected == Some(index);
+                        let onclick = Callback::from(move |_| {
+                            selected_setter.set(if is_selected { None } else { Some(index) });
+                        });
+
+                        html! {
+                            <tr
+                                class={format!(
+                                    "asn1-diff-row asn1-diff-row-{}{}",
+                                    change.kind.as_ref(),
+                                    if is_selected { " asn1-diff-row-selected" } else { "" }
+                                )}
+                                {onclick}
+                            >
+                                <td>{change.kind.as_ref()}</td>
+                                <td>{&change.path}</td>
+                                <td>{change.original_value.clone().unwrap_or_default()}</td>
+                                <td>{change.changed_value.clone().unwrap_or_default()}</td>
+                            </tr>
+                        }
+                    });
+
+                    html! {
+                        <div class="vertical">
+                            <span class="total">{render_summary(&diff.changes)}</span>
+                            {if diff.changes.is_empty() {
+                                html! {}
+                            } else {
+                                html! {
+                                    <table class="asn1-diff-table">
+                                        <tr>
+                                            <th>{"change"}</th>
+                                            <th>{"path"}</th>
+                                            <th>{"original"}</th>
+                                            <th>{"changed"}</th>
+                                        </tr>
+                                        {for rows}
+                                    </table>
+                                }
+                            }}
+                            <div class="asn1-diff-trees">
+                                <ContextProvider<DiffMarks> context={diff_marks(&diff.changes, |change| change.original)}>
+                                    <div class="asn1-diff-tree">
+                                        {render_trees(&diff.original, original_node)}
+                                    </div>
+                                </ContextProvider<DiffMarks>>
+                                <ContextProvider<DiffMarks> context={diff_marks(&diff.changes, |change| change.changed)}>
+                                    <div class="asn1-diff-tree">
+                                        {render_trees(&diff.changed, changed_node)}
+                                    </div>
+                                </ContextProvider<DiffMarks>>
+                            </div>
+                        </div>
+                    }
+                }
+            }}
+        </div>
+    }
+}
@@ -1,3 +1,4 @@
+mod asn1_diff;
 mod diff_algo;
 mod diff_viewer;
 mod task;
@@ -12,6 +13,7 @@ use yew::{Callback, Html, TargetCast, function_component, html, use_effect_with,
 use yew_agent::oneshot::use_oneshot_runner;
 use yew_hooks::use_local_storage;
 
+use self::asn1_diff::Asn1Diff;
 use self::diff_algo::DiffAlgo;
 use self::diff_viewer::DiffViewer;
 pub use self::task::{DiffTask, DiffTaskParams, JsonCodec};
@@ -29,6 +31,11 @@ const DEFAULT_ALGORITHM: DiffAlgo = DiffAlgo(Algorithm::Myers);
 const LOCAL_STORAGE_ORIGINAL: &str = "ORIGINAL_DATA";
 const LOCAL_STORAGE_ALGORITHM: &str = "ALGORITHM";
 const LOCAL_STORAGE_CHANGED: &str = "CHANGED_DATA";
+const LOCAL_STORAGE_MODE: &str = "DIFF_MODE";
+
+const TEXT_MODE: &str = "text";
+const ASN1_MODE: &str = "ASN.1";
+const DIFF_MODES: [&str; 2] = [TEXT_MODE, ASN1_MODE];
 
 const ALL_ALGORITHMS: &[DiffAlgo] = &[
     DiffAlgo(Algorithm::Myers),
@@ -76,6 +83,7 @@ pub fn diff_page() -> Html {
     let original = use_state_eq(|| DEFAULT_ORIGINAL.to_owned());
     let changed = use_state_eq(|| DEFAULT_CHANGED.to_owned());
     let algorithm = use_state_eq(|| DEFAULT_ALGORITHM);
+    let mode = use_state_eq(|| TEXT_MODE);
     let diffs = use_state_eq(|| {
         let original = DEFAULT_ORIGINAL.chars().collect::<Vec<_>>();
         let changed = DEFAULT_CHANGED.chars().collect::<Vec<_>>();
@@ -119,10 +127,18 @@ pub fn diff_page() -> Html {
     let changed_setter = changed.setter();
     let algorithm_local_storage = use_local_storage::<String>(LOCAL_STORAGE_ALGORITHM.to_owned());
     let algorithm_setter = algorithm.setter();
+    let mode_local_storage = use_local_storage::<String>(LOCAL_STORAGE_MODE.to_owned());
+    let mode_setter = mode.setter();
     let diffs_setter = diffs.setter();
     use_effect_with([], move |_: &[(); 0]| {
         let mut flag = false;
 
+        if let Some(raw_mode) = (*mode_local_storage).as_ref()
+            && let Some(mode) = DIFF_MODES.iter().find(|mode| **mode == raw_mode.as_str())
+        {
+            mode_setter.set(mode);
+        }
+
         if let Some(original) = (*original_local_storage).as_ref() {
             original_setter.set(original.to_string());
             flag = true;
@@ -158,6 +174,11 @@ pub fn diff_page() -> Html {
         local_storage.set((*algorithm).to_string());
     });
 
+    let local_storage = use_local_storage::<String>(LOCAL_STORAGE_MODE.to_owned());
+    use_effect_with(mode.clone(), move |mode| {
+        local_storage.set((**mode).to_owned());
+    });
+
     let original_setter = original.setter();
     let on_original_input = Callback::from(move |event: html::oninput::Event| {
         let input: HtmlInputElement = event.target_unchecked_into();
@@ -185,8 +206,20 @@ pub fn diff_page() -> Html {
         }
     });
 
+    let mode_setter = mode.setter();
+    let on_mode_change = Callback::from(move |event: Event| {
+        let input: HtmlInputElement = event.target_unchecked_into();
+        let value = input.value();
+
+        if let Some(mode) = DIFF_MODES.iter().find(|mode| **mode == value) {
+            mode_setter.set(mode);
+        }
+    });
+
+    let is_text_mode = *mode == TEXT_MODE;
     let onkeydown = Callback::from(move |event: KeyboardEvent| {
-        if event.ctrl_key() && event.code() == "Enter" {
+        // The ASN.1 diff handles the shortcut by itself.
+        if is_text_mode && event.ctrl_key() && event.code() == "Enter" {
             diffs_worker.emit(());
         }
     });
@@ -194,39 +227,66 @@ pub fn diff_page() -> Html {
     html! {
         <div class={"vertical asn1-page"} {onkeydown}>
             <div class="horizontal">
-                <span>{"Diff algorithm:"}</span>
+                <span>{"Compare as:"}</span>
                 <div>
-                    <select class="base-input" onchange={on_algorithm_change}>
-                        {render_algorithm_options(*algorithm)}
+                    <select class="base-input" onchange={on_mode_change}>
+                        {DIFF_MODES
+                            .iter()
+                            .map(|diff_mode| html! {
+                                <option selected={*mode == *diff_mode} value={*diff_mode}>{diff_mode}</option>
+                            })
+                            .collect::<Vec<_>>()}
                     </select>
                 </div>
-                <span class="total">{"Alternatively, you can use this tool, which is quite good too:"}</span>
-                <a class="a-link" href="https://diffviewer.vercel.app/v2">{"diffviewer.vercel.app"}</a>
-            </div>
-            <div class="horizontal">
-                <textarea
-                    rows="8"
-                    placeholder={"original"}
-                    class="base-input"
-                    value={(*original).clone()}
-                    oninput={on_original_input}
-                />
-                <textarea
-                    rows="8"
-                    placeholder={"changed"}
-                    class="base-input"
-                    value={(*changed).clone()}
-                    oninput={on_changed_input}
-                />
-            </div>
-            <div class="horizontal">
-                <button class="action-button" onclick={onclick}>{"Diff"}</button>
-                <span class="total">{"(ctrl+enter)"}</span>
+                {if is_text_mode {
+                    html! {
+                        <>
+                            <span>{"Diff algorithm:"}</span>
+                            <div>
+                                <select class="base-input" onchange={on_algorithm_change}>
+                                    {render_algorithm_options(*algorithm)}
+                                </select>
+                            </div>
+                            <span class="total">{"Alternatively, you can use this tool, which is quite good too:"}</span>
+                            <a class="a-link" href="https://diffviewer.vercel.app/v2">{"diffviewer.vercel.app"}</a>
+                        </>
+                    }
+                } else {
+                    html! {}
+                }}
             </div>
-            {match (*diffs).clone() {
-                DiffsState::None => html! {},
-                DiffsState::Loading => html! { <Loader /> },
-                DiffsState::Diffs(diff) => html! { <DiffViewer {diff} /> },
+            {if is_text_mode {
+                html! {
+                    <>
+                        <div class="horizontal">
+                            <textarea
+                                rows="8"
+                                placeholder={"original"}
+                                class="base-input"
+                                value={(*original).clone()}
+                                oninput={on_original_input}
+                            />
+                            <textarea
+                                rows="8"
+                                placeholder={"changed"}
+                                class="base-input"
+                                value={(*changed).clone()}
+                                oninput={on_changed_input}
+                            />
+                        </div>
+                        <div class="horizontal">
+                            <button class="action-button" onclick={onclick}>{"Diff"}</button>
+                            <span class="total">{"(ctrl+enter)"}</span>
+                        </div>
+                        {match (*diffs).clone() {
+                            DiffsState::None => html! {},
+                            DiffsState::Loading => html! { <Loader /> },
+                            DiffsState::Diffs(diff) => html! { <DiffViewer {diff} /> },
+                        }}
+                    </>
+                }
+            } else {
+                html! { <Asn1Diff /> }
             }}
         </div>
     }
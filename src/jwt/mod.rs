@@ -4,7 +4,7 @@ pub mod macros;
 #[allow(clippy::module_inception)]
 mod jwt;
 pub mod jwt_utils;
-mod jwte;
+pub mod jwte;
 pub mod signature;
 
 use std::str::FromStr;
@@ -5,11 +5,11 @@ macro_rules! verify_hmac {
         msg: $msg:expr,
         digest: $digest:expr,
     ) => {{
-        use hmac::{Hmac, Mac};
+        use hmac::{Hmac, KeyInit, Mac};
 
         let mut mac = Hmac::<$hash_alg>::new_from_slice($key).expect("hmac key length should be checked");
         mac.update($msg);
-        mac.verify($digest.into()).is_ok()
+        mac.verify_slice($digest).is_ok()
     }};
 }
 
@@ -19,7 +19,7 @@ macro_rules! sign_hmac {
         key: $key:expr,
         msg: $msg:expr,
     ) => {{
-        use hmac::{Hmac, Mac};
+        use hmac::{Hmac, KeyInit, Mac};
 
         let mut mac = Hmac::<$hash_alg>::new_from_slice($key).expect("hmac key length should be checked");
         mac.update($msg);
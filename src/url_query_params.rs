@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use web_sys::window;
 
+use crate::asn1::Framing;
 use crate::crypto_helper::Algorithm;
 use crate::serde::{deserialize_bytes, serialize_bytes};
 
@@ -39,13 +40,15 @@ pub fn generate_jwt_link(jwt: String) -> String {
 pub struct Asn1 {
     #[serde(serialize_with = "serialize_bytes", deserialize_with = "deserialize_bytes")]
     pub asn1: Vec<u8>,
+    #[serde(default)]
+    pub framing: Framing,
 }
 
-pub fn generate_asn1_link(asn1: Vec<u8>) -> String {
+pub fn generate_asn1_link(asn1: Vec<u8>, framing: Framing) -> String {
     let mut link = origin();
 
     link.push_str("/asn1/?");
-    link.push_str(&serde_qs::to_string(&Asn1 { asn1 }).unwrap());
+    link.push_str(&serde_qs::to_string(&Asn1 { asn1, framing }).unwrap());
 
     link
 }